@@ -1,7 +1,28 @@
 fn main() {
+    #[cfg(feature = "syzygy")]
     build_fathom();
+    embed_git_hash();
 }
 
+// Make the current commit available to the engine so that it can be included in the version,
+// to identify exactly which build is being used.
+fn embed_git_hash() {
+    println!("cargo:rerun-if-changed=.git/HEAD");
+    println!("cargo:rerun-if-changed=.git/refs");
+
+    let git_hash = std::process::Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|hash| hash.trim().to_string())
+        .unwrap_or_default();
+
+    println!("cargo:rustc-env=GIT_SHORT_HASH={git_hash}");
+}
+
+#[cfg(feature = "syzygy")]
 fn build_fathom() {
     println!("cargo:rerun-if-changed=src/engine/tablebases/fathom/src");
 
@@ -0,0 +1,23 @@
+#![no_main]
+
+use engine::chess::game::Game;
+use engine::chess::san;
+use libfuzzer_sys::fuzz_target;
+
+// Positions with castling, promotions, en passant and ambiguous moves available, so that
+// every part of the parser can be reached
+const POSITIONS: [&str; 4] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+];
+
+fuzz_target!(|input: &str| {
+    engine::init();
+
+    for fen in POSITIONS {
+        let game = Game::from_fen(fen).unwrap();
+        drop(san::parse_move(&game, input));
+    }
+});
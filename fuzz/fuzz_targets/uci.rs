@@ -0,0 +1,10 @@
+#![no_main]
+
+use engine::engine::uci::parser;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    engine::init();
+
+    drop(parser::parse(input));
+});
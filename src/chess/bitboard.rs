@@ -87,8 +87,10 @@ impl Bitboard {
         self.0.trailing_zeros() as usize
     }
 
+    // Moves every square on the bitboard one step in the given direction. Squares that
+    // would move off the board are dropped.
     #[inline(always)]
-    pub fn in_direction(self, direction: Direction) -> Self {
+    pub const fn shift(self, direction: Direction) -> Self {
         match direction {
             Direction::North => self.north(),
             Direction::NorthEast => self.north_east(),
@@ -101,20 +103,29 @@ impl Bitboard {
         }
     }
 
+    // All of the squares that can be reached by repeatedly moving in the given direction,
+    // not including the starting squares (e.g. the squares in front of a pawn).
     #[inline(always)]
-    pub fn forward(self, player: Player) -> Self {
-        match player {
-            Player::White => self.north(),
-            Player::Black => self.south(),
+    pub const fn span(self, direction: Direction) -> Self {
+        let mut span = Self::EMPTY;
+        let mut current = self.shift(direction);
+
+        while current.any() {
+            span = Self(span.0 | current.0);
+            current = current.shift(direction);
         }
+
+        span
     }
 
     #[inline(always)]
-    pub fn backward(self, player: Player) -> Self {
-        match player {
-            Player::White => self.south(),
-            Player::Black => self.north(),
-        }
+    pub const fn forward(self, player: Player) -> Self {
+        self.shift(Direction::forward(player))
+    }
+
+    #[inline(always)]
+    pub const fn backward(self, player: Player) -> Self {
+        self.shift(Direction::backward(player))
     }
 
     #[inline(always)]
@@ -128,39 +139,39 @@ impl Bitboard {
     }
 
     #[inline(always)]
-    pub fn east(self) -> Self {
+    pub const fn east(self) -> Self {
         // If we go east and land on A, we wrapped around.
-        Self(self.0 << 1) & Self::NOT_A_FILE
+        Self((self.0 << 1) & Self::NOT_A_FILE.0)
     }
 
     #[inline(always)]
-    pub fn north_east(self) -> Self {
+    pub const fn north_east(self) -> Self {
         // If we go east and land on A, we wrapped around.
-        Self(self.0 << 9) & Self::NOT_A_FILE
+        Self((self.0 << 9) & Self::NOT_A_FILE.0)
     }
 
     #[inline(always)]
-    pub fn south_east(self) -> Self {
+    pub const fn south_east(self) -> Self {
         // If we go east and land on A, we wrapped around.
-        Self(self.0 >> 7) & Self::NOT_A_FILE
+        Self((self.0 >> 7) & Self::NOT_A_FILE.0)
     }
 
     #[inline(always)]
-    pub fn west(self) -> Self {
+    pub const fn west(self) -> Self {
         // If we go west and land on H, we wrapped around.
-        Self(self.0 >> 1) & Self::NOT_H_FILE
+        Self((self.0 >> 1) & Self::NOT_H_FILE.0)
     }
 
     #[inline(always)]
-    pub fn south_west(self) -> Self {
+    pub const fn south_west(self) -> Self {
         // If we go west and land on H, we wrapped around.
-        Self(self.0 >> 9) & Self::NOT_H_FILE
+        Self((self.0 >> 9) & Self::NOT_H_FILE.0)
     }
 
     #[inline(always)]
-    pub fn north_west(self) -> Self {
+    pub const fn north_west(self) -> Self {
         // If we go west and land on H, we wrapped around.
-        Self(self.0 << 7) & Self::NOT_H_FILE
+        Self((self.0 << 7) & Self::NOT_H_FILE.0)
     }
 
     #[inline(always)]
@@ -444,21 +455,13 @@ pub mod bitboards {
     pub const H8_BB: Bitboard = H8.bb();
 
     pub const A_FILE: Bitboard = Bitboard::new(A1_BB.0 | A2_BB.0 | A3_BB.0 | A4_BB.0 | A5_BB.0 | A6_BB.0 | A7_BB.0 | A8_BB.0);
-    pub const B_FILE: Bitboard = Bitboard::new(B1_BB.0 | B2_BB.0 | B3_BB.0 | B4_BB.0 | B5_BB.0 | B6_BB.0 | B7_BB.0 | B8_BB.0);
-    pub const C_FILE: Bitboard = Bitboard::new(C1_BB.0 | C2_BB.0 | C3_BB.0 | C4_BB.0 | C5_BB.0 | C6_BB.0 | C7_BB.0 | C8_BB.0);
-    pub const D_FILE: Bitboard = Bitboard::new(D1_BB.0 | D2_BB.0 | D3_BB.0 | D4_BB.0 | D5_BB.0 | D6_BB.0 | D7_BB.0 | D8_BB.0);
-    pub const E_FILE: Bitboard = Bitboard::new(E1_BB.0 | E2_BB.0 | E3_BB.0 | E4_BB.0 | E5_BB.0 | E6_BB.0 | E7_BB.0 | E8_BB.0);
-    pub const F_FILE: Bitboard = Bitboard::new(F1_BB.0 | F2_BB.0 | F3_BB.0 | F4_BB.0 | F5_BB.0 | F6_BB.0 | F7_BB.0 | F8_BB.0);
-    pub const G_FILE: Bitboard = Bitboard::new(G1_BB.0 | G2_BB.0 | G3_BB.0 | G4_BB.0 | G5_BB.0 | G6_BB.0 | G7_BB.0 | G8_BB.0);
     pub const H_FILE: Bitboard = Bitboard::new(H1_BB.0 | H2_BB.0 | H3_BB.0 | H4_BB.0 | H5_BB.0 | H6_BB.0 | H7_BB.0 | H8_BB.0);
 
     pub const RANK_1: Bitboard = Bitboard::new(A1_BB.0 | B1_BB.0 | C1_BB.0 | D1_BB.0 | E1_BB.0 | F1_BB.0 | G1_BB.0 | H1_BB.0);
     pub const RANK_2: Bitboard = Bitboard::new(A2_BB.0 | B2_BB.0 | C2_BB.0 | D2_BB.0 | E2_BB.0 | F2_BB.0 | G2_BB.0 | H2_BB.0);
-    #[expect(unused, reason = "Unused")]
     pub const RANK_3: Bitboard = Bitboard::new(A3_BB.0 | B3_BB.0 | C3_BB.0 | D3_BB.0 | E3_BB.0 | F3_BB.0 | G3_BB.0 | H3_BB.0);
     pub const RANK_4: Bitboard = Bitboard::new(A4_BB.0 | B4_BB.0 | C4_BB.0 | D4_BB.0 | E4_BB.0 | F4_BB.0 | G4_BB.0 | H4_BB.0);
     pub const RANK_5: Bitboard = Bitboard::new(A5_BB.0 | B5_BB.0 | C5_BB.0 | D5_BB.0 | E5_BB.0 | F5_BB.0 | G5_BB.0 | H5_BB.0);
-    #[expect(unused, reason = "Unused")]
     pub const RANK_6: Bitboard = Bitboard::new(A6_BB.0 | B6_BB.0 | C6_BB.0 | D6_BB.0 | E6_BB.0 | F6_BB.0 | G6_BB.0 | H6_BB.0);
     pub const RANK_7: Bitboard = Bitboard::new(A7_BB.0 | B7_BB.0 | C7_BB.0 | D7_BB.0 | E7_BB.0 | F7_BB.0 | G7_BB.0 | H7_BB.0);
     pub const RANK_8: Bitboard = Bitboard::new(A8_BB.0 | B8_BB.0 | C8_BB.0 | D8_BB.0 | E8_BB.0 | F8_BB.0 | G8_BB.0 | H8_BB.0);
@@ -476,7 +479,6 @@ pub mod bitboards {
         A2_BB.0 | C2_BB.0 | E2_BB.0 | G2_BB.0 |
         B1_BB.0 | D1_BB.0 | F1_BB.0 | H1_BB.0 );
 
-    #[expect(unused, reason = "Unused")]
     pub const DARK_SQUARES: Bitboard = Bitboard::new(
         B8_BB.0 | D8_BB.0 | F8_BB.0 | H8_BB.0 |
         A7_BB.0 | C7_BB.0 | E7_BB.0 | G7_BB.0 |
@@ -528,6 +530,92 @@ mod tests {
         );
     }
 
+    // The (file, rank) offset for one step in each direction
+    const fn offset(direction: Direction) -> (i8, i8) {
+        match direction {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 1),
+            Direction::East => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, -1),
+            Direction::West => (-1, 0),
+            Direction::NorthWest => (-1, 1),
+        }
+    }
+
+    // Shift each square on its own using square arithmetic, to check against the bitboard shifts
+    fn naive_shift(bitboard: Bitboard, direction: Direction) -> Bitboard {
+        let (file_delta, rank_delta) = offset(direction);
+        let mut shifted = Bitboard::EMPTY;
+
+        for square in bitboard {
+            if let Some(dst) = square.offset(file_delta, rank_delta) {
+                shifted |= dst.bb();
+            }
+        }
+
+        shifted
+    }
+
+    #[test]
+    fn test_shift_matches_naive_shift() {
+        for direction in Direction::ALL {
+            for square in Square::all() {
+                assert_eq!(
+                    square.bb().shift(*direction),
+                    naive_shift(square.bb(), *direction),
+                    "{square} {direction:?}"
+                );
+            }
+
+            for bitboard in [
+                Bitboard::FULL,
+                bitboards::A_FILE,
+                bitboards::H_FILE,
+                bitboards::RANK_1,
+                bitboards::RANK_8,
+                bitboards::LIGHT_SQUARES,
+            ] {
+                assert_eq!(
+                    bitboard.shift(*direction),
+                    naive_shift(bitboard, *direction)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_span_matches_naive_span() {
+        for direction in Direction::ALL {
+            for square in Square::all() {
+                let mut expected = Bitboard::EMPTY;
+                let mut current = naive_shift(square.bb(), *direction);
+
+                while current.any() {
+                    expected |= current;
+                    current = naive_shift(current, *direction);
+                }
+
+                assert_eq!(
+                    square.bb().span(*direction),
+                    expected,
+                    "{square} {direction:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_forward_and_backward() {
+        use crate::chess::square::squares::all::*;
+
+        assert_eq!(E4.bb().forward(Player::White), E5.bb());
+        assert_eq!(E4.bb().forward(Player::Black), E3.bb());
+        assert_eq!(E4.bb().backward(Player::White), E3.bb());
+        assert_eq!(E4.bb().backward(Player::Black), E5.bb());
+    }
+
     #[test]
     fn bitboard_size() {
         assert_eq!(std::mem::size_of::<Bitboard>(), std::mem::size_of::<u64>());
@@ -2,10 +2,11 @@ use crate::chess::{
     movegen,
     piece::{Piece, PieceKind},
     player::Player,
-    square::Square,
+    square::{File, Rank, Square},
 };
 
 use crate::chess::bitboard::Bitboard;
+use crate::chess::lookup::Lookup;
 use crate::chess::player::ByPlayer;
 
 #[derive(Clone)]
@@ -95,24 +96,27 @@ impl Board {
         self.all_rooks() | self.all_queens()
     }
 
+    // Whether the player has any pieces other than pawns and their king
+    pub fn has_non_pawn_material(&self, player: Player) -> bool {
+        (self.knights(player) | self.diagonal_sliders(player) | self.rooks(player)).any()
+    }
+
     #[inline(always)]
     pub fn piece_at(&self, square: Square) -> Option<Piece> {
-        // We know array_idx can only return up to Square::N - 1
-        unsafe { *self.squares.get_unchecked(square.array_idx()) }
+        // SAFETY: `Square::array_idx` is below `Square::N`
+        *unsafe { self.squares.lookup(square.array_idx()) }
     }
 
     #[inline(always)]
-    pub fn remove_at(&mut self, square: Square) -> bool {
-        let Some(piece) = self.piece_at(square) else {
-            return false;
-        };
+    pub fn remove_at(&mut self, square: Square) -> Option<Piece> {
+        let piece = self.piece_at(square)?;
 
         self.pieces[piece.kind.array_idx()] ^= square.bb();
         self.colors
             .for_player_mut(piece.player)
             .unset_inplace(square);
         self.squares[square.array_idx()] = None;
-        true
+        Some(piece)
     }
 
     #[inline(always)]
@@ -129,44 +133,52 @@ impl Board {
     }
 }
 
+// The pieces are drawn with their 'filled' symbol for white, since most terminals have a dark
+// background
+pub fn piece_symbol(piece: Option<Piece>) -> &'static str {
+    match piece {
+        Some(Piece { player, kind }) => match kind {
+            PieceKind::Pawn => match player {
+                Player::White => "♟",
+                Player::Black => "♙",
+            },
+            PieceKind::Knight => match player {
+                Player::White => "♞",
+                Player::Black => "♘",
+            },
+            PieceKind::Bishop => match player {
+                Player::White => "♝",
+                Player::Black => "♗",
+            },
+            PieceKind::Rook => match player {
+                Player::White => "♜",
+                Player::Black => "♖",
+            },
+            PieceKind::Queen => match player {
+                Player::White => "♛",
+                Player::Black => "♕",
+            },
+            PieceKind::King => match player {
+                Player::White => "♚",
+                Player::Black => "♔",
+            },
+        },
+        None => ".",
+    }
+}
+
 impl std::fmt::Debug for Board {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "\n{}\n",
-            (0..8)
+            Rank::ALL
+                .iter()
                 .rev()
                 .map(|rank| {
-                    (0..8)
-                        .map(|file| match self.piece_at(Square::from_idxs(file, rank)) {
-                            Some(Piece { player, kind }) => match kind {
-                                PieceKind::Pawn => match player {
-                                    Player::White => "♟",
-                                    Player::Black => "♙",
-                                },
-                                PieceKind::Knight => match player {
-                                    Player::White => "♞",
-                                    Player::Black => "♘",
-                                },
-                                PieceKind::Bishop => match player {
-                                    Player::White => "♝",
-                                    Player::Black => "♗",
-                                },
-                                PieceKind::Rook => match player {
-                                    Player::White => "♜",
-                                    Player::Black => "♖",
-                                },
-                                PieceKind::Queen => match player {
-                                    Player::White => "♛",
-                                    Player::Black => "♕",
-                                },
-                                PieceKind::King => match player {
-                                    Player::White => "♚",
-                                    Player::Black => "♔",
-                                },
-                            },
-                            None => ".",
-                        })
+                    File::ALL
+                        .iter()
+                        .map(|file| piece_symbol(self.piece_at(Square::new(*file, *rank))))
                         .collect::<Vec<_>>()
                         .join(" ")
                 })
@@ -236,3 +248,18 @@ impl TryFrom<[Option<Piece>; Square::N]> for Board {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::chess::game::Game;
+    use crate::chess::player::Player;
+
+    #[test]
+    fn test_has_non_pawn_material() {
+        crate::init();
+
+        let game = Game::from_fen("4k3/pppp4/8/8/8/8/4PPPP/4KB2 w - - 0 1").unwrap();
+        assert!(game.board.has_non_pawn_material(Player::White));
+        assert!(!game.board.has_non_pawn_material(Player::Black));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::chess::bitboard::Bitboard;
+use crate::chess::movegen::tables::attacks;
+use crate::chess::moves::Move;
+use crate::chess::piece::{Piece, PieceKind};
+use crate::chess::player::Player;
+use crate::chess::square::Square;
+use crate::chess::zobrist::ZobristHash;
+
+use super::{h1, h2, TABLE_SIZE};
+
+// The number of distinct piece/square pairs that a non-pawn piece can move between. Moves in
+// opposite directions share a key, so only one direction is stored.
+pub const REVERSIBLE_MOVES: usize = 3668;
+
+pub fn move_key(piece: Piece, src: Square, dst: Square) -> u64 {
+    let mut key = ZobristHash::uninit();
+    key.toggle_piece_on_square(src, piece);
+    key.toggle_piece_on_square(dst, piece);
+    key.toggle_side_to_play();
+    key.0
+}
+
+fn attacks_on_empty_board(kind: PieceKind, s: Square) -> Bitboard {
+    match kind {
+        PieceKind::Pawn => Bitboard::EMPTY,
+        PieceKind::Knight => attacks::generate_knight_attacks(s),
+        PieceKind::Bishop => attacks::generate_bishop_attacks(s, Bitboard::EMPTY),
+        PieceKind::Rook => attacks::generate_rook_attacks(s, Bitboard::EMPTY),
+        PieceKind::Queen => {
+            attacks::generate_bishop_attacks(s, Bitboard::EMPTY)
+                | attacks::generate_rook_attacks(s, Bitboard::EMPTY)
+        }
+        PieceKind::King => attacks::generate_king_attacks(s),
+    }
+}
+
+// Builds the keys and moves (as `Move::to_u16`, with 0 for an empty slot) from the zobrist
+// components, which must have been initialised
+pub fn generate() -> (Vec<u64>, Vec<u16>) {
+    let mut keys = vec![0; TABLE_SIZE];
+    let mut moves = vec![0; TABLE_SIZE];
+    let mut count = 0;
+
+    for player in [Player::White, Player::Black] {
+        for kind in PieceKind::ALL {
+            let piece = Piece::new(player, kind);
+
+            for src in Square::all() {
+                for dst in attacks_on_empty_board(kind, src) {
+                    if dst.array_idx() <= src.array_idx() {
+                        continue;
+                    }
+
+                    insert(
+                        &mut keys,
+                        &mut moves,
+                        move_key(piece, src, dst),
+                        Move::quiet(src, dst).to_u16(),
+                    );
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    assert_eq!(
+        count, REVERSIBLE_MOVES,
+        "Unexpected number of reversible moves"
+    );
+
+    (keys, moves)
+}
+
+fn insert(keys: &mut [u64], moves: &mut [u16], mut key: u64, mut mv: u16) {
+    let mut i = h1(key);
+
+    // Keep displacing whatever is in the slot to its other slot until we reach an empty one.
+    // There's plenty of room in the table for every move, so this always terminates.
+    loop {
+        std::mem::swap(&mut keys[i], &mut key);
+        std::mem::swap(&mut moves[i], &mut mv);
+
+        if mv == 0 {
+            return;
+        }
+
+        i = if i == h1(key) { h2(key) } else { h1(key) };
+    }
+}
+
+// The tables as Rust source, to be written to `tables.rs`
+#[cfg(feature = "cli")]
+pub fn write_tables() -> String {
+    use std::fmt::Write;
+
+    let (keys, moves) = generate();
+    let mut output = String::new();
+
+    writeln!(
+        output,
+        "// Generated by the 'cuckoo-tables' command. Don't edit by hand."
+    )
+    .unwrap();
+    writeln!(output).unwrap();
+    writeln!(output, "use super::TABLE_SIZE;").unwrap();
+    writeln!(output).unwrap();
+
+    writeln!(output, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        output,
+        "#[expect(clippy::unreadable_literal, reason = \"Not intended to be readable!\")]"
+    )
+    .unwrap();
+    writeln!(output, "pub static KEYS: [u64; TABLE_SIZE] = [").unwrap();
+    for chunk in keys.chunks(4) {
+        let line: Vec<String> = chunk.iter().map(|key| format!("0x{key:016x},")).collect();
+        writeln!(output, "    {}", line.join(" ")).unwrap();
+    }
+    writeln!(output, "];").unwrap();
+    writeln!(output).unwrap();
+
+    writeln!(output, "#[rustfmt::skip]").unwrap();
+    writeln!(output, "pub static MOVES: [u16; TABLE_SIZE] = [").unwrap();
+    for chunk in moves.chunks(16) {
+        let line: Vec<String> = chunk.iter().map(|mv| format!("{mv},")).collect();
+        writeln!(output, "    {}", line.join(" ")).unwrap();
+    }
+    writeln!(output, "];").unwrap();
+
+    output
+}
@@ -0,0 +1,114 @@
+use crate::chess::lookup::Lookup;
+use crate::chess::moves::Move;
+
+// Only needed to regenerate the tables, and to check them in the tests
+#[cfg(any(test, feature = "cli"))]
+mod generate;
+mod tables;
+
+#[cfg(feature = "cli")]
+pub use generate::write_tables;
+
+// Tables of every reversible move (a non-pawn piece moving between two squares on an empty board),
+// keyed by the change that move makes to the zobrist hash. If the difference between the current
+// hash and an earlier one in the history is one of these keys, the earlier position may be
+// reachable in a single move, which is what's needed to detect upcoming repetitions.
+//
+// Each move is stored in one of two slots chosen by two hash functions of its key, using cuckoo
+// hashing so that a lookup only needs to check those two slots.
+//
+// The keys depend on the zobrist components, so the tables in `tables.rs` are generated with the
+// 'cuckoo-tables' command and need regenerating if the components change. The tests check that
+// they're up to date.
+const TABLE_SIZE: usize = 8192;
+
+const fn h1(key: u64) -> usize {
+    (key & 0x1fff) as usize
+}
+
+const fn h2(key: u64) -> usize {
+    ((key >> 16) & 0x1fff) as usize
+}
+
+// Find the reversible move whose effect on the zobrist hash is `key`, if there is one. The move is
+// returned with its squares in a fixed order, so may need to be reversed to be played.
+pub fn lookup(key: u64) -> Option<Move> {
+    // SAFETY: `h1` and `h2` mask the key down to an index below `TABLE_SIZE`
+    unsafe {
+        if *tables::KEYS.lookup(h1(key)) == key {
+            return Move::from_u16(*tables::MOVES.lookup(h1(key)));
+        }
+
+        if *tables::KEYS.lookup(h2(key)) == key {
+            return Move::from_u16(*tables::MOVES.lookup(h2(key)));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::generate::{generate, move_key, REVERSIBLE_MOVES};
+    use super::*;
+    use crate::chess::piece::Piece;
+    use crate::chess::square::squares::all::*;
+
+    // If this fails, the zobrist components have changed and the tables need regenerating
+    #[test]
+    fn test_tables_are_up_to_date() {
+        crate::init();
+
+        let (keys, moves) = generate();
+
+        assert!(
+            keys == tables::KEYS && moves == tables::MOVES,
+            "Cuckoo tables are out of date: regenerate them with 'cuckoo-tables'"
+        );
+    }
+
+    // Every stored move has to be found from its key
+    #[test]
+    fn test_tables_are_valid() {
+        for i in 0..TABLE_SIZE {
+            let (key, mv) = (tables::KEYS[i], tables::MOVES[i]);
+
+            if mv == 0 {
+                assert_eq!(key, 0, "Empty cuckoo slot {i} has a key");
+                continue;
+            }
+
+            assert!(
+                i == h1(key) || i == h2(key),
+                "Cuckoo slot {i} holds a key that doesn't hash to it"
+            );
+            assert_eq!(
+                lookup(key),
+                Move::from_u16(mv),
+                "Cuckoo lookup failed for slot {i}"
+            );
+        }
+
+        assert_eq!(
+            tables::MOVES.iter().filter(|&&mv| mv != 0).count(),
+            REVERSIBLE_MOVES
+        );
+    }
+
+    #[test]
+    fn test_lookup_reversible_move() {
+        crate::init();
+
+        let key = move_key(Piece::WHITE_KNIGHT, G1, F3);
+        assert_eq!(lookup(key), Some(Move::quiet(G1, F3)));
+        assert_eq!(lookup(key), lookup(move_key(Piece::WHITE_KNIGHT, F3, G1)));
+    }
+
+    #[test]
+    fn test_lookup_irreversible_move() {
+        crate::init();
+
+        assert_eq!(lookup(move_key(Piece::WHITE_KNIGHT, G1, G3)), None);
+        assert_eq!(lookup(move_key(Piece::WHITE_PAWN, E2, E3)), None);
+    }
+}
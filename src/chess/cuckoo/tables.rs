@@ -0,0 +1,2572 @@
+// Generated by the 'cuckoo-tables' command. Don't edit by hand.
+
+use super::TABLE_SIZE;
+
+#[rustfmt::skip]
+#[expect(clippy::unreadable_literal, reason = "Not intended to be readable!")]
+pub static KEYS: [u64; TABLE_SIZE] = [
+    0x0000000000000000, 0x536e687a00018dab, 0x0000000000000000, 0xbdea48f94504a003,
+    0x0000000000000000, 0x6e26a98c8005900f, 0x886cdc872256c006, 0x0000000000000000,
+    0x0000000000000000, 0x84fe1c157dfb4009, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xa42fd74b400dd8e3, 0x3d49726bc00edc1d, 0x0000000000000000,
+    0x864b76a9e010b2f1, 0x0000000000000000, 0x0000000000000000, 0x8d36c9b269a74013,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x722061a14017a620,
+    0x7c1f815d8ca44018, 0x0353d94c2019f9aa, 0x1248e2bf2581801a, 0x0000000000000000,
+    0xce6d7d60fec1e01c, 0x4b3037d6401d1469, 0x0000000000000000, 0x0000000000000000,
+    0x7ee26d27a05ea020, 0x3520474b5e30a021, 0x67fcc8d5517c2022, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf8cb1214a5582026, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd9ce98dce02a46a7, 0x20fb29e1fcc4c02b,
+    0x0000000000000000, 0xf13d1b90314bc02d, 0x0000000000000000, 0x0000000000000000,
+    0x43bdfa0f3eb1c030, 0x0000000000000000, 0x0000000000000000, 0x9a28e35160330271,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd22c5a5fdec92037,
+    0xb66ddf2f203892b0, 0x0000000000000000, 0x6c70a6fa088c803a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb8e53481bece403e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x14698a2f74660044, 0x60a6c6f15a4ac045, 0x0000000000000000, 0xb4caae3d0047467e,
+    0x0000000000000000, 0xb7318b1b230f8049, 0x0000000000000000, 0x1c67000ba04b83a8,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf1ed5e454f34c055, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd3fdf034022dc05a, 0x53729a247a10805b,
+    0x0000000000000000, 0xe8388fc34005605d, 0x89f711dc790fc05e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x233c3335e06295c5, 0x0000000000000000,
+    0xa9f115360064056c, 0x5238b49b6d816065, 0x633ff2cbc06658a4, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x321989e2fa44e06b,
+    0x0000000000000000, 0xa8b69486c422606d, 0x0000000000000000, 0x0000000000000000,
+    0xa34e5e309c4f0070, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xe47ce875c0763585, 0x0000000000000000,
+    0x0000000000000000, 0x35a00becbb30a079, 0x0000000000000000, 0x0000000000000000,
+    0x0aba85839b4e007c, 0x0000000000000000, 0x43bce338f505207e, 0x151497937f1da07f,
+    0x0000000000000000, 0x0000000000000000, 0xbae691e58b950082, 0x0000000000000000,
+    0xcadd13082e0aa084, 0x0000000000000000, 0xe09b9c924086973b, 0x065c1008ff738087,
+    0x54309a0fe0885188, 0x2c51ea7884362089, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1ca1f48a5d81a090, 0x0000000000000000, 0x0000000000000000, 0x1e5f9021aeb6a093,
+    0x0000000000000000, 0x0000000000000000, 0x38879bda60c5e096, 0x453cc679119a8097,
+    0x9bd1ded9ca22a098, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xa94355c6f479c09e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x7a292265a3f7c0a5, 0x9d1e6c5cc0a6f85e, 0x0000000000000000,
+    0x0000000000000000, 0x2550cbc720a9df4f, 0x60ed64a3e0aa3f8b, 0x0000000000000000,
+    0xd008edee1fd260ac, 0x0000000000000000, 0x0000000000000000, 0x2f5d92ce4f66e0af,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xacedecfbe17fa0b4, 0x0ab56c201d9c40b5, 0xae5ed5a6ecaca0b6, 0x2d32b6efb78da0b7,
+    0x0000000000000000, 0xab56db326ba300b9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf511045af41640be, 0x81bc2a55d3d520bf,
+    0x0000000000000000, 0xad627f8f00c13b04, 0x0000000000000000, 0x0000000000000000,
+    0x158ac63a3e09e0c4, 0xcf2f2445efce80c5, 0x0000000000000000, 0x0000000000000000,
+    0x6037bba3c85280c8, 0x9c6bb8bc941840c9, 0x630a3e46c0ca607e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x1ca9def9d64b40ce, 0x0000000000000000,
+    0xfcac827b9f9d80d0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x791cfdd1194c40d8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x8b505e76961bc0dc, 0x93480b88e9db60dd, 0x84cb1481634c20de, 0x0000000000000000,
+    0x0000000000000000, 0xb6935b66a0e12c21, 0xb2936aba864380e2, 0x508f742460e3a30f,
+    0x0000000000000000, 0x0000000000000000, 0xfa7ae7db40e6490d, 0x0000000000000000,
+    0xd31b34abef4aa0e8, 0xee269d7fcf35a0e9, 0x0000000000000000, 0x0000000000000000,
+    0x2993e7f19ed2c0ec, 0x0000000000000000, 0xea7a922130a220ee, 0x51f5ddc6c0ef048f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x099506e6e21f60f3,
+    0x0000000000000000, 0x710f2be3d8cea0f5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4d5930c31ee2e0f9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xde97908611dbc101, 0xe4eb75e31bda0102, 0x285a87e01ca9c103,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xdb2f9da1e1071469,
+    0x0000000000000000, 0x0e437b93410907a2, 0x8bbc100a1e62a10a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x4220c22f4698e110, 0x16ff9717924a8111, 0x59f8498fb7b9a112, 0x7eb1ad28e636e113,
+    0x35f4a48d78c6e114, 0x0000000000000000, 0x7e43fdf526b08116, 0x5c04955a6b7b6117,
+    0x0000000000000000, 0x0000000000000000, 0x0937c2484443a11a, 0x9b61c7bd5221e11b,
+    0x23fe2372bdd9c11c, 0xab38a9bff401211d, 0x0000000000000000, 0x0f0a13e2211f242e,
+    0xfd1cdd0081204ae5, 0x8b091f463ce86121, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6b4a0fbe2b3f2126, 0x0000000000000000,
+    0x0000000000000000, 0xb248a2b5f5f8a129, 0x0000000000000000, 0x0000000000000000,
+    0xd2d2f6537b07612c, 0x0000000000000000, 0x0000000000000000, 0xff0ecdfa5e84412f,
+    0xc8be65c90130129c, 0x5361ad9190dfc131, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x33c2480e8fefe135, 0x9be538ad81362853, 0x0000000000000000,
+    0xa8287ee92d27e138, 0x0000000000000000, 0x0000000000000000, 0x3540a334013b28d0,
+    0x0000000000000000, 0x0000000000000000, 0x0bd0688b2296a13e, 0x0000000000000000,
+    0x0000000000000000, 0x28cce0b2a7fc2141, 0xc0e29f5e5385e142, 0x0000000000000000,
+    0x931f588e21442a63, 0x0000000000000000, 0x0000000000000000, 0x66ca8e84c1473016,
+    0x335ab7f8026cc148, 0x3e26986fd82bc149, 0x0000000000000000, 0x0000000000000000,
+    0xd075aa6d89aca14c, 0x5667e6a6a3d9814d, 0x0000000000000000, 0x0774dd61e14f13c0,
+    0x57772f1ca77d4150, 0x77dcb5a14151dfbd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x2a92a2ce9940c155, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x40b2d6a57541215b,
+    0x1e5352a697d9c15c, 0x896d6e133402015d, 0x0000000000000000, 0x0d20b2c96784a15f,
+    0x3083b5d9bcf12160, 0x45cc214c5c34e161, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe8bbd3c47b738165, 0x0000000000000000, 0x0000000000000000,
+    0xebadbdfdb590e168, 0x0000000000000000, 0xda06b5b2fcd0a16a, 0x42ae4684c16bac87,
+    0x16891c425812816c, 0x0000000000000000, 0x6ae9eac0aa2b216e, 0xe98118d26236a16f,
+    0x2ed8dfc52eefa170, 0x0000000000000000, 0x0000000000000000, 0x77ea492092fb2173,
+    0x35b233648174ae1b, 0x9aa519a97e738175, 0x0000000000000000, 0x0000000000000000,
+    0x857f282f0178a967, 0x35fe47cf6f8ae179, 0x333daee6e627617a, 0x5f4d6839631e817b,
+    0x0000000000000000, 0x0000000000000000, 0x9c5abc6b4fb0817e, 0x0000000000000000,
+    0xbf3c6f58cb092180, 0x0000000000000000, 0x0000000000000000, 0xb85a19e6a183f59d,
+    0x37a441f215c10184, 0x0000000000000000, 0x78a5d98d81867f20, 0x326938e7e187f405,
+    0x8f46edad050ba188, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5d41fed01fe7e190, 0x85fff6c801913927, 0x42e10a53fbc84192, 0x0000000000000000,
+    0x1e0197ea08adc194, 0x1a600b9693d06195, 0x0000000000000000, 0x0000000000000000,
+    0x34f66267e19869ee, 0x4592bc47387c2199, 0x0000000000000000, 0x0000000000000000,
+    0xc2d5a0b1939c219c, 0x0000000000000000, 0x261bf8d2899b419e, 0x55d9d71d619fa186,
+    0x84e8ecfb95aee1a0, 0x0000000000000000, 0x0000000000000000, 0xb5a45a1b12eca1a3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x9bd6921db9ae01a8, 0x52f2158521a924da, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x369d73a432bc21b8, 0x4eadc371d4e341b9, 0x790d05f4280861ba, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd2f10819887be1c1, 0x6458be46bfd101c2, 0xf8ea132c5225e1c3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x11d8b11962cba1c7,
+    0x0000000000000000, 0x94e9467be1c91ed8, 0x0000000000000000, 0x9d680292464701cb,
+    0x5dc83d7bd8ac41cc, 0x0000000000000000, 0x773cf4cbb6d021ce, 0x0000000000000000,
+    0xc232a0278a9be1d0, 0x0000000000000000, 0x67c6d18db53201d2, 0xe61fd66be1d3b648,
+    0x0000000000000000, 0x0000000000000000, 0x1d7823f281d646d6, 0x0000000000000000,
+    0x0000000000000000, 0x8d147fb91b2fc1d9, 0xac97c42e01da2577, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x024e84fc7d8101df,
+    0x0000000000000000, 0x27ee83185ed2c1e1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2c7b2e531855c1e7,
+    0x190512cd508ec1e8, 0x22fb051084d3e1e9, 0x0000000000000000, 0x0240062655a5e1eb,
+    0xef99cabbd27021ec, 0x7ada2b236f7421ed, 0x1ef5586b638f21ee, 0x2cf9eecca1ef643e,
+    0x0000000000000000, 0x4bd9f235d2be01f1, 0x0000000000000000, 0x0000000000000000,
+    0x82a23dcc52cf81f4, 0x31ac49e128f981f5, 0x0000000000000000, 0x6fb775a3c1f738a3,
+    0xec7cbe9ba48081f8, 0xe5f9120efe9b61f9, 0x0000000000000000, 0x4487566d1f7c81fb,
+    0xc1dc477f0b28c1fc, 0x62b8af30b6b5c1fd, 0x0000000000000000, 0xb6be96529789a1ff,
+    0x0000000000000000, 0x141c98a62d3ce201, 0x0000000000000000, 0xb9c6b762a4226203,
+    0xec9bff9282045d76, 0x5ed53ac8c1bc6205, 0x911e20b6495a6206, 0x0000000000000000,
+    0x0000000000000000, 0x065bb6645db84209, 0x75993f64a062e20a, 0x3241c7c3e20bdeaf,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf637d3f5a78f820f,
+    0x0000000000000000, 0x0000000000000000, 0x4da49a2906db4212, 0x3f4d7cabfe374213,
+    0xaf673244f133a214, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf8e4eabc221a8375, 0x71425f270400c21b,
+    0x820632780ff8621c, 0x0000000000000000, 0xd979c67c5baf021e, 0x78b8132834ed621f,
+    0x0000000000000000, 0x0000000000000000, 0x0dfe5677cb530222, 0x5ec1fd4da5edc223,
+    0x5d28ccff049c0224, 0x088f48568cee0225, 0x0000000000000000, 0xdd4dc6ab4227b948,
+    0x8665517ec7b8a228, 0x0000000000000000, 0x0cac3e2649c4822a, 0x968686a5e22be692,
+    0x0000000000000000, 0x10e872f363fd422d, 0x0000000000000000, 0xdc22a1e85c24822f,
+    0x7273679ec262a230, 0x4b5068a7167d6231, 0x12b4d01433e28232, 0x0000000000000000,
+    0x6a2efd1109334234, 0x5a69347e0cdb0235, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xdb71a3e649efa239, 0x670aa002e23aee4c, 0xef673dc0c23b0bce,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x80ca1bece582a240, 0x0000000000000000, 0xb67fa07c100a4242, 0xe798dbdfadb7a243,
+    0x0000000000000000, 0x0000000000000000, 0x004fcc849d76e246, 0x0000000000000000,
+    0x14881f3613be8248, 0x0000000000000000, 0xb72bce29624a15ff, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6f282259624e4acd, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xaac2ac97bdd9e252, 0x0000000000000000,
+    0x0000000000000000, 0x850b970b0255bfa7, 0x209ab0a9a49d4256, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe0b1b1f14ed9a25b,
+    0x0000000000000000, 0x9675329c225dd823, 0xf578757d7aa5625e, 0xeb53bfd16e7ea25f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2f4f9848226478e1, 0x9b6b29633bbf6265, 0x0000000000000000, 0x0000000000000000,
+    0xbfd5d7d3d3c0e268, 0x03e62b1b02696b3b, 0x57a35b264066826a, 0x0000000000000000,
+    0xfc08011d44d2226c, 0x0000000000000000, 0x0d4f508e31fa626e, 0xc51220fe6150026f,
+    0xe9d7da7485056270, 0xa6c43d0e6fefa271, 0x0000000000000000, 0x0000000000000000,
+    0x497d6a73a42c8274, 0x306fba27bae9c275, 0x0000000000000000, 0x53331328fd6d6277,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x8944ee6ae27b63d5,
+    0x2dca86afea7e027c, 0x0000000000000000, 0x9c25971d806fc27e, 0x0000000000000000,
+    0x1f4b587189ca8280, 0x0000000000000000, 0x0000000000000000, 0x575733e169dc4283,
+    0x0000000000000000, 0x51bc5b8aab0b6285, 0x0000000000000000, 0x0000000000000000,
+    0xbbb42d83dc7cc288, 0x0000000000000000, 0x0000000000000000, 0x02d289bd9563428b,
+    0x0000000000000000, 0x0b5f34e419e7c28d, 0x70e4ce81828e9deb, 0xa0c8df05080c028f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xbe1cf3dfc2937feb,
+    0x56a5757b12994294, 0x0000000000000000, 0x0000000000000000, 0xe715ee8078f82297,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x097bbabb1e95829d, 0x0000000000000000, 0x6d1b7a96138ca29f,
+    0x0000000000000000, 0x0000000000000000, 0x28b81d83a2a2867e, 0x0000000000000000,
+    0x0000000000000000, 0x2c59718082a56f99, 0x5f2b0512a9ef62a6, 0xf2f45d538fb862a7,
+    0x01c4e25b940c62a8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x993ebc8882ada4dc, 0x0000000000000000, 0x9b39f68f22af6aa0,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2a02bfd2a01382b3,
+    0x0000000000000000, 0x8b3049a5f430c2b5, 0xd20551b54eb122b6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0c5f1f66fa3802ba, 0x0000000000000000,
+    0xa2bdec0dbc6142bc, 0x6fd1477c42bd899b, 0x336b9d7ba5f522be, 0x0000000000000000,
+    0x29cfc852cf3e42c0, 0x0000000000000000, 0x0000000000000000, 0x24411ac8403402c3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x81e1f95ba5e882c7,
+    0x2ff8fa08498342c8, 0x0000000000000000, 0x7762f0fe7ac0c2ca, 0xfa0bc3af6f1022cb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x9e11eded6e2242cf,
+    0x0000000000000000, 0x346c0c8ae2d1f933, 0x223837f71786e2d2, 0x0000000000000000,
+    0x0000000000000000, 0x81abd06ca2d5a1da, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xcbd09c2eeca242d9, 0x0000000000000000, 0x6f56ccf0a2db9e46,
+    0x37f275ce804762dc, 0x0000000000000000, 0x80b4a9eabae042de, 0x0000000000000000,
+    0xe176951ff97ca2e0, 0x0000000000000000, 0xa8d40fc5e6dde2e2, 0x0000000000000000,
+    0x0000000000000000, 0x33d74e09dc1c82e5, 0xe8bf65e150b4e2e6, 0x0000000000000000,
+    0x0000000000000000, 0xec689632cb4fc2e9, 0x0000000000000000, 0xf835c6532468c2eb,
+    0x0000000000000000, 0x0000000000000000, 0xa49cd88087d482ee, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4cac49ea3b2ac2f2, 0xecfbcaf26f82a2f3,
+    0xfb474dfe82f4fcc9, 0x0000000000000000, 0x1caf85e5a2f6a500, 0x0000000000000000,
+    0x0000000000000000, 0xb155b91f107682f9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x494577b9b194c2fe, 0x4dd253a90c5e82ff,
+    0x0000000000000000, 0x0000000000000000, 0x700a5c975d8ba302, 0x0000000000000000,
+    0xe68e1db7a8b06304, 0x0000000000000000, 0x9431e604a078c306, 0x1c9824a583076dea,
+    0xd759cf66f4336308, 0xa7b47e3e63c96309, 0xda63baa6230a54ce, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x72e097ade09e430e, 0xbf37983f430f8914,
+    0x0000000000000000, 0x81e27a99474bc311, 0x18e9566c83129cc1, 0x1b47a97a4112c313,
+    0x2a11b7b9c3144594, 0x0000000000000000, 0xf87da8f0c503e316, 0x0000000000000000,
+    0xe04ee7f29ce1a318, 0x0000000000000000, 0xf3b43b29e449231a, 0xf8ee839acbfa431b,
+    0x8272e0cf1c2e231c, 0x0000000000000000, 0x0000000000000000, 0x9076392f6960e31f,
+    0xa33cd640f129c320, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xbd5f333acad6c324, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x74495e3a432958ee, 0x0000000000000000, 0x0000000000000000,
+    0xcba0173798d4832c, 0x387e3951052a032d, 0x2d9cef62af9f632e, 0x9dfb7f5b2594032f,
+    0xeb36a85dc330f7e2, 0xab717f1cc331cd47, 0x0000000000000000, 0xe740c9f2e7456333,
+    0x30900e0294362334, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa9eb08bf92ade338, 0x0000000000000000, 0x0000000000000000, 0x1ec87be9cc61233b,
+    0x77ff8edc94fec33c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xee915d14c635c342, 0x0000000000000000,
+    0xe6b51ff6204d2344, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1d531a1183286348, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4e26da6a71b3634d, 0x0000000000000000, 0x0000000000000000,
+    0xe009c2f585eda350, 0x0000000000000000, 0x522b104ea3522f9c, 0x0000000000000000,
+    0x0000000000000000, 0x24d6334a36bd0355, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc77dc7a7631ca35a, 0x0000000000000000,
+    0x0000000000000000, 0xda29c53a484dc35d, 0x0000000000000000, 0x449df998835f578e,
+    0x5fa5d167036085c4, 0xe9e5648ba361fd5d, 0xfbf2a40827d52362, 0x0000000000000000,
+    0x0000000000000000, 0x881022f3c3c74365, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xfb08bf5948a1a369, 0x0000000000000000, 0xe9289b2e39e9c36b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd998735903726463, 0xf0962ea80b136373,
+    0x0000000000000000, 0x2b41a8b4faad0375, 0x0000000000000000, 0x0000000000000000,
+    0x1c3d961aa378689d, 0xe8632f6a8379bb19, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xe08d609ea382e214, 0xe26424beb172c383,
+    0x0000000000000000, 0x1b7730c13efd0385, 0x0c4afa003d2cc386, 0x5ba4a2e058cb0387,
+    0x0000000000000000, 0xc479b3b7cd798389, 0x3948da11e38a3f21, 0x4adbadec5f90638b,
+    0x0000000000000000, 0xfe45a1781443238d, 0x83a06215dfe1e38e, 0x0000000000000000,
+    0xd52e0af283906be7, 0x0000000000000000, 0xcc525b07cf330392, 0x39fb10fba393abee,
+    0x4841866132ca8394, 0x0000000000000000, 0x21ac1b8fde17e396, 0x95d708c9c397ecdd,
+    0x0000000000000000, 0xa33ca47d63993696, 0x0000000000000000, 0x0000000000000000,
+    0x51d2e970a39c8e59, 0x0000000000000000, 0xfa9a9b300e0bc39e, 0x737dd9bdc4aa439f,
+    0x7bd7c910e42083a0, 0x3fbdbefa63a193b6, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6afbb77223a6febb, 0x3d07618bc3a74761,
+    0x251d7ed42983c3a8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xb127288eeb5c43ad, 0x3d5cdd459d3cc3ae, 0xb52f466b5dcb83af,
+    0x0000000000000000, 0x2bc58586b6e723b1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xde2e68bd7c86c3b6, 0x7595d7d875dac3b7,
+    0x68a37eaa921103b8, 0x3ea43a43f8d223b9, 0x0000000000000000, 0x847e99777eba83bb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe8e46b8843c1e791, 0x5b11c741a3c27419, 0x0000000000000000,
+    0x0000000000000000, 0x2eab497c7bca43c5, 0x888da6b56f60e3c6, 0x0000000000000000,
+    0xf7b2e73097b343c8, 0x366164b4725883c9, 0xc641b97418c723ca, 0x6b92c290a3cb1dfd,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x915c2344ed7cc3cf,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x5c336f7123d5dd7e, 0x255668cb78ede3d6, 0xbb5c806703d7477e,
+    0x0000000000000000, 0xd8af987aed9ea3d9, 0x0000000000000000, 0x0000000000000000,
+    0xdd0c0c12590fa3dc, 0x0000000000000000, 0xf262b400a5b1c3de, 0x0000000000000000,
+    0x0000000000000000, 0xd50cf7c7959723e1, 0x8bf1794f3f25c3e2, 0x0000000000000000,
+    0xd0c3b2068bc163e4, 0x0000000000000000, 0x0000000000000000, 0x491f6ea863e7007c,
+    0x96c12e5592a6e3e8, 0x0000000000000000, 0xdccb7e13d8dd83ea, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0cc64562444fc3ee, 0x0000000000000000,
+    0x84469e262ad763f0, 0x0000000000000000, 0x431a3f75096683f2, 0x0893238243f3e390,
+    0x59965f01e4e803f4, 0x01ccb13103f53e62, 0x0000000000000000, 0xefa1dad678c503f7,
+    0x0000000000000000, 0x635e792a1f68e3f9, 0x6f46e8d07a5123fa, 0x0000000000000000,
+    0x0000000000000000, 0x969ae2708bd1e3fd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0166ea27640231b0, 0x6ffdcb7fa403528c,
+    0x0000000000000000, 0xaad377cd61b54405, 0x6407fc162057c406, 0x3e6d6bddc4074634,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x90a1db71fdfce40b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x26e35957a3fe840f,
+    0x4e3e73fc6b632410, 0xc3bbc4a1e4111dcd, 0x0000000000000000, 0x737772abce9a8413,
+    0x6cb65ffb4414dfdf, 0x0000000000000000, 0xd3320b452daca416, 0x0000000000000000,
+    0x15083b41d04ac418, 0x0000000000000000, 0x0000000000000000, 0x30ae1a362f14841b,
+    0xd9942122c41ca9ea, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x8151d1cf448c6420, 0xd63f8a87a25ca421, 0x0000000000000000, 0x75f28508c423c6a4,
+    0xb3c1584e1fde0424, 0x0000000000000000, 0x23092d8cc6efe426, 0x85b5deea7c5c0427,
+    0x0000000000000000, 0x29356c96e4294dd8, 0x076d4044cc88442a, 0x0000000000000000,
+    0x9f38a318242cafa7, 0x23f4626aa42d6e2a, 0x8fd2b98d642e3ade, 0x0000000000000000,
+    0x8fefbabde4302382, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x192ec1660435551d, 0x24865824c0970436, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5c545ef2a43bf130,
+    0x0000000000000000, 0xd0f96c463af2a43d, 0x2e54a159643e9dd5, 0x7c3c951d5055c43f,
+    0xb20543582440a8f3, 0x0000000000000000, 0xca00cd2f64427474, 0x0000000000000000,
+    0x48d73477d1ad2444, 0x0000000000000000, 0xad067bf00446aa7c, 0x2799d55c8764c447,
+    0x0000000000000000, 0x342c50e1c332a449, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xdf4ed1e339ab444d, 0x0000000000000000, 0x0000000000000000,
+    0x3572d95644503789, 0x50a7804c0451138b, 0x0000000000000000, 0x81f63639cc7ce453,
+    0x0000000000000000, 0xda26bb53f3c28455, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x557bd94fe132c459, 0x0000000000000000, 0x0000000000000000,
+    0x4cb6346ff5b6045c, 0x8cf1cd21e45de809, 0xdcb0b7adc0b4445e, 0x0000000000000000,
+    0x0000000000000000, 0x52f83c5a611bc461, 0x0000000000000000, 0xc817962de463e2db,
+    0x02ce8bb3544f6464, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x29b3f838020d846b,
+    0x48ac9778f3b1846c, 0x421192fb029e246d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x37581060ebe16475, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x91b6733110d3447a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6da2f50a4e512482, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf59b632724d86488, 0x108552a037484489, 0x0000000000000000, 0x0000000000000000,
+    0xa8486d466688048c, 0x0000000000000000, 0xe011796c9486448e, 0xf856d556e48f3f51,
+    0xa221a6855531c490, 0xd45c578824910a37, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xb11c664264954096, 0x0000000000000000, 0xf60daefef0f60497,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0c43a9200ac9249b,
+    0xa6408faf649c4e05, 0xe068d393a49d6fe4, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x10009e9ca7e8a4a4, 0x0000000000000000, 0x0000000000000000, 0x61638b0604a7191a,
+    0x65650dd7cc6044a8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x34a8e7abc4ad0771, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xefcedce2caf824b2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe1094cc032b264b7,
+    0xd3be65469a3324b8, 0x39f714393a7c44b9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x626b892b94c864bf,
+    0x0000000000000000, 0x79952891d055c4c1, 0x0000000000000000, 0x0000000000000000,
+    0xc9f1e95304c42ccc, 0x0000000000000000, 0x562343f804c62b6e, 0xb6b6d2057d0ee4c7,
+    0x0000000000000000, 0x4bba2f0041ca24c9, 0x0000000000000000, 0x0000000000000000,
+    0x5b31d9acc7cc24cc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8a59ede4b70924d2, 0x0000000000000000,
+    0x60036f31477124d4, 0x0000000000000000, 0x972171704a2424d6, 0x0000000000000000,
+    0x2e1c53f4650004d8, 0x0000000000000000, 0xdf78e21fa4daa90a, 0x0000000000000000,
+    0xba1d8286c5db44dc, 0x8bcfc3f7de0d64dd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x06e28a779fbdc4e1, 0x37a7e3883923c4e2, 0x5b8a9bda6fa044e3,
+    0x3e5cf2e84e6844e4, 0xb29e1106262804e5, 0x0000000000000000, 0x0000000000000000,
+    0xd5410043a4e8a980, 0x0000000000000000, 0x4d4d04edcab364ea, 0xa1ae03e3bd7b04eb,
+    0x0000000000000000, 0x17a762d373caa4ed, 0x0000000000000000, 0x0000000000000000,
+    0xb9f365de446864f0, 0x0000000000000000, 0x0000000000000000, 0xf1842ae3fd94a4f3,
+    0xd0e1c15504f4b08e, 0x0000000000000000, 0x0000000000000000, 0x52fe6d2dd15044f7,
+    0x783bb8cafd0d84f8, 0x0000000000000000, 0xe960987d454ea4fa, 0x4d2cbbfd800e04fb,
+    0x0000000000000000, 0x0000000000000000, 0xf6718f733059e4fe, 0x92127b00c195c4ff,
+    0x1ae2f320e500137f, 0xee593be428500501, 0x0000000000000000, 0x0000000000000000,
+    0x30bca1d7a504c24e, 0x0a2f8ea1d6b26505, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5d148b6d050ccdd0, 0x0000000000000000, 0x0000000000000000, 0x6e71b63506c5650f,
+    0x9d2d20f6da976510, 0x52eeadfa8eddc511, 0x375adf993f424512, 0x0000000000000000,
+    0xb8354e8a0514e0b4, 0xf7261e0f85159b0b, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc8c51c1c51afe51a, 0x0000000000000000,
+    0xc491dcf662fee51c, 0x0000000000000000, 0xda4ef49ac51edf9e, 0x0000000000000000,
+    0x0000000000000000, 0x14571a87d4172521, 0x2edf72d2a2490522, 0x0000000000000000,
+    0x0000000000000000, 0xdb2c6c868d482525, 0xa966e8ffc5268243, 0xd71fd5d845274e64,
+    0xa80fa143be2ea528, 0x84045184495d8529, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x9c375299e52de1f4, 0x0000000000000000, 0x95384bc9a52f9326,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc834e541fac20534, 0x0000000000000000, 0x45d9ebbc453613d0, 0x0000000000000000,
+    0x436ef36b4538b9a5, 0xb5f547480bcf0539, 0x0000000000000000, 0x0000000000000000,
+    0x639e6b67653c243e, 0xbbe45b7a5384453d, 0x0000000000000000, 0x37fc5f4a82afc53f,
+    0xd6d0bb5e054079df, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5614a5fa054ff4b8,
+    0x0000000000000000, 0x5ac7f81a7247c551, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x741e27bb2555796a, 0x0000000000000000, 0x8910e8dd6557f4c3,
+    0x0000000000000000, 0x0000000000000000, 0x2f9cdd030c1e055a, 0xb32f6497a077655b,
+    0x78882ee16300a55c, 0x29620cd0055d7a0f, 0x0000000000000000, 0x0000000000000000,
+    0xe9d3c053c0faa560, 0x0000000000000000, 0x7f0803f9812ba562, 0xa38d43c23f64a563,
+    0x6038487e25647002, 0x0000000000000000, 0x962cc6336566ff13, 0x0000000000000000,
+    0x81e22c493ad7c568, 0xc14046f11d4e8569, 0x31b7a538ba4ca56a, 0x7078ee0ccc3fc56b,
+    0xfe446e3bf79d056c, 0x906c31a6c6a0656d, 0x0000000000000000, 0x5e247b89856f075e,
+    0x9338833785705dec, 0x0000000000000000, 0x58afa885c572f426, 0x0000000000000000,
+    0x0000000000000000, 0x851d43b18575ad0b, 0x0000000000000000, 0x1d172328c2ac8577,
+    0x54b8ba16d9a34578, 0xe32174c48984c579, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8382915b96b4e57d, 0xd6365816857ec11d, 0x0000000000000000,
+    0x5672972586054580, 0x0000000000000000, 0x28a2c62f45820f50, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xeaaddf7509e28587,
+    0x0000000000000000, 0xc385e945a5f4a589, 0x00d5d2fcc58ae01d, 0x3a7869f10bb1858b,
+    0x0000000000000000, 0x8ccd1466366c258d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa8174c9e65942277, 0x9498b2a838f9c595, 0x382b2278f4a4a596, 0x0000000000000000,
+    0x25d067b74598b433, 0x0000000000000000, 0x7f9101bf224d859a, 0x0000000000000000,
+    0x0000000000000000, 0x8d7cba8373a6059d, 0x8ba5b16d2b1d059e, 0x0000000000000000,
+    0x0000000000000000, 0x7cccb8ad2b5645a1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x374086101a03c5a5, 0x0000000000000000, 0xfa479ec5c013a5a7,
+    0x5d0c1d648fede5a8, 0xb0591ddc46c445a9, 0x70b2623f5a4c25aa, 0xf0db6ad31daf45ab,
+    0x0000000000000000, 0xd188f21a45ad61c9, 0x0000000000000000, 0xafb0d01a65afa500,
+    0x0000000000000000, 0x0000000000000000, 0x8496ca0085b2bff9, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0324e70d028f25b8, 0x566f228d45b99f9e, 0x19c194cb9c5325ba, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0852ca8885bedaeb, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x409cd92f4e1a25c2, 0x30d153b14aa3a5c3,
+    0x4c0e0848a66125c4, 0xfb6678da701325c5, 0xe7c59ace05c6f186, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe18972ddde3405cb,
+    0x0000000000000000, 0x18a217c0784825cd, 0x0000000000000000, 0x0000000000000000,
+    0x50446d9cd68ba5d0, 0xf5ae14734da305d1, 0x3f063dce9ab6c5d2, 0x0000000000000000,
+    0xfd6ea01ee96ae5d4, 0xa7d1d1ec8d5c45d5, 0x5f162379fad2c5d6, 0xa24db402ed67a5d7,
+    0x0000000000000000, 0x326c76cd435945d9, 0x0000000000000000, 0x0000000000000000,
+    0xa4dfb8ad05dc2222, 0xbdd880e60a8165dd, 0xa92853bb2d18e5de, 0x0000000000000000,
+    0xd28f071990e165e0, 0x0000000000000000, 0xdb0838eb6c69e5e2, 0x1fcf335f45e3764f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf6f7b21dde27a5e7,
+    0x999ec8f905e89392, 0x8956d4a83194a5e9, 0xdfbb388aeca865ea, 0x72c69a7ba90d45eb,
+    0x0000000000000000, 0x1d121869bdcf05ed, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x064dbd16c5f40e42, 0x2f080b2bc1ac25f5, 0xdeb5a612a7c845f6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x75364114617225fa, 0x0000000000000000,
+    0x0000000000000000, 0xda9b4a5e05fdb304, 0xa860acdf55f365fe, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x38e2237aca060604, 0x0000000000000000, 0xa12fb8a10606a9ac, 0x0000000000000000,
+    0xc2cc7ab7478c2608, 0x0000000000000000, 0x59ab19719cc2660a, 0x0000000000000000,
+    0x0000000000000000, 0x8f8396181a39e60d, 0x0000000000000000, 0xe54e789f89a4060f,
+    0xd57af4201dc76610, 0x0000000000000000, 0xa48ff541a5cd8612, 0x24009f51ddf0c613,
+    0x0000000000000000, 0x10ab5f67a61539e2, 0x71f9cb0de6167658, 0x894b4d162bbca617,
+    0x0000000000000000, 0x95a5ed684b162619, 0x0000000000000000, 0xe2a8021f9272e61b,
+    0xa2950b01dcfb061c, 0x5362c9ebad38261d, 0xb4cfdcdb061ec649, 0x0000000000000000,
+    0x1f460d0977680620, 0x0000000000000000, 0x0000000000000000, 0x338f09c170bac623,
+    0xffaa85d4e624fa3e, 0xbdf5a48c51784625, 0x0000000000000000, 0xfbdd6a5966275223,
+    0x4114a4b2874ae628, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x984664c45128462d, 0x0000000000000000, 0x0000000000000000,
+    0x5617fb26a630ff13, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa72e134385a4a634, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf454b9033266263c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5ffa4beca6405f6d, 0x0000000000000000, 0x907ba916c64228a6, 0x0000000000000000,
+    0x620b210de644bac7, 0xc250df47beeae645, 0x0cc99b0a225f8646, 0x0000000000000000,
+    0x0000000000000000, 0x6e141df1af916649, 0x0000000000000000, 0x0000000000000000,
+    0xd23e53f4c64c62f6, 0x58838945e64dc67e, 0x0000000000000000, 0x2747e3dbfffda64f,
+    0x0000000000000000, 0x6043b6bb8651709d, 0x2e2251938652d9e4, 0x1f16205c89008653,
+    0xe4ae8a550af72654, 0x5964ad580310e655, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x8730a4a3914dc65b,
+    0x0000000000000000, 0xf14ba9c3265d5450, 0x0000000000000000, 0x0000000000000000,
+    0xec41045d242fe660, 0xb4f2d319b534e661, 0xad4bb778b76d0662, 0x0000000000000000,
+    0x1f4d480fa664d602, 0x0000000000000000, 0x57e7107d26663829, 0x5568f7e8b9d14667,
+    0x5dacefc9de9ce668, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x543d56192021666c, 0x0000000000000000, 0x0000000000000000, 0x0bbd9d571d98666f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd31b43f28676cbe2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5a40cfa0f179267b,
+    0x0000000000000000, 0x0000000000000000, 0xb184db398fbee67e, 0xfd610a00743c467f,
+    0x0000000000000000, 0x97112cd9d754a681, 0x9986ed9ca682dac6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x5b4a9ad2a4cc2686, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x761a319a13442690, 0x0000000000000000, 0x798dd71da692e910, 0x0000000000000000,
+    0x0000000000000000, 0xfd5aa0682695e6d4, 0x0000000000000000, 0x232bf43905c58697,
+    0xf6343c7069bd2698, 0x0000000000000000, 0x5b196994c6aec69a, 0x0000000000000000,
+    0x59877e6619bce69c, 0x0000000000000000, 0x0000000000000000, 0xeb679cb40a34269f,
+    0xb6af3b5fc6a0a5d4, 0x0000000000000000, 0x0000000000000000, 0xd0664a5d06a3dcb0,
+    0x14b25b9209a226a4, 0x0000000000000000, 0x0000000000000000, 0x1f33ebf8d2f446a7,
+    0xc8ffaa10ee0566a8, 0x0000000000000000, 0x0000000000000000, 0x97b8abee34f866ab,
+    0x0e388e93082b66ac, 0x0000000000000000, 0x86cdc4e0987786ae, 0x0000000000000000,
+    0x0000000000000000, 0x4efcd50a66b1a3a6, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x65d1fba7c9c2a6b5, 0x19d8855dc6b68570, 0x0000000000000000,
+    0x92063a9a46b87aab, 0x0000000000000000, 0x3a3c5c01b5b346ba, 0xf5c7938662de06bb,
+    0x31d3489c523006bc, 0xc5f3e8a7273fc6bd, 0x0000000000000000, 0x0000000000000000,
+    0xc462c7bb62b486c0, 0x4d6ee66e5718e6c1, 0x0000000000000000, 0x0000000000000000,
+    0xd921cb83f3cd06c4, 0x0000000000000000, 0x623f6bddc6c6f3d9, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xe80558ae46caa2e2, 0x9551dc351e76e6cb,
+    0x0000000000000000, 0xec537c7070a4a6cd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd2177339c50926d1, 0x0000000000000000, 0x0000000000000000,
+    0x4bc3de8406d4dc80, 0x82121213b3ac86d5, 0x4eb1e156ccc126d6, 0x0000000000000000,
+    0x0000000000000000, 0xa9778d2946f6e6d9, 0x8afc2fb3045ae6da, 0x0000000000000000,
+    0x0000000000000000, 0x19bd5fecf15646dd, 0x0000000000000000, 0xca9a4d0ea6dfe791,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd733f5147d2926e3,
+    0xe73fa01a26e45a89, 0x0000000000000000, 0x0000000000000000, 0x846238624c0d06e7,
+    0x0000000000000000, 0x0000000000000000, 0x44a0c36ee907c6ea, 0x0000000000000000,
+    0x0000000000000000, 0xcdbc7dc2806726ed, 0x0832166c1a7926ee, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc250210f86f324a7,
+    0xc3739e2206e466f4, 0x0000000000000000, 0xc5a93db146f60842, 0x0000000000000000,
+    0x0d6637ccddb826f8, 0x23f93364288726f9, 0xbcda8020bcc0e6fa, 0x0000000000000000,
+    0x96528d6d86fcdbd8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xfecba83747014a68, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x69026cc0270629cd, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6c6e56e1ba5ec70d, 0x92b50f8abcfd270e, 0x428ea72160e8470f,
+    0x29671278c578a710, 0x0000000000000000, 0x0000000000000000, 0x7559512e7c872713,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb6d9ae1027181128, 0x72aef7873f3e4719, 0x5d7d7b41071a94d9, 0xd940710337f4c71b,
+    0x0000000000000000, 0x0000000000000000, 0xad457658c71ea87b, 0x8fa95ac73208271f,
+    0xd85ebe602720c615, 0xf31aed628721e7d9, 0x0000000000000000, 0x66174c1bb65c0723,
+    0x0000000000000000, 0x0000000000000000, 0x05bcaedab72c0726, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5f750c644d81272c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1de4829288a8c730, 0x692adc49c7311012, 0x0000000000000000, 0xb1a1a8a5ce0f8733,
+    0x83c9f984b2b6a734, 0x0000000000000000, 0x873d18da1b1c4736, 0x0000000000000000,
+    0x0000000000000000, 0x08fbb75d24fa2739, 0x6153d8d34055873a, 0x0000000000000000,
+    0x0000000000000000, 0xc8e88880024b273d, 0x3eefb31e7350e73e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x537e930187426aa6, 0x0000000000000000,
+    0x0000000000000000, 0x0674a930dc230745, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x77cda9a0c780474c, 0x9a1d525c4952c74d, 0xb0bb0630f1b1674e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc59f29d289a6c752, 0xbc2026fb5b154753,
+    0x0000000000000000, 0xde5b1f7207270755, 0x34d397fc67566a89, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x34dd2758a8c0a75e, 0x0000000000000000,
+    0x595be0bddd6f8760, 0xc138bb3187618b23, 0x33c042a0d9dc2762, 0xd7cc260af03b4763,
+    0x0000000000000000, 0x69039485e43d6765, 0x0000000000000000, 0x887cc88ff9640767,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xcf3d37277061076d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe7e4c8990652a771, 0x7f41dcbc6cac2772, 0x0000000000000000,
+    0xdcbaab2507742ce9, 0xc436146247755986, 0x1fe23923739c0776, 0xd8d4e42c9fa0c777,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x94e26cda5c6c877c, 0x0000000000000000, 0x4e69b34ba644477e, 0x0000000000000000,
+    0x0000000000000000, 0x7d1a5c6907812a16, 0x0000000000000000, 0xcbf44055ae972783,
+    0x665e04fc58784784, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x263ebca380d3078e, 0x2f781140269ce78f,
+    0x0000000000000000, 0xc515fd3cbf8f8791, 0x1b2ec01ec7929258, 0x0000000000000000,
+    0xe9abb86707945093, 0x7bdf829faa71c795, 0x210e568454cac796, 0x48d05e51f3f12797,
+    0x02425532b0f1e798, 0x0000000000000000, 0x0000000000000000, 0x514c47c9c888c79b,
+    0x0000000000000000, 0x0000000000000000, 0x88a56524039cc79e, 0x0000000000000000,
+    0xcffcf92add6127a0, 0x0000000000000000, 0xdc2579a84224e7a2, 0xffcd07b52befa7a3,
+    0x0000000000000000, 0xfa5bce1f07a5fbfc, 0x0000000000000000, 0x1c2ce6e9ecc807a7,
+    0x0000000000000000, 0x9b795861a1a667a9, 0xc14ffab69c41c7aa, 0x0000000000000000,
+    0x6db1f57503eae7ac, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xdeb91a92737aa7b0, 0x0a4a8ebf4e64a7b1, 0x0000000000000000, 0x395b5216b48b87b3,
+    0xbc869501dbf2a7b4, 0x0000000000000000, 0xeb78ea7d6f3347b6, 0xffec616c526087b7,
+    0x9e6671ec52d7a7b8, 0x1599815b3cdc67b9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x49fa37375fde67be, 0x0000000000000000,
+    0xf2365e984a3827c0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xae4d644e6e69e7c5, 0x8f780f2e50f187c6, 0x28a65da5e7c7735b,
+    0x0000000000000000, 0x0000000000000000, 0x8c4873bf3e1987ca, 0xe3826d93f229c7cb,
+    0x0000000000000000, 0xd65201f384c787cd, 0x0000000000000000, 0x0000000000000000,
+    0xec1c00737783a7d0, 0x0000000000000000, 0x52f04090c9b787d2, 0x201638f83dbfc7d3,
+    0x0000000000000000, 0x3528cfd44c4127d5, 0xf0a51766e7d6f2d9, 0x0000000000000000,
+    0x0000000000000000, 0xd531626b68e1e7d9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x45be4870e7ddce28, 0x598bef55273fe7de, 0x0000000000000000,
+    0x7e935e66af63e7e0, 0x0000000000000000, 0x0000000000000000, 0x1be2afeebb8327e3,
+    0x66e6bb8fc7e4efc0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0d3871c2bd2567e9, 0x0000000000000000, 0xaf4044a3ebfde7eb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x59f76c2607ef0857,
+    0x0000000000000000, 0x86ac7e98d0ea27f1, 0x688a203e6ec3a7f2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x315e2a166a8ac7f8, 0x187d9e89c7f9ae90, 0x2a67be0d001ea7fa, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x3c49b6c8722a07fe, 0x4414f4b1866647ff,
+    0x0000000000000000, 0xb44cac3048011ec4, 0x84e8960f099aa802, 0x0d1661bbb0ab4803,
+    0x0000000000000000, 0xc39763b00805f03e, 0x0000000000000000, 0xf3a22d05228ea807,
+    0x0000000000000000, 0x8bfaa8f008091752, 0xe7022babe80a22a6, 0x809f988df072080b,
+    0x0000000000000000, 0x0000000000000000, 0x89285ad9480e39ff, 0x0000000000000000,
+    0x5e00300066edc810, 0x39deeec4c8110c65, 0x0000000000000000, 0xc9a8c2df46c70813,
+    0x4c4549bf323c8814, 0x6567251b744f6815, 0xbdc7e9b6933e8816, 0x0000000000000000,
+    0x622fe22b35132818, 0xd43d20c9e8594819, 0xdfdf8913481a78c5, 0x0000000000000000,
+    0x0000000000000000, 0x6c4faca3d1af881d, 0x0000000000000000, 0xfef31be433d0881f,
+    0x9c862b5b07476820, 0x98f84bdee821fc81, 0xdb8c6342cefe4822, 0xe7653f0766e68823,
+    0x4cbbe96337e1e824, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6dbb282e6828d6cc, 0x0000000000000000, 0xee37817b4da1082a, 0x129967be1677e82b,
+    0xf98dcc19eace482c, 0x5c893307282d196e, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x5920b9706832dbd7, 0x3311febd08338c73,
+    0x0000000000000000, 0xb5960f06f6c1a835, 0xefcecad25698a836, 0xa89f7c190b558837,
+    0x2ca1eed3e6ac6838, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x30e90f987354e83d, 0xdb0f4ac05ada683e, 0x77e72578a2f9883f,
+    0x8da838492f888840, 0x0000000000000000, 0x92c5168c93364842, 0x9f0bae6301a92843,
+    0x39765bbcc84450bc, 0xe72e8e80e2226845, 0x3113bf554ac66846, 0x3bb53e2f451a8847,
+    0x95cf02ecc09bc848, 0xf02ec35374124849, 0x0000000000000000, 0x27bd532ca84b2a1d,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6d84beaa6cdfc84f,
+    0x1c85264c2850b526, 0xbcfbca3acabb6851, 0x0000000000000000, 0x0fb4f6a588530882,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1a7a4262cfc36857,
+    0xbe3e94340858e655, 0x0000000000000000, 0x0000000000000000, 0x6d69f5a3b9db485b,
+    0x0000000000000000, 0x0000000000000000, 0xfc665bdb0357885e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x9b90b7096864df96, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2b34f24e1e90886c, 0x716eba7de86dff51, 0x1c56df4ce86e55ff, 0x0000000000000000,
+    0xdb93e578313ac870, 0x0000000000000000, 0x605e2649b7e6a872, 0xcd07cb8cd72c4873,
+    0x77d4720dbca02874, 0xc668d26beaaac875, 0x11525aba687636e0, 0x3b4d0381e877aed3,
+    0x586e6f0b0878f37c, 0x0000000000000000, 0x0000000000000000, 0xa43dfbe3e90f487b,
+    0xae069cd09d75887c, 0x0000000000000000, 0x5066620a4264487e, 0x8bbaa0d35524287f,
+    0xb9523fd9a2c2e880, 0x0000000000000000, 0x71db2a16eafd0882, 0x0000000000000000,
+    0x706ca1cb52edc884, 0x00a11673888518e3, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x388d87aec420688a, 0x5eac31179655288b,
+    0x0000000000000000, 0xc36a417a488ddd4f, 0xfb79662cc88e9092, 0x0000000000000000,
+    0x629d5270fde26890, 0x21bd62b358ba8891, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd0b4bec6089595ff, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xa10c9fb38899f730, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x37a0ee12289df9cf, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x384bcb2333cb88a1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xe1d6feaaddbf48a6, 0x0ca421f35175c8a7,
+    0x0000000000000000, 0xd3057b418d2428a9, 0x0000000000000000, 0x1da231c95f9408ab,
+    0xa3dbd5b42cc408ac, 0x0000000000000000, 0x22a6f76fce76e8ae, 0xd509396668af3ac1,
+    0x6ed7e00756c6c8b0, 0x0000000000000000, 0x0000000000000000, 0xd7d103bd68b39b1b,
+    0x9f9e3670231708b4, 0x0000000000000000, 0x0000000000000000, 0x876cbf42c8b7fe94,
+    0x6c9ebf1e593d68b8, 0x0000000000000000, 0x0000000000000000, 0x5b88d994c39b88bb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xee1917f6e8c254b8, 0x0000000000000000,
+    0x0000000000000000, 0xcaaf7e4dc9f9a8c5, 0xb3e8d116a8c6fb3c, 0xb6263dd4dedb68c7,
+    0x0000000000000000, 0xabab968052ffe8c9, 0x0000000000000000, 0x9d311bfcbfa3e8cb,
+    0x3107ece3c8ccafa5, 0xf3801596adbe28cd, 0x0000000000000000, 0xfe77d66b432ba8cf,
+    0x2408075428d01f82, 0x024a6a78e8d17c17, 0x1d3f6f2ab7f368d2, 0x4094b9cbc8d35cd7,
+    0x23be8791c995a8d4, 0x0000000000000000, 0x0000000000000000, 0x8abf2cef231aa8d7,
+    0x0000000000000000, 0x0000000000000000, 0xd22a45e7ec1028da, 0x0c2a4ff9a8db2252,
+    0x27d1a81608dc555b, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x29506a9dc8e0a8c7, 0xb12feb0ce8e12ff9, 0x0000000000000000, 0x7c82b68db3e748e3,
+    0xa0b26d371c81a8e4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5591242a88e802a8, 0xcc260ddbd8ade8e9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x12782731a06ba8ed, 0x0000000000000000, 0x312e88e45afc68ef,
+    0xfe8efcf597f5e8f0, 0x0000000000000000, 0x6bdb968b68f20644, 0x730f7a9808f3771c,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xa70233c901ebe8f7,
+    0x677e1712f6f108f8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x7c3ffa77bd3928fe, 0x0000000000000000,
+    0x64bad53f170bc900, 0x0000000000000000, 0xb6af9356890297ec, 0x0000000000000000,
+    0x5b728f86fe2fc904, 0x9c5c300feafda905, 0x0000000000000000, 0x7ed5d41784b3a907,
+    0xae92df1d90222908, 0x0000000000000000, 0xcfeb020c0ac3690a, 0x0000000000000000,
+    0x24543b23b4d3290c, 0x1bad96cea8fe890d, 0x5982cdb8e90e4cb2, 0x0000000000000000,
+    0x7384d319e910bd6a, 0x0000000000000000, 0x38d1600866b72912, 0x6b85f10230bc2913,
+    0xbf826c57f1ede914, 0xf26d7168491558c1, 0xddf96a82483ba916, 0x23d95efcb3048917,
+    0x1c35d6cfbd3c2918, 0x0000000000000000, 0xcf1d8230de1f691a, 0xd036ded68984a91b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe649a2d2c921cae4, 0x8985b7c9e922cb7e, 0xb388027fba170923,
+    0x09ba471de924175f, 0x0000000000000000, 0x4f7db49b6d424926, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4e133ee5173de92d, 0x0000000000000000, 0x047f309bf88b092f,
+    0x5d95ecbf450a8930, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe69b54b345f24935, 0x2035892369363980, 0x0000000000000000,
+    0xd093c80b28236938, 0x0000000000000000, 0x51daaca822f5c93a, 0x0000000000000000,
+    0x3293658dd7fa493c, 0x288a12b96d7bc93d, 0x4a9fdfa553a6a93e, 0x8c26f4e25977693f,
+    0xcc32cce3c0868940, 0x0000000000000000, 0x0000000000000000, 0xaac24344b8bf2943,
+    0xde76ad3d656e8944, 0x0000000000000000, 0x07fb7d4a7f814946, 0x8b585d787019c947,
+    0x2c2d0098b501c948, 0x81a689aec9496ea3, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0bc25481e94effb2, 0x0000000000000000,
+    0xcdf24313c950a92d, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf056be048955c0de, 0x0000000000000000, 0x359af3b15d5e8957,
+    0x7c969ec608cb4958, 0x14dd041e495920c8, 0x0000000000000000, 0x0000000000000000,
+    0x2100b87d695c3919, 0x4ac5ada07d79e95d, 0xb20f4b0a3e4a695e, 0xc357281bd6c5a95f,
+    0x0545e6c209609533, 0x6a791a3babc66961, 0x0000000000000000, 0x0000000000000000,
+    0x875ecce1a22f8964, 0xe26e15f37eb20965, 0x0000000000000000, 0x88a3e51ada3ee967,
+    0x0000000000000000, 0x9a04ef791e0be969, 0x0000000000000000, 0x6d4261197db6c96b,
+    0x0000000000000000, 0x0da3a0ecc687096d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x26d087cc69ee0973,
+    0x97870a4e13796974, 0x0000000000000000, 0x8dcedcde467d0976, 0x0000000000000000,
+    0xa6e77ffca9788c2f, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5a6b7f7ee9807c9b, 0x0000000000000000, 0x4ad2d3fff6da2982, 0x0e524328a983c4d8,
+    0x0000000000000000, 0x0000000000000000, 0xb6fd6098e7b96986, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x41d6ea77498aa330, 0xe3e645e7f719698b,
+    0x0000000000000000, 0x0000000000000000, 0xe53b87e9c98ebf7c, 0x8ce257b941e4698f,
+    0xcc64706e57014990, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xfca3a7d22fcb2995, 0x7dd380dbe9965925, 0x0000000000000000,
+    0x15bf1e9f364fa998, 0x0000000000000000, 0x2cb766a1f6ca099a, 0xb60bce315856a99b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xbb6a57eb0eeb49a2, 0x0000000000000000,
+    0x0000000000000000, 0xbfbf2ccee9a5a809, 0xc16c792da7f369a6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x326112e1ef7049ac, 0x0000000000000000, 0x82f76a261737a9ae, 0x0000000000000000,
+    0x9e4127a4012089b0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6c2eceb51f5509b7,
+    0xf2823f67c9ce69b8, 0x1a9dd616587649b9, 0x0000000000000000, 0x0fec451bc511c9bb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xfbde93ad5f6969ca, 0xdc97770a0ee629cb,
+    0x0000000000000000, 0xe1d1d973cbf389cd, 0xdb1f6a5e69cebac1, 0x0000000000000000,
+    0x0000000000000000, 0xc20abfc65aec69d1, 0xad5779c45346c9d2, 0x0000000000000000,
+    0x3a27d7cd89d4ce51, 0x0000000000000000, 0x3e55edd4c9d6c8d1, 0x0000000000000000,
+    0x0000000000000000, 0xe21f677228b909d9, 0x3e78db089804e9da, 0xfda01c8c022249db,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xfd09e2ade80c89df,
+    0x0000000000000000, 0x0000000000000000, 0x8dd43152636e89e2, 0x0000000000000000,
+    0x0000000000000000, 0x79029fd9a9e5b7fc, 0x0000000000000000, 0x0000000000000000,
+    0xcdb6bb39a9e8f376, 0xff15ce1989e93f6b, 0x7c84027a7359e9ea, 0x16db9be78418e9eb,
+    0x7e476940ea84a9ec, 0xc14a6e9da92be9ed, 0x3624d22fcb7ce9ee, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe6b2c6afcd1f49f4, 0x53d43595292a89f5, 0x0000000000000000, 0xb4fe0dad160829f7,
+    0x0000000000000000, 0x09428478db0349f9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe40896db7faea9fd, 0xfb75ebb3c9febcd5, 0x0000000000000000,
+    0xa45a0a14a3c8ea00, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x151455240a0400af, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x4502d8ccaae9ca08, 0x6dd20fe821686a09, 0xb0c599f6b2218a0a, 0x0000000000000000,
+    0x39acb346c171ca0c, 0xaabf547fc488ca0d, 0x0000000000000000, 0x20591664ea0fa83d,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x5ad70d66ea16a958, 0x0000000000000000,
+    0x0000000000000000, 0xfd578886ea04ea19, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xdb5090968a1ddcd2, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1ed9ed7fde8caa21, 0x0000000000000000, 0x0d7585f96b98aa23,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd7005595deb92a27,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x18bfc8dce722ea2b,
+    0x192c49c7a60a4a2c, 0x0000000000000000, 0x0000000000000000, 0x36554f8bf9244a2f,
+    0x87a44963ce88aa30, 0x0000000000000000, 0x0000000000000000, 0x2cd2182bba6d6a33,
+    0x7ec8d47f7fe08a34, 0x0000000000000000, 0x0000000000000000, 0x2dbe3a2554714a37,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xa90bb1536a3b6690,
+    0x0000000000000000, 0x97f95a3daa3d7f22, 0x80208019ca3e5bc6, 0x0000000000000000,
+    0x0000000000000000, 0x4ee78e9be3394a41, 0x0000000000000000, 0x1504b835e6caaa43,
+    0x2a59cec5e1554a44, 0x0000000000000000, 0x34c9d53292b46a46, 0xa6d1d47daa4750c9,
+    0x0000000000000000, 0x0000000000000000, 0x2dc8b1709e294a4a, 0x0000000000000000,
+    0x0000000000000000, 0xb2e61731ca4da430, 0xd6180fefcabd0a4e, 0xcee1ec80ca4fbad3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xbf74166404866a54, 0x0000000000000000, 0x0000000000000000, 0x3e2ca54a4a574682,
+    0xf76b9c62e1ce0a58, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4bebab03ca5ec231, 0x0000000000000000,
+    0x0000000000000000, 0xb32c018855288a61, 0xfc6311746a2fea62, 0x260037320a638846,
+    0x0000000000000000, 0xf54484f02a4a6a65, 0xa342c5932a66c3cf, 0x276ab65fe5678a67,
+    0xbc04d5411b2f8a68, 0x0000000000000000, 0x88dbff0a9b156a6a, 0x0000000000000000,
+    0x6756a72b8a6c95e6, 0x560a1dbfea6d403e, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe7a7856daa71e6f6, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xa196be86ca75692f, 0x4f7bae389c37ca76, 0x3e6ffbedca7743f0,
+    0xbc14e3e138122a78, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb98ef3f7903e2a7c, 0x0000000000000000, 0x0000000000000000, 0x6815e5816403ca7f,
+    0x0000000000000000, 0x0000000000000000, 0x4636b4ed6a8216d3, 0x0000000000000000,
+    0x43e0349a931b6a84, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x922b392e5f85ca88, 0x5cf1f7eab951ca89, 0x2c3e2350335c6a8a, 0x0000000000000000,
+    0x51dcd230ea8c4fbb, 0x0000000000000000, 0x4d3493a4aa8e2450, 0x9e8768f7c4958a8f,
+    0x0000000000000000, 0x0000000000000000, 0x2d63604faa92c459, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x7af5844f0a96afaa, 0x0000000000000000,
+    0x0000000000000000, 0x089d52fcfb572a99, 0x92a3426c91474a9a, 0x68cbfd1711de4a9b,
+    0x0000000000000000, 0x481b17b2f3016a9d, 0x0e9881d6b0dbaa9e, 0x290a864f5e254a9f,
+    0x617a5767aaa065b8, 0xcaa48239caa18aa5, 0xdf563300caa2f036, 0x0de1a72e33052aa3,
+    0xfb9a13103d9f4aa4, 0x96285a588235caa5, 0xbdb669311db12aa6, 0x0000000000000000,
+    0x0000000000000000, 0xb56100614aa91df1, 0xbf6c5daee0e70aaa, 0x1ae401e814018aab,
+    0x6f1d39bb8cf50aac, 0x0000000000000000, 0x0000000000000000, 0xf61a6da7fbf32aaf,
+    0x6fa0add78ab083f7, 0x0000000000000000, 0x3e21ec88cab27dcd, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x04e9015dbc3feab6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5afc5da3715fcabc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa541208dcac04f83, 0xd2f34adb84c28ac1, 0x0000000000000000, 0x43db6fa154bdcac3,
+    0x0eb545b08ac40916, 0xc4982fce4f034ac5, 0x8665293ccac6bdc5, 0x7fb55ee98fb6cac7,
+    0x2b4e19e50ac8d0b0, 0xadd034474ac93916, 0x0000000000000000, 0x6b0b590af26b0acb,
+    0x10eb6c6416fb4acc, 0xbc640d6ba0b1eacd, 0x0000000000000000, 0x0000000000000000,
+    0x6ed9e70b69772ad0, 0x0000000000000000, 0xb6208d396ad28c10, 0xfe34b3b38ad3cb46,
+    0x0000000000000000, 0x0000000000000000, 0x7a30f1274074cad6, 0xf759c27655a42ad7,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe44d615d8addcc87, 0x27bca7e06ade2ec9, 0x0000000000000000,
+    0x6afdbeee24508ae0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xaad9f365793a4ae4, 0x5c85c1fee9b8aae5, 0xc89ebe579d350ae6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x9756ad818aa56aea, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc1fbb2d537e5aaee, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8c2ea26f0af2e3b1, 0x01925e5f6c83eaf3,
+    0xb788c7ee57338af4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x191dc7bee0b3caf9, 0xdbaec3c81cb24afa, 0x011ed91feafb4c2e,
+    0x66f47e757fbdaafc, 0xb9a2ba36e7a2eafd, 0xdb83b73cc5ab6afe, 0xb2fe325c802a4aff,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x6bd5aca6a99ecb03,
+    0xc587a8eb44364b04, 0x0000000000000000, 0xc6511b6bd6ccab06, 0x0000000000000000,
+    0x0000000000000000, 0x0aa41af1762aeb09, 0xb71f2b852f866b0a, 0x933783532a52ab0b,
+    0x0000000000000000, 0xbe2160a7ab0dc2dc, 0x0000000000000000, 0x0000000000000000,
+    0xe1158bef2b10443f, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x82f2b7cd27926b16, 0x0000000000000000,
+    0x1548b4d6ec90ab18, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd1a72e93b3ccab1d, 0x0000000000000000, 0x77b962128b1fa0a9,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x507da671403e8b23,
+    0x0000000000000000, 0x8b8ec14537748b25, 0x0000000000000000, 0x56dbfc279e2acb27,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x913b257327c1cb2d, 0x0000000000000000, 0x0000000000000000,
+    0xd34acc78d3f4eb30, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe3fd6dc754dbcb34, 0x0000000000000000, 0x0000000000000000, 0x167dec202bf8eb37,
+    0x2145f38fab386a96, 0xe0ceca424abbab39, 0x0000000000000000, 0xe37f9c8c6f070b3b,
+    0x0000000000000000, 0x772130bd41526b3d, 0x0000000000000000, 0xa0715b57ab3fb0cd,
+    0x0000000000000000, 0x0000000000000000, 0xde9abe9de45f8b42, 0x28e3eeeccb439a19,
+    0x0000000000000000, 0x0000000000000000, 0xc25a70d27e44ab46, 0x6b4c251b4b473d5c,
+    0x0000000000000000, 0x0000000000000000, 0x32231a16baf44b4a, 0x0000000000000000,
+    0x0000000000000000, 0x6fd7f147ef5c2b4d, 0x0000000000000000, 0xa51dbd6f1aaacb4f,
+    0x0000000000000000, 0x0000000000000000, 0x5ffee89812be2b52, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x479cf2316fca6b56, 0x5e4cf5b711202b57,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5cf52e62482deb5b,
+    0xd97fa9ec6b5c2742, 0x0000000000000000, 0x0000000000000000, 0xe057efd10b9c8b5f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x47675d71effdcb63,
+    0xd64460166b643757, 0x0000000000000000, 0x0000000000000000, 0x16fc5ab052176b67,
+    0xfe719557f1bceb68, 0x4d5e1abc0b699178, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xbe35c7e6ce21eb6e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xa8bb0564cb73ec54,
+    0xe4c63788dbcc2b74, 0x0000000000000000, 0x8682b0a12b760190, 0x0000000000000000,
+    0x4daccc092b7805a9, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xacbea0c1a8b98b7c, 0x0000000000000000, 0x3e6fc70cb9438b7e, 0xd90d98cd64090b7f,
+    0x0000000000000000, 0x0000000000000000, 0x16c7c6b4b1528b82, 0x0ec7ffc56b83b323,
+    0xc098c07cd43bcb84, 0x52adc4c2e6cb0b85, 0x5c20811ac5476b86, 0x0000000000000000,
+    0x0000000000000000, 0xb9f29596b3e90b89, 0x0000000000000000, 0x3aa4b20c98218b8b,
+    0xd5020e96ab8cc86e, 0x60b380182b8d3708, 0x8eb4514bc5be0b8e, 0x0000000000000000,
+    0x56210846b672ab90, 0x0000000000000000, 0x663dc073f2b68b92, 0xada6e80b01de2b93,
+    0x0000000000000000, 0x4300cd1ed5386b95, 0x50a61e658b964227, 0x0000000000000000,
+    0x0000000000000000, 0x95eb79261bbf4b99, 0xed6257c6829a6b9a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x88e6c035cb9ec907, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xce317a7d9bc42ba7,
+    0x29cf2bd1eba80bc7, 0x9f6a337457978ba9, 0x0000000000000000, 0xf21b84d415b6abab,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x083c733363488bb0, 0x0000000000000000, 0xf66df4c480942bb2, 0x0000000000000000,
+    0x0000000000000000, 0xb046a1530346ebb5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8769f4e3dee78bba, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x87c6b83c7a14abc4, 0x0000000000000000, 0x60f9ee11c6eb4bc6, 0x92025fb32bc76527,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x229e6c3329078bcb,
+    0x0000000000000000, 0x0000000000000000, 0xdf73d53a57488bce, 0x0000000000000000,
+    0xe0678d167438cbd0, 0x0000000000000000, 0xb2bbe68eb780abd2, 0x0000000000000000,
+    0xfefc4119abfe8bd4, 0x00d7f56bad580bd5, 0xc7111b8d6f148bd6, 0x0000000000000000,
+    0x0000000000000000, 0x6eda096d2ce1cbd9, 0x0000000000000000, 0xf417910965b0ebdb,
+    0x0000000000000000, 0x98ba18ff4bdd6f7e, 0xef3b2e3b2672cbde, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xad3d6a9762590be2, 0x0000000000000000,
+    0x291f0b31652d8be4, 0x0000000000000000, 0x91bc62a5a2e54be6, 0x18a858022be70033,
+    0x0000000000000000, 0x0000000000000000, 0x8f9630ae4bea0360, 0xe6ba98c98d82ebeb,
+    0x0000000000000000, 0x0000000000000000, 0xa801b31b6bee4b5b, 0xe25ae41381cb6bef,
+    0xff549ce928494bf0, 0x0000000000000000, 0x1c2b0cc562ecebf2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd512c6c40e6e6bf6, 0x0000000000000000,
+    0x97ecec5c2bf866ea, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x328db63f4a4f6bfc, 0x0000000000000000, 0xfb360bb0c0dd4bfe, 0x0000000000000000,
+    0xcef5c426fc482c00, 0x0000000000000000, 0xfef0914092790c02, 0x0000000000000000,
+    0xc686d26d3ce9ec04, 0x0000000000000000, 0x0cde63a5b0868c06, 0x0f325fd0ac078183,
+    0x0000000000000000, 0x3ff4c00f8c094065, 0x0000000000000000, 0x06faf6b6cbf30c0b,
+    0x8033bc3bc4386c0c, 0x0000000000000000, 0xec1fb9850c11ec0e, 0x0000000000000000,
+    0x27e2f0b308112c10, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xb54f58345aadcc15, 0x453b6460bad4cc16, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x3f37c2706c1ad93e, 0xf330f2881f87ac1b,
+    0x0000000000000000, 0x0000000000000000, 0xfa1db50bf11f4c1e, 0x81889f34cc1fe009,
+    0x358a1a716c20fe5f, 0x2b850cb85c618c21, 0x0000000000000000, 0xead3c6dc47dc4c23,
+    0x0000000000000000, 0x0000000000000000, 0x340111d80c2608f2, 0x0000000000000000,
+    0x552513510c284ccc, 0x57fd3a3568a62c29, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x550a5ceb2fef2c2d, 0x76023231affb2c2e, 0xbbaca7cf6c2fa38a,
+    0x7829982be9b24c30, 0x0000000000000000, 0x0000000000000000, 0x81a61178f9d7cc33,
+    0x437abb63ac34a913, 0x0000000000000000, 0x0000000000000000, 0xf63b25420644ec37,
+    0xecfb006dfc588c38, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xa920803420a92c45, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6786484724f60c4a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x297a17c1ec4e4ff0, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc584ff2c7bbdcc54, 0x50cf56590fab6c55, 0x0000000000000000, 0xde398ef4eef5cc57,
+    0x7a126b5b0a09ec58, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf9e5d3d10b470c65, 0x0000000000000000, 0xd50264b38c67783d,
+    0x0000000000000000, 0xd5c84b5389980c69, 0x4abac634ec6aba00, 0xf3c7be95d9c6cc6b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0924c2c70f3c4c6f,
+    0x0000000000000000, 0x6e301701b5510c71, 0xa1a3cbd66494cc72, 0x66875740d38a0c73,
+    0x87f75b78ca16ec74, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x432b1f2ad7f3ec78, 0x283bce07020acc79, 0x0000000000000000, 0x0000000000000000,
+    0x34465cb79e7ccc7c, 0x0000000000000000, 0x0000000000000000, 0x2a207ee7794c0c7f,
+    0x0000000000000000, 0xfcd28e73166e8c81, 0x0000000000000000, 0x0000000000000000,
+    0x1242563b61e9ac84, 0x0000000000000000, 0x0000000000000000, 0x0aaca6e6678dac87,
+    0x6d17aaf22c88c938, 0x0a9083880381ec89, 0x0000000000000000, 0x0000000000000000,
+    0x541da760ec8ca926, 0x0c762eec2423cc8d, 0x15ddfebaefbb0c8e, 0x0000000000000000,
+    0xda179af343a92c90, 0x82250b802c918b0a, 0x0000000000000000, 0xb7e20b5d2fe46c93,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x9b2829faec973815,
+    0x0000000000000000, 0xbaca24e881e6cc99, 0x579760241969ec9a, 0x0000000000000000,
+    0x0000000000000000, 0x66c1082751078c9d, 0x0000000000000000, 0x0000000000000000,
+    0xac6f34a342e04ca0, 0x4eaa1f818ad7aca1, 0x0000000000000000, 0x0000000000000000,
+    0xae0790449d95cca4, 0x48fdf27635996ca5, 0x0000000000000000, 0x0000000000000000,
+    0x954350b4cf99aca8, 0xb5c28c7a00efaca9, 0x0000000000000000, 0x20d444ed8b9a0cab,
+    0x0000000000000000, 0x3c8e1e2962b2ecad, 0x0000000000000000, 0x3f63d13242e80caf,
+    0x0000000000000000, 0x0000000000000000, 0xacdcc6bdbb520cb2, 0xb5fe782d91afccb3,
+    0x0000000000000000, 0x0000000000000000, 0xef934999182a8cb6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4148939d41dbacba, 0x7ea5ccf04983ecbb,
+    0x0000000000000000, 0x94a9887cb6de8cbd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd38d62450cc2a3ca, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6ff0ff2eb569ccc6, 0x861e33e1ecc70b8e,
+    0x882bb6820de16cc8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb317b96b97caaccc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc979a466e0ab2cd0, 0x182042be21e66cd1, 0x0000000000000000, 0x0000000000000000,
+    0x01f208e027b66cd4, 0xc72ce35f69b90cd5, 0x0000000000000000, 0xba3c4ed061c58cd7,
+    0x0000000000000000, 0x0fd198c105db4cd9, 0x3750bc8199338cda, 0x0000000000000000,
+    0x0000000000000000, 0x223e69cd24408cdd, 0x0000000000000000, 0x66af4fb2a1b36cdf,
+    0x0000000000000000, 0x0000000000000000, 0xcb0336f192250ce2, 0x0000000000000000,
+    0x0000000000000000, 0xc01ca9fb2bbbece5, 0x9d97a10651580ce6, 0x33c6ab2f90f04ce7,
+    0x15cef9e01804ece8, 0x5ff9d2bbace97317, 0xe8d647f3e02e6cea, 0x0000000000000000,
+    0x0000000000000000, 0x371848c4eb628ced, 0x0000000000000000, 0xf608ba2dd0f6ccef,
+    0x0000000000000000, 0x0000000000000000, 0xad3192a04b120cf2, 0x0000000000000000,
+    0x9e1665b16ef50cf4, 0x0000000000000000, 0x3739f2ce0aafecf6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xbe01609c2cffb203,
+    0x4e1e123ae58ead00, 0x24bd60cdad012589, 0x37446c980fb00d02, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe80e25b02bc8cd07,
+    0x0000000000000000, 0xe62063ce1d17ed09, 0x1ce310dccd0ad5a5, 0x288d3ecd627ced0b,
+    0x312a61a4b349ed0c, 0x0000000000000000, 0x0000000000000000, 0x06a8492c97082d0f,
+    0x0000000000000000, 0xcccdda3c6d11abd2, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x4a807155793d8d18, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x3589968d2d1c61c7, 0xdacaefe2ba3f0d1d, 0xd5fb4d8b6d1e938d, 0xd67bd752e8ec2d1f,
+    0x0000000000000000, 0x8369475fc9e52d21, 0x0000000000000000, 0x517486cc0d23b0e0,
+    0x6c4a80024d243275, 0xe309c5919fe10d25, 0x0000000000000000, 0x0000000000000000,
+    0xa259331a02988d28, 0xdb6c9c5af2546d29, 0x0000000000000000, 0x054af2e2e57ced2b,
+    0x0000000000000000, 0x0000000000000000, 0xfd63a37f14fecd2e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x189bc5530d35a652, 0x0000000000000000, 0x47288cdc4d378cc2,
+    0x06f586a090590d38, 0xfd66d9a0f979ed39, 0x0000000000000000, 0x0000000000000000,
+    0x386a50e7ad3c2cab, 0x0000000000000000, 0x0000000000000000, 0x82a31650204e8d3f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xebc167c4e5482d44, 0x0000000000000000, 0x22cedcda36aa0d46, 0x34076bdbc355ed47,
+    0x47546b748d480446, 0xac4feb0470a04d49, 0x0000000000000000, 0x0000000000000000,
+    0x76a7e1837a49ed4c, 0x828741b80f462d4d, 0x0000000000000000, 0x0000000000000000,
+    0xbb98f2b8f9f60d50, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc1b821ee6d54fc36, 0x0000000000000000, 0x0000000000000000, 0xfcd234b959332d57,
+    0x0000000000000000, 0x0000000000000000, 0xfcdbb8eefb586d5a, 0x21b5285971644d5b,
+    0x34e081320d5c8ad2, 0x9d575d436b9ced5d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1dd107040d61fffd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1c064ee939c66d69, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x848f2dd11adead71, 0x479ff4fe60a66d72, 0x0000000000000000,
+    0x3bf629e26d74b807, 0x25526cbcde06ad75, 0x69940b29cc170d76, 0x0000000000000000,
+    0x0000000000000000, 0xe6543a5a2fdd2d79, 0x1ce25fe17283cd7a, 0x0000000000000000,
+    0xb322d8ade0204d7c, 0x53b9f99390744d7d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x643817a61481ad85, 0x0000000000000000, 0x0c744bc0088d0d87,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0139592a9fef6d8b,
+    0xc95b6d73c18b2d8c, 0x7171d50b14904d8d, 0xf3be0722283ead8e, 0x0000000000000000,
+    0xde3d249b33daed90, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf7c2eb8c729aed95, 0x0000000000000000, 0xc1ea534397750d97,
+    0x0000000000000000, 0x00b1b3966880cd99, 0x0000000000000000, 0x9e508aa950098d9b,
+    0xd0123dc2ad9cd2c8, 0x0000000000000000, 0xb0e481b6bf43ed9e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x7475335c46a08da2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x398d1bd60f036da8, 0x0000000000000000, 0x0000000000000000, 0x2aa9449ad5ca0dab,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x7f952fb2cdb2481f, 0x82a1322c73b50db3,
+    0x0000000000000000, 0x0000000000000000, 0xf6502446ece80db6, 0xe5e3382dedb754ea,
+    0x0000000000000000, 0xc6c4deb0eff72db9, 0x0000000000000000, 0xacf3b12a8dbb764e,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xce127541adbf0c9d,
+    0x9cc6da5b8dc047b6, 0xf48aecd82dc189ed, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x57582b8926286dc6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xa480e996edcab954, 0xde098671f01cedcb,
+    0x0000000000000000, 0xc2eeb09badc58dcd, 0x0000000000000000, 0x0000000000000000,
+    0xeac61aa5b9172dd0, 0x5a4c2c2c6dd15cf1, 0x0000000000000000, 0x6e9881ac8dd3c7f1,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1cbfd2eb38028dd8, 0xf08af47eb5778dd9, 0x1390ecfc1e418dda, 0x0000000000000000,
+    0xb9c6d056eddc7672, 0x0000000000000000, 0xfeb19943e4f16dde, 0x0000000000000000,
+    0x0000000000000000, 0xb0411fbb6df58de1, 0x0000000000000000, 0x12ef1c859fe5ede3,
+    0xab2c03d7d6fe8de4, 0x0000000000000000, 0x0000000000000000, 0x0e3701dad491ade7,
+    0x3687da9c4a834de8, 0x9e25679cd32cade9, 0xb97023f0adea7364, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8a4bcc08e66ccdee, 0x0000000000000000,
+    0x0dec9ccd24bfadf0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6b22bfb3898bedf4, 0x0000000000000000, 0x0000000000000000, 0xfd64f58579f1adf7,
+    0x0000000000000000, 0x0000000000000000, 0x26d97f060ec66dfa, 0xa6021970d1a36dfb,
+    0x0000000000000000, 0x682eacbfcdfde682, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x9d26354283c58e01, 0xf04b8653c5582e02, 0xeceb8756ae037f4f,
+    0x0000000000000000, 0x98f7ecb171a22e05, 0x0000000000000000, 0x0000000000000000,
+    0x9533c48ae9150e08, 0xce0a6b9efd2f6e09, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xd5d7a020b7634e10, 0x7cf76520ce11bf20, 0x850c3ff32519ee12, 0x0000000000000000,
+    0x8b4d3d82ee149f42, 0x0000000000000000, 0x2ae326551e3a6e16, 0x723a7d674e178079,
+    0xd69ce2362e189439, 0x46af3aa121bbee19, 0x0000000000000000, 0xcfedbb470e1b9bd0,
+    0x0000000000000000, 0x39fa0d64c6b44e1d, 0x0000000000000000, 0xe1c3bd9c519cae1f,
+    0x0000000000000000, 0x3a481c3d2e21c256, 0xf8c6e30b10fd2e22, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x60a1d53d4e26c70d, 0x3ab6f600b289ee27,
+    0x358174b03d32ce28, 0x4b55d41835774e29, 0x407bd931c5c10e2a, 0x0000000000000000,
+    0x0000000000000000, 0x593ee580af3dee2d, 0x3d246575dd0c0e2e, 0x51cd098082850e2f,
+    0x0000000000000000, 0x0000000000000000, 0x1d1f1ca8b95a4e32, 0x0000000000000000,
+    0x084d6112673d6e34, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x09f339eb4e38d5b3, 0x6c8679ed3a3fae39, 0x0000000000000000, 0x697f8151ee3b87d0,
+    0x78149d65a21a0e3c, 0x707a5e1091d44e3d, 0x0000000000000000, 0x0000000000000000,
+    0xfc024f23e3f42e40, 0x0000000000000000, 0xf98df503ee42c646, 0x0000000000000000,
+    0x0000000000000000, 0x5ee25b8649876e45, 0xe7d8164880e3ee46, 0x05635092ee47c0b9,
+    0x0000000000000000, 0x80a44ea174f9ae49, 0x0000000000000000, 0x0000000000000000,
+    0x34416b1469490e4c, 0x44219f02ce4dc500, 0x0000000000000000, 0x0000000000000000,
+    0x84e77cb7a14dae50, 0x26568e1a04974e51, 0x0000000000000000, 0x0000000000000000,
+    0x3ab23eb8ee54b0b1, 0xc6870960ee55edb3, 0x7321231e0e5622a8, 0x0000000000000000,
+    0x0000000000000000, 0x514761b3ae599cc1, 0x60781c1e7979ee5a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x8eb4f175a57d4e5e, 0x0000000000000000,
+    0xd66fb5c66e603545, 0x148971e0e162ee61, 0xb998afe43af34e62, 0x0000000000000000,
+    0x32cf6ba7ce646998, 0xbc4f3a02e02c2e65, 0x0000000000000000, 0xdeb764d684684e67,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x86addacf8e6bd68e,
+    0x24be038aae6ca958, 0x52aeeba26e6d8209, 0xb9dbb50fae6e6504, 0x0000000000000000,
+    0x0000000000000000, 0x8a3f6dac10684e71, 0x0000000000000000, 0x927f47140e736823,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x988167e4bbfa2e78, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf1086a96ce7c5b1a, 0x0000000000000000, 0xae1b51da1e538e7e, 0xae5c6408ee7f0ac8,
+    0x0000000000000000, 0x0000000000000000, 0xf35e2fa20e823342, 0xb9d8a81ae472ce83,
+    0x0000000000000000, 0x5e2c5d302e854fbf, 0x0000000000000000, 0x0000000000000000,
+    0xf4b608ac0e88afcc, 0x0000000000000000, 0x5f0854406d7a2e8a, 0x0000000000000000,
+    0x0000000000000000, 0xcb9ac7e1db948e8d, 0x0000000000000000, 0x00f270d0483b2e8f,
+    0x98358de34e90a8f0, 0xab3915d52d7e8e91, 0xdc3ad7c54e92faa5, 0x74fb32d6aff3ee93,
+    0xf4dfdef70634ae94, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x48df5198193c8e98, 0x0000000000000000, 0x9f5558ecae9a06b8, 0x0000000000000000,
+    0x1734b4bd6e9cd8a4, 0x0000000000000000, 0x65077df2a0870e9e, 0x0000000000000000,
+    0x0dfee3299975eea0, 0xeae81d503c976ea1, 0x7d11a7becea231f5, 0x771550a8c05feea3,
+    0xe7235bf9a3a06ea4, 0x0000000000000000, 0x7b887b609cf82ea6, 0x787ab2ca0819aea7,
+    0xb216f2a4cb42cea8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8e796e8f3bccaead, 0x6a4e15f34eaede75, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xa608ad83ceb25392, 0x0000000000000000,
+    0x72ac317aedc64eb4, 0x24b5402672a44eb5, 0x0b4dff021ad96eb6, 0x1644f56b366feeb7,
+    0xb00a8db7eeb8b45c, 0x6028a833ec040eb9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x92098667cebdaec5, 0x6538e48e680b0ebe, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x88318b100691aec4, 0xb21a9e394ec5b449, 0x0000000000000000, 0x85bf598a899beec7,
+    0x524fb6645c9f2ec8, 0xd5c410ecb09f2ec9, 0x0000000000000000, 0x0000000000000000,
+    0xe4fa95b82a93aecc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x19e811bfe41f2ed0, 0xba8f396b9e652ed1, 0x0000000000000000, 0xe4c2e89c9fe00ed3,
+    0x0000000000000000, 0xf4848112b25cced5, 0x0000000000000000, 0xa57384428ed73778,
+    0x0000000000000000, 0x1693f2afced96f06, 0x5f202ff6d0f7aeda, 0x0000000000000000,
+    0x0000000000000000, 0xc0e656742b010edd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6510a04b8976aee1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x759e58e6b7698ee6, 0x13563fb68ee772fe,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf4d3b5c9b2c1eeeb,
+    0x0000000000000000, 0x0000000000000000, 0xdda8a41e4b022eee, 0x0000000000000000,
+    0x9dd5d48fe39c8ef0, 0xe51a364f0ebf4ef1, 0x29cf616dcef229d1, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x93f52d29aefa2fcc, 0xc35a70e34f4ceefb,
+    0x0000000000000000, 0x51b1d9a96efd5e76, 0x0260a42e14752efe, 0x5cec847512b28eff,
+    0x0000000000000000, 0x0000000000000000, 0x135b51564f0246df, 0xeb51963c8f03d7d8,
+    0x5ed7d25ef271cf04, 0x0000000000000000, 0x1c8e5f49cf06c9f9, 0x0000000000000000,
+    0xd78bab0a5a08ef08, 0x0000000000000000, 0x948c32393fbecf0a, 0x0000000000000000,
+    0xdd87fc17ef0cf231, 0x8b2b03e8e8690f0d, 0x1da59c5b56f3ef0e, 0xe82e877fcf0f9f08,
+    0x6cb57c8f745d8f10, 0xcccb1cc219d6ef11, 0xb0d392166f128d0f, 0x74f77524ef524f13,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1dc28dafb7c9af1d, 0x0000000000000000, 0x0000000000000000,
+    0x0dfa2bab6be5af20, 0x5bc47bf4eb7daf21, 0xba43d8e7aaccef22, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2732c7ae04db2f27,
+    0x0000000000000000, 0x77c1f38a9122af29, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6ed845c83f26ef2d, 0x0000000000000000, 0xeca205e0af2fadab,
+    0x0000000000000000, 0x0000000000000000, 0xec0c9e9d3d0e0f32, 0x0000000000000000,
+    0x7a7b4f23f3974f34, 0x8fc609339ab46f35, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x172c3b771f4f6f3c, 0x51160d3e6f3dd332, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xca3ef7fe2e988f41, 0x141a52a7ebc70f42, 0x0000000000000000,
+    0x0000000000000000, 0xc361a92c01bf2f45, 0x0000000000000000, 0x7e291271ad3d6f47,
+    0x505b61b2c2c1ef48, 0x0000000000000000, 0x0000000000000000, 0xb67f32bd3c5bcf4b,
+    0x0000000000000000, 0xa15c6287dd0c2f4d, 0x0000000000000000, 0xf5797b06e9202f4f,
+    0x219cdf586f50f17a, 0x6cf840e7a7d9ef51, 0x94cdaf75cf52f356, 0x0000000000000000,
+    0x0000000000000000, 0x3064b0c7a109cf55, 0x0000000000000000, 0xcaef09ad46ee4f57,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x5f6cd2dca9cb6f5d, 0x0000000000000000, 0xb5df6b015cf20f5f,
+    0x0000000000000000, 0x0000000000000000, 0x599e7831ef6235b6, 0x0000000000000000,
+    0x36fe649693924f64, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x41b6e23fef6ac42a, 0x0000000000000000,
+    0x37f5429ab52c0f6c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x909c73b38f70c243, 0x3d059810145d0f71, 0x0000000000000000, 0x0000000000000000,
+    0x21a02ce7b1d08f74, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd5d2ee230f7af7b1, 0x0000000000000000,
+    0x43cf2ad80f7c6ca4, 0x0000000000000000, 0xa71e170eef7e6bd0, 0x0000000000000000,
+    0x0000000000000000, 0x67717ab0f0ad2f81, 0x83080183b6664f82, 0x2dbd732b7b8aef83,
+    0x369c2004dc9bcf84, 0x0000000000000000, 0x0000000000000000, 0xaecc9db42f871bc6,
+    0x0000000000000000, 0x0000000000000000, 0xc5fa3b60d6544f8a, 0x0000000000000000,
+    0xa8c32a608f8c2765, 0x3eda6d7c2f59cf8d, 0x680c68772f8e0136, 0xf0e0b30a513aaf8f,
+    0x0000000000000000, 0xfddb63c82cf0af91, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa292cd64c3f76f98, 0xf70c51658f997095, 0x0000000000000000, 0x0000000000000000,
+    0xca6b7323f95f4f9c, 0x0000000000000000, 0x6852b949a1478f9e, 0xd72274ed2f9f0761,
+    0x91bb9fe24fa0fd70, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x27df4bbb74ec8fa5, 0xa46e2433264d8fa6, 0x7bb7aa5bafa71b26,
+    0x0000000000000000, 0xb371f91e3b422fa9, 0xbe3af9866faa3e86, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xac9efec3c8d92fae, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb663d77520060fb6, 0x0000000000000000,
+    0x0f66d2d883a1efb8, 0x0000000000000000, 0x0000000000000000, 0x3d7376860fbbaac7,
+    0x0b4f9a2e3677afbc, 0x8e0e5d6a771e6fbd, 0xb94532e64fbe09e8, 0xeefd40856fbf75a0,
+    0x9ab5d5fba864cfc0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5b9505f4e70f6fc4, 0x0000000000000000, 0x8df297fb4fc65c17, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x27fa279ecc770fcc, 0x0000000000000000, 0x63a5cc36859d4fce, 0x848eb749acc2efcf,
+    0x0000000000000000, 0xe229df28cfd15991, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf836737e8d120fd5, 0x11303fea9dd94fd6, 0x5322ca363516afd7,
+    0x6bfd2d28ef260fd8, 0x0000000000000000, 0xc399a8ecf41f4fda, 0x0000000000000000,
+    0x0b5ec8b7f0162fdc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x456079aa6e2aafe1, 0x0000000000000000, 0x0000000000000000,
+    0x30c828be9862afe4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x868933f16e8fcfea, 0x2e9757f7b4a28feb,
+    0xe23fbba90fec70b1, 0x0000000000000000, 0x06683d2f81bdefee, 0xa83a09a06e78efef,
+    0x1abc19472589aff0, 0x0000000000000000, 0x18b63b004ff2c8c7, 0x0000000000000000,
+    0x0000000000000000, 0xc267ae13df5eeff5, 0x0000000000000000, 0xcec553caaff7a5d5,
+    0x0000000000000000, 0xb18a8667706deff9, 0x0000000000000000, 0x11333c3be9e1affb,
+    0x0000000000000000, 0x0000000000000000, 0xcc471ec7cd86effe, 0xc6513ca80ffff05c,
+    0xde7402914b911000, 0x0000000000000000, 0x0408491a1002e444, 0x0000000000000000,
+    0x127bd7690e5ab004, 0x0000000000000000, 0x0000000000000000, 0xf8dc07ca7007c90a,
+    0x67fb8bbcb0083ebb, 0x26835f971bf55009, 0x7f9bba22d00a938d, 0x0000000000000000,
+    0x0000000000000000, 0x61f9e76b92d9700d, 0x0000000000000000, 0xc03c1ad07ddd100f,
+    0x0000000000000000, 0xe2692763750a3011, 0xb7a1739f901202bc, 0x49457cfa3013a2eb,
+    0x076d40a9287c5014, 0x5efcbbbba6b09015, 0x51d8e35e901616b9, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xbaab3906f201101f,
+    0xfa481b52902001b8, 0x0000000000000000, 0x0000000000000000, 0xb99fa2b1b81bb023,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6f26b0da15b91029, 0x7b9413e78b2ef02a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x839d10d28dd6d030, 0x0000000000000000, 0x0000000000000000, 0x0d7fbd4064c0b033,
+    0x0c5122801aa2b034, 0x0000000000000000, 0x16a2f1d7322f9036, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf0c9818d507db03b,
+    0xe0cb8aad38ac903c, 0xd227cd91bbb6303d, 0xec5324a2103eabd5, 0x0000000000000000,
+    0x0000000000000000, 0x9eea61e0ba225041, 0x0000000000000000, 0xb53e88225b1af043,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x46fe5e5ce06a3048, 0xc9d755b201d43049, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x345af36b1f6e904d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd13a65149babd052, 0x0000000000000000,
+    0x4c68827616a01054, 0x9634990170553704, 0x0000000000000000, 0x89e32b96fedfd057,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x58d1ce16905cf65c, 0x8873110cf9c7d05d, 0x0000000000000000, 0x0000000000000000,
+    0xededa7b65cb09060, 0x5ba67ba213cf3061, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xfa3e2759f06666f6, 0x0000000000000000,
+    0x5e6ae8064d99f068, 0xa1f5a9e92ae9f069, 0x0000000000000000, 0x9a73a305f06b9800,
+    0x0000000000000000, 0x0000000000000000, 0x69345c66706ecdca, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd58663f07076be2d, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x99bf2ec3707caf3d, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xfd3813a25c729080, 0x75ee74c7b1c3f081, 0x0000000000000000, 0x0000000000000000,
+    0x990aa31b9712d084, 0xf7c99abe7085eae4, 0x8225369d26719086, 0xe5f65852cc0ff087,
+    0x522aba92a8ac7088, 0x0000000000000000, 0x057edfdaac84108a, 0xf4a795ae1162108b,
+    0x0000000000000000, 0x5feb7316408dd08d, 0x742835e8f5fc508e, 0x50cfe97bb24e708f,
+    0x0000000000000000, 0x0000000000000000, 0xcfb0df48b9595092, 0x904d76419093bb87,
+    0x0000000000000000, 0xc8f8fc2619d27095, 0x0000000000000000, 0x0000000000000000,
+    0x327170d52e673098, 0x0000000000000000, 0x0000000000000000, 0xdd0211d4fb70509b,
+    0x0000000000000000, 0xabab81a9d09d2bea, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x943423a1d26050a3,
+    0xb2485549815d30a4, 0x0000000000000000, 0x0000000000000000, 0x1dea2c0a0074d0a7,
+    0x039958e2061a50a8, 0x0000000000000000, 0xc849638fab97f0aa, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x76ed8203b0ae54f5, 0x0000000000000000,
+    0xed70c492d0b072c4, 0xe326ddd2a12010b1, 0x34709dda5559f0b2, 0xf4066444ada3f0b3,
+    0x938008dbc40510b4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5213bb44567b10bb,
+    0x385a65f050bc6f9e, 0x0000000000000000, 0x56f5f490f42110be, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x18863116fe9470c3,
+    0x0000000000000000, 0x0000000000000000, 0xe0c8faff03e830c6, 0x0000000000000000,
+    0x0000000000000000, 0x48d7af8390c9ab85, 0x0000000000000000, 0x9f4ee387d0cbee2f,
+    0x3e64b552446330cc, 0x1bc1b477de1b50cd, 0x9907cfae483690ce, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd062e04ad0d21ce2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb3cdd3207048f0d7,
+    0x0000000000000000, 0x0000000000000000, 0x950ad39e59d690da, 0x65d81b740f60d0db,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xa0ce68713d5d90df,
+    0x1fa57baab0e0e14c, 0xab99cb3730e1b0f0, 0xb1f7619870e2983d, 0x0000000000000000,
+    0x8c8cea8618d530e4, 0x0000000000000000, 0x3e31145fd58f30e6, 0x0000000000000000,
+    0x0000000000000000, 0xffc67b71051c10e9, 0x1d969a83b27570ea, 0x0000000000000000,
+    0x213753c8a10570ec, 0x7f1c2b78aaa3b0ed, 0xc2593e5750eea8c2, 0x0000000000000000,
+    0xa46884ab7c0250f0, 0x9ad91e6075be50f1, 0x0000000000000000, 0x5a74f978d000f0f3,
+    0x0000000000000000, 0x0000000000000000, 0x240fdbaed0f66242, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x8e2aab807bc2d0fb,
+    0x0000000000000000, 0x1cc388e5cf1d70fd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x92c4bb667f2cb101, 0x0000000000000000, 0x0000000000000000,
+    0x4474154ac7b4b104, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe634878c4d46f10c, 0xa0caed0fe983910d, 0xb41d3f12910e9c3c, 0x0000000000000000,
+    0x0122fa8cad1ad110, 0x0000000000000000, 0x0000000000000000, 0xf4a23c81559e9113,
+    0x0000000000000000, 0xc8cfe290f11515aa, 0x0000000000000000, 0xa1dfc4f062cbf117,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x408fee27dd05b11b,
+    0x0000000000000000, 0x0000000000000000, 0xa948a25ea242511e, 0x8ceda37b383a311f,
+    0x286aa4533120d808, 0x0000000000000000, 0x0000000000000000, 0x5e19e1c6de433123,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x52e76ff5a999f128, 0x07dfdc18d7a7f129, 0x0000000000000000, 0xaf4e444ad58a112b,
+    0xe4114b12741af12c, 0xfd252b11445cb12d, 0x0000000000000000, 0x5be1a9035fcd312f,
+    0x2e30ab419b1bf130, 0x0000000000000000, 0x814f81c329d9d132, 0x23be79db1411b133,
+    0x0000000000000000, 0x0000000000000000, 0x2a4ca69cc7061136, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0854a3f652e0d13a, 0xaea0e73c713bc6a3,
+    0x0fc6d05bf13c4924, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8b2d4de1314957bc, 0x0000000000000000, 0x0000000000000000,
+    0x1fa86ffd714ce28e, 0x1827f6e0c403314d, 0x0000000000000000, 0x0000000000000000,
+    0x0bdfdbce52af1150, 0x0000000000000000, 0xec79a2d1315277c6, 0x67fd729a91539c0d,
+    0xd4a6a65b116a9154, 0x2fb2ad3e24c3b155, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2c6dcbc9ac6b715a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x78b15ca77514d15e, 0x2618b403f15fa878,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa62fa4a18441b164, 0x0000000000000000, 0x0000000000000000, 0x3405ac3da69e7167,
+    0x0000000000000000, 0x0a0ba237448f7169, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0ea1223b116e8faa, 0x0000000000000000,
+    0xa316133bcc3d9170, 0x28a02968fcd7b171, 0x936e6f58baa45172, 0x9db37a969173d83c,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe9644fbab3a83178, 0xf5cab42aec505179, 0xf84466b0635a117a, 0x3ff3ffb0439c917b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd4f4d5401f97d183,
+    0x0000000000000000, 0x86d73a3197979185, 0x207249bafd627186, 0xacb6ec6bb1877ef1,
+    0xecc79ca6fdc07188, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xed03a29c318db170, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x771f41b8d09a7191, 0x919a8b84eec8b192, 0x0000000000000000,
+    0x0000000000000000, 0x310f8e0971953bb4, 0x51ddebd56d27f196, 0xa5dc1cd451979113,
+    0x973d0d815198a491, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x652b46445ff8919c, 0x0000000000000000, 0x4c4d07e848e5d19e, 0x3fb23ecdadd3b19f,
+    0x0c034f0d10f7d1a0, 0x0000000000000000, 0x070a58d0b1a26d23, 0x0000000000000000,
+    0xe60f3020e30451a4, 0x371568ae99cf31a5, 0x0000000000000000, 0x6ae44ee52959d1a7,
+    0xab2528ca4a3311a8, 0x4dc71ceeaf5db1a9, 0x0000000000000000, 0x78a8a0bb4e70f1ab,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xd95ba7d36bd531b0, 0x0000000000000000, 0x0000000000000000, 0xb30d860351b3d0cd,
+    0x0000000000000000, 0x722fbf04b8bed1b5, 0x707500a7ab0c31b6, 0x70f3de77f1b791d0,
+    0xa1198e7d51b8e6e3, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1e5d01ba02c451bc, 0x0000000000000000, 0xc8ac0c82c135f1be, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xda15ff512aa291c2, 0x0000000000000000,
+    0x0000000000000000, 0x0d5ba707d1c5f574, 0xdd6a082ce68b71c6, 0x0000000000000000,
+    0x0000000000000000, 0xf979323d886031c9, 0xf34b39449739f1ca, 0x0000000000000000,
+    0x60e6604e99ec71cc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5952744eb1d0c066, 0x0000000000000000, 0x0000000000000000, 0x6f6c12f1e80571d3,
+    0x0000000000000000, 0x3020e0d6e514f1d5, 0x0000000000000000, 0x0000000000000000,
+    0xb6104309e0b031d8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf0d38934d1dc3e48, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x82bb5cb647f451e0, 0x0000000000000000, 0x396c60997cb8b1e2, 0x0000000000000000,
+    0x7b6f486dfc3831e4, 0xf5dfdae4be7fd1e5, 0x0000000000000000, 0xf81db31851e76c37,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc7e5cd5851ed1565, 0x13d022bb11eee120, 0x6ce0f1d74bda31ef,
+    0xf3b34716fa2f31f0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2ba52b8692b771f4, 0xce1f069e4afdb1f5, 0x0000000000000000, 0x9796052b6d8f11f7,
+    0x0000000000000000, 0x0000000000000000, 0x896c7da851fab38d, 0x0000000000000000,
+    0x1e6603fb8a3911fc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x7549ca6d03bb7203,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8dfc86aa42d0f209, 0x0000000000000000, 0xc1126cf59493320b,
+    0x0000000000000000, 0x9011223161f2f20d, 0x0000000000000000, 0xa2284965320f519f,
+    0x0000000000000000, 0x152c649b1d2af211, 0x5622e69832126e1d, 0x30cbc10c921301ed,
+    0xa0fe3e47666f9214, 0x5b9962e1f117b215, 0xe8b4736d1180f216, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf0842cffd21a9f64, 0x72a4693e521b5518,
+    0x0000000000000000, 0xf93326474bb3321d, 0x72b0aaa7e028b21e, 0xf31ab5850491d21f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1b193f8324eb9223,
+    0x0000000000000000, 0x1798e0761d467225, 0xce51e12abdf7f226, 0x0000000000000000,
+    0x16a167a51228be75, 0x0000000000000000, 0x0000000000000000, 0xb7da5ba45e2af22b,
+    0x0000000000000000, 0x6dc0b5a5a77e722d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x2f4b02a6ca953231, 0x6649ba27a97f1232, 0x0000000000000000,
+    0xf4d8cccc55979234, 0x94484969aac87235, 0x0000000000000000, 0xad87b7dbac2bd237,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe769995ff2e4b23b,
+    0x8b172925b23ccc29, 0x23d1222c2119923d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xefede5187243abd6,
+    0x0000000000000000, 0x4ecb402c4f453245, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa52e703fffa5b24c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x7bede0e29e583251, 0x4fc50e6041fcf252, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x055bc57452585ec6, 0x5e4012175259e646, 0x496b7189ca74325a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0022d36e7cfc725e, 0x3f1e8819f63b725f,
+    0x0000000000000000, 0x0000000000000000, 0xe660d9ca3082f262, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x24d6acc19268fb1a, 0x0000000000000000, 0x0000000000000000, 0xfc2f8ff8e39cd26b,
+    0x0000000000000000, 0xee7551b3330d126d, 0x0000000000000000, 0x0a0feab23b88b26f,
+    0x01d61a2af9571270, 0x0000000000000000, 0x416d7eea65291272, 0x935cbd9e6fea9273,
+    0x0000000000000000, 0x08c678354256b275, 0x0000000000000000, 0x0000000000000000,
+    0xa9bb1c4c1dd7f278, 0x4ad49e2a6c9e5279, 0x0000000000000000, 0xef042cfc327be652,
+    0x0000000000000000, 0x4786e6e6d16e527d, 0x0000000000000000, 0x0000000000000000,
+    0x75ba053db28087be, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc69a25a19284f592, 0xbecf939194133285, 0x0000000000000000, 0x0000000000000000,
+    0xdf051c914c749288, 0x0000000000000000, 0x0000000000000000, 0x9bdb41b9f890328b,
+    0x9c6e945639a7128c, 0xd16037d21773528d, 0x0000000000000000, 0x0000000000000000,
+    0x8bb591df7a9ef290, 0x0000000000000000, 0x81bda4b648b13292, 0x0000000000000000,
+    0xfe0873279ab5d294, 0x0000000000000000, 0x0e5b23845b5b5296, 0x0000000000000000,
+    0x930ff24fec9af298, 0x0000000000000000, 0x0000000000000000, 0xaaeb6ac3731b929b,
+    0xfdcad04ad4b3f29c, 0x0000000000000000, 0x0000000000000000, 0x33bba03b86d5329f,
+    0x9408c273f8ef32a0, 0xefd8c1cdaee832a1, 0x99ea6a1d19e272a2, 0x0000000000000000,
+    0x0000000000000000, 0x3a90471d685192a5, 0xbeee16904db772a6, 0x2e4b35ba8fdc92a7,
+    0x0000000000000000, 0xcc0b4687b2a9be4e, 0xef13b01f72aa0cab, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xbc55a25e7eb212af,
+    0x693bfa8ad2b08a09, 0x0000000000000000, 0x676dad928c67f2b2, 0x0000000000000000,
+    0x0000000000000000, 0x6309d8a4061ef2b5, 0x6afc31699ce592b6, 0x91d86553636972b7,
+    0xfcffb09432b80f7e, 0x0000000000000000, 0x0000000000000000, 0x84ef96f24d9a32bb,
+    0xfd6453198d5a92bc, 0x0000000000000000, 0x3005a8d423f8b2be, 0x0000000000000000,
+    0x0000000000000000, 0x906b7498990db2c1, 0xe4537ffe92c271a5, 0x0000000000000000,
+    0x25e0621e92c4631c, 0x90fec930ad5452c5, 0x2b6b8e6cd2c6c98e, 0x0000000000000000,
+    0x1c8d456012c833fc, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x9afaffc032ccbd4f, 0x0000000000000000, 0x79416fde1006d2ce, 0x6207140892cf2ed9,
+    0x4ac38bf898f9d2d0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xcfd5f8b1553532d9, 0xa3c9cafad550b2da, 0x0000000000000000,
+    0x0000000000000000, 0x0a2d6d9c39d772dd, 0x0000000000000000, 0x0000000000000000,
+    0x29a8e0b80251f2e0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x44e351d3c584b2e4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x286894bc1ed9d2e9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4e137c7c12ed0307, 0xea06a918d2ee8acd, 0x0000000000000000,
+    0xb656dd6ea8c5d2f0, 0x5c7a703291c892f1, 0xa7bb2e1f32f232b2, 0x0000000000000000,
+    0x0000000000000000, 0xc9ee6ad3c116d2f5, 0x0000000000000000, 0x895622ee86a1b2f7,
+    0x0000000000000000, 0x7b8db71333e272f9, 0x7ec25c507d2cd2fa, 0xad07765be0e872fb,
+    0x58ccbacb3f4592fc, 0x0000000000000000, 0x32704ae68153b2fe, 0x79f50c669ff572ff,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x047cc0df7304164e, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8d22ea9bcfc15309, 0x0000000000000000, 0x0000000000000000,
+    0x288e86d0330c48d1, 0x0000000000000000, 0x0000000000000000, 0x688ea940330ff4c3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xfe15d76b8a6a9316, 0xfd46ece593174572,
+    0x9e62033bb1699318, 0x1de226ff97adf319, 0x2983583fd2e7531a, 0x60194380931bf9a5,
+    0xc3562102531cbc2e, 0x0cf4fc94b31da3c5, 0x0000000000000000, 0xa385475b531f7ac7,
+    0x0000000000000000, 0xdb2f28ffb321f8eb, 0x8fbdee6a2d211322, 0xfa80186eeceb5323,
+    0x6211bb16bac09324, 0x0000000000000000, 0x92c121b5d7b73326, 0x1fa812e4c267d327,
+    0x0000000000000000, 0xd0544ee5c6e07329, 0x749117625f94d32a, 0x0000000000000000,
+    0x0000000000000000, 0x7307c70bb32d746c, 0x3018c030b66af32e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x019bb7e693325ee1, 0xeedc048793339e96,
+    0x6cc30d9bbb897334, 0x0000000000000000, 0x1d618fb004d3f336, 0xee164f257337c8d4,
+    0x0000000000000000, 0x84dbd8af501cb339, 0x89550a35df16f33a, 0x0fd2eca851ab333b,
+    0x9f0154c1f76fb33c, 0xe64f39b9f8b0533d, 0x75f67ade933e5754, 0xd80b6b54933ff086,
+    0x0000000000000000, 0x48f1ccff7096d341, 0x97c98ec43ee4f342, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf202f5efd34c5948, 0x0000000000000000, 0x0000000000000000, 0x656c0361129e334f,
+    0x0000000000000000, 0x20c33f1db351a7c6, 0x0000000000000000, 0x0000000000000000,
+    0x31ffb029ac2bf354, 0x0000000000000000, 0x2e4e99211356971c, 0xa09fbbfcce697357,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x37267e980eccf35b,
+    0x422ef5991b4c335c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc6e2226c0775b364, 0xdb389966fcbbd365, 0x39fe2eaf80b27366, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x7c88cb12336c357a, 0x0000000000000000, 0xcbece663285f736e, 0x0000000000000000,
+    0x10fad9d955811370, 0x0000000000000000, 0xc8cbf4d76531f372, 0x1bd0e76ce8e61373,
+    0x0000000000000000, 0x0000000000000000, 0x8819513414089376, 0x365a4b1cb377c7d3,
+    0x0000000000000000, 0x401a604c2ab09379, 0xd53a4127ac0f937a, 0xa36ddd563dda337b,
+    0x41e36dab337c5c76, 0xab960ed58c6cf37d, 0x0000000000000000, 0x065084d5d37f0a88,
+    0x0000000000000000, 0xf872b4d99b319381, 0xf0874669d382a4da, 0x0000000000000000,
+    0x048b413d44cfd384, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x215c401b7b1ef38a, 0xce56a4b790f2d38b,
+    0x23a1cfceb38c5fe0, 0x082c62f320ef938d, 0x238fd6ce8b18338e, 0x0000000000000000,
+    0x414a0d195f903390, 0xb08e4a0fb2fd1391, 0xda4f24ffa42a9392, 0x0000000000000000,
+    0x0000000000000000, 0x5911d18dafa25395, 0x0000000000000000, 0xe3de7be7a4eeb397,
+    0x8832ab22eaedf398, 0x0000000000000000, 0x0000000000000000, 0x4a7f3657f2f5939b,
+    0x0000000000000000, 0x00af8b8cb21e939d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xed739e4df3a49d71, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc67ad94e836ad3a9, 0x0000000000000000, 0x872b680e7fb933ab,
+    0x3f6eae481b07d3ac, 0xcb355b5013adb4e0, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc1628369350673b5, 0x35367674d01753b6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5fb0d95586ceb3bb,
+    0x0000000000000000, 0x0000000000000000, 0xa982c712e9c7b3be, 0x206b5712eae8b3bf,
+    0xacf4e8b7cc7333c0, 0x0000000000000000, 0x816f95a44a7733c2, 0xaf8cbe76341a93c3,
+    0x10d41897e87bb3c4, 0x0000000000000000, 0x0000000000000000, 0x5ac5ab870e6553c7,
+    0x6a6d44d5508df3c8, 0x5f6048ee0f0ff3c9, 0xdc678c0f6761b3ca, 0x8adc6211e25b93cb,
+    0x7829f68e93cc5570, 0x0000000000000000, 0x0000000000000000, 0x90ba9ad793d013cf,
+    0xe42c1afe33d0f1ed, 0xa1b518b217fed3d1, 0x96f8f55105d953d2, 0x49234e25b35913d3,
+    0x0000000000000000, 0xc8876f91259bf3d5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6b5a2f20182bf3d9, 0x605919c9daa6d3da, 0x0000000000000000,
+    0x0000000000000000, 0x7cb4aa04241953dd, 0x51ed07f946f853de, 0x0000000000000000,
+    0x39c3f438250e33e0, 0x471eaa979d1333e1, 0x0000000000000000, 0x5a0a498bf3e359fa,
+    0x0000000000000000, 0x1aafc27e613093e5, 0xfca37084eedbb3e6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2a2cd98415c433eb,
+    0x0000000000000000, 0x28540420d40773ed, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xdb1f7e2f67d0d3f1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x85a603f61d3e53f9, 0x5ca4eee5414f93fa, 0x0000000000000000,
+    0x5ee7d74c019e73fc, 0xf273a2eee66913fd, 0x6c41a08a3a6dd3fe, 0x0000000000000000,
+    0x0faf2664b400084f, 0x0000000000000000, 0x0000000000000000, 0x9a07a3a8aa5d7403,
+    0x0000000000000000, 0xe62081d8140512a6, 0x98fc1646fbe2b406, 0x2106a22bd4999407,
+    0x0000000000000000, 0x0000000000000000, 0xc476faac5343340a, 0xfc96868aafd7140b,
+    0x4e6545385d49b40c, 0x9d033314b2d7340d, 0x0000000000000000, 0x0000000000000000,
+    0x7f62a5a7459e7410, 0x0853cd5d9783d411, 0xd16780ffed02f412, 0x0000000000000000,
+    0x0000000000000000, 0x6674ec55a1791415, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8afc5feb5598f419, 0x0000000000000000, 0xdf95a75b341bb8ee,
+    0xa511f7d32243d41c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf530740cb42017bc, 0xd4f93322ba795421, 0x0000000000000000, 0x0000000000000000,
+    0x75037839d1ae9424, 0x9eaf18465ae15425, 0x0376a94274269877, 0x0000000000000000,
+    0x21f000cb94282b3f, 0x0000000000000000, 0x7f8eb6c2320b142a, 0x8e64dc0002dc142b,
+    0xf50931c7020a942c, 0x70f9a712c6c1b42d, 0x0000000000000000, 0x0000000000000000,
+    0x3466dec6a0c4f430, 0x0000000000000000, 0x0000000000000000, 0xc9ab32e5ce61d433,
+    0x0000000000000000, 0xf7188a38d0c15435, 0xf7b41541b436939b, 0x0000000000000000,
+    0x0000000000000000, 0x4b99fd2cb43955a0, 0x0000000000000000, 0x93ca89033e26343b,
+    0x0000000000000000, 0x0000000000000000, 0x7a4555d3e319543e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb873addb76dcf444, 0x0000000000000000, 0x981188c9a5085446, 0x0000000000000000,
+    0x23c0e8bb2ade3448, 0x220ebff3b16af449, 0xf8636f49f44a464f, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xaded15d9944e14b7, 0x0000000000000000,
+    0x2a1e18f54f093450, 0x39fe25c3473e7451, 0x12fe2ceeb452f259, 0x0000000000000000,
+    0x0000000000000000, 0x12e8eb3b379b3455, 0x701990f8973a5456, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x778df4de545c0514, 0x839f5470dcb0945d, 0x0000000000000000, 0xc98bbcd2aa80b45f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x7193e33b946632c6, 0x0000000000000000,
+    0x527a563b2fd6d468, 0x8a835ddaf4692615, 0xfc1344801ed1d46a, 0xb4f684b7a796146b,
+    0xee8caa54346c31b6, 0x0000000000000000, 0x0000000000000000, 0xf3bb0a1ced14d46f,
+    0x0000000000000000, 0x7687dc48a70d7471, 0x0000000000000000, 0x0000000000000000,
+    0x237c6d2c9445f474, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf68bfde162e6747c, 0x0000000000000000, 0x618ca02cd5b5147e, 0x0000000000000000,
+    0x0342d5d281c59480, 0x9d8f0e8a6ffe7481, 0x616a68ef4e7d7482, 0x0000000000000000,
+    0xf51c9e5eb4f0b484, 0x0000000000000000, 0x756438dc6e7f1486, 0x0000000000000000,
+    0x0000000000000000, 0x2eba69609489108a, 0xcfd6f63e6939b48a, 0x8f1457ef07d4148b,
+    0xe762b7a85fea148c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1ec65dfbce37f490, 0x0000000000000000, 0x0000000000000000, 0xa784c52246d2f493,
+    0x0000000000000000, 0xbe27551d5495567b, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x154e8f24fa38f49a, 0x9c4a9b8c4f53d49b,
+    0x0000000000000000, 0xa9243805447af49d, 0x0000000000000000, 0x0000000000000000,
+    0xddb13031163e94a0, 0xe09f525e358994a1, 0x760e44b89b0e54a2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xda764dc53a2b34a7,
+    0x63ffd370b4a859df, 0x0000000000000000, 0xab2ad0b5212bd4aa, 0x0000000000000000,
+    0x4b40f4da342f54ac, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x31136dbebd9254b1, 0x0000000000000000, 0xb3c9c81476e8d4b3,
+    0x0000000000000000, 0x04402a2cea1654b5, 0x0000000000000000, 0xc5a6513e5ef1f4b7,
+    0x538808fd94b87235, 0x0000000000000000, 0x0000000000000000, 0x2b5d806594bb5b7a,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa2ffdd5adf4414c0, 0x0000000000000000, 0x0000000000000000, 0x37910652d4c37dc3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1580888d5a0c54c7,
+    0xd33dfa4a42bd94c8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0572c529972794cc, 0x6c0e9714aba754cd, 0xcb7ca3990f3ef4ce, 0x999b7b1df94474cf,
+    0x0000000000000000, 0xcf8362a839c934d1, 0x0000000000000000, 0xa3d0735c34d3541b,
+    0xe2924c1f280c14d4, 0x92ae8b27d9bdb4d5, 0x0000000000000000, 0xad4a6434f4d74a92,
+    0x0000000000000000, 0xe243d26b34d9abce, 0x8e45c3708fe454da, 0x5206a199b4db8833,
+    0x0000000000000000, 0x912f343bfac954dd, 0x05eaf79634de319f, 0x0000000000000000,
+    0x3c211024033bd4e0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0f4961869cedf4e5, 0x18aa5f45adfcd4e6, 0xafc37520cd1274e7,
+    0x0000000000000000, 0x653b22158a8df4e9, 0x972e150034ea6a6c, 0x0000000000000000,
+    0xf822200fb76514ec, 0x75fcb79fdf1f74ed, 0xc870f9f754eee572, 0x0000000000000000,
+    0x0000000000000000, 0xe5516c3714f18aa6, 0x0000000000000000, 0xf64e57d074f32bea,
+    0x10f821093d49f4f4, 0xe4d864f4634874f5, 0x0000000000000000, 0x0000000000000000,
+    0x15f782c9f1f0b4f8, 0x0000000000000000, 0x0000000000000000, 0x86c4d7d8905734fb,
+    0x20495dd0f4fca02b, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x4b11d14d47bbb500, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x661a96372f765506, 0x0000000000000000,
+    0x0000000000000000, 0x0ac5c078bfd33509, 0x0000000000000000, 0x8a13602b150be86c,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4527af64a20b3511, 0x729a47601c3a9512, 0x5d8535ebb68bf513,
+    0x0000000000000000, 0xdf3003c9ead5f515, 0x0000000000000000, 0x0000000000000000,
+    0xac88cc9aec927518, 0x0000000000000000, 0x0000000000000000, 0x61b4ec633ee4951b,
+    0x0000000000000000, 0x1d49819f4891d51d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1709927b5af9b525, 0x64e3cfb3f52674b1, 0x0000000000000000,
+    0x3755aeab75a09528, 0x8038db251c1dd529, 0x0000000000000000, 0x9ad1750d3fe0952b,
+    0xa549288482b2552c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x908f115cf57d3533,
+    0x0c8f89c29f421534, 0x02021fa4dd65b535, 0x0000000000000000, 0x0000000000000000,
+    0xf41c921fbdb69538, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x9bb301599999f53c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x51ca00efaa27f540, 0x3984dc7618343541, 0x2fb65194d542673a, 0x2635586b92ed3543,
+    0x0000000000000000, 0x1d40f91d3545c114, 0x30e8b9cde283b546, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x5b0acdff460e754a, 0x0000000000000000,
+    0x6b389d7b56dff54c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8938b9255f7f5551, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x7c65778afa031555, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4723e4b2755a7e0e, 0xe8cfe3c57503b55b,
+    0xab64ff14355c561d, 0x0000000000000000, 0x27379c7db55e2a62, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x1439826d3562b2ee, 0x1e13e73c4622f563,
+    0x0000000000000000, 0xd535a4291c277565, 0x4b89787ef34eb566, 0x2e1567747567b917,
+    0x13e32463f5687ec4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe7d6926bb0be556c, 0x4c0a87ca7431956d, 0x0000000000000000, 0x0000000000000000,
+    0x8d5a00fb9e851570, 0x957e7a6b1571e7d9, 0x0000000000000000, 0x0000000000000000,
+    0xb2a6752b37d97574, 0x869c48e927b1d575, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x9fab7ddcf57a8686, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc8b0ab2a2f1c957f,
+    0x0000000000000000, 0xcd3badd557f4b581, 0x037078fbfe225582, 0x0000000000000000,
+    0x0000000000000000, 0x51d5ceba3a515585, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1a3a5da6f5898ab2, 0xc80a23535938158a, 0x0000000000000000,
+    0x0000000000000000, 0x53341f3ec859758d, 0x0508278b358e7152, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x00e8050bb592267b, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe259670d5597829d,
+    0x0000000000000000, 0x9736d7ef155d9599, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x89bdcaef69bf559d, 0x0000000000000000, 0x0000000000000000,
+    0x85a295673e9e15a0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa7c8573f09cef5a4, 0x9e3801d455a5c088, 0x4220ca8da40315a6, 0x0000000000000000,
+    0x95608ff5351e15a8, 0x0000000000000000, 0xbde35e1aa373d5aa, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb726ab9a04b1d5ae, 0x0000000000000000,
+    0x0000000000000000, 0xa09e7f4915b13793, 0x0000000000000000, 0x12d15173b5b3e958,
+    0x0000000000000000, 0x0000000000000000, 0xe1314a47205615b6, 0x713a7935f13f35b7,
+    0x0000000000000000, 0x6c86483d5a49f5b9, 0x0000000000000000, 0xa0a3ed17993395bb,
+    0x0013efd79f2eb5bc, 0xfce83bb8053a35bd, 0x0000000000000000, 0xaa6d6cb875bf2aa3,
+    0x1e609b74ecbfd5c0, 0x0000000000000000, 0x0000000000000000, 0x13ee49ee63b595c3,
+    0x0000000000000000, 0xde185d9495c54ca4, 0x0000000000000000, 0xab0112c22c6c55c7,
+    0x0000000000000000, 0x4a0d087d6062b5c9, 0xf6c0131081f7b5ca, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe105e8c3cc8fd5cf,
+    0x9ca127c3cc45f5d0, 0x44f878606efbb5d1, 0x657da596a084b5d2, 0xeca2f2c2704835d3,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x32a2447f474115d9, 0x0000000000000000, 0x0000000000000000,
+    0xcaca09b792e3d5dc, 0x0000000000000000, 0x217a368b15de265b, 0x38c7ab289ba3d5df,
+    0x0000000000000000, 0x9f742b30d01b55e1, 0x01cbaf771e20d5e2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6ccaa85bef03d5e6, 0xa2100a40e8d075e7,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2fdbdfaab44bd5eb,
+    0x64195d1ed5ec0316, 0x0000000000000000, 0xac2b3532137875ee, 0x24be665c147515ef,
+    0xb6e05446c62f95f0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xce991b93d5f4222b, 0x0000000000000000, 0x7eebb59f6313b5f6, 0x0000000000000000,
+    0x5574833955f8a792, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x79840195d5ffcd3f,
+    0x7a4a7ea089e11600, 0x429face62ecf7601, 0xa14ba1e2d602d807, 0x0000000000000000,
+    0x0000000000000000, 0x137f185c7605eea7, 0x8dced7e448363606, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb270165f6f4d360c, 0x4212f287560d6d2b, 0x49c550543065b60e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2cde16ab3ec15612, 0x2050721a361326b6,
+    0x0000000000000000, 0x0000000000000000, 0xcd48969336162775, 0x0000000000000000,
+    0x72732e078b3fd618, 0x0000000000000000, 0xf294cfcc11e5761a, 0x0000000000000000,
+    0x0000000000000000, 0xe5f83ad9d61d1ca6, 0x0000000000000000, 0x482cc42b4867161f,
+    0xc1c2e12b1620dc5c, 0x0000000000000000, 0x0000000000000000, 0xbc71f0c6362308f2,
+    0x0000000000000000, 0x0000000000000000, 0x58674bf5801fd626, 0x0000000000000000,
+    0x0000000000000000, 0x6fee118cfbec9629, 0xa5c89f93e99eb62a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf0dc023456d2f62e, 0x0000000000000000,
+    0xca3a5516560ab630, 0xa741343ff6311ceb, 0x657bf9e6308d1632, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xff3d8024a18bb63c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xf683d0f690eaf642, 0xaccd00c23643fe11,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0c1b3b40d64784d6,
+    0x3fd2d28bb64893d0, 0xd4c51fc0e504d649, 0xdf224763364a2f7a, 0x0000000000000000,
+    0x0000000000000000, 0x3075d12237d1164d, 0xb3f2273344e3d64e, 0x957b122e06d7f64f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x38c907f1565341ed,
+    0xe734b951a67e5654, 0xc2d1d34e981a3655, 0x0000000000000000, 0xf10192223657eb8d,
+    0x697e2fc06c8cb658, 0x0000000000000000, 0x204be313aa8d365a, 0x0000000000000000,
+    0x54720a1eeae5b65c, 0xc47dc463965d1a13, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xb11263a0f9d6b661, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0e59b2b5b9ebb667,
+    0x0000000000000000, 0x0000000000000000, 0xc286f835d66abd28, 0x1dfae999966b8d99,
+    0xe49c14c71b70166c, 0x0000000000000000, 0x3b44274021b6766e, 0x0000000000000000,
+    0x0000000000000000, 0x816f058a1f037671, 0x19cb5573b6728446, 0xa87f1a0f967300ce,
+    0xf507ad3f5dbff674, 0x83fbf0aecb071675, 0xdce4c068f72a3676, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe7b0ee8a7984967b,
+    0x31afd68da749367c, 0x03f716137954167d, 0xb0e4d6762a71567e, 0x0000000000000000,
+    0x79c76104930f9680, 0x0000000000000000, 0x1d0f802216826149, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x54c99d4c54f2d686, 0x0000000000000000,
+    0x0000000000000000, 0xd1ba90495689e505, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x072a5b158d7ad68d, 0xbce41d25cb09368e, 0x0000000000000000,
+    0x383204fc677fb690, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0f23b3eaa4227694, 0x0000000000000000, 0x4a601dd495081696, 0x180ff95d8a03f697,
+    0x7926ff84979ff698, 0x0000000000000000, 0x049a76268007569a, 0xe2f9deb2d17d569b,
+    0x0000000000000000, 0x92f6b20c373c369d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x12857ecf3761d6a3,
+    0x01b10879f09e36a4, 0x0000000000000000, 0x34915e3f56a62ec8, 0xe5b68d9016a797c9,
+    0x0cb0455729ba16a8, 0x0000000000000000, 0x0000000000000000, 0x11af177b128b16ab,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x765488552c7316b0, 0x0000000000000000, 0x368cfce62c5216b2, 0x2bd57f85cd4bd6b3,
+    0x21fb4be26199f6b4, 0x4935acc808f296b5, 0xd785a22d1bd236b6, 0xc6d667ca4d8436b7,
+    0x0000000000000000, 0x6682a51396fb96b9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xbd1bce11f13bb6bd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x081207336e4236c1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe6a08edf76c521ff, 0x497e7120d1cbd6c6, 0x0000000000000000,
+    0xf7fe17dae3c496c8, 0x0000000000000000, 0x0000000000000000, 0x3b111d8413d216cb,
+    0x75d3a53a9f4f36cc, 0x062c9c1f7a7956cd, 0x2f38db54d2d576ce, 0x0000000000000000,
+    0xa9c7f00a6cb616d0, 0x7190d9a4f6d18fff, 0x0000000000000000, 0x96e1fb55deca96d3,
+    0xfda97c8849d276d4, 0x35bd135e3a12f6d5, 0x6390a3f12319d6d6, 0xe4609fb9b6d7b318,
+    0x0000000000000000, 0x3757d864870716d9, 0x565eb0f1100e96da, 0xa27e10ca650156db,
+    0xb43fe46096dc787a, 0x1f918eab930676dd, 0xeb58a16277d916de, 0x6da186a2b53116df,
+    0xb814528c2e13b6e0, 0x0000000000000000, 0x0000000000000000, 0x00db7dbf92a3d6e3,
+    0xf453b245361f76e4, 0x0000000000000000, 0x1a39fd7396e6dd69, 0x0000000000000000,
+    0xa4bca58a14ba16e8, 0x70bd898e56e9527d, 0x0000000000000000, 0xf92593d4c0c236eb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc6713dc8aa1ad6ef,
+    0x40784bd5b8e076f0, 0x0000000000000000, 0x0000000000000000, 0xa77070a9b6e4d6f3,
+    0x263e529cd8d016f4, 0x1b294e0cf3cd96f5, 0xb04606efd61b96f6, 0x0000000000000000,
+    0x4da3040d6db4b6f8, 0x4ff7daddf5b376f9, 0xfdafb2c7fd9b36fa, 0x98959bfeebd216fb,
+    0x0000000000000000, 0x0000000000000000, 0x10231669934d96fe, 0x6ee4855c2f40f6ff,
+    0x0000000000000000, 0xfe2d0bfd2d46d701, 0x0000000000000000, 0x0a8f0e47d4eeb703,
+    0x81dbe2099c8e3704, 0x0000000000000000, 0xbbf2e8ee98851706, 0x8c55309313847707,
+    0x5e0edbbfb36a3708, 0x0000000000000000, 0x0396b8310c34970a, 0x88b57179eb8ad70b,
+    0x0000000000000000, 0xab461267970d76f4, 0x1faca815ced9170e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb2f724d0070a1712, 0x0000000000000000,
+    0x24837632ac157714, 0xc921ea2b576a7715, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xdeb9cab3a885d71b,
+    0xc9284ea2d71cf793, 0xff985b2e371df4a8, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x85f9dc37b178b721, 0x3fad5623117eb722, 0x0000000000000000,
+    0x824b684c7724861d, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x61f41555f73cd728, 0x0000000000000000, 0xd4191509b72a9043, 0xb3d20e850725b72b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0e7ff3087191d730, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x12be2acf22177734, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb087c401578ab73b,
+    0xaeefbaad076d773c, 0x0000000000000000, 0x0f862d77ee6fd73e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xb1d1e1e934b3b745, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd3cebdeafd223749, 0x0000000000000000, 0x0000000000000000,
+    0x19b0d6d1e32d174c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xcceb8239f7528fa5, 0x0000000000000000,
+    0x834be50be8813754, 0xe8deaee2d75536d9, 0x0000000000000000, 0x57f5d4189c8bf757,
+    0x0000000000000000, 0x84a84d9941cef759, 0x6f8edd3f375a7533, 0x0000000000000000,
+    0x4f286085f75c29d6, 0x92bb75c9a7e9575d, 0x0000000000000000, 0xd85c951e9ce8575f,
+    0xb23abada8c9af760, 0xdca2d80897619093, 0xd3e48039d38ed762, 0x58f1faf82ebe9763,
+    0x0000000000000000, 0x0000000000000000, 0x67f1f03c4f9d7766, 0x0000000000000000,
+    0x42dec8028b155768, 0x0000000000000000, 0xa9e48ea3019e376a, 0xadd7a2cf8215576b,
+    0xdc17ff24576cd824, 0x0000000000000000, 0x660e9b0a776ebd5c, 0xf8194c42d76fb87a,
+    0x0000000000000000, 0xabc4e6a6577168b3, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x67537dfb5e865777,
+    0x5c2c47633a9a5778, 0x0000000000000000, 0xb446aa48377aa9b0, 0x084ae501f77b3d7f,
+    0xdb61768008b2d77c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6a1afcfdab1d7780, 0x5bff7f609781509b, 0x0000000000000000, 0x7ec4bd92b7834904,
+    0x5ff5e024d9e43784, 0x7a73fee41d34b785, 0x1ed39ade17865e31, 0x2c2464eea6063787,
+    0xdce5a67f804cb788, 0xb7d8daaeb789bfbe, 0xd80fcac2963ad78a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x212557e76278578e, 0x88b86ed6978f2ca5,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x5cfd0a38adfe9793,
+    0x0000000000000000, 0x0000000000000000, 0x3984948bb796e90a, 0x0000000000000000,
+    0x77bf466764dcd798, 0x0000000000000000, 0x0000000000000000, 0x5b655d8e1dedd79b,
+    0x0000000000000000, 0xa5ed13f58007179d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x18802afc97a3e46d,
+    0x0000000000000000, 0xc027339998add7a5, 0xe37372c70876f7a6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x12a6237cd7ab9406,
+    0x0000000000000000, 0x6082dba7566ff7ad, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd97efd4bd5e6b7b1, 0x1ad846b6053457b2, 0x0000000000000000,
+    0x0000000000000000, 0xb772021ff3db37b5, 0xaead0c5de0a017b6, 0x0000000000000000,
+    0xdf0fb55af7b8b2be, 0x0000000000000000, 0x1dd746bed9e8b7ba, 0x21efb30aae4357bb,
+    0x0cd3436517bca03e, 0x0000000000000000, 0x0000000000000000, 0xb14df167449857bf,
+    0x0000000000000000, 0x0000000000000000, 0xbe13a10d2a9bd7c2, 0x0000000000000000,
+    0x0000000000000000, 0xf15b5cd1dac777c5, 0x639ee40b57c6960c, 0x0000000000000000,
+    0x3fe15717fd2017c8, 0x7b43913877c9f104, 0xe55f390b6d1eb7ca, 0x0000000000000000,
+    0x0000000000000000, 0xcbcf6bee97cd63c9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x4256b72737d18891, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x43383af1098497d8, 0x4bc753b6344537d9, 0x0000000000000000, 0x0000000000000000,
+    0x94a8ab2647f4f7dc, 0x606d76c5b49637dd, 0x0000000000000000, 0x0000000000000000,
+    0xed4881109cd397e0, 0xb85387b24598f7e1, 0xd1d539b26a3117e2, 0x0000000000000000,
+    0x0000000000000000, 0x67df9fe0a0d3d7e5, 0xb3b92d0c5c1c17e6, 0x13d37a61f7e74f70,
+    0x769e778d9d5197e8, 0x0000000000000000, 0xcd24574b37ea321a, 0x706e2c0b04be57eb,
+    0xf36e815ab7ec54d7, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x491b20a2c11a57f1, 0x0000000000000000, 0x0000000000000000,
+    0xc24ecd9d3ce357f4, 0x0000000000000000, 0x7edd417d9695b7f6, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x901720eb97a0b7fb,
+    0xf3742280b57a97fc, 0x0000000000000000, 0x5a694bd089af97fe, 0x503f856377ffc4d4,
+    0x0fc2afc996c4d800, 0x0000000000000000, 0xa7f4f9f831aab802, 0x7b745206c4b77803,
+    0x0000000000000000, 0xad96489918058435, 0x0000000000000000, 0x3bdbf1c09653f807,
+    0xe201c47767979808, 0x0000000000000000, 0x034c883605ce780a, 0x0000000000000000,
+    0xf175108741acd80c, 0x0000000000000000, 0x26e18b41980e0bfe, 0x4cac7f6d8f56980f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe08334dbd6de3814, 0x2bd15e4e21415815, 0x0000000000000000, 0x0000000000000000,
+    0x08a66f9c5833d818, 0x0000000000000000, 0x0000000000000000, 0xa6bffc041f22181b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x36361d767d5ed823,
+    0xb1b006f3b824861b, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x7fad5890d31c9829, 0x0000000000000000, 0x6be3c3ddb3f6982b,
+    0xaabf1a41582c2c93, 0x0000000000000000, 0xdbbf63d72501182e, 0x0000000000000000,
+    0xe6a11c64e8c27830, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6d60729545e2b838, 0x0000000000000000, 0x6b5bc3dd8acb783a, 0x0000000000000000,
+    0x2122c488f968b83c, 0xe9c89ffe983d1654, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x1c242cf3a082f841, 0xc838272bb4d55842, 0x993350fd95aad843,
+    0x0000000000000000, 0x6945ed6056dfb845, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8d09b8a5dfcd5849, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x02aeb6e0b84dd948, 0x0000000000000000, 0xd5a89799d662f84f,
+    0x0000000000000000, 0x5a8d113cec913851, 0x0000000000000000, 0x0000000000000000,
+    0x2d88217134807854, 0xcf301269b8550455, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc6a7edc75dacf859, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8559951cfb08f85d, 0x96361c9b985ef258, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x9b343946b862f2b5, 0x0000000000000000,
+    0x0000000000000000, 0xdab28627d95f5865, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x84c8207bce1dd86a, 0x43fefd742221186b,
+    0xab1ceb2b586c20c8, 0x5063d5585874786d, 0x0000000000000000, 0x0000000000000000,
+    0x4d596d566d8bd870, 0x0000000000000000, 0x0000000000000000, 0x4d265d0a12c87873,
+    0xefe514163053b874, 0x0000000000000000, 0x0000000000000000, 0x6f442f2644e39877,
+    0x5c08b8f495be9878, 0x5efcc1e4ef9ad879, 0x489bd395d42b187a, 0x62698c393474d87b,
+    0x96ac71b488a3387c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x3529fe1f19d7b881, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x312a263b2a8bd885, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc251cd7a0559b88d, 0x0000000000000000, 0x0000000000000000,
+    0x4324ba90b89045c2, 0x4788de643693f891, 0xb3e66063d01e3892, 0x569fce3f58930b6e,
+    0x4957cd333894fd59, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x3b64fc2bedf6d898, 0x0000000000000000, 0x3366776c120ad89a, 0x0000000000000000,
+    0x669a4463a973f89c, 0xb7ab0909d5bdb89d, 0xed9eed46389edb79, 0xd384899f7dd8789f,
+    0x0000000000000000, 0x570ca5fa38a162a5, 0x0000000000000000, 0x61b067a682dbd8a3,
+    0x768b1936845218a4, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xdec89c510bf498ac, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb70ffed2040938b0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x27773b3078b8f4a1, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x144b44a178bd741b, 0x42c983b80dbc78be, 0x81650a3d109518bf,
+    0x0000000000000000, 0xbebf815a1427d8c1, 0x711b20c860c238c2, 0xcaa24a148c3058c3,
+    0x1acce2ff917298c4, 0xa73fdf8c575538c5, 0xf4df2adc4ec9f8c6, 0x7821bc56d8c772fb,
+    0x0000000000000000, 0xc62df6e144c2d8c9, 0x0000000000000000, 0x0000000000000000,
+    0x8c6a86fdc84c98cc, 0x1fbd718c8f5278cd, 0x0000000000000000, 0x2eaa5b5638cf9101,
+    0x837c064598d05667, 0x0000000000000000, 0x6dc1f2f49e97f8d2, 0x059061e2b8d36cd9,
+    0x0000000000000000, 0x63a430bbf8d2f8d5, 0xff7a58d7a8c558d6, 0x0000000000000000,
+    0xb016dcfb8e7918d8, 0x0000000000000000, 0xed9afb26395c38da, 0x7cdffb4ae2ecb8db,
+    0x0a06fae12ce998dc, 0x8a46900ce5ec38dd, 0x829cc91958de00ce, 0x0000000000000000,
+    0xc59cbceab8e054c8, 0xece837656f4718e1, 0x1a82578788da78e2, 0x784f420dfbfb98e3,
+    0x079855b7c1b818e4, 0x0000000000000000, 0xcc8ff6ec38e60086, 0x0000000000000000,
+    0x0000000000000000, 0xe7851d9382e918e9, 0x0995d44362eab8ea, 0x8abfaaf938ebb008,
+    0xc1e47da4480c98ec, 0xb2161a80bbced8ed, 0x68e7ef6918eed5ec, 0x398ce66a1643d8ef,
+    0x953dabf7d8f0a56d, 0x0000000000000000, 0x4fe7e4ab64e338f2, 0x9e533d726f3538f3,
+    0x801cb6ad18f47ad4, 0x0000000000000000, 0x0000000000000000, 0x0d7ac9c7896838f7,
+    0xcfe72f17943678f8, 0x34eff18c2fb718f9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x3138f3e678fdb94b, 0x0bb6286bd80418fe, 0x8066595ef8ff683d,
+    0x0000000000000000, 0x45b882e9e0b83901, 0x83ea00f879024120, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x85636213f89b3906, 0x0000000000000000,
+    0x3c80210d83925908, 0x0000000000000000, 0x0517bd15390a4352, 0x0000000000000000,
+    0xf9a6145ac6d8b90c, 0x0000000000000000, 0x329100bcc282790e, 0x0000000000000000,
+    0x22d17420efa97910, 0xeecb70c498c91911, 0x785183319779f912, 0x0000000000000000,
+    0x0000000000000000, 0x8baf02d2d9158dd0, 0xdcc65ca8e2025916, 0x321f134bf2b6f917,
+    0x0000000000000000, 0x6ad8c1d61a28f919, 0xac7c346e8c56991a, 0x0000000000000000,
+    0x7ed565e2c764391c, 0x0000000000000000, 0x5f36bbeed86b991e, 0x570285cfcec9b91f,
+    0x0000000000000000, 0x0000000000000000, 0xafde5ec739715922, 0x0000000000000000,
+    0x0000000000000000, 0x84986226d925ee46, 0x7d20f0e3d9268c57, 0x45c3ac2dead59927,
+    0xc192a49a7928d0c3, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xe3e033b9beffd92f,
+    0x0000000000000000, 0x0000000000000000, 0xa29c1b3bf0671932, 0xb2c20b8ab7845933,
+    0x0000000000000000, 0xafa86e44f2f35935, 0xb5972b08f936bd86, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x27b332a27b3cd93a, 0xc723e4b1a75c193b,
+    0x0000000000000000, 0x0000000000000000, 0x234fe194ef9bf93e, 0x0000000000000000,
+    0x0000000000000000, 0x3d0dc519b0e29941, 0x0000000000000000, 0x5169ed2a1943e9cd,
+    0x0000000000000000, 0xc0498f1a0fbb5945, 0xec7b315653f8f946, 0x0000000000000000,
+    0x1209ee2c9334b948, 0xb1404758cdda5949, 0x0000000000000000, 0x54695c3dba89794b,
+    0x0000000000000000, 0x82f051c71d76994d, 0x0000000000000000, 0x0000000000000000,
+    0xc40df34439509bc5, 0x0000000000000000, 0x0000000000000000, 0xe6ba1e1a33633953,
+    0x20a144791db71954, 0x63184d5b60869955, 0x36b6bc21d956022b, 0x0000000000000000,
+    0xac055698195897d9, 0x0000000000000000, 0x0000000000000000, 0x76d20f62f747195b,
+    0x0000000000000000, 0x0000000000000000, 0xdac2e39a8081b95e, 0xfb210b4156cbf95f,
+    0x0000000000000000, 0x7ca47e1f391ff961, 0x0000000000000000, 0x6e9c271d19635e4d,
+    0x1eb753ace4c91964, 0x0000000000000000, 0x57510650ba4f1966, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xd877847a796a6003, 0x0000000000000000,
+    0xba51a97bc477796c, 0x0000000000000000, 0x0db0f27fa7f9b96e, 0x0000000000000000,
+    0x62fa9df8f97068a1, 0x0000000000000000, 0x95006b4080c7f972, 0x0000000000000000,
+    0x0000000000000000, 0x7067fdc9f975be14, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x91466b1e815e997c, 0x0000000000000000, 0x0000000000000000, 0x49057028c230997f,
+    0xa77a1edc2dbe1980, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x475702e19dd87986, 0x0000000000000000,
+    0x0000000000000000, 0x4165afdc735ad989, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xcc1a3862f98dacc6, 0x38de0329398eb546, 0x0000000000000000,
+    0x0000000000000000, 0xba7ded639991a8f2, 0x0000000000000000, 0xb1b1cf59d993c304,
+    0x27df0223c7551994, 0x0000000000000000, 0x0cf77085d996921e, 0xeb72119e5997428f,
+    0x0000000000000000, 0x0000000000000000, 0x974c9cf82c3cd99a, 0x0000000000000000,
+    0x0000000000000000, 0x2758ff29199d13c2, 0x0000000000000000, 0xd647e2f5dcd3b99f,
+    0x0000000000000000, 0x5561aa92968ff9a1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x08f40e2da75e99a5, 0x0000000000000000, 0xc8f734e719a78049,
+    0x0000000000000000, 0x086c2cfa79a99e5c, 0x1df821b6f76b59aa, 0xbd2bbb2739ab739b,
+    0x0000000000000000, 0xa78ae26e278739ad, 0x4ad9d77554be79ae, 0x0000000000000000,
+    0x35cb8094a01239b0, 0x0000000000000000, 0x0000000000000000, 0x6b1590e94ad879b3,
+    0x0000000000000000, 0x8e9960d3cbd819b5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xa5da556ef531b9ba, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x556879447c7d79be, 0x0000000000000000,
+    0xee0fc7ac9d6939c0, 0x5227f17515e2f9c1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb801140799cb861e,
+    0x0000000000000000, 0xbfc1ae6d1b1459cd, 0x3b83864bea1739ce, 0x8b48a87f1e9e19cf,
+    0x4b2a5f6157be19d0, 0x2e7627d6f9d1e562, 0x8fe3c03ed9d2bf13, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0a49873f39d6d288, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x713e31d759da56d5, 0xeda7dc5af45099db,
+    0x0000000000000000, 0x0000000000000000, 0xcebbc9f38156f9de, 0xcc1395b319df7bf2,
+    0x0000000000000000, 0x479162485ba039e1, 0x3f1e24bc59e23b0f, 0x7a516b5f79a7b9e3,
+    0xec474cc9d9e47d99, 0xe90654a212a939e5, 0x0000000000000000, 0x202b20bff4f2f9e7,
+    0x4ff8977bc7f979e8, 0x0000000000000000, 0x0000000000000000, 0x4dd2b65ba4b979eb,
+    0x06f12e0699ecb44a, 0xc2c31b4319ed298b, 0xc75c514d9136d9ee, 0x0000000000000000,
+    0xed0d03f48c47f9f0, 0x1e5957fb1641d9f1, 0x0000000000000000, 0x0000000000000000,
+    0x88fd96e604d159f4, 0xf55e3645dea359f5, 0x0000000000000000, 0x5dcea162b0def9f7,
+    0x08df916ff9f8f366, 0x61752addd19839f9, 0xfffd485259fa57f4, 0x0000000000000000,
+    0x69c97f38102f39fc, 0x3b41cecb9c93b9fd, 0x38e7b162b957f9fe, 0x3bc3d264b9ff68f3,
+    0x15b96747205fda00, 0x0000000000000000, 0x3a878575f270ba02, 0x49ed172aa9a9fa03,
+    0xc02679c8882f7a04, 0x0d6f0503f0235a05, 0x0000000000000000, 0x285697e80df07a07,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x1e00878729e89a0b,
+    0x0000000000000000, 0x8e614c3602739a0d, 0x0000000000000000, 0xc5f7888ddafe1a0f,
+    0x0000000000000000, 0x0bf059e50b377a11, 0xc3a25d4c598f3a12, 0xb13ce08fda13e504,
+    0x0000000000000000, 0x821dabf7d37d3a15, 0x0000000000000000, 0xda52040cda17020b,
+    0x0000000000000000, 0xc48bf5da3a19332d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf1cda072630e1a1f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe9e9831f5a721a24, 0x6040c1846fc5fa25, 0x0000000000000000, 0x15c6be8ac6b21a27,
+    0x2d8f8b4e88b0fa28, 0x0000000000000000, 0x0000000000000000, 0x1145bf2953885a2b,
+    0x0000000000000000, 0xdf702a34b6623a2d, 0x0000000000000000, 0x0000000000000000,
+    0x90c5992431737a30, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf1fb306bf2c0da35, 0x0000000000000000, 0x0000000000000000,
+    0xe3f1fb74535bfa38, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe741dd8fa229da3c, 0x56acd41fba3d0423, 0x638f0c23c465fa3e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc140cbb47e8ffa42, 0x0000000000000000,
+    0x0000000000000000, 0x6456c54a982e3a45, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x972467bd5a498aea, 0x62d0f4cd5dc2ba4a, 0x6d69143d48bd7a4b,
+    0x506becf2fa4c3dae, 0x0000000000000000, 0x4dd749e41a4ee832, 0xe8dec5f80ea91a4f,
+    0x30caaffacabbfa50, 0x0000000000000000, 0x0000000000000000, 0xbc11812296635a53,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd38af4f91a571c7d,
+    0x0000000000000000, 0xa2a6f5b7fdc95a59, 0x0a2df373d2779a5a, 0x0000000000000000,
+    0x0000000000000000, 0x3f7c39a30adefa5d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc8cdff90463ffa61, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x6e8d8a10484a5a66, 0x0000000000000000,
+    0x0000000000000000, 0x6880fe5ca8169a69, 0x25e5d5e7fe0b5a6a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x5a520aff22067a71, 0x6464525e3a72d6e9, 0xedb351fb4188ba73,
+    0xdecad58cc0bada74, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x097fe713daf53a79, 0x83e126821fd31a7a, 0x0000000000000000,
+    0x3317cc697b77da7c, 0xa11654157581ba7d, 0x03025a9ee54d7a7e, 0x0000000000000000,
+    0x0000000000000000, 0x0db4a372ef2f1a81, 0x8418fd7880fc7a82, 0x0000000000000000,
+    0xc3e36603ddcbda84, 0xb98db9c33ed47a85, 0x0000000000000000, 0x80d6d039fe00ba87,
+    0x0000000000000000, 0x1003b4d0281f9a89, 0x0000000000000000, 0xaa4ee38004663a8b,
+    0x0000000000000000, 0x11827b24c348da8d, 0xc08b83ea26e95a8e, 0x0000000000000000,
+    0xd51bf83eaa7c5a90, 0x7b94f0cc39731a91, 0x11559e3c2fa49a92, 0x0000000000000000,
+    0xc1dba410a5821a94, 0x239497cf40dada95, 0x502884ca3a96a71b, 0x0000000000000000,
+    0x10fd2e9f7a98b3c0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xd7c7ff499a9f1e27,
+    0x0000000000000000, 0x73dc37234d719aa1, 0x0000000000000000, 0x0000000000000000,
+    0xd955ee0f319a9aa4, 0x882e0446ff2adaa5, 0x12561ec234db7aa6, 0x0000000000000000,
+    0x0000000000000000, 0xb948ff5d55215aa9, 0x0000000000000000, 0x349d55f8ae4d7aab,
+    0xa1c7f60838a53aac, 0x189f4e779aad5a35, 0xf99941309aae8dc1, 0x0000000000000000,
+    0xeca6d3f3c5f2bab0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xee16d768a7e5fab7,
+    0x0000000000000000, 0x0000000000000000, 0xd2740850533e3aba, 0x0000000000000000,
+    0x89b00ca07abc6e19, 0xa6b038c6e1e25abd, 0xf81d7fde9abef87a, 0x0000000000000000,
+    0x58600a377d7fdac0, 0x9fbd9b905d657ac1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x59259d5e1ac5c652, 0xa7fbc2ce9ac6b86c, 0xcacbe2994781bac7,
+    0x0000000000000000, 0x6ba871619ac90877, 0x0000000000000000, 0x5e850106c7e09acb,
+    0x123c9ef23acc1d80, 0x40ee6e2c88895acd, 0x0000000000000000, 0x0000000000000000,
+    0xdb131723909d3ad0, 0x0000000000000000, 0x0000000000000000, 0xd146971f5cc99ad3,
+    0xc15c9639b7c83ad4, 0xd23291277e9fdad5, 0xc815a07df6f9bad6, 0x9de63248805d3ad7,
+    0x422657027ad88b89, 0xa8e1dfa464c93ad9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xbb035ae10d583add, 0x7d8b42905adeee5e, 0x0000000000000000,
+    0xa155c981fe13fae0, 0x1ebaa45d3d0ddae1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x230ce16471acbae5, 0x0000000000000000, 0xb6a956d05893dae7,
+    0x90d114a31e59fae8, 0x7a2df1703ae9f092, 0x0000000000000000, 0x1e018bbd7aebedee,
+    0x0000000000000000, 0x0000000000000000, 0x0c4469741aee1655, 0x39977ed83aef01f5,
+    0x0000000000000000, 0x0000000000000000, 0x5f3157bc57b8daf2, 0xde85a3867af3e8db,
+    0x0000000000000000, 0x4344afcbf37ddaf5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd35b18cf5afdfa38, 0x0000000000000000, 0x3bbcd055180ebaff,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf4ff4d7c4cda1b03,
+    0x8bf46c909b04b643, 0x0000000000000000, 0xb669f7d8540f3b06, 0xf57439d83df3bb07,
+    0x0000000000000000, 0x586381191b09aa77, 0x0000000000000000, 0x4c9716d6d5639b0b,
+    0x89e649db172c3b0c, 0x9a8ace548327bb0d, 0xb932475c9b0e0d85, 0x9edd10ce32159b0f,
+    0xe77079b8ee6c9b10, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x256e25833b19ea8e, 0x4a53608331135b1a, 0xff64c0de0e9f3b1b,
+    0x3ad32716afb5db1c, 0x0000000000000000, 0x83c14583bce09b1e, 0x741578021b1fd332,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x3b7a6c6d7b252d21, 0xae797b1231717b26, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc238130f5c13bb2c, 0x0000000000000000, 0x0000000000000000, 0x1dc2f2a5ddce9b2f,
+    0x373688fdbfc69b30, 0x7d1d9bbe7b314837, 0xe1178fb59b65bb32, 0x0000000000000000,
+    0xc477fb23bb3473de, 0x0000000000000000, 0x6198a7c2b5281b36, 0x0000000000000000,
+    0xa068eb60bd603b38, 0x0000000000000000, 0x0000000000000000, 0xe99d6299c0943b3b,
+    0xeb2074ec93eb9b3c, 0xf373e40973fa5b3d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4367f55c65a9db42, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x35e1748a0ba29b48, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x60f37a937b4ce944, 0x0000000000000000, 0x7a4943bd9b4ef412, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x45d0e7066566db52, 0x0000000000000000,
+    0x0000000000000000, 0x4014343686eebb55, 0x0000000000000000, 0x0000000000000000,
+    0x9af52ac307a03b58, 0x0000000000000000, 0x2751aea236149b5a, 0x0000000000000000,
+    0x894561479b5ce3bb, 0x3b31f2f93b5dbc74, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x2e9efc3c6adedb62, 0x370103ba73927b63,
+    0x0000000000000000, 0x2ad628ce0c189b65, 0x677de8457b66eb18, 0x0000000000000000,
+    0x900ea66ddb687f42, 0x0000000000000000, 0x0000000000000000, 0x0ffccc10dbe1db6b,
+    0xe2f3a76e1b8a9b6c, 0xa9dd33a126d3db6d, 0x0000000000000000, 0x7a10026f0c8a7b6f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x3d2f4824fb73bf80,
+    0x0000000000000000, 0xf5f0132cfb7527c6, 0x0000000000000000, 0x3d81566c9b77b7c9,
+    0x0000000000000000, 0xde963e5dfb79f292, 0x68200400104c9b7a, 0x0000000000000000,
+    0x0000000000000000, 0x0d7894353b7d04c4, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x19a9dcc9f2889b85, 0x31a6e17bd37a5b86, 0x70c5ff573b87b10d,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x37b753608d591b8e, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x8acc3f34afb9db9d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x47676dac69ce9ba2, 0x0000000000000000,
+    0xbc9646b93ba4093e, 0xd1501993bba54f7e, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb450a43c9c035baa, 0x0000000000000000,
+    0x91e4158cdfd67bac, 0x0000000000000000, 0x75f7e7459bae845e, 0x31f3a43a29a1dbaf,
+    0xa3371fea4a2cbbb0, 0xf0407071f443fbb1, 0x35334d523a633bb2, 0x0000000000000000,
+    0x65283566b0e69bb4, 0x0000000000000000, 0x0000000000000000, 0x8697e473b80dbbb7,
+    0x0000000000000000, 0xf1433d4a02cc9bb9, 0x607b58f6f4f45bba, 0xcf436862dc9c5bbb,
+    0x0000000000000000, 0x232c614b2536bbbd, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x6b77f255cbfdbbc5, 0x62e89f92c3757bc6, 0x0000000000000000,
+    0x0000000000000000, 0x47d0c087bfe47bc9, 0x914b0a6f7bca9724, 0x2e607b42e4601bcb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xf80b91ae0fa6fbd0, 0xdec100264f889bd1, 0x0000000000000000, 0x8717c8a1e2ec3bd3,
+    0x0000000000000000, 0xc84b493543d37bd5, 0x0000000000000000, 0x2b1d7e9a82827bd7,
+    0xcc697d67573f7bd8, 0x0000000000000000, 0xa860d3a09e441bda, 0x0000000000000000,
+    0xf6f168f299ccfbdc, 0x2dc8b2dd582c5bdd, 0x2b2f27f2f0149bde, 0x25b70734510b9bdf,
+    0x12b4544446bdfbe0, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x61cf8bac86ff1be6, 0x4346c39283541be7,
+    0x0000000000000000, 0x610d13e3ee9c7be9, 0x35064443b8799bea, 0x0000000000000000,
+    0xa26ad5cec1dedbec, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb8c94e9b3bf079e1, 0x0000000000000000, 0x1ed15de615de9bf2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x7370b90142503bfb,
+    0x6dad0e5ca1963bfc, 0x0000000000000000, 0xabcc20977bfe3c7d, 0xf6ddeb8edfebdbff,
+    0x0000000000000000, 0x0000000000000000, 0x8ded8a44ef74dc02, 0x9a42f33c5c03e500,
+    0x0000000000000000, 0xad67ad05c6bbbc05, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xc5cb7c635b1adc0c, 0xf80ff847a752bc0d, 0x0000000000000000, 0x423a54283f3d1c0f,
+    0x0000000000000000, 0x0000000000000000, 0xafd380b80c949c12, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x9fca3cb71c175b63,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x2f0a747aece39c1b,
+    0x0000000000000000, 0x9632839040c53c1d, 0x0000000000000000, 0xb1527b29b9ebdc1f,
+    0xc1279689dc2063a1, 0x0000000000000000, 0x0000000000000000, 0x9ed79effbc2377f4,
+    0x0000000000000000, 0x0000000000000000, 0xbf4e1e8059e1fc26, 0x0000000000000000,
+    0xe8432f48fc286d99, 0x0000000000000000, 0x6d75111768f5fc2a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0e853b116130fc2e, 0xf698adeabc2f6cb3,
+    0x304f002dd2987c30, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc8c36cb53c3514f1, 0xac97d963145b9c36, 0x0000000000000000,
+    0x0000000000000000, 0x9cb24cef7c39c7f8, 0x0000000000000000, 0x0000000000000000,
+    0xcc4ae56abc0cbc3c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x995301ece70b7c46, 0x0000000000000000,
+    0x0000000000000000, 0x34aae5c95c496148, 0x3d6fdb9d79ba5c4a, 0xebc69d1d8f0abc4b,
+    0x45f5f698436b9c4c, 0x0000000000000000, 0x73971de220b1fc4e, 0xca4a5dfa5ae19c4f,
+    0x0000000000000000, 0x0000000000000000, 0xae5a43791c52ba98, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xf5f3444ddc57cc07,
+    0x7130ad08dd9ddc58, 0xaa51e96a9c599ac5, 0x0000000000000000, 0x34bf29a18beffc5b,
+    0xb6315de3a26e1c5c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x72c0f5eadc63471a,
+    0x0000000000000000, 0xde158660fd261c65, 0x1f32f80417159c66, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x42a21cff5c6ec8e8, 0x0000000000000000,
+    0x72ca36907c7062f6, 0x2a20724491cd3c71, 0x0000000000000000, 0x3e5103146b273c73,
+    0x680bed95de4f5c74, 0x0000000000000000, 0x76eeee67bc7697c5, 0x0000000000000000,
+    0x0000000000000000, 0xc484247bbc791a02, 0x0000000000000000, 0x93ff99e1729ddc7b,
+    0xee2fad783c7cb970, 0xfb35ab7b5c7dcf7e, 0x0000000000000000, 0x0000000000000000,
+    0x97f4eb13370d1c80, 0x536f96ca843d1c81, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x4b66fbdf7c865412, 0x613f0f3d91c23c87,
+    0x6af4e249bf331c88, 0xbb1517c2e095dc89, 0x0000000000000000, 0x0000000000000000,
+    0x8a856ccb3c8c2cba, 0x1f9bde602dcdfc8d, 0x0000000000000000, 0x4eb3dea8d61a1c8f,
+    0x01383ad2c0d31c90, 0x0000000000000000, 0x0000000000000000, 0x47eaa9546ee6fc93,
+    0x0000000000000000, 0x0000000000000000, 0xd01186645c963541, 0x7a0e4dc25c972c34,
+    0xe370ae7dcbd05c98, 0x0000000000000000, 0x0000000000000000, 0x4daf0cb03c9baa57,
+    0x0000000000000000, 0x722bf67f7c9dfda7, 0x0000000000000000, 0x3da8ea5385f87c9f,
+    0xfbaddef03ca0c3f2, 0x0000000000000000, 0x0000000000000000, 0x57c45b892065fca3,
+    0xbb8d47e0f067dca4, 0x585dc065f3149ca5, 0x3af1a86a59175ca6, 0x0000000000000000,
+    0x0000000000000000, 0x5299abf98ad37ca9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb53e447cb1473cae, 0x0000000000000000,
+    0xeabe23ddbcb0e8e4, 0x0000000000000000, 0x0000000000000000, 0xb7383298bcb3b605,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xec8d5901e2cf5cba, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0fc591a3dcbe5acd, 0x0000000000000000,
+    0x65f4ae7905131cc0, 0x212ac91965d05cc1, 0xe221ee9760a35cc2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb70900599636bcc7,
+    0xbe76b4125cc86464, 0xb54090f55ad83cc9, 0xadfcd01414305cca, 0x319991b82eea7ccb,
+    0x0000000000000000, 0xe5525486fbc35ccd, 0x95bf5e8863519cce, 0x306f37b3bccfbfc6,
+    0x0000000000000000, 0x0000000000000000, 0xabf59ea37cd2f9ed, 0x0000000000000000,
+    0x709ecb6a7247fcd4, 0xd3b18786c9909cd5, 0xeae48ea616435cd6, 0xfa39dc2f0fb81cd7,
+    0x10b7f13e9321fcd8, 0xb3ee5a362de55cd9, 0x0000000000000000, 0x3efd53a3ccea3cdb,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xc315b83882c1fcdf,
+    0x0000000000000000, 0x0000000000000000, 0xb398ac0cdce248ed, 0x9c2679e37ce31c73,
+    0x46889c00fce49b55, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6e93c2688944dce8, 0x0000000000000000, 0xe769ff1775ca1cea, 0xc740713f6d4dbceb,
+    0x0000000000000000, 0x0000000000000000, 0x0d7ef5f3b1451cee, 0x0000000000000000,
+    0x0000000000000000, 0xfefa70dfa17adcf1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x209339385cf71fc6,
+    0x419e5c8ca877fcf8, 0x9367b161793edcf9, 0x86e8bb3fd1c87cfa, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x19ebb80d7838fcfe, 0x5f8768973cfffff0,
+    0x61ec16fd99f73d00, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xdc2ba761aa2add04, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x5526846ae6e09d0c, 0xfe5a5144e090dd0d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xb10df553dd127f56, 0x8e59401988b37d13,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xe0a731d188a19d18, 0x0000000000000000, 0x807ed0d10b1ffd1a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x9dec46960249fd1f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x182040f636879d28, 0x019769a73d2915df, 0xf2a2887ac7987d2a, 0x0000000000000000,
+    0x782f51a8135cbd2c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x90cf3b497d31572a, 0x0000000000000000, 0x0000000000000000,
+    0x179fbb99f8fa9d34, 0x9194382bb98dfd35, 0x0000000000000000, 0x0000000000000000,
+    0x3bdf327e696bfd38, 0xce1cd02a77ee3d39, 0x59b3d5e59d48bd3a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xb95a0a8f8ea33d44, 0xbd48efc7cc08dd45, 0x0000000000000000, 0x0000000000000000,
+    0x01919bbed8e6fd48, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x9d6fde0e5d4f6f99,
+    0x0000000000000000, 0x0000000000000000, 0x53ff826c855f5d52, 0x0000000000000000,
+    0xe5f6668231723d54, 0xc0bdfab4fd558e6b, 0x0000000000000000, 0xbed9c7e5c018dd57,
+    0x38c68929f53e5d58, 0x41d0fcb81d591eb4, 0x0000000000000000, 0x0000000000000000,
+    0xc05ca3b6dd5cf0e2, 0x39b8dc1b4b5bbd5d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xce548d07cec67d62, 0x0000000000000000,
+    0x7987053dbc65bd64, 0xf8e5dccf70659d65, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x103527626a891d69, 0x9af8731a19173d6a, 0x0af407203a561d6b,
+    0x0000000000000000, 0x059a53765552dd6d, 0x0000000000000000, 0x0000000000000000,
+    0x637fe2ba9d705dd3, 0x4e94d4c83d71ad24, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x165b0e9d0009bd76, 0x8876f036f63c1d77,
+    0x0000000000000000, 0xf420f3fa8bf01d79, 0xcb44b6be3d7aedda, 0x79c6841726cd3d7b,
+    0x0000000000000000, 0x0000000000000000, 0x9cee878d9ffa3d7e, 0x35a5ca0621685d7f,
+    0xecb45af4bd809451, 0x22b7c38c346b5d81, 0x11c0b4499d829098, 0x859495d9089cdd83,
+    0xddd9dd9f466dbd84, 0x0000000000000000, 0xb4cece17fd869acc, 0x0000000000000000,
+    0x0000000000000000, 0x3388e3492a641d89, 0x0000000000000000, 0x440eed2c8c5a1d8b,
+    0xf7f3a3fc7d8cbbc5, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x60f75d0f763a7d94, 0x67566870d65c7d95, 0xd4272322d2269d96, 0x0000000000000000,
+    0x0000000000000000, 0x2cc282b09d993c17, 0x0000000000000000, 0xf8e59df489fb1d9b,
+    0x0000000000000000, 0x0000000000000000, 0x052c5dbe1bb05d9e, 0x0000000000000000,
+    0x0000000000000000, 0xa9d8f21c1da1703b, 0x3208f88fdda239ff, 0xf9d6904bfda38ad6,
+    0xcce8ac185ac8fda4, 0x0000000000000000, 0x0000000000000000, 0x03b4fb2682033da7,
+    0x0000000000000000, 0x0000000000000000, 0x7047572845707daa, 0x0000000000000000,
+    0x0000000000000000, 0xdc0dc400f7c4fdad, 0xc373b9e1dfd73dae, 0x0000000000000000,
+    0x0000000000000000, 0x9d017434ddb11655, 0x0000000000000000, 0xb0d51ac07cdc9db3,
+    0x0000000000000000, 0xd4bee2be39af3db5, 0x31b3c72b907e1db6, 0x35e751e75db79e27,
+    0x48231aab5db8bdcd, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x2f9ebcb79dbc475e, 0x0000000000000000, 0x0000000000000000, 0xcf522e5e15611dbf,
+    0x0000000000000000, 0x0000000000000000, 0x29f499ad9dc2e199, 0x71ff7e070c291dc3,
+    0x0000000000000000, 0x8dfc6c17058d7dc5, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa642b419c88e9dcc, 0xee7812f7bdcddb34, 0x0000000000000000, 0x513eaa59df8bddcf,
+    0xec85af3d797bbdd0, 0x3dead2350c28ddd1, 0xf6e7397153437dd2, 0x1546c9737dd3ea6c,
+    0x0000000000000000, 0xe19d1876ddd59d31, 0x0000000000000000, 0x1cf8cf432b829dd7,
+    0x0000000000000000, 0x5073de3598c65dd9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x1bd192601dde1150, 0x6fd5e8ba1ffdfddf,
+    0x0000000000000000, 0x78693127f1a01de1, 0x0000000000000000, 0x17ef7b303de3e442,
+    0x4f4cb55357109de4, 0x0000000000000000, 0x0000000000000000, 0xdde2ef6837069de7,
+    0x0000000000000000, 0x7818e604fde901c1, 0x9d39a65764f0ddea, 0x99f7dcccde457deb,
+    0x3a48ce9d5decbb86, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc1ed8740414a1df1, 0x0000000000000000, 0x50e22f35ddf3e608,
+    0x34844dca1df49b73, 0x0000000000000000, 0x1559b8ad61aebdf6, 0xe0a394587df72536,
+    0xd4451a9fedccfdf8, 0x0000000000000000, 0x3ab289757dfaf273, 0x9329430df8fe9dfb,
+    0x547a360eb53dddfc, 0xdeea6f23d2759dfd, 0x27d1ca395dfe8e5e, 0x0000000000000000,
+    0x565e1a9d4908de00, 0x0000000000000000, 0x1a32ffbbde02ae14, 0x0000000000000000,
+    0x23c4a1aa3e041bbb, 0x0befecb53e058a37, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x897b9f3e5e0a34dd, 0x6c8417c4cf395e0b,
+    0x6aeaeefa5b135e0c, 0x0000000000000000, 0x218c177204755e0e, 0x0000000000000000,
+    0x0000000000000000, 0x99e86fd23e115ebb, 0x0000000000000000, 0xd86e95039e13db3c,
+    0xbb68fa4239a2be14, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x08934039601ffe19, 0x3740c3e45e1a0bd5, 0x0000000000000000,
+    0x0000000000000000, 0x98adecf04ca95e1d, 0x0000000000000000, 0x3a0a0ccbc1cefe1f,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x1eaaffd376883e24, 0x47bf42eb4063fe25, 0x0000000000000000, 0xe019281cde27ac2d,
+    0x0000000000000000, 0x0000000000000000, 0xa4cd3fa7210a9e2a, 0x21d2ec8fea569e2b,
+    0x0000000000000000, 0xd974fcd5dbbffe2d, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xc66a192a5e31eb46, 0x0000000000000000, 0x723f039481bdfe33,
+    0x697de7b945cf7e34, 0x0000000000000000, 0xbfe4cd31be36fa00, 0xc3c5653bcc205e37,
+    0xbda00a7f48293e38, 0x0000000000000000, 0x0000000000000000, 0x5df0dd187e3baa3e,
+    0x0000000000000000, 0x70be5e19ff2d7e3d, 0x0000000000000000, 0xd26cba91cc875e3f,
+    0x0000000000000000, 0xfa6f58d3d8e65e41, 0x0000000000000000, 0x0000000000000000,
+    0x8698e1a7d7611e44, 0x0000000000000000, 0xd43bf5cf7e46f17a, 0x0000000000000000,
+    0xdc256cbaeeaabe48, 0x0000000000000000, 0x4732d230b252fe4a, 0x1f04b76284b9be4b,
+    0x0000000000000000, 0x405cdc1b5e4df26d, 0x045d49ed0fb4de4e, 0x0000000000000000,
+    0x36fe5d5d3a993e50, 0x0000000000000000, 0x499e30c19e525eef, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x936e19c1b09ede58, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xd4a7bfddf497be5c, 0x0000000000000000, 0xfbc3b153f5645e5e, 0x7d1e9f41ab92be5f,
+    0x0000000000000000, 0x0000000000000000, 0x456345b4e1463e62, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc646ae45bcc55e66, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x3c91079d6c94be6b,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xfccae8fb20d8be73,
+    0xf78dc411de747b42, 0xed1def32238cbe75, 0x2e54ed8e3b23be76, 0x0000000000000000,
+    0x0000000000000000, 0xa3bd67f9d148be79, 0x520cb08c89f37e7a, 0x0000000000000000,
+    0x0000000000000000, 0x2aef6b4628025e7d, 0x1617b4b840ccde7e, 0x0000000000000000,
+    0x0000000000000000, 0x5e3d1660d3933e81, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xdb676e793e85a572, 0xabe2b3ffa58b7e86, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0a7b696abe8b9c52,
+    0x1927532ff0a8de8c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe367f9c5b8c11e91, 0x0000000000000000, 0x0000000000000000,
+    0xf111f2ec5e94ed90, 0xa614570dde9566f6, 0x06ec7b380fa85e96, 0x4e20389f2c173e97,
+    0x28d96f896fd65e98, 0x0000000000000000, 0x0000000000000000, 0x09a793f6de9b1b58,
+    0x0000000000000000, 0xe6de72573e9d691b, 0xe4d66a97e9e73e9e, 0x50ff8a671e9f5e98,
+    0x0000000000000000, 0x0000000000000000, 0x72ebcac91ea2473a, 0x53570a1f2b721ea3,
+    0x0000000000000000, 0x41b143a857e47ea5, 0x79c3072d34811ea6, 0x0000000000000000,
+    0x0000000000000000, 0xa2f666a6d7729ea9, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x3c45f306feafd997,
+    0x0000000000000000, 0xf724503fc2f31eb1, 0x202da5ac7eb233cc, 0x0000000000000000,
+    0x776fb0caa8d15eb4, 0x0000000000000000, 0x0000000000000000, 0x1275ca86ead97eb7,
+    0x2dd2ab50f8381eb8, 0x0000000000000000, 0x0000000000000000, 0x9763373bbebbb0f3,
+    0x875a98050c065ebc, 0x0000000000000000, 0x1f4ef1bd9ebe9f06, 0x7cd6500aa1661ebf,
+    0x0000000000000000, 0x82a26ad35fef7ec1, 0x0000000000000000, 0x0000000000000000,
+    0x4139ccca7ec4347c, 0xcf64aa57d29b5ec5, 0x44ce08121ec69471, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x6ca49d53fecc6307, 0x8ba239f0c96f7ecd, 0xb50b06066ac9bece, 0x0000000000000000,
+    0x0000000000000000, 0x02bb2a4ccd633ed1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x7cd1c65c1ed76885,
+    0x6a0be2e7407a5ed8, 0x0000000000000000, 0x0000000000000000, 0x9fea81cf2bad3edb,
+    0x0000000000000000, 0x0000000000000000, 0x953f28f25ff77ede, 0x0000000000000000,
+    0x94abf042bee001d6, 0x4d9d5ca49e7e1ee1, 0x0c87091a9ee260d8, 0xa4bc108168355ee3,
+    0x0000000000000000, 0x0000000000000000, 0x7cb496e831e83ee6, 0x0000000000000000,
+    0xb5ea822fdee8f5d0, 0x0000000000000000, 0x0000000000000000, 0x63244fa53eeb8f87,
+    0x0000000000000000, 0x38fcf2a284b15eed, 0x0000000000000000, 0x32f2025233cdfeef,
+    0x0000000000000000, 0x2c8e0e55fef1cd74, 0xabfb9ae217311ef2, 0x537aec37fef336f5,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xab22e56eac48bef7,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x40388b15befb0dde,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0xb1861bae0aee3f03,
+    0x0000000000000000, 0x4a308a55fc965f05, 0x71e6abe9a4ff5f06, 0x0000000000000000,
+    0x345502c05b5b5f08, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x10e1a447c1527f0c, 0x0000000000000000, 0x9c3f42158b387f0e, 0x0000000000000000,
+    0x229f0a40e2bf7f10, 0x0000000000000000, 0x0000000000000000, 0xc22b266a5f13a6f8,
+    0x9d1f8ec103ae3f14, 0x0000000000000000, 0xe47e25907f167b31, 0x09a6003e0699ff17,
+    0x0000000000000000, 0x0000000000000000, 0x9bb8a8e6df1a3433, 0xcb24d8366f32df1b,
+    0x0000000000000000, 0x0000000000000000, 0x9b60028f7f1eb356, 0x0000000000000000,
+    0x3ccf9d207f20db36, 0x8c3077a29234bf21, 0xbf45e182728a9f22, 0x0000000000000000,
+    0x5156b554a08fdf24, 0x0000000000000000, 0x0000000000000000, 0xb380a9319f27daeb,
+    0x0000000000000000, 0x49ec57df04897f29, 0x32f13ad1ea3d7f2a, 0x0000000000000000,
+    0x7d6280f428b9bf2c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x9cd222d03f300ae6, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0xa4d8961dac841f34, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xe7e205f6b38e1f39, 0xd7d7c327ab1d1f3a, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xaa9374e7bf3eb935, 0x0000000000000000,
+    0x6bc899760809ff40, 0x0000000000000000, 0xa6d2c4739f42cf13, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x9cb559909f464529, 0x82d2f82176881f47,
+    0x0000000000000000, 0xb3280d3b1f4947a3, 0x788ab512ff4a6bab, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x021807cf48db7f4f,
+    0x09cb00c61f501aff, 0xc5f607925dbedf51, 0x01a2c953ff075f52, 0xd34fb0227f534ac4,
+    0x0000000000000000, 0x0b5439565f55b67e, 0x15a86143561bbf56, 0x0000000000000000,
+    0x0000000000000000, 0x8c83ce8cbf5974e0, 0x0186598fb6a87f5a, 0x0000000000000000,
+    0xfc18102424eeff5c, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xf565bfd8cfce7f61, 0x0000000000000000, 0xe33de6288c10df63,
+    0xdceb04c9bf64bad3, 0x044fa7ab9f6548c2, 0x0000000000000000, 0x0000000000000000,
+    0xf68673dab7f4ff68, 0xe5995018df69e131, 0x0000000000000000, 0x1c9a051543fcbf6b,
+    0x0000000000000000, 0xa15fa6b4a8d97f6d, 0xdc10fd88ff6eaa8f, 0x55000b4507659f6f,
+    0x18943a71bc39ff70, 0x93074e11fa2b9f71, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x547015438400bf76, 0x0000000000000000,
+    0x65bc2e52b4ac9f78, 0xcfaf7c860b82df79, 0xa56e12761d555f7a, 0x83b70fb5502e3f7b,
+    0xd06293a0ae715f7c, 0x0000000000000000, 0x4fa6284ee7019f7e, 0x0000000000000000,
+    0x07ffe9013f801dd7, 0x0000000000000000, 0x74f946ae9f82482d, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x2ae98e2c30f1ff89, 0x0000000000000000, 0xbd2670d45f8b2617,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x15d7cbbf3b959f8f,
+    0x18cdff17270a3f90, 0x0000000000000000, 0x4df4f47c44e05f92, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x1f7bdfdd2632bf96, 0x1ae2ca3f76b99f97,
+    0x0000000000000000, 0x7bba5b5263911f99, 0x0000000000000000, 0x0000000000000000,
+    0x115b2aff7f0edf9c, 0x0000000000000000, 0x52ad8aabc9cb3f9e, 0x0000000000000000,
+    0x0000000000000000, 0xd5f55b320fe47fa1, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x59c529677fa6ee42, 0xcc73da4fdfa74280,
+    0x0000000000000000, 0x0000000000000000, 0xba1a35ab3faae243, 0x0000000000000000,
+    0x0000000000000000, 0x2183809d79395fad, 0x0000000000000000, 0x0000000000000000,
+    0x35f00a4cf1f63fb0, 0x0000000000000000, 0xfa4c82021b19bfb2, 0x0000000000000000,
+    0x0000000000000000, 0x111fef26386adfb5, 0x0000000000000000, 0xcbeaf78c9fb790d2,
+    0x01d491232fa8dfb8, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd647458fffbd34ce, 0x0971674536c25fbe, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x7637168e7b3ebfc5, 0xaf1f5b389fc6b534, 0xd8f16aa2bcd89fc7,
+    0x0000000000000000, 0x0000000000000000, 0x5764018dffca0734, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0xc2b0ea27b2ba7fce, 0x0000000000000000,
+    0x18f6fd56aff77fd0, 0x0000000000000000, 0x6ea7631ee9ff9fd2, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x5635c9223fdd947c, 0x31e8dbce7fde83d9, 0x02b3967d1fdfe96b,
+    0x571328c29fe0bd34, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0x0000000000000000, 0x0000000000000000, 0x0000000000000000,
+    0x0000000000000000, 0xd03a4b2bdf307fe9, 0x75673f0bcfeb1fea, 0xda05d3ed8de91feb,
+    0x0000000000000000, 0x07f39fa25fedca3b, 0x0000000000000000, 0x08581c1fbfef459e,
+    0xfba27ef0951b5ff0, 0x0000000000000000, 0x192c5616bff2f98e, 0xc8cd074a1ff39e91,
+    0x90a289bfe8b27ff4, 0x223f04447ff5b3ac, 0x0000000000000000, 0x9ee65bd5dff72ca8,
+    0xafb21daf5aaa3ff8, 0x533b0b1f69db1ff9, 0xc871a3c574fc1ffa, 0x0000000000000000,
+    0x0000000000000000, 0x4ff7947ee456fffd, 0x0000000000000000, 0xce631d5693e11fff,
+];
+
+#[rustfmt::skip]
+pub static MOVES: [u16; TABLE_SIZE] = [
+    0, 2980, 0, 838, 0, 771, 1032, 0, 0, 3465, 0, 0, 0, 3377, 1747, 0,
+    1601, 0, 0, 2712, 0, 0, 0, 2074, 3735, 3834, 576, 0, 2828, 2203, 0, 0,
+    3150, 2644, 2854, 0, 0, 0, 3947, 0, 0, 0, 2842, 3994, 0, 3078, 0, 0,
+    835, 0, 0, 2189, 0, 0, 0, 2785, 2988, 0, 1492, 0, 0, 0, 1422, 0,
+    0, 0, 0, 0, 1739, 1423, 0, 2137, 0, 3039, 0, 2247, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 3530, 0, 0, 0, 0, 2339, 1877, 0, 3241, 1422, 0,
+    0, 0, 3998, 0, 3832, 3891, 3503, 0, 0, 0, 0, 4092, 0, 259, 0, 0,
+    1099, 0, 0, 0, 0, 0, 1426, 0, 0, 2958, 0, 0, 4055, 0, 448, 2651,
+    0, 0, 3591, 0, 1666, 0, 1676, 3150, 2921, 4078, 0, 0, 0, 0, 0, 0,
+    3572, 0, 0, 1355, 0, 0, 3898, 1988, 1351, 0, 0, 0, 0, 0, 3657, 0,
+    0, 0, 0, 0, 0, 1414, 2945, 0, 0, 3409, 640, 0, 324, 0, 0, 2336,
+    0, 0, 0, 0, 4086, 3305, 2824, 2201, 0, 3632, 0, 0, 0, 0, 2974, 1799,
+    0, 3551, 0, 0, 3177, 2712, 0, 0, 643, 1624, 3023, 0, 0, 0, 258, 0,
+    3371, 0, 0, 0, 0, 0, 0, 0, 1741, 0, 0, 0, 2907, 2010, 2072, 0,
+    0, 2833, 1425, 2657, 0, 0, 1154, 0, 1291, 3431, 0, 0, 3893, 0, 452, 1870,
+    0, 0, 0, 1217, 0, 1226, 0, 0, 0, 1682, 0, 0, 0, 0, 0, 0,
+    0, 1746, 3827, 1225, 0, 0, 0, 1754, 0, 2438, 1871, 0, 0, 0, 0, 0,
+    3493, 2721, 2788, 1731, 3153, 0, 2261, 3917, 0, 0, 1947, 4022, 4023, 1489, 0, 3297,
+    3691, 966, 0, 0, 0, 0, 2378, 0, 0, 3229, 0, 0, 2706, 0, 0, 3427,
+    1812, 2452, 0, 0, 0, 2462, 1811, 0, 3313, 0, 0, 1414, 0, 0, 3673, 0,
+    0, 2121, 3157, 0, 3178, 0, 0, 1746, 3088, 1923, 0, 0, 970, 1934, 0, 2828,
+    3558, 1752, 0, 0, 0, 3364, 0, 0, 0, 0, 0, 2593, 1682, 3821, 0, 3876,
+    2397, 3592, 0, 0, 0, 1683, 0, 0, 841, 0, 3961, 3288, 3299, 0, 2560, 3210,
+    3494, 0, 0, 1494, 2649, 770, 0, 0, 2576, 2113, 2824, 2560, 0, 0, 2528, 0,
+    1752, 0, 0, 4093, 2916, 0, 4029, 3565, 2443, 0, 0, 0, 0, 0, 0, 0,
+    4029, 1429, 3566, 0, 2200, 4028, 0, 0, 1869, 2714, 0, 0, 2532, 0, 2129, 3730,
+    2387, 0, 0, 3105, 0, 0, 0, 0, 1932, 1753, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 2014, 2257, 1487, 0, 0, 0, 0, 0,
+    0, 1539, 3535, 2781, 0, 0, 0, 1361, 0, 2467, 0, 1754, 1609, 0, 1997, 0,
+    2391, 0, 2712, 2064, 0, 0, 1412, 0, 0, 3827, 3572, 0, 0, 0, 0, 2517,
+    0, 2898, 0, 0, 0, 0, 0, 3956, 1169, 1617, 0, 1098, 3605, 1089, 909, 2690,
+    0, 1096, 0, 0, 707, 3559, 0, 2963, 3885, 712, 0, 2789, 1427, 2518, 0, 3962,
+    0, 1169, 0, 1358, 1486, 1754, 2007, 0, 0, 2584, 1423, 2434, 0, 0, 0, 3049,
+    0, 0, 3308, 2786, 3031, 0, 0, 0, 0, 0, 904, 3876, 3963, 0, 1753, 3413,
+    0, 0, 3099, 2781, 3756, 1351, 0, 4089, 2202, 0, 1740, 1806, 0, 2013, 0, 387,
+    3306, 3704, 642, 0, 129, 1358, 0, 0, 0, 3437, 968, 3891, 0, 0, 0, 0,
+    64, 0, 4086, 3663, 0, 0, 2924, 0, 3505, 0, 129, 0, 0, 0, 3050, 0,
+    0, 0, 2373, 0, 0, 1747, 3307, 0, 0, 0, 0, 3827, 0, 1491, 3925, 4079,
+    0, 0, 0, 0, 3308, 4077, 0, 0, 706, 2648, 1291, 0, 1554, 0, 2503, 3633,
+    2630, 1477, 0, 0, 1858, 3444, 0, 907, 0, 0, 0, 2129, 3092, 0, 649, 0,
+    2203, 0, 0, 2381, 0, 1683, 0, 0, 4041, 0, 0, 3764, 0, 3619, 1552, 1233,
+    0, 0, 0, 3959, 3054, 0, 0, 1026, 0, 0, 0, 0, 0, 1292, 0, 3050,
+    0, 0, 1805, 0, 0, 2706, 2722, 2136, 2463, 0, 0, 0, 0, 454, 0, 1949,
+    0, 0, 0, 900, 0, 1745, 2922, 0, 0, 0, 4026, 0, 387, 1217, 1858, 0,
+    2852, 0, 0, 2322, 0, 0, 0, 1489, 1152, 0, 2593, 2131, 0, 0, 0, 714,
+    0, 2308, 3584, 0, 0, 1426, 0, 0, 0, 3373, 0, 4039, 3684, 0, 3332, 0,
+    2526, 0, 1544, 0, 0, 2007, 3503, 0, 0, 973, 0, 649, 0, 0, 389, 0,
+    0, 0, 3085, 2260, 2056, 0, 584, 0, 0, 1489, 0, 0, 0, 0, 1672, 1228,
+    0, 0, 4032, 0, 2794, 0, 323, 3649, 2009, 1799, 2269, 0, 0, 0, 1488, 1097,
+    0, 3267, 2274, 3769, 3444, 0, 1553, 0, 2401, 0, 2854, 2972, 387, 0, 0, 3046,
+    1611, 0, 0, 0, 3548, 0, 0, 0, 0, 2139, 0, 0, 3826, 2268, 2706, 2698,
+    1811, 2117, 0, 649, 2209, 0, 0, 0, 2338, 0, 0, 1883, 3114, 0, 0, 0,
+    0, 0, 2508, 0, 2987, 0, 0, 0, 2651, 0, 0, 0, 0, 2332, 0, 0,
+    3890, 0, 3051, 0, 0, 2203, 0, 0, 0, 0, 4022, 0, 0, 1681, 0, 2391,
+    1739, 1811, 2006, 0, 0, 2649, 0, 0, 0, 1429, 0, 1347, 0, 0, 0, 0,
+    0, 0, 3372, 3096, 0, 3769, 0, 0, 2511, 576, 0, 0, 0, 0, 0, 0,
+    0, 0, 2517, 3236, 0, 3689, 772, 901, 0, 2664, 2313, 712, 0, 3591, 1091, 0,
+    4025, 0, 2572, 3569, 3990, 0, 1493, 2637, 0, 773, 0, 0, 2845, 0, 2820, 2446,
+    1478, 1935, 0, 0, 0, 0, 3362, 2313, 3819, 0, 0, 0, 0, 2438, 3548, 2851,
+    0, 773, 0, 0, 0, 0, 3994, 2690, 1745, 1880, 0, 3893, 0, 0, 0, 0,
+    0, 3925, 3505, 0, 0, 2332, 3829, 0, 1949, 2048, 1298, 2532, 0, 0, 0, 2844,
+    0, 0, 0, 0, 0, 1810, 1731, 3113, 0, 2985, 0, 0, 3746, 0, 1492, 0,
+    0, 2455, 584, 0, 3309, 0, 0, 1884, 3961, 0, 1228, 0, 0, 0, 2463, 0,
+    3951, 0, 454, 3742, 3234, 1357, 0, 2988, 0, 2398, 3976, 0, 0, 3574, 0, 0,
+    0, 0, 2917, 3527, 0, 1350, 1881, 2268, 0, 0, 0, 2469, 0, 0, 0, 1228,
+    2528, 776, 0, 1935, 3624, 0, 705, 0, 2066, 0, 0, 3301, 2644, 0, 0, 0,
+    4026, 2845, 0, 2194, 2518, 0, 3502, 3994, 0, 3300, 2073, 0, 1229, 903, 4015, 0,
+    2630, 0, 0, 0, 0, 2945, 1290, 0, 0, 0, 0, 2469, 0, 1233, 3504, 3573,
+    2257, 0, 1032, 0, 2880, 0, 1296, 3397, 0, 3439, 0, 0, 0, 4094, 0, 0,
+    3787, 2656, 0, 1945, 0, 3248, 0, 0, 0, 3112, 0, 0, 1746, 3886, 3397, 0,
+    0, 3313, 0, 2202, 2755, 0, 0, 0, 0, 0, 0, 2787, 1878, 451, 0, 0,
+    0, 0, 0, 0, 0, 2633, 0, 0, 0, 0, 836, 0, 0, 0, 0, 0,
+    0, 0, 2443, 0, 0, 0, 0, 0, 3572, 1168, 0, 0, 2853, 0, 1669, 1486,
+    2880, 3884, 0, 0, 0, 2008, 0, 2846, 0, 0, 0, 2007, 3677, 2404, 0, 0,
+    0, 0, 0, 0, 1926, 0, 0, 2331, 2982, 0, 0, 0, 0, 4088, 0, 0,
+    0, 0, 576, 0, 0, 0, 0, 3248, 4039, 1349, 0, 0, 0, 0, 0, 3753,
+    0, 2130, 0, 0, 908, 0, 2767, 3852, 0, 3957, 0, 0, 2138, 0, 0, 0,
+    0, 0, 905, 0, 1104, 0, 906, 0, 2194, 0, 3474, 0, 1494, 4094, 0, 0,
+    0, 3364, 2973, 2400, 1220, 2531, 0, 0, 3478, 0, 3184, 2658, 0, 3892, 0, 0,
+    2387, 0, 0, 4088, 3509, 0, 0, 3373, 1227, 0, 2203, 3080, 0, 0, 2898, 1741,
+    1155, 2889, 0, 0, 3493, 3478, 0, 0, 0, 0, 0, 0, 3625, 0, 0, 1421,
+    3047, 2469, 2397, 0, 2586, 1347, 0, 0, 0, 0, 2918, 0, 2658, 0, 1169, 0,
+    0, 2008, 2579, 0, 0, 641, 1683, 709, 2304, 3892, 0, 0, 0, 3226, 0, 2889,
+    0, 0, 0, 0, 2893, 0, 840, 0, 1814, 389, 0, 0, 4068, 2526, 0, 2396,
+    1032, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2369,
+    0, 128, 0, 0, 0, 1616, 0, 1741, 0, 0, 2793, 2579, 3436, 2527, 0, 0,
+    3435, 0, 2192, 2986, 451, 0, 3502, 0, 1688, 2404, 3670, 1364, 3169, 2452, 0, 2339,
+    3955, 0, 2137, 0, 0, 4022, 0, 3171, 1221, 1947, 0, 0, 0, 1748, 779, 0,
+    2981, 0, 3598, 0, 0, 0, 0, 2391, 0, 776, 2398, 3917, 0, 3633, 0, 0,
+    0, 0, 0, 0, 2073, 3951, 3811, 0, 3699, 0, 1291, 0, 0, 4024, 4041, 0,
+    0, 2852, 0, 0, 0, 2593, 0, 3612, 3521, 3169, 1947, 2138, 0, 641, 0, 2391,
+    0, 0, 1233, 0, 0, 0, 0, 0, 2985, 3960, 3948, 0, 0, 0, 3503, 0,
+    0, 0, 3248, 3673, 2464, 3442, 3769, 0, 0, 0, 0, 1812, 0, 2788, 0, 0,
+    3960, 3657, 3039, 0, 779, 3956, 2272, 1941, 0, 3619, 0, 0, 2980, 3413, 4090, 0,
+    2136, 0, 323, 3684, 0, 0, 0, 3314, 2721, 3882, 2531, 2656, 0, 2713, 0, 0,
+    0, 0, 0, 0, 3503, 1867, 3543, 0, 0, 0, 1299, 0, 0, 2446, 3521, 0,
+    0, 0, 0, 0, 1999, 0, 1934, 0, 3241, 0, 3507, 0, 0, 1293, 0, 3168,
+    2247, 0, 2333, 2261, 0, 643, 2304, 3440, 0, 2787, 0, 2787, 3483, 3808, 3527, 0,
+    1681, 0, 0, 1493, 902, 2462, 0, 2261, 772, 0, 0, 0, 0, 1876, 0, 0,
+    4087, 0, 0, 0, 3177, 0, 0, 0, 0, 0, 0, 0, 1946, 0, 0, 0,
+    3242, 0, 2793, 0, 3438, 3762, 2657, 0, 0, 3834, 0, 0, 2989, 3202, 0, 2467,
+    0, 968, 2443, 3507, 974, 3985, 0, 0, 0, 0, 0, 4078, 0, 1098, 0, 0,
+    2258, 2528, 3697, 0, 3309, 0, 2400, 709, 3933, 0, 0, 0, 3957, 0, 0, 2856,
+    0, 0, 0, 0, 0, 0, 3431, 0, 0, 0, 0, 3301, 0, 0, 2333, 1875,
+    0, 3763, 1232, 0, 0, 0, 2534, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    908, 0, 2508, 0, 0, 1424, 0, 1415, 3297, 0, 3049, 0, 1819, 0, 0, 3624,
+    3722, 0, 0, 3821, 3435, 0, 0, 4050, 2272, 0, 0, 1747, 3819, 0, 3113, 0,
+    0, 3898, 0, 0, 0, 3046, 2334, 0, 2209, 0, 3807, 642, 1604, 1624, 0, 0,
+    1869, 2983, 0, 0, 450, 0, 3714, 0, 0, 0, 2641, 1618, 0, 1553, 0, 0,
+    0, 129, 0, 0, 1552, 3817, 3178, 0, 0, 2503, 2144, 0, 0, 324, 0, 2592,
+    0, 0, 0, 3051, 2793, 0, 0, 3941, 0, 0, 3591, 0, 0, 3691, 3570, 0,
+    0, 0, 0, 640, 2714, 0, 3714, 0, 2716, 2200, 3814, 0, 1932, 0, 0, 0,
+    0, 3421, 0, 0, 0, 0, 1297, 0, 0, 0, 0, 0, 0, 3429, 3632, 2989,
+    2593, 0, 0, 3956, 0, 0, 0, 0, 3600, 3430, 2592, 1811, 0, 0, 1610, 2056,
+    648, 3697, 0, 2202, 0, 0, 3933, 0, 0, 0, 0, 0, 1734, 0, 0, 0,
+    3964, 2820, 0, 2381, 3769, 0, 3157, 0, 0, 1810, 2981, 0, 0, 3959, 4091, 0,
+    0, 0, 1163, 0, 0, 1421, 0, 0, 0, 0, 0, 0, 3502, 3929, 3308, 0,
+    0, 0, 1680, 1294, 0, 3373, 3828, 0, 0, 0, 0, 0, 0, 0, 2243, 0,
+    3241, 3501, 3145, 3242, 0, 3439, 0, 3421, 0, 0, 0, 0, 0, 2265, 0, 0,
+    0, 4079, 1286, 0, 2785, 3681, 1926, 974, 0, 0, 0, 0, 4021, 0, 1426, 0,
+    0, 1162, 0, 3442, 3569, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1428, 1421,
+    0, 1731, 3909, 0, 2272, 1674, 1616, 2499, 1880, 0, 0, 2767, 0, 0, 2839, 0,
+    258, 0, 2011, 4012, 0, 4063, 0, 3157, 0, 2455, 387, 0, 3438, 0, 0, 0,
+    2856, 4032, 0, 3624, 3019, 0, 3787, 1868, 2771, 2844, 0, 0, 0, 0, 2702, 0,
+    2395, 0, 0, 0, 0, 4023, 3573, 903, 0, 0, 3818, 3832, 0, 2777, 0, 0,
+    3099, 0, 3078, 1882, 0, 2517, 1608, 0, 0, 257, 0, 0, 0, 577, 1546, 0,
+    1297, 0, 0, 2074, 2453, 0, 0, 0, 0, 1746, 0, 3779, 0, 0, 0, 3348,
+    0, 2462, 2194, 0, 0, 0, 0, 0, 2650, 2768, 513, 0, 0, 0, 3462, 2316,
+    0, 2006, 2192, 3052, 0, 1025, 0, 1096, 0, 1364, 454, 2464, 0, 0, 2644, 0,
+    2533, 3566, 0, 2989, 453, 3222, 2641, 0, 1731, 450, 259, 0, 0, 3046, 0, 3761,
+    2794, 2266, 2196, 3249, 1097, 0, 0, 0, 3054, 0, 2469, 902, 453, 2337, 0, 0,
+    0, 0, 3171, 1553, 0, 2729, 454, 3860, 3864, 0, 0, 0, 0, 2987, 2403, 3634,
+    1991, 0, 3761, 3698, 3829, 3436, 2397, 3240, 1877, 3015, 0, 1867, 0, 0, 0, 2593,
+    2178, 1234, 0, 3287, 0, 0, 0, 1676, 773, 0, 0, 2659, 0, 0, 3565, 0,
+    0, 0, 0, 0, 2316, 0, 0, 0, 0, 0, 0, 0, 3632, 2452, 840, 0,
+    1737, 0, 3920, 1753, 3372, 3600, 3882, 1932, 1168, 0, 0, 2013, 2519, 0, 3884, 1754,
+    1163, 0, 2243, 0, 3899, 3605, 0, 0, 0, 0, 3037, 3608, 0, 2434, 1156, 0,
+    2885, 3958, 0, 0, 0, 453, 0, 0, 0, 3313, 0, 0, 0, 2917, 0, 0,
+    0, 3428, 0, 0, 0, 0, 2006, 3153, 0, 3559, 0, 1152, 2131, 0, 3023, 2576,
+    1423, 0, 0, 3698, 2065, 0, 0, 1487, 1991, 0, 0, 2851, 0, 0, 0, 0,
+    0, 0, 965, 0, 0, 3441, 3177, 2186, 0, 3956, 0, 1163, 1819, 4059, 0, 3465,
+    2257, 1234, 2128, 2073, 3243, 0, 0, 3501, 0, 0, 1672, 1552, 2333, 0, 0, 0,
+    129, 3105, 0, 1033, 643, 0, 0, 0, 3633, 4006, 0, 0, 0, 3795, 0, 1861,
+    3364, 0, 3143, 3046, 0, 0, 0, 2533, 1493, 0, 0, 0, 0, 0, 2467, 0,
+    1682, 0, 1813, 0, 2695, 2243, 0, 1429, 2334, 0, 3868, 0, 3440, 2794, 2201, 0,
+    584, 0, 1804, 2954, 1233, 2398, 3624, 2836, 2898, 0, 2378, 903, 0, 0, 0, 0,
+    0, 3868, 3048, 2400, 2195, 0, 2651, 0, 0, 0, 0, 0, 0, 4094, 0, 2880,
+    1877, 0, 0, 0, 0, 3696, 2529, 0, 2641, 0, 1161, 0, 1284, 3313, 3876, 578,
+    1357, 0, 0, 2268, 1604, 0, 2186, 3749, 2958, 3568, 0, 0, 0, 0, 771, 0,
+    2322, 0, 0, 0, 0, 3229, 0, 194, 3704, 2009, 0, 0, 779, 3359, 3456, 2779,
+    713, 771, 0, 0, 3735, 1871, 0, 3362, 0, 450, 0, 2138, 0, 974, 0, 0,
+    0, 0, 0, 1420, 2729, 0, 2592, 0, 1793, 0, 0, 0, 0, 0, 0, 0,
+    904, 0, 1943, 3365, 0, 0, 838, 0, 0, 0, 3990, 2918, 0, 0, 3704, 839,
+    1884, 0, 0, 0, 0, 2324, 1282, 0, 3038, 0, 1748, 3176, 0, 0, 0, 0,
+    0, 0, 3235, 0, 0, 3958, 2202, 0, 0, 0, 0, 0, 1358, 0, 3817, 0,
+    2378, 0, 0, 0, 0, 0, 0, 3306, 3176, 2579, 0, 2059, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2755, 2331, 0, 2208, 1802, 0,
+    0, 2534, 1879, 0, 2858, 0, 2846, 0, 0, 1991, 3898, 4063, 0, 0, 0, 385,
+    0, 0, 3860, 0, 0, 2378, 0, 0, 3028, 2986, 3248, 3677, 2399, 2259, 967, 0,
+    0, 0, 0, 0, 3437, 2195, 0, 1601, 0, 1168, 0, 0, 0, 1422, 1680, 0,
+    1813, 0, 0, 0, 3864, 0, 0, 0, 2056, 904, 4085, 0, 2721, 3478, 0, 3509,
+    0, 0, 0, 0, 0, 0, 3795, 0, 0, 4089, 0, 0, 0, 643, 0, 0,
+    0, 3494, 0, 3409, 0, 0, 0, 3568, 0, 0, 0, 1539, 3340, 0, 0, 1883,
+    3232, 0, 0, 2982, 1360, 0, 0, 2723, 0, 0, 0, 2052, 0, 1487, 2373, 0,
+    0, 969, 0, 2331, 3456, 0, 3178, 2121, 0, 0, 3297, 0, 0, 2975, 1536, 3143,
+    0, 0, 0, 0, 3762, 0, 0, 3161, 3088, 0, 0, 0, 0, 0, 2196, 0,
+    0, 1728, 844, 2434, 0, 2267, 2780, 2592, 909, 0, 259, 0, 3234, 1169, 0, 0,
+    0, 1689, 0, 0, 0, 1811, 3242, 3078, 1868, 0, 0, 0, 838, 0, 0, 3974,
+    0, 0, 966, 0, 3092, 0, 0, 0, 3899, 3288, 2178, 0, 3964, 0, 1942, 3951,
+    0, 0, 3688, 0, 0, 0, 1284, 0, 0, 2399, 776, 648, 0, 3506, 644, 2579,
+    902, 2137, 3171, 908, 1876, 3010, 3283, 0, 0, 2454, 2446, 3397, 2774, 0, 0, 2664,
+    3684, 0, 1024, 0, 0, 0, 3684, 0, 0, 0, 0, 0, 2144, 0, 0, 0,
+    2690, 2396, 0, 3873, 3048, 1493, 2204, 779, 714, 3730, 0, 2403, 901, 3626, 0, 0,
+    3803, 0, 2121, 3104, 0, 0, 2792, 2771, 0, 0, 0, 0, 0, 3955, 3442, 0,
+    2525, 0, 0, 0, 3982, 2576, 2389, 0, 0, 0, 3161, 0, 0, 0, 3356, 0,
+    0, 0, 1299, 2716, 2130, 0, 0, 0, 0, 2972, 1883, 1683, 4047, 2248, 3742, 1162,
+    0, 0, 0, 3925, 3306, 0, 2729, 0, 0, 3504, 2915, 2272, 0, 2885, 0, 0,
+    4028, 0, 0, 0, 0, 0, 3920, 0, 3964, 0, 0, 0, 0, 3104, 0, 4087,
+    0, 0, 0, 3551, 0, 388, 0, 2124, 0, 0, 0, 0, 0, 1749, 0, 0,
+    4027, 0, 0, 0, 3241, 0, 0, 2584, 971, 3052, 0, 1688, 0, 2919, 0, 1946,
+    0, 0, 1291, 3761, 0, 0, 1674, 2304, 0, 0, 1689, 0, 0, 3243, 0, 3048,
+    0, 0, 2469, 0, 0, 0, 1226, 3938, 0, 0, 0, 1536, 4071, 0, 0, 2178,
+    0, 0, 0, 3344, 2319, 0, 0, 967, 3689, 843, 0, 0, 0, 0, 713, 0,
+    0, 0, 0, 2072, 2786, 0, 2859, 0, 2972, 0, 0, 0, 3507, 0, 1227, 4024,
+    0, 0, 3933, 1351, 3244, 973, 3444, 0, 0, 3039, 0, 3598, 2851, 3405, 4086, 0,
+    1219, 0, 4029, 966, 0, 3920, 3730, 0, 0, 841, 3571, 0, 0, 0, 714, 0,
+    0, 0, 0, 0, 0, 0, 0, 64, 1422, 1423, 0, 1357, 0, 0, 0, 0,
+    2641, 0, 1347, 0, 0, 2400, 0, 0, 0, 0, 2527, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 259, 0, 3807, 1098, 0, 0, 0, 3436, 0, 0, 2332, 0,
+    3010, 0, 2907, 0, 2833, 3113, 1881, 0, 0, 1096, 0, 4085, 0, 1290, 3893, 0,
+    0, 0, 3852, 0, 2517, 0, 2714, 3976, 0, 0, 2438, 3896, 0, 0, 2922, 4063,
+    1737, 0, 1876, 0, 0, 0, 3827, 0, 3015, 0, 0, 0, 3232, 0, 1616, 0,
+    3893, 0, 2852, 0, 2519, 0, 3462, 1869, 0, 2712, 0, 2389, 3947, 0, 2839, 0,
+    1423, 0, 0, 0, 0, 2857, 3698, 0, 0, 0, 2006, 2698, 0, 0, 2722, 2920,
+    3688, 3177, 0, 2336, 0, 0, 2325, 0, 3283, 838, 0, 0, 0, 2844, 3530, 2138,
+    772, 0, 0, 714, 2846, 0, 0, 901, 3314, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 3366, 0, 0, 0, 0, 1945, 0, 0, 0, 2982, 0,
+    0, 0, 0, 0, 1666, 2251, 0, 1286, 4006, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 4086, 0, 1796, 0, 3137, 1753, 2585, 0, 0, 0, 1032,
+    0, 3301, 385, 2843, 3566, 0, 0, 0, 3566, 3742, 0, 0, 3437, 0, 0, 2269,
+    0, 1488, 0, 0, 773, 0, 0, 3037, 2534, 1876, 0, 0, 3344, 3229, 2988, 0,
+    3891, 969, 0, 2921, 0, 0, 0, 3023, 0, 2723, 512, 0, 0, 2788, 0, 0,
+    2059, 324, 0, 0, 3763, 2945, 0, 0, 128, 3665, 0, 3605, 0, 2724, 0, 2404,
+    0, 0, 2267, 1884, 0, 0, 3344, 0, 0, 0, 4022, 1539, 0, 836, 0, 0,
+    0, 0, 3344, 0, 0, 0, 1361, 3143, 1940, 0, 0, 0, 3559, 0, 0, 0,
+    3826, 1429, 0, 0, 4047, 3308, 0, 4092, 0, 3312, 2777, 0, 0, 2266, 0, 3153,
+    0, 0, 2248, 0, 0, 3543, 2381, 1154, 1360, 2986, 2138, 0, 0, 1988, 0, 1161,
+    0, 0, 3548, 0, 3950, 0, 2974, 0, 0, 0, 0, 0, 0, 0, 0, 837,
+    1477, 3754, 774, 0, 0, 0, 0, 3896, 0, 1923, 2267, 1949, 3161, 0, 0, 2852,
+    0, 3567, 0, 0, 0, 0, 0, 0, 577, 0, 0, 0, 3340, 2763, 3164, 1361,
+    0, 2793, 0, 3868, 2794, 4089, 0, 0, 3483, 1364, 0, 3031, 0, 0, 2526, 0,
+    0, 0, 0, 0, 0, 2209, 0, 2728, 3037, 778, 0, 0, 2657, 0, 0, 3241,
+    0, 0, 0, 0, 3179, 0, 2657, 3427, 1601, 320, 0, 0, 2052, 2072, 0, 0,
+    4087, 0, 0, 0, 2774, 0, 0, 835, 0, 0, 3483, 2787, 4015, 712, 0, 0,
+    0, 1218, 0, 0, 0, 0, 0, 0, 0, 2465, 0, 0, 0, 0, 0, 0,
+    0, 2950, 1666, 0, 2326, 1988, 3298, 0, 0, 3438, 970, 0, 839, 3307, 0, 0,
+    0, 0, 0, 0, 0, 1356, 0, 2468, 0, 0, 0, 841, 3297, 1358, 2144, 0,
+    2530, 0, 0, 0, 0, 2919, 0, 1349, 0, 1948, 0, 3941, 3184, 0, 4093, 0,
+    0, 0, 3283, 0, 0, 0, 0, 0, 1164, 0, 0, 2137, 0, 0, 0, 0,
+    0, 0, 2463, 2530, 0, 0, 966, 3608, 0, 4028, 0, 3752, 0, 0, 0, 3470,
+    2724, 3052, 0, 0, 0, 0, 584, 0, 0, 0, 1219, 1681, 0, 3626, 0, 0,
+    3400, 578, 0, 1163, 0, 0, 0, 0, 1363, 2723, 3429, 0, 1425, 0, 3795, 0,
+    0, 324, 0, 2404, 2463, 0, 0, 3762, 1878, 1673, 2073, 0, 0, 0, 3019, 0,
+    1363, 0, 0, 0, 452, 0, 0, 779, 0, 0, 2779, 388, 0, 2454, 0, 0,
+    0, 3834, 2709, 3164, 0, 2273, 0, 0, 2774, 451, 0, 0, 0, 0, 0, 0,
+    2052, 2657, 1024, 0, 192, 0, 2584, 2388, 2984, 2721, 0, 2322, 0, 776, 0, 1816,
+    0, 1025, 1291, 0, 0, 0, 3028, 1356, 1934, 1492, 3291, 0, 0, 3879, 649, 2257,
+    0, 0, 2332, 0, 1098, 0, 0, 0, 1728, 1867, 0, 3377, 3608, 2013, 0, 0,
+    193, 0, 2388, 0, 0, 1153, 3038, 2656, 0, 1098, 0, 0, 1878, 2565, 0, 0,
+    4093, 3169, 0, 0, 3844, 1998, 967, 0, 0, 1423, 2527, 0, 0, 0, 3184, 0,
+    1943, 1160, 2777, 0, 4027, 4055, 0, 3974, 0, 0, 0, 2072, 2713, 2268, 1813, 0,
+    0, 2728, 0, 3249, 0, 0, 0, 0, 3306, 0, 0, 0, 1869, 0, 3378, 1676,
+    0, 0, 3696, 1618, 0, 3440, 0, 0, 3951, 0, 3998, 0, 0, 2266, 0, 3886,
+    2916, 3438, 838, 1283, 579, 0, 0, 0, 2332, 0, 3527, 0, 3353, 0, 1683, 0,
+    1688, 3985, 3049, 4088, 712, 0, 2048, 2014, 1943, 0, 0, 0, 0, 3962, 2901, 0,
+    0, 0, 970, 0, 2113, 2326, 2461, 256, 1877, 2527, 0, 0, 0, 1669, 2265, 0,
+    0, 0, 0, 0, 2898, 3234, 0, 2924, 3405, 3985, 0, 0, 2529, 0, 0, 0,
+    320, 2651, 0, 2073, 0, 2633, 0, 3431, 0, 2824, 2324, 0, 0, 3884, 0, 0,
+    0, 2529, 0, 0, 0, 0, 2586, 3649, 0, 0, 0, 3362, 0, 0, 2203, 0,
+    1491, 3400, 909, 0, 0, 0, 0, 0, 0, 0, 1674, 193, 0, 1545, 2124, 1601,
+    0, 0, 3287, 4023, 3047, 0, 3348, 0, 3376, 0, 1421, 0, 3879, 2637, 449, 3886,
+    1296, 2461, 2331, 3372, 0, 0, 0, 0, 0, 0, 0, 0, 0, 706, 0, 0,
+    708, 1153, 3917, 0, 0, 0, 0, 1675, 0, 2839, 0, 0, 0, 2064, 0, 2467,
+    0, 0, 2189, 0, 1104, 1618, 0, 0, 0, 0, 0, 0, 2136, 1813, 0, 0,
+    0, 973, 2268, 0, 0, 3819, 0, 2781, 3506, 0, 0, 1227, 0, 1609, 0, 2136,
+    1225, 3897, 1477, 0, 0, 1689, 0, 3742, 0, 0, 0, 0, 0, 4024, 0, 2572,
+    0, 0, 2695, 0, 3764, 0, 0, 0, 0, 0, 2324, 0, 1608, 0, 0, 0,
+    1026, 3885, 0, 0, 2565, 0, 0, 0, 0, 0, 2568, 0, 2072, 0, 3811, 0,
+    0, 1748, 706, 3963, 1485, 0, 0, 2530, 0, 0, 3051, 0, 1817, 2853, 3137, 324,
+    0, 3210, 0, 0, 0, 0, 0, 0, 2461, 3242, 0, 0, 2981, 0, 3665, 388,
+    1220, 0, 0, 0, 0, 2339, 2503, 3616, 0, 3504, 2204, 0, 0, 0, 4029, 0,
+    0, 0, 0, 0, 0, 0, 4094, 0, 2073, 0, 0, 1812, 3236, 1812, 1355, 3437,
+    3371, 0, 0, 0, 4020, 0, 3099, 0, 0, 0, 0, 0, 3960, 0, 3827, 2065,
+    0, 2594, 0, 0, 0, 3570, 2468, 772, 1941, 0, 2337, 0, 1364, 0, 0, 0,
+    0, 3474, 0, 0, 1356, 0, 0, 0, 0, 0, 3897, 3233, 1880, 0, 3982, 3899,
+    2267, 0, 1738, 0, 0, 2527, 0, 3828, 0, 3990, 0, 1812, 0, 0, 1479, 1689,
+    3503, 0, 2453, 0, 3054, 0, 0, 2901, 902, 2209, 2568, 0, 0, 2709, 0, 3557,
+    0, 3896, 514, 2950, 2908, 1292, 1487, 0, 0, 0, 0, 0, 0, 0, 0, 3430,
+    2258, 0, 0, 3495, 0, 0, 0, 0, 0, 2404, 2716, 0, 0, 0, 0, 0,
+    2462, 0, 0, 1880, 1283, 0, 3049, 0, 0, 0, 0, 3681, 3150, 3229, 2064, 0,
+    0, 3113, 0, 3145, 0, 0, 0, 0, 1298, 1737, 0, 0, 0, 3787, 0, 0,
+    0, 0, 3828, 0, 1478, 3502, 0, 3307, 0, 0, 0, 0, 513, 3753, 0, 0,
+    3236, 3985, 0, 0, 0, 0, 2836, 0, 2254, 2982, 0, 2387, 0, 0, 3994, 0,
+    0, 0, 0, 0, 0, 0, 322, 0, 0, 0, 0, 0, 1997, 0, 0, 0,
+    2910, 2202, 0, 0, 3762, 3769, 1944, 3826, 2006, 0, 3072, 2630, 0, 513, 2794, 2880,
+    0, 0, 3598, 3584, 0, 3177, 0, 0, 2918, 0, 0, 1617, 0, 2201, 0, 0,
+    0, 0, 0, 2454, 1299, 0, 0, 2917, 1544, 0, 3803, 0, 0, 0, 2259, 0,
+    3754, 3697, 3363, 3377, 2182, 0, 0, 0, 0, 0, 0, 3267, 2397, 0, 3825, 0,
+    0, 0, 0, 453, 0, 0, 3681, 0, 0, 4003, 0, 3633, 2984, 3749, 2390, 0,
+    0, 0, 2144, 0, 0, 0, 0, 1604, 0, 0, 2657, 3619, 0, 0, 0, 714,
+    64, 1412, 1350, 0, 454, 0, 842, 0, 0, 2709, 1799, 0, 1356, 2916, 3826, 0,
+    2464, 1163, 0, 3974, 0, 0, 2586, 0, 0, 0, 0, 2192, 0, 1804, 0, 0,
+    0, 3503, 0, 0, 1804, 0, 0, 0, 0, 0, 0, 0, 579, 4024, 2499, 0,
+    2209, 0, 0, 2989, 0, 1619, 0, 3673, 0, 0, 0, 3571, 0, 0, 2981, 3752,
+    1364, 0, 0, 1356, 0, 0, 0, 0, 2966, 3046, 0, 514, 1946, 1428, 0, 2319,
+    1156, 0, 1479, 2820, 0, 0, 3143, 0, 0, 0, 3938, 1988, 2916, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 2918, 0, 0, 2012, 1948, 0, 0,
+    3860, 0, 3019, 2923, 389, 772, 0, 0, 0, 0, 3348, 0, 0, 0, 1933, 1096,
+    0, 0, 0, 0, 1293, 0, 0, 3028, 0, 3722, 0, 0, 0, 0, 2534, 0,
+    2986, 3897, 3663, 2916, 0, 0, 0, 0, 2248, 2842, 1682, 2720, 0, 0, 0, 0,
+    0, 0, 0, 192, 0, 2209, 3821, 3557, 3092, 0, 0, 0, 0, 3096, 0, 0,
+    0, 2722, 2189, 0, 0, 3106, 3501, 4063, 2923, 0, 0, 0, 3899, 0, 2182, 2637,
+    2254, 0, 4071, 0, 705, 1754, 0, 2381, 1494, 2052, 0, 1933, 0, 0, 0, 0,
+    3808, 0, 0, 2963, 0, 1494, 3436, 1162, 3567, 0, 0, 0, 2728, 0, 2975, 0,
+    0, 0, 3045, 0, 0, 2460, 906, 0, 0, 3053, 2465, 0, 3287, 0, 0, 0,
+    3222, 0, 0, 3896, 0, 1490, 0, 0, 2954, 0, 0, 0, 1752, 0, 0, 0,
+    3879, 0, 4079, 0, 3976, 3539, 0, 3031, 0, 0, 0, 0, 0, 2924, 2369, 3301,
+    2560, 0, 0, 0, 1427, 3961, 0, 3215, 0, 0, 1684, 0, 2984, 0, 0, 0,
+    0, 0, 0, 1232, 0, 0, 0, 0, 0, 2923, 0, 2560, 0, 1748, 0, 2779,
+    0, 3649, 2012, 3677, 3400, 513, 1878, 0, 0, 0, 256, 3429, 0, 4077, 909, 1806,
+    0, 0, 0, 2201, 0, 3241, 1672, 0, 1616, 0, 0, 3807, 0, 1941, 0, 0,
+    0, 771, 2511, 0, 1282, 1297, 0, 2186, 0, 0, 0, 1360, 3634, 1949, 0, 0,
+    0, 0, 0, 3170, 0, 2267, 0, 0, 0, 0, 0, 0, 1359, 0, 0, 0,
+    0, 3632, 1818, 0, 0, 0, 0, 0, 449, 3371, 2193, 0, 0, 0, 3470, 2918,
+    0, 0, 1162, 0, 0, 0, 0, 0, 971, 0, 0, 3574, 0, 451, 0, 3054,
+    2921, 0, 2331, 3787, 0, 778, 0, 0, 1422, 1728, 0, 1802, 0, 1361, 0, 0,
+    2850, 0, 0, 0, 1746, 1618, 0, 0, 1796, 0, 0, 1169, 840, 1943, 0, 0,
+    1358, 0, 1096, 0, 2576, 0, 836, 0, 3222, 0, 0, 3598, 2072, 0, 0, 972,
+    2755, 771, 4091, 0, 0, 4014, 3202, 1486, 0, 1429, 1219, 0, 0, 0, 0, 1618,
+    2721, 0, 1294, 0, 0, 1673, 3072, 1806, 1479, 0, 0, 2974, 2767, 0, 3677, 0,
+    0, 3574, 3899, 0, 2308, 1426, 1154, 0, 512, 0, 0, 0, 3844, 0, 1285, 2586,
+    1486, 0, 0, 0, 0, 0, 0, 0, 0, 2777, 1296, 0, 0, 2259, 0, 0,
+    2658, 0, 0, 0, 4012, 0, 0, 0, 0, 2208, 0, 0, 0, 3941, 1420, 0,
+    2858, 3714, 3072, 0, 0, 966, 0, 3080, 0, 1349, 1814, 1683, 3314, 0, 1941, 2308,
+    0, 0, 0, 0, 1617, 0, 0, 0, 0, 2465, 0, 0, 1424, 0, 0, 4071,
+    0, 0, 0, 0, 0, 0, 1217, 972, 1624, 3982, 2009, 1544, 2917, 1228, 0, 1793,
+    0, 3096, 450, 3612, 322, 0, 1228, 2572, 0, 2402, 2534, 0, 0, 3275, 389, 0,
+    0, 0, 1282, 4079, 2462, 0, 128, 3332, 0, 2839, 1490, 2129, 3957, 2723, 3370, 1817,
+    0, 1089, 3226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3569, 0, 0, 3243,
+    0, 3470, 0, 0, 2201, 0, 3164, 3373, 0, 0, 0, 2698, 2922, 0, 0, 0,
+    0, 0, 0, 0, 1491, 1090, 3373, 0, 0, 0, 0, 0, 2247, 0, 3366, 0,
+    1298, 0, 1942, 3275, 0, 0, 4026, 3868, 0, 2532, 1675, 1748, 3085, 1228, 0, 3359,
+    0, 4092, 1879, 0, 3413, 0, 0, 0, 0, 0, 3312, 3366, 1488, 3763, 3048, 0,
+    2192, 2203, 4059, 0, 0, 1624, 0, 192, 3299, 0, 0, 3600, 0, 3539, 0, 0,
+    0, 0, 0, 0, 2337, 0, 0, 0, 0, 1234, 0, 1819, 4013, 3811, 0, 0,
+    0, 0, 0, 0, 0, 1552, 2723, 0, 0, 0, 0, 3501, 0, 0, 3249, 3047,
+    4050, 0, 3925, 2260, 3566, 0, 0, 2789, 2966, 321, 4013, 2511, 1728, 0, 0, 2196,
+    1297, 1293, 3500, 385, 0, 2508, 0, 0, 0, 2268, 1546, 0, 0, 3371, 1553, 0,
+    3551, 1858, 0, 2323, 0, 194, 3047, 0, 0, 0, 0, 2565, 0, 3762, 0, 0,
+    0, 3670, 0, 0, 0, 0, 0, 0, 0, 2989, 770, 0, 2527, 3298, 3443, 0,
+    2387, 0, 0, 3372, 0, 3998, 2763, 3365, 0, 0, 1871, 3308, 772, 3592, 0, 0,
+    3834, 2137, 2966, 0, 0, 1877, 0, 0, 0, 3356, 0, 844, 2585, 0, 0, 0,
+    384, 64, 0, 0, 843, 1947, 1818, 0, 2266, 0, 3817, 3625, 1428, 1804, 0, 0,
+    2778, 0, 0, 2274, 0, 903, 3673, 0, 0, 1624, 0, 2722, 0, 0, 3735, 0,
+    0, 0, 0, 0, 1286, 0, 1676, 0, 3377, 1096, 3948, 0, 0, 0, 2625, 0,
+    2443, 2531, 1479, 0, 0, 2779, 844, 0, 0, 0, 0, 0, 1999, 1358, 0, 2144,
+    0, 0, 0, 0, 0, 0, 707, 0, 4003, 1948, 2333, 1161, 3164, 0, 0, 3894,
+    0, 4012, 0, 0, 1097, 0, 0, 0, 0, 0, 0, 0, 2373, 0, 3465, 0,
+    2625, 3568, 321, 0, 3883, 0, 3665, 0, 0, 1991, 1611, 3291, 3539, 0, 0, 0,
+    3106, 0, 0, 1226, 0, 836, 0, 0, 0, 0, 3779, 384, 0, 1293, 0, 0,
+    2768, 1616, 2664, 0, 0, 0, 0, 1544, 1286, 0, 1609, 0, 3161, 0, 0, 0,
+    0, 323, 0, 3976, 0, 1942, 0, 2059, 968, 0, 0, 2715, 0, 0, 0, 0,
+    2499, 0, 0, 1425, 0, 0, 0, 2402, 3722, 0, 0, 0, 3353, 4021, 2124, 969,
+    0, 3294, 0, 2945, 2907, 3376, 0, 3235, 0, 3379, 1421, 2519, 0, 1154, 4094, 0,
+    2201, 0, 0, 0, 0, 3418, 3814, 2005, 0, 386, 967, 0, 1672, 4091, 1796, 0,
+    0, 1949, 0, 452, 1088, 2466, 0, 0, 2763, 0, 0, 1104, 3428, 0, 0, 0,
+    3569, 0, 0, 0, 0, 0, 2901, 0, 0, 1813, 0, 2117, 0, 0, 0, 0,
+    0, 2397, 3441, 4027, 0, 3570, 0, 0, 2144, 0, 0, 2954, 0, 3184, 0, 0,
+    0, 0, 0, 0, 0, 2326, 1428, 0, 777, 713, 0, 3899, 2324, 0, 0, 0,
+    0, 0, 0, 2014, 2768, 2254, 0, 0, 3714, 0, 0, 0, 3814, 0, 0, 0,
+    1098, 707, 2466, 3632, 0, 3176, 4025, 0, 0, 0, 3500, 0, 452, 0, 0, 0,
+    0, 1490, 0, 0, 0, 844, 0, 0, 0, 0, 1232, 707, 1546, 0, 2972, 0,
+    0, 0, 2336, 1876, 0, 2729, 3891, 649, 2396, 0, 0, 0, 1816, 2954, 0, 0,
+    967, 577, 0, 0, 1819, 3722, 0, 0, 0, 0, 842, 0, 0, 0, 0, 967,
+    0, 3376, 1881, 0, 0, 2064, 0, 0, 0, 3306, 902, 0, 0, 3605, 1152, 0,
+    0, 0, 3413, 0, 0, 0, 0, 2007, 0, 1299, 0, 0, 0, 3559, 0, 0,
+    4087, 0, 0, 0, 3438, 1617, 3779, 0, 3690, 0, 1161, 0, 0, 0, 3567, 0,
+    0, 513, 0, 2983, 0, 0, 2889, 2010, 0, 384, 0, 3698, 1871, 2771, 0, 3210,
+    2706, 0, 0, 2858, 0, 3696, 0, 3844, 0, 3418, 3366, 0, 0, 0, 0, 642,
+    3176, 1363, 3964, 1492, 0, 0, 0, 0, 0, 1881, 0, 0, 3179, 0, 3379, 2461,
+    0, 2398, 3370, 0, 0, 0, 2389, 1802, 0, 0, 0, 2128, 3045, 0, 2469, 2788,
+    2859, 0, 0, 0, 3941, 0, 3817, 0, 1234, 0, 0, 0, 0, 0, 0, 1024,
+    2721, 3088, 4059, 0, 0, 4029, 2326, 0, 0, 0, 0, 0, 3501, 778, 2786, 0,
+    0, 0, 2337, 448, 0, 0, 4027, 0, 2856, 0, 1234, 0, 0, 3275, 0, 4025,
+    3486, 0, 0, 907, 0, 0, 3184, 0, 0, 2920, 3178, 0, 0, 0, 2138, 0,
+    3665, 641, 2402, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3492, 0, 0, 0,
+    0, 0, 1997, 3974, 0, 0, 0, 3474, 3864, 1803, 1536, 0, 0, 3436, 2950, 2768,
+    0, 0, 0, 3114, 1415, 3696, 0, 3243, 3113, 0, 648, 0, 2843, 3696, 0, 0,
+    0, 3218, 0, 0, 0, 0, 0, 3106, 0, 0, 2136, 2403, 837, 0, 2006, 0,
+    0, 1666, 1413, 3275, 2136, 2251, 2650, 0, 0, 0, 0, 3234, 1669, 2656, 2398, 0,
+    1293, 0, 2452, 0, 0, 0, 2659, 0, 0, 3299, 0, 0, 0, 2319, 3876, 0,
+    3177, 0, 0, 0, 3768, 0, 3047, 3663, 3218, 0, 3106, 905, 0, 3893, 0, 0,
+    0, 0, 0, 1861, 3053, 0, 386, 2702, 3340, 0, 0, 258, 0, 0, 0, 0,
+    4006, 0, 2781, 2532, 3698, 3092, 3287, 2828, 0, 3294, 0, 0, 0, 3019, 0, 0,
+    0, 2885, 0, 0, 0, 2722, 2858, 0, 2851, 0, 0, 974, 838, 2658, 1747, 0,
+    2308, 1948, 0, 3879, 4041, 4055, 837, 1945, 0, 1024, 1536, 256, 3947, 3826, 3567, 4023,
+    1414, 0, 0, 3760, 3535, 0, 2333, 0, 2010, 905, 0, 3112, 0, 0, 0, 258,
+    778, 0, 0, 389, 1681, 3232, 4077, 0, 2901, 3312, 2715, 3632, 0, 0, 4022, 3112,
+    0, 1999, 0, 3897, 1169, 0, 4039, 2833, 3958, 0, 843, 2786, 0, 3600, 454, 0,
+    0, 0, 2909, 0, 3803, 1552, 0, 0, 0, 0, 0, 2129, 2117, 3379, 0, 0,
+    0, 3818, 2966, 0, 3356, 0, 0, 0, 1298, 0, 2319, 903, 0, 0, 0, 0,
+    192, 0, 0, 0, 1427, 0, 0, 0, 0, 0, 0, 4014, 2251, 0, 970, 0,
+    0, 0, 0, 0, 0, 4032, 0, 0, 0, 3509, 0, 0, 3892, 0, 0, 0,
+    0, 0, 1681, 0, 3153, 256, 0, 3470, 0, 1097, 2853, 0, 2403, 836, 0, 1553,
+    1362, 3243, 3363, 1421, 0, 0, 2859, 0, 1156, 0, 2316, 3495, 1817, 0, 842, 1490,
+    0, 2983, 0, 0, 0, 0, 0, 3755, 1292, 0, 3176, 1090, 257, 0, 0, 0,
+    3218, 3584, 0, 644, 2465, 1424, 774, 3820, 2852, 3267, 2729, 0, 0, 0, 2917, 641,
+    0, 0, 0, 3828, 0, 0, 1351, 0, 3633, 0, 0, 2792, 0, 3307, 0, 0,
+    0, 0, 0, 3080, 0, 908, 320, 0, 0, 0, 0, 2983, 0, 1494, 0, 0,
+    0, 3427, 3249, 0, 0, 3573, 2401, 0, 2989, 0, 3964, 2014, 1943, 0, 0, 3232,
+    0, 0, 2853, 0, 0, 1363, 3884, 0, 1545, 1226, 2853, 0, 0, 2836, 0, 0,
+    0, 1091, 0, 0, 0, 0, 0, 0, 3508, 1284, 0, 0, 3929, 2466, 0, 0,
+    1802, 3444, 4059, 0, 0, 3441, 3409, 3010, 2828, 0, 1818, 2916, 2859, 0, 0, 0,
+    0, 2323, 0, 0, 1858, 0, 1357, 0, 0, 0, 0, 3893, 4087, 0, 3508, 3436,
+    3570, 0, 3909, 2842, 0, 2011, 0, 1026, 194, 0, 3571, 0, 3505, 0, 2397, 2462,
+    0, 0, 0, 0, 642, 2786, 0, 0, 2461, 0, 0, 2464, 0, 0, 0, 0,
+    0, 0, 0, 3535, 4091, 0, 0, 0, 0, 2395, 0, 641, 3039, 0, 2924, 0,
+    3892, 0, 0, 0, 0, 0, 0, 0, 3892, 0, 2908, 0, 1816, 777, 0, 0,
+    0, 907, 3465, 2466, 0, 3053, 0, 0, 0, 3557, 0, 0, 0, 576, 0, 3958,
+    0, 3754, 0, 0, 3441, 2389, 0, 0, 0, 1689, 0, 0, 0, 257, 3543, 0,
+    0, 0, 3749, 0, 0, 2313, 0, 0, 0, 0, 966, 1934, 1221, 2909, 0, 0,
+    1348, 0, 0, 3819, 2434, 0, 0, 1168, 3137, 1422, 1218, 3755, 4050, 0, 0, 0,
+    0, 2009, 0, 0, 0, 4089, 0, 0, 0, 0, 0, 0, 0, 2630, 0, 0,
+    1034, 2984, 3956, 1034, 1616, 0, 0, 0, 3825, 0, 2592, 0, 3353, 1617, 1734, 1033,
+    0, 3756, 0, 3689, 2208, 0, 0, 0, 0, 0, 0, 0, 1089, 0, 0, 0,
+    2463, 0, 0, 0, 0, 0, 0, 0, 3509, 0, 0, 0, 0, 1033, 2924, 973,
+    0, 1161, 3663, 2331, 3612, 322, 3779, 3283, 0, 3509, 0, 0, 3178, 1485, 0, 2390,
+    3527, 0, 1883, 2695, 0, 3566, 907, 0, 1803, 0, 3691, 3462, 3050, 384, 2723, 0,
+    1489, 3568, 905, 3769, 1999, 0, 1152, 0, 0, 2924, 513, 3929, 3752, 708, 193, 1870,
+    2193, 0, 2113, 1285, 3483, 0, 0, 3104, 194, 2266, 0, 0, 0, 3291, 3752, 3307,
+    0, 64, 3670, 0, 0, 0, 1284, 0, 3949, 0, 2859, 0, 3443, 0, 2833, 0,
+    3746, 1805, 4014, 0, 0, 2763, 3443, 4021, 0, 3506, 2460, 0, 3400, 0, 1923, 3917,
+    0, 0, 3592, 0, 0, 643, 2648, 2792, 3820, 0, 0, 0, 0, 0, 0, 2056,
+    0, 0, 2461, 3104, 0, 706, 3633, 0, 0, 0, 3015, 3811, 0, 0, 903, 0,
+    0, 2633, 0, 3957, 0, 2182, 2917, 0, 3378, 3437, 0, 3558, 0, 2593, 0, 0,
+    4071, 0, 0, 2261, 2981, 3704, 3085, 0, 1032, 0, 0, 2304, 0, 0, 3405, 2066,
+    0, 3626, 0, 3938, 3763, 0, 2137, 0, 0, 0, 1491, 0, 2010, 0, 1688, 0,
+    3170, 0, 2066, 0, 0, 836, 0, 0, 0, 0, 0, 0, 1876, 0, 0, 3249,
+    4090, 0, 0, 0, 0, 0, 1486, 0, 0, 2788, 0, 0, 0, 3557, 844, 0,
+    0, 2273, 0, 2438, 2857, 0, 3226, 3356, 0, 0, 3539, 0, 0, 3085, 0, 3763,
+    0, 3379, 0, 0, 0, 3112, 0, 3756, 0, 649, 1291, 4077, 0, 3210, 3444, 0,
+    713, 0, 0, 3689, 0, 449, 0, 0, 0, 0, 2722, 0, 0, 0, 1749, 0,
+    2196, 3291, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2844, 0, 4047, 3808, 3873,
+    1488, 3957, 3372, 0, 0, 0, 1097, 0, 0, 0, 2958, 3374, 0, 0, 2396, 1227,
+    0, 2950, 1618, 641, 1359, 2139, 0, 2267, 3397, 0, 0, 4025, 3949, 1413, 1796, 0,
+    2468, 3947, 0, 0, 3418, 3657, 0, 3051, 3898, 3294, 2824, 0, 1033, 3571, 3373, 3486,
+    3456, 0, 2988, 2565, 3440, 3362, 0, 1347, 0, 0, 0, 3492, 0, 2842, 0, 3873,
+    0, 642, 708, 3492, 0, 965, 0, 2339, 0, 2755, 0, 0, 0, 0, 0, 385,
+    0, 0, 0, 0, 901, 3492, 0, 1494, 2664, 0, 0, 2787, 0, 3052, 0, 0,
+    3314, 0, 0, 0, 0, 3202, 0, 0, 3860, 0, 0, 0, 3749, 2247, 1942, 0,
+    0, 0, 1486, 0, 0, 3112, 0, 0, 0, 3909, 3371, 971, 2918, 0, 449, 3202,
+    3567, 0, 0, 707, 0, 0, 0, 2446, 0, 1738, 1945, 0, 0, 3958, 0, 0,
+    0, 3409, 0, 0, 0, 0, 2186, 0, 0, 841, 3314, 0, 0, 0, 0, 0,
+    0, 1944, 2594, 2014, 3891, 0, 0, 0, 0, 2528, 3691, 0, 3113, 3244, 2889, 0,
+    0, 3892, 971, 0, 901, 3572, 0, 1884, 0, 3960, 0, 1932, 0, 2720, 3486, 0,
+    1226, 3746, 4090, 0, 3929, 1099, 2113, 0, 3891, 0, 0, 0, 0, 0, 0, 194,
+    0, 1861, 0, 0, 3833, 2771, 3438, 0, 0, 448, 0, 3574, 3429, 2330, 2131, 0,
+    1349, 0, 0, 0, 0, 0, 0, 1362, 0, 0, 3612, 0, 1747, 2920, 1739, 0,
+    1232, 1156, 0, 0, 0, 3616, 2658, 3169, 0, 3236, 0, 3649, 3828, 3844, 0, 0,
+    1360, 0, 0, 3240, 3574, 909, 3443, 2851, 1737, 2709, 0, 0, 0, 3761, 3768, 0,
+    1033, 642, 0, 0, 0, 3442, 0, 1611, 2005, 3756, 0, 1684, 0, 0, 3574, 2921,
+    0, 0, 3956, 3150, 0, 2208, 0, 0, 0, 0, 0, 0, 0, 3312, 0, 3508,
+    0, 0, 0, 3332, 4014, 0, 3894, 2396, 0, 3626, 0, 257, 1942, 2909, 2767, 3080,
+    3738, 0, 0, 0, 0, 0, 0, 0, 0, 1091, 3704, 2330, 3567, 0, 3616, 3379,
+    0, 0, 0, 0, 0, 1229, 3949, 0, 0, 0, 0, 0, 2121, 0, 0, 2274,
+    1155, 2131, 1604, 0, 2059, 0, 1884, 0, 4086, 0, 0, 1877, 3964, 3306, 0, 0,
+    0, 0, 3768, 0, 0, 0, 0, 0, 2786, 0, 0, 0, 2499, 0, 3507, 0,
+    0, 0, 3222, 0, 0, 3833, 0, 0, 1997, 0, 1793, 0, 2013, 1941, 0, 0,
+    0, 0, 3218, 2780, 0, 779, 3478, 0, 2248, 0, 0, 3760, 3690, 4003, 0, 3873,
+    0, 0, 0, 900, 0, 1429, 0, 2313, 0, 578, 1362, 0, 0, 972, 0, 0,
+    0, 0, 0, 0, 0, 3698, 3958, 777, 0, 0, 0, 0, 0, 0, 2893, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2568, 0, 0,
+    0, 0, 1817, 0, 3962, 2625, 0, 0, 0, 0, 2526, 0, 3314, 0, 322, 2008,
+    1882, 1682, 2568, 0, 3697, 0, 0, 2519, 0, 2533, 2714, 1754, 0, 3833, 0, 0,
+    0, 0, 0, 0, 0, 1033, 1741, 0, 0, 3031, 3372, 4047, 0, 0, 0, 0,
+    1610, 2373, 0, 3619, 0, 3427, 0, 3833, 512, 0, 1539, 0, 1292, 1356, 1104, 2893,
+    3340, 0, 0, 0, 0, 0, 2530, 1348, 0, 2338, 3852, 0, 2316, 0, 0, 0,
+    1162, 0, 4023, 0, 0, 0, 0, 0, 0, 0, 0, 576, 4026, 0, 2274, 1923,
+    0, 0, 1816, 3215, 0, 1554, 0, 0, 0, 0, 0, 0, 4021, 2836, 0, 1619,
+    0, 0, 3215, 0, 0, 0, 0, 3145, 0, 0, 0, 3920, 0, 2259, 0, 3418,
+    3437, 0, 0, 974, 0, 0, 1487, 0, 1226, 0, 968, 0, 0, 0, 3795, 3015,
+    2007, 0, 0, 0, 0, 3348, 3530, 0, 0, 2014, 0, 0, 3950, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 2534, 0, 0, 2525, 3521, 1088, 3530, 0, 2852, 1089,
+    0, 0, 3735, 0, 0, 0, 0, 773, 3171, 2339, 0, 2066, 3826, 0, 0, 0,
+    0, 0, 0, 2401, 0, 2189, 1282, 0, 0, 0, 0, 0, 0, 0, 2690, 0,
+    707, 837, 0, 2584, 2533, 0, 3962, 0, 0, 1221, 0, 3215, 906, 1617, 0, 0,
+    2251, 2396, 0, 0, 0, 0, 1806, 2658, 4039, 3814, 0, 0, 259, 3746, 0, 844,
+    4006, 0, 0, 1681, 0, 0, 3378, 128, 3827, 0, 0, 4055, 0, 3037, 0, 1819,
+    3300, 0, 0, 3506, 259, 578, 1739, 0, 0, 909, 0, 0, 0, 0, 386, 0,
+    902, 0, 0, 969, 0, 0, 0, 0, 0, 0, 3543, 0, 0, 0, 4050, 0,
+    2695, 2572, 2526, 0, 0, 0, 0, 3028, 4022, 3761, 2983, 2332, 0, 2716, 3096, 2794,
+    0, 0, 2859, 0, 3852, 2857, 1091, 2048, 708, 3909, 0, 3242, 0, 0, 0, 706,
+    0, 0, 3501, 2987, 2243, 0, 0, 0, 64, 0, 2526, 1949, 0, 0, 706, 0,
+    0, 4021, 0, 0, 0, 0, 0, 3699, 2857, 3054, 2325, 0, 0, 0, 2322, 2729,
+    1546, 0, 0, 0, 1609, 0, 0, 0, 0, 0, 0, 0, 3405, 3359, 0, 0,
+    0, 0, 3462, 193, 0, 0, 0, 0, 1998, 0, 3299, 0, 0, 0, 0, 974,
+    0, 0, 0, 0, 0, 0, 0, 0, 324, 2698, 3768, 0, 4087, 0, 0, 0,
+    0, 3353, 0, 0, 2339, 3371, 0, 0, 4041, 2398, 1299, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 1412, 3332, 0, 0, 1552, 0, 0, 0, 0, 0, 0, 1292,
+    0, 0, 3376, 0, 578, 2963, 0, 584, 3591, 584, 0, 0, 2401, 3521, 0, 0,
+    0, 0, 1669, 0, 1747, 3834, 0, 0, 0, 3738, 1104, 2008, 0, 3616, 0, 0,
+    389, 2012, 0, 0, 0, 0, 3963, 1553, 0, 3242, 3933, 3456, 0, 0, 3379, 2983,
+    1217, 843, 1425, 1227, 3761, 0, 3864, 0, 0, 2012, 0, 3763, 3421, 0, 0, 0,
+    0, 0, 0, 0, 2856, 771, 3176, 0, 0, 2273, 0, 3961, 0, 0, 1674, 0,
+    0, 1219, 3509, 577, 3957, 0, 0, 2973, 0, 0, 3099, 0, 0, 2637, 2649, 0,
+    0, 2911, 0, 3378, 0, 2194, 3832, 2922, 2202, 0, 0, 0, 512, 0, 0, 1364,
+    0, 0, 3704, 4020, 0, 3584, 0, 0, 0, 0, 0, 0, 1813, 901, 0, 2702,
+    2333, 2529, 321, 1943, 0, 708, 0, 3494, 0, 578, 0, 0, 0, 0, 3137, 1160,
+    0, 2404, 0, 3990, 3657, 0, 0, 1878, 0, 2792, 2117, 3474, 3738, 0, 0, 0,
+    0, 837, 0, 3573, 3267, 0, 2958, 3233, 2266, 0, 2656, 3807, 2920, 777, 2454, 0,
+    2338, 0, 3697, 0, 3088, 4088, 0, 0, 0, 0, 3243, 129, 448, 0, 2788, 0,
+    0, 2915, 0, 3502, 3047, 0, 0, 0, 0, 4094, 129, 0, 0, 3072, 0, 1424,
+    0, 0, 0, 0, 714, 1819, 0, 2853, 0, 0, 3696, 577, 0, 388, 0, 0,
+    0, 2985, 0, 3803, 1104, 0, 2274, 386, 1412, 0, 0, 3505, 0, 3882, 0, 3305,
+    0, 3535, 0, 0, 1624, 0, 2625, 0, 3010, 0, 2011, 2893, 0, 3178, 4093, 0,
+    2778, 0, 1818, 0, 0, 0, 0, 0, 2468, 0, 0, 0, 3730, 0, 1032, 2923,
+    0, 0, 1867, 0, 0, 0, 1793, 0, 0, 0, 0, 2664, 0, 0, 0, 0,
+    0, 0, 0, 2254, 2633, 2907, 3551, 0, 0, 2774, 2369, 0, 0, 4023, 904, 0,
+    0, 1926, 0, 0, 0, 3508, 2787, 0, 0, 0, 0, 3307, 3249, 0, 0, 0,
+    0, 1357, 0, 0, 1357, 1292, 4012, 1812, 3308, 0, 0, 3890, 0, 2656, 1487, 3494,
+    0, 0, 643, 2007, 0, 3145, 1689, 0, 0, 1882, 0, 0, 0, 0, 0, 773,
+    0, 3023, 1162, 0, 2048, 0, 0, 3359, 1875, 0, 0, 1299, 1161, 0, 2503, 2336,
+    0, 3938, 0, 0, 3444, 1227, 2178, 0, 0, 0, 0, 0, 2981, 2182, 3982, 0,
+    0, 3486, 0, 0, 0, 0, 0, 1884, 1234, 0, 0, 3288, 0, 0, 3697, 0,
+    1926, 1362, 2851, 3608, 0, 0, 1734, 0, 3504, 0, 0, 1734, 0, 3548, 0, 3421,
+    0, 3078, 2124, 2402, 0, 0, 0, 1942, 0, 0, 0, 3882, 0, 0, 0, 0,
+    0, 0, 0, 1163, 0, 4092, 3808, 0, 3364, 0, 0, 0, 2273, 0, 1164, 0,
+    2463, 0, 0, 2728, 3046, 0, 1293, 1811, 0, 0, 1946, 1799, 0, 0, 320, 0,
+    1878, 2508, 1477, 0, 972, 0, 0, 2909, 0, 2911, 576, 0, 3294, 0, 0, 0,
+    1097, 0, 0, 0, 1944, 0, 0, 0, 0, 2987, 3834, 0, 0, 0, 1748, 0,
+    2985, 0, 708, 0, 0, 0, 1746, 1611, 0, 3821, 2850, 0, 0, 0, 0, 2846,
+    840, 2982, 1026, 3828, 0, 3738, 1941, 0, 0, 3053, 323, 0, 2649, 0, 0, 0,
+    0, 4021, 0, 2911, 2820, 3184, 0, 0, 3157, 2011, 0, 3502, 0, 3832, 3883, 2982,
+    1414, 1221, 0, 0, 0, 0, 577, 0, 4090, 2338, 4068, 2911, 2702, 0, 2644, 0,
+    2963, 0, 2511, 0, 0, 0, 0, 0, 0, 512, 0, 1296, 0, 0, 0, 4028,
+    3054, 0, 1861, 0, 0, 0, 3998, 4032, 0, 2664, 0, 0, 4068, 0, 3963, 0,
+    0, 3886, 0, 0, 0, 0, 3288, 648, 0, 0, 2974, 0, 0, 906, 0, 0,
+    1944, 0, 1740, 0, 0, 1682, 0, 2369, 3592, 0, 0, 0, 0, 2531, 4029, 0,
+    0, 0, 0, 0, 0, 512, 3168, 3762, 0, 0, 2910, 0, 0, 0, 4068, 0,
+    3050, 0, 321, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2274, 3226, 2885,
+    842, 0, 0, 0, 0, 0, 0, 0, 0, 4086, 1752, 3949, 0, 4003, 0, 3112,
+    3431, 0, 2592, 3681, 1882, 3754, 0, 1427, 3670, 837, 194, 0, 0, 1940, 0, 3374,
+];
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy)]
+use crate::chess::player::Player;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Direction {
     North,
     NorthEast,
@@ -30,12 +32,23 @@ impl Direction {
         Self::SouthWest,
         Self::NorthWest,
     ];
-}
 
-impl std::ops::Not for Direction {
-    type Output = Self;
+    // The direction a player's pawns move in
+    #[inline(always)]
+    pub const fn forward(player: Player) -> Self {
+        match player {
+            Player::White => Self::North,
+            Player::Black => Self::South,
+        }
+    }
 
-    fn not(self) -> Self::Output {
+    #[inline(always)]
+    pub const fn backward(player: Player) -> Self {
+        Self::forward(player).opposite()
+    }
+
+    #[inline(always)]
+    pub const fn opposite(self) -> Self {
         match self {
             Self::North => Self::South,
             Self::NorthEast => Self::SouthWest,
@@ -48,3 +61,11 @@ impl std::ops::Not for Direction {
         }
     }
 }
+
+impl std::ops::Not for Direction {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        self.opposite()
+    }
+}
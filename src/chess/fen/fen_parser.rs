@@ -15,8 +15,8 @@ use nom::sequence::terminated;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, one_of, space1},
-    combinator::{eof, map, value},
+    character::complete::{anychar, char, one_of, space1},
+    combinator::{eof, map, map_opt, value},
     multi::many1,
     sequence::{pair, preceded, tuple},
     IResult,
@@ -56,16 +56,22 @@ fn fen_empty_squares(input: &str) -> IResult<&str, Vec<Option<Piece>>> {
 }
 
 fn fen_line(input: &str) -> IResult<&str, FenRank> {
-    let (input, squares) = many1(alt((
-        map(fen_piece, |p| vec![Some(p); 1]),
-        fen_empty_squares,
-    )))(input)?;
-
-    Ok((input, FenRank(squares.concat())))
+    map_opt(
+        many1(alt((
+            map(fen_piece, |p| vec![Some(p); 1]),
+            fen_empty_squares,
+        ))),
+        |squares| {
+            let squares = squares.concat();
+
+            // Each rank must describe exactly one square per file
+            (squares.len() == File::N).then_some(FenRank(squares))
+        },
+    )(input)
 }
 
 fn fen_position(input: &str) -> IResult<&str, Board> {
-    let (input, board) = map(
+    let (input, board) = map_opt(
         tuple((
             fen_line,
             preceded(char('/'), fen_line),
@@ -87,11 +93,9 @@ fn fen_position(input: &str) -> IResult<&str, Board> {
             all_pieces.extend(line7.0);
             all_pieces.extend(line8.0);
 
-            assert_eq!(all_pieces.len(), Square::N);
-
-            let pieces_array: [Option<Piece>; Square::N] = all_pieces.try_into().unwrap();
+            let pieces_array: [Option<Piece>; Square::N] = all_pieces.try_into().ok()?;
 
-            pieces_array.try_into().unwrap()
+            pieces_array.try_into().ok()
         },
     )(input)?;
 
@@ -152,46 +156,16 @@ fn fen_castling(input: &str) -> IResult<&str, ByPlayer<CastleRights>> {
 }
 
 fn fen_file(input: &str) -> IResult<&str, File> {
-    let (input, file) = one_of("abcdefgh")(input)?;
-
-    Ok((
-        input,
-        match file {
-            'a' => File::A,
-            'b' => File::B,
-            'c' => File::C,
-            'd' => File::D,
-            'e' => File::E,
-            'f' => File::F,
-            'g' => File::G,
-            'h' => File::H,
-            _ => unreachable!(),
-        },
-    ))
+    map_opt(anychar, File::from_char)(input)
 }
 
 fn fen_rank(input: &str) -> IResult<&str, Rank> {
-    let (input, rank) = one_of("12345678")(input)?;
-
-    Ok((
-        input,
-        match rank {
-            '1' => Rank::R1,
-            '2' => Rank::R2,
-            '3' => Rank::R3,
-            '4' => Rank::R4,
-            '5' => Rank::R5,
-            '6' => Rank::R6,
-            '7' => Rank::R7,
-            '8' => Rank::R8,
-            _ => unreachable!(),
-        },
-    ))
+    map_opt(anychar, Rank::from_char)(input)
 }
 
 fn fen_square(input: &str) -> IResult<&str, Square> {
     map(pair(fen_file, fen_rank), |(file, rank)| {
-        Square::from_file_and_rank(file, rank)
+        Square::new(file, rank)
     })(input)
 }
 
@@ -221,6 +195,7 @@ fn fen_parser(input: &str) -> IResult<&str, Game> {
             tuple((space0, eof)),
         )(input)?;
 
+    // GUIs and EPD files often leave off the move counters, so fill in the defaults
     let halfmove_clock = halfmove_clock.unwrap_or(0);
     let fullmove_number = fullmove_number.unwrap_or(1);
 
@@ -241,7 +216,12 @@ fn fen_parser(input: &str) -> IResult<&str, Game> {
 
 #[inline(always)]
 fn plies_from_fullmove_number(fullmove_number: u32, player: Player) -> u32 {
-    (fullmove_number - 1) * 2 + u32::from(player == Player::Black)
+    // Some tools write a fullmove number of 0, which we treat as the first move. Absurdly large
+    // ones are clamped rather than allowed to overflow.
+    fullmove_number
+        .saturating_sub(1)
+        .saturating_mul(2)
+        .saturating_add(u32::from(player == Player::Black))
 }
 
 pub fn parse(input: &str) -> Result<Game, String> {
@@ -301,11 +281,57 @@ mod tests {
         assert!(parse("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - ").is_ok());
     }
 
+    #[test]
+    fn parse_without_move_counters_uses_defaults() {
+        crate::init();
+
+        let game = parse("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3").unwrap();
+        assert_eq!(game.halfmove_clock, 0);
+        assert_eq!(game.plies, 1);
+        assert_eq!(
+            game.en_passant_target,
+            Some(crate::chess::square::squares::all::E3)
+        );
+
+        let game = parse("8/8/4k3/8/8/4K3/8/8 w - - 12").unwrap();
+        assert_eq!(game.halfmove_clock, 12);
+        assert_eq!(game.plies, 0);
+    }
+
+    #[test]
+    fn parse_fullmove_number_zero() {
+        crate::init();
+
+        let game = parse("8/8/4k3/8/8/4K3/8/8 b - - 0 0").unwrap();
+        assert_eq!(game.plies, 1);
+    }
+
+    #[test]
+    fn parse_rejects_missing_fields() {
+        crate::init();
+
+        assert!(parse("8/8/4k3/8/8/4K3/8/8 w -").is_err());
+        assert!(parse("8/8/4k3/8/8/4K3/8/8").is_err());
+    }
+
+    #[test]
+    fn parse_rejects_ranks_of_the_wrong_width() {
+        crate::init();
+
+        assert!(parse("9/7/4k3/8/8/4K3/8/8 w - - 0 1").is_err());
+        assert!(parse("rnbqkbnr/ppppppppp/8/8/8/8/PPPPPPP/RNBQKBNR w KQkq - 0 1").is_err());
+    }
+
     #[test]
     fn plies_from_fullmove_number() {
         assert_eq!(super::plies_from_fullmove_number(1, Player::White), 0);
         assert_eq!(super::plies_from_fullmove_number(1, Player::Black), 1);
         assert_eq!(super::plies_from_fullmove_number(2, Player::White), 2);
         assert_eq!(super::plies_from_fullmove_number(2, Player::Black), 3);
+        assert_eq!(super::plies_from_fullmove_number(0, Player::White), 0);
+        assert_eq!(
+            super::plies_from_fullmove_number(u32::MAX, Player::Black),
+            u32::MAX
+        );
     }
 }
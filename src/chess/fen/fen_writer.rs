@@ -1,5 +1,6 @@
 use crate::chess::{
     board::Board,
+    fen::FenFormat,
     game::Game,
     piece::Piece,
     square::{Square, FILES, RANKS},
@@ -64,7 +65,7 @@ fn format_board(board: &Board) -> String {
         .map(|r| {
             FILES
                 .into_iter()
-                .map(|f| board.piece_at(Square::from_file_and_rank(f, r)))
+                .map(|f| board.piece_at(Square::new(f, r)))
                 .collect::<Vec<_>>()
         })
         .map(|r| format_rank(&r))
@@ -79,27 +80,41 @@ fn format_current_player(game: &Game) -> String {
     }
 }
 
-fn format_castle_rights(game: &Game) -> String {
+pub fn format_castle_rights(game: &Game) -> String {
+    format_castle_rights_with(game, ['K', 'Q', 'k', 'q'])
+}
+
+// Shredder-FEN names castling rights by the file of the rook rather than the side of the board,
+// which is what's needed to describe Chess960 positions. We only support standard chess, so the
+// rooks always start on the a and h files.
+fn format_shredder_castle_rights(game: &Game) -> String {
+    format_castle_rights_with(game, ['H', 'A', 'h', 'a'])
+}
+
+fn format_castle_rights_with(
+    game: &Game,
+    [white_king, white_queen, black_king, black_queen]: [char; 4],
+) -> String {
     let [white_castle_rights, black_castle_rights] = game.castle_rights.inner();
 
-    match (
-        white_castle_rights.king_side,
-        white_castle_rights.queen_side,
-        black_castle_rights.king_side,
-        black_castle_rights.queen_side,
-    ) {
-        (false, false, false, false) => "-".to_string(),
-        (white_king, white_queen, black_king, black_queen) => format!(
-            "{}{}{}{}",
-            if white_king { "K" } else { "" },
-            if white_queen { "Q" } else { "" },
-            if black_king { "k" } else { "" },
-            if black_queen { "q" } else { "" }
-        ),
+    let rights: String = [
+        (white_castle_rights.king_side, white_king),
+        (white_castle_rights.queen_side, white_queen),
+        (black_castle_rights.king_side, black_king),
+        (black_castle_rights.queen_side, black_queen),
+    ]
+    .into_iter()
+    .filter_map(|(has_right, c)| has_right.then_some(c))
+    .collect();
+
+    if rights.is_empty() {
+        "-".to_string()
+    } else {
+        rights
     }
 }
 
-fn format_en_passant_target(game: &Game) -> String {
+pub fn format_en_passant_target(game: &Game) -> String {
     match game.en_passant_target {
         Some(sq) => sq.notation(),
         None => "-".to_string(),
@@ -114,16 +129,29 @@ fn format_fullmove_number(game: &Game) -> String {
     game.turn().to_string()
 }
 
-pub fn write(game: &Game) -> String {
-    format!(
-        "{} {} {} {} {} {}",
+pub fn write(game: &Game, format: FenFormat) -> String {
+    let castle_rights = match format {
+        FenFormat::Standard | FenFormat::Epd => format_castle_rights(game),
+        FenFormat::Shredder => format_shredder_castle_rights(game),
+    };
+
+    let fields = format!(
+        "{} {} {} {}",
         format_board(&game.board),
         format_current_player(game),
-        format_castle_rights(game),
+        castle_rights,
         format_en_passant_target(game),
-        format_halfmove_clock(game),
-        format_fullmove_number(game),
-    )
+    );
+
+    match format {
+        FenFormat::Standard | FenFormat::Shredder => format!(
+            "{} {} {}",
+            fields,
+            format_halfmove_clock(game),
+            format_fullmove_number(game),
+        ),
+        FenFormat::Epd => fields,
+    }
 }
 
 #[cfg(test)]
@@ -139,4 +167,35 @@ mod tests {
             "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1"
         );
     }
+
+    #[test]
+    fn format_shredder_fen() {
+        crate::init();
+
+        let game =
+            Game::from_fen("r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w Kq - 3 12")
+                .unwrap();
+
+        assert_eq!(
+            write(&game, FenFormat::Shredder),
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w Ha - 3 12"
+        );
+        assert_eq!(
+            write(&Game::new(), FenFormat::Shredder),
+            "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w HAha - 0 1"
+        );
+    }
+
+    #[test]
+    fn format_epd() {
+        crate::init();
+
+        let game =
+            Game::from_fen("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b - e3 0 3").unwrap();
+
+        assert_eq!(
+            write(&game, FenFormat::Epd),
+            "rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b - e3"
+        );
+    }
 }
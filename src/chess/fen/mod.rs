@@ -2,6 +2,15 @@ mod fen_parser;
 mod fen_writer;
 
 pub use fen_parser::parse;
-pub use fen_writer::write;
+pub use fen_writer::{format_castle_rights, format_en_passant_target, write};
 
 pub const START_POS: &str = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FenFormat {
+    Standard,
+    // Castling rights are given by the rook's file, e.g. 'HAha'
+    Shredder,
+    // Only the position itself, without the halfmove clock or fullmove number
+    Epd,
+}
@@ -5,8 +5,15 @@ use crate::chess::player::ByPlayer;
 use crate::chess::square::squares;
 use crate::chess::zobrist::ZobristHash;
 use crate::chess::{
-    board::Board, fen, movegen::generate_legal_moves, moves::Move, piece::PieceKind,
-    player::Player, square::Square, zobrist,
+    board::{self, Board},
+    fen,
+    movegen::{self, generate_legal_moves},
+    moves::Move,
+    piece::PieceKind,
+    player::Player,
+    san,
+    square::{File, Rank, Square},
+    zobrist,
 };
 use crate::engine::eval::IncrementalEvalFields;
 
@@ -63,6 +70,29 @@ impl Default for CastleRights {
     }
 }
 
+// How a game has ended, according to the rules of chess. Unlike the draw detection used in
+// search, repetitions must be threefold and insufficient material must make mate impossible.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Checkmate { winner: Player },
+    Stalemate,
+    FiftyMove,
+    Repetition,
+    InsufficientMaterial,
+}
+
+impl std::fmt::Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Checkmate { winner } => write!(f, "{winner:?} wins by checkmate"),
+            Self::Stalemate => write!(f, "draw by stalemate"),
+            Self::FiftyMove => write!(f, "draw by the fifty move rule"),
+            Self::Repetition => write!(f, "draw by threefold repetition"),
+            Self::InsufficientMaterial => write!(f, "draw by insufficient material"),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct History {
     pub mv: Option<Move>,
@@ -74,6 +104,17 @@ pub struct History {
     pub incremental_eval: IncrementalEvalFields,
 }
 
+// A move that was played to reach the current position, as shown to people (e.g. in `d history`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayedMove {
+    pub mv: Move,
+    pub player: Player,
+    pub turn: u32,
+    pub san: String,
+    // The position after the move was played
+    pub fen: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Game {
     pub player: Player,
@@ -86,6 +127,10 @@ pub struct Game {
     pub zobrist: ZobristHash,
     pub incremental_eval: IncrementalEvalFields,
     pub history: Vec<History>,
+
+    // The zobrist keys of each earlier position, kept separately from the history so that checking
+    // for repetitions doesn't need to walk over the whole of each history entry
+    pub repetition_keys: Vec<u64>,
 }
 
 impl Game {
@@ -114,6 +159,7 @@ impl Game {
             zobrist: ZobristHash::uninit(),
             incremental_eval: incremental_eval_fields,
             history: Vec::new(),
+            repetition_keys: Vec::new(),
         };
 
         game.zobrist = zobrist::hash(&game);
@@ -125,13 +171,58 @@ impl Game {
     }
 
     pub fn to_fen(&self) -> String {
-        fen::write(self)
+        fen::write(self, fen::FenFormat::Standard)
     }
 
     pub fn turn(&self) -> u32 {
         self.plies / 2 + 1
     }
 
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.moves().is_empty() {
+            return Some(if self.is_king_in_check() {
+                Outcome::Checkmate {
+                    winner: self.player.other(),
+                }
+            } else {
+                Outcome::Stalemate
+            });
+        }
+
+        if self.halfmove_clock >= 100 {
+            return Some(Outcome::FiftyMove);
+        }
+
+        if self.repetition_count() >= 3 {
+            return Some(Outcome::Repetition);
+        }
+
+        if self.has_insufficient_mating_material() {
+            return Some(Outcome::InsufficientMaterial);
+        }
+
+        None
+    }
+
+    // Only the positions where checkmate is impossible: K v K, K+minor v K, and K+B v K+B
+    // with the bishops on the same colour.
+    fn has_insufficient_mating_material(&self) -> bool {
+        let all_pieces = self.board.occupancy();
+        let minor_pieces = self.board.all_knights() | self.board.all_bishops();
+        let bishops = self.board.all_bishops();
+
+        match all_pieces.count() {
+            2 => true,
+            3 => minor_pieces.any(),
+            4 => {
+                bishops.count() == 2
+                    && self.board.occupancy_for(Player::White).count() == 2
+                    && ((bishops & bitboards::LIGHT_SQUARES).count() != 1)
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_stalemate_by_fifty_move_rule(&self) -> bool {
         if self.halfmove_clock >= 100 {
             let mut movelist = MoveList::new();
@@ -142,12 +233,51 @@ impl Game {
         false
     }
 
-    pub fn is_repeated_position(&self) -> bool {
-        self.history
+    // The keys of earlier positions that the current one could be a repetition of, most recent
+    // first. Nothing before the last pawn move or capture can repeat, and the side to move is
+    // part of the key, so only every other position needs to be checked.
+    fn repeatable_keys(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.repetition_keys
             .iter()
             .rev()
             .take(self.halfmove_clock as usize)
-            .any(|h| h.zobrist == self.zobrist)
+            .enumerate()
+            .skip(1)
+            .step_by(2)
+            .map(|(i, key)| (i + 1, *key))
+    }
+
+    pub fn is_repeated_position(&self) -> bool {
+        self.repeatable_keys().any(|(_, key)| key == self.zobrist.0)
+    }
+
+    // How many times the current position has occurred, including this time
+    pub fn repetition_count(&self) -> usize {
+        1 + self
+            .repeatable_keys()
+            .filter(|(_, key)| *key == self.zobrist.0)
+            .count()
+    }
+
+    // Repeating a position from fewer than `recent_plies` plies ago (e.g. one after the root of a
+    // search) is a draw straight away, since if the repetition was good for either side they could
+    // repeat it again. Repeating an older position is only a draw once it has occurred three times.
+    pub fn is_draw_by_repetition(&self, recent_plies: usize) -> bool {
+        let mut seen_before = false;
+
+        for (plies_ago, key) in self.repeatable_keys() {
+            if key != self.zobrist.0 {
+                continue;
+            }
+
+            if plies_ago < recent_plies || seen_before {
+                return true;
+            }
+
+            seen_before = true;
+        }
+
+        false
     }
 
     pub fn is_stalemate_by_insufficient_material(&self) -> bool {
@@ -197,8 +327,7 @@ impl Game {
     }
 
     fn remove_at(&mut self, sq: Square) -> Piece {
-        let removed_piece = self.board.piece_at(sq).unwrap();
-        self.board.remove_at(sq);
+        let removed_piece = self.board.remove_at(sq).unwrap();
         self.zobrist.toggle_piece_on_square(sq, removed_piece);
         self.incremental_eval.remove_at(sq, removed_piece);
         removed_piece
@@ -226,6 +355,10 @@ impl Game {
         movelist
     }
 
+    pub fn is_legal(&self, mv: Move) -> bool {
+        movegen::is_legal(self, mv)
+    }
+
     pub fn make_move(&mut self, mv: Move) {
         let from = mv.src();
         let to = mv.dst();
@@ -247,6 +380,7 @@ impl Game {
         };
 
         self.history.push(history);
+        self.repetition_keys.push(self.zobrist.0);
 
         let moved_piece = self.remove_at(from);
 
@@ -350,6 +484,7 @@ impl Game {
         };
 
         self.history.push(history);
+        self.repetition_keys.push(self.zobrist.0);
 
         self.zobrist.set_en_passant(self.en_passant_target, None);
         self.en_passant_target = None;
@@ -362,6 +497,7 @@ impl Game {
 
     pub fn undo_move(&mut self) {
         let history = self.history.pop().unwrap();
+        self.repetition_keys.pop();
         let mv = history.mv.unwrap();
         let from = mv.src();
         let to = mv.dst();
@@ -396,8 +532,7 @@ impl Game {
                 .set_at(capture_square, Piece::new(other_player, PieceKind::Pawn));
         }
 
-        let moved_piece = self.board.piece_at(to).unwrap();
-        self.board.remove_at(to);
+        let moved_piece = self.board.remove_at(to).unwrap();
 
         if let Some(captured_piece) = history.captured {
             self.board.set_at(to, captured_piece);
@@ -412,6 +547,7 @@ impl Game {
 
     pub fn undo_null_move(&mut self) {
         let history = self.history.pop().unwrap();
+        self.repetition_keys.pop();
         assert!(history.mv.is_none());
 
         self.plies -= 1;
@@ -421,6 +557,47 @@ impl Game {
         self.halfmove_clock = history.halfmove_clock;
         self.incremental_eval = history.incremental_eval;
     }
+
+    // The position before any of the moves in the history were played
+    pub fn start_position(&self) -> Self {
+        let mut game = self.clone();
+
+        while let Some(history) = game.history.last() {
+            if history.mv.is_some() {
+                game.undo_move();
+            } else {
+                game.undo_null_move();
+            }
+        }
+
+        game
+    }
+
+    // Every move played from the start position to reach this one, oldest first. Null moves are
+    // only made during the search, so they're never part of a game's history.
+    pub fn played_moves(&self) -> Vec<PlayedMove> {
+        let mut game = self.start_position();
+
+        self.history
+            .iter()
+            .filter_map(|history| history.mv)
+            .map(|mv| {
+                let player = game.player;
+                let turn = game.turn();
+                let san = san::format_move(&game, mv);
+
+                game.make_move(mv);
+
+                PlayedMove {
+                    mv,
+                    player,
+                    turn,
+                    san,
+                    fen: game.to_fen(),
+                }
+            })
+            .collect()
+    }
 }
 
 impl Default for Game {
@@ -429,10 +606,104 @@ impl Default for Game {
     }
 }
 
+// Shows the board with coordinates, along with the rest of the state that isn't visible from the
+// pieces. The alternate form (`{:#}`) highlights the squares of the last move in colour.
+impl std::fmt::Display for Game {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use colored::Colorize;
+
+        let last_move = f
+            .alternate()
+            .then(|| self.history.last().and_then(|h| h.mv))
+            .flatten();
+
+        let files = format!("   {}", File::ALL.map(|file| file.to_string()).join(" "));
+
+        writeln!(f, "{files}")?;
+
+        for rank in Rank::ALL.iter().rev() {
+            write!(f, "{rank} ")?;
+
+            for file in File::ALL {
+                let square = Square::new(file, *rank);
+                let symbol = board::piece_symbol(self.board.piece_at(square));
+
+                if last_move.is_some_and(|mv| mv.src() == square || mv.dst() == square) {
+                    write!(f, " {}", symbol.on_yellow())?;
+                } else {
+                    write!(f, " {symbol}")?;
+                }
+            }
+
+            writeln!(f, "  {rank}")?;
+        }
+
+        writeln!(f, "{files}")?;
+        writeln!(f)?;
+        writeln!(f, "Side to move: {:?}", self.player)?;
+        writeln!(f, "Castling: {}", fen::format_castle_rights(self))?;
+        writeln!(f, "En passant: {}", fen::format_en_passant_target(self))?;
+        writeln!(f, "Halfmove clock: {}", self.halfmove_clock)?;
+        writeln!(f, "Repetitions: {}", self.repetition_count())?;
+        write!(f, "Hash: {:#018x}", self.zobrist.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_played_moves() {
+        crate::init();
+
+        let mut game = Game::from_fen("4k3/8/8/8/8/8/4P3/R3K3 w Q - 0 30").unwrap();
+
+        for mv in ["e2e4", "e8d7", "e1c1"] {
+            let mv = game
+                .moves()
+                .into_iter()
+                .find(|m| format!("{m:?}") == mv)
+                .unwrap();
+            game.make_move(mv);
+        }
+
+        assert_eq!(
+            game.start_position().to_fen(),
+            "4k3/8/8/8/8/8/4P3/R3K3 w Q - 0 30"
+        );
+
+        let played: Vec<(u32, Player, String, String)> = game
+            .played_moves()
+            .into_iter()
+            .map(|m| (m.turn, m.player, m.san, m.fen))
+            .collect();
+
+        assert_eq!(
+            played,
+            [
+                (
+                    30,
+                    Player::White,
+                    "e4".to_string(),
+                    "4k3/8/8/8/4P3/8/8/R3K3 b Q - 0 30".to_string()
+                ),
+                (
+                    30,
+                    Player::Black,
+                    "Kd7".to_string(),
+                    "8/3k4/8/8/4P3/8/8/R3K3 w Q - 1 31".to_string()
+                ),
+                (
+                    31,
+                    Player::White,
+                    "O-O-O+".to_string(),
+                    "8/3k4/8/8/4P3/8/8/2KR4 b - - 2 31".to_string()
+                ),
+            ]
+        );
+    }
+
     #[test]
     fn test_draw_by_insufficient_material() {
         crate::init();
@@ -452,4 +723,144 @@ mod tests {
             .unwrap()
             .is_stalemate_by_insufficient_material());
     }
+
+    #[test]
+    fn test_outcome() {
+        crate::init();
+
+        let outcome = |fen: &str| Game::from_fen(fen).unwrap().outcome();
+
+        assert_eq!(outcome(fen::START_POS), None);
+        assert_eq!(
+            outcome("rnb1kbnr/pppp1ppp/8/4p3/6Pq/5P2/PPPPP2P/RNBQKBNR w KQkq - 1 3"),
+            Some(Outcome::Checkmate {
+                winner: Player::Black
+            })
+        );
+        assert_eq!(
+            outcome("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1"),
+            Some(Outcome::Stalemate)
+        );
+        assert_eq!(
+            outcome("8/8/4k3/8/8/2KR4/8/8 w - - 100 80"),
+            Some(Outcome::FiftyMove)
+        );
+
+        // Same coloured bishops can't mate, but a rook can
+        assert_eq!(
+            outcome("8/8/3k4/3b4/8/2KB4/8/8 w - - 0 1"),
+            Some(Outcome::InsufficientMaterial)
+        );
+        assert_eq!(outcome("8/8/4k3/8/8/2KR4/8/8 w - - 0 1"), None);
+    }
+
+    #[test]
+    fn test_outcome_threefold_repetition() {
+        use crate::chess::moves::MoveListExt;
+        use crate::chess::square::squares::all::*;
+
+        crate::init();
+
+        let mut game = Game::new();
+
+        for _ in 0..2 {
+            for (src, dst) in [(G1, F3), (G8, F6), (F3, G1), (F6, G8)] {
+                assert_eq!(game.outcome(), None);
+
+                let mv = game.moves().expect_matching(src, dst, None);
+                game.make_move(mv);
+            }
+        }
+
+        assert_eq!(game.outcome(), Some(Outcome::Repetition));
+    }
+
+    #[test]
+    fn test_repetition_count() {
+        use crate::chess::moves::MoveListExt;
+        use crate::chess::square::squares::all::*;
+
+        crate::init();
+
+        let mut game = Game::new();
+        assert_eq!(game.repetition_count(), 1);
+
+        for repetitions in 2..=3 {
+            for (src, dst) in [(G1, F3), (G8, F6), (F3, G1), (F6, G8)] {
+                let mv = game.moves().expect_matching(src, dst, None);
+                game.make_move(mv);
+            }
+
+            assert_eq!(game.repetition_count(), repetitions);
+        }
+
+        // Positions before a pawn move can't be repeated
+        game.make_move(game.moves().expect_matching(E2, E4, None));
+        assert_eq!(game.repetition_count(), 1);
+
+        game.undo_move();
+        assert_eq!(game.repetition_count(), 3);
+        assert_eq!(game.repetition_keys.len(), game.history.len());
+    }
+
+    #[test]
+    fn test_recent_repetitions_are_draws() {
+        use crate::chess::moves::MoveListExt;
+        use crate::chess::square::squares::all::*;
+
+        crate::init();
+
+        let mut game = Game::new();
+
+        for (src, dst) in [(G1, F3), (G8, F6), (F3, G1), (F6, G8)] {
+            let mv = game.moves().expect_matching(src, dst, None);
+            game.make_move(mv);
+        }
+
+        // The start position was seen 4 plies ago
+        assert!(!game.is_draw_by_repetition(0));
+        assert!(!game.is_draw_by_repetition(4));
+        assert!(game.is_draw_by_repetition(5));
+    }
+
+    #[test]
+    fn test_display() {
+        crate::init();
+
+        let game =
+            Game::from_fen("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b Kq e3 0 3").unwrap();
+
+        let display = game.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert_eq!(lines[0], "   a b c d e f g h");
+        assert_eq!(lines[4], "5  . . . . . . . .  5");
+        assert_eq!(lines[5], "4  . . . ♙ ♟ . . .  4");
+        assert!(lines.contains(&"Side to move: Black"));
+        assert!(lines.contains(&"Castling: Kq"));
+        assert!(lines.contains(&"En passant: e3"));
+        assert!(lines.contains(&"Halfmove clock: 0"));
+        assert!(lines.contains(&"Repetitions: 1"));
+        assert!(lines.contains(&format!("Hash: {:#018x}", game.zobrist.0).as_str()));
+    }
+
+    #[test]
+    fn test_display_repetitions() {
+        use crate::chess::moves::MoveListExt;
+        use crate::chess::square::squares::all::*;
+
+        crate::init();
+
+        let mut game = Game::new();
+        for (src, dst) in [(G1, F3), (G8, F6), (F3, G1), (F6, G8)] {
+            let mv = game.moves().expect_matching(src, dst, None);
+            game.make_move(mv);
+        }
+
+        let display = game.to_string();
+        let lines: Vec<&str> = display.lines().collect();
+
+        assert!(lines.contains(&"Halfmove clock: 4"));
+        assert!(lines.contains(&"Repetitions: 2"));
+    }
 }
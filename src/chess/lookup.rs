@@ -0,0 +1,108 @@
+// Indexing into a table with an index that's guaranteed to be in bounds, e.g. one that came from
+// a `Square`. Release builds skip the bounds check as the lookups are on hot paths, but debug
+// builds keep it so that a bad index fails loudly instead of silently reading out of bounds.
+pub trait Lookup<T> {
+    /// # Safety
+    ///
+    /// `idx` must be less than the length of the table. This is only checked in debug builds.
+    unsafe fn lookup(&self, idx: usize) -> &T;
+}
+
+impl<T> Lookup<T> for [T] {
+    #[inline(always)]
+    unsafe fn lookup(&self, idx: usize) -> &T {
+        debug_assert!(
+            idx < self.len(),
+            "Index {idx} is out of bounds for a table of length {}",
+            self.len()
+        );
+
+        unsafe { self.get_unchecked(idx) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::bitboard::Bitboard;
+    use crate::chess::game::{CastleRightsSide, Game};
+    use crate::chess::movegen::tables;
+    use crate::chess::piece::{Piece, PieceKind};
+    use crate::chess::player::Player;
+    use crate::chess::square::Square;
+    use crate::chess::zobrist::ZobristHash;
+    use crate::chess::{cuckoo, masks};
+    use rand::prelude::*;
+
+    // Debug builds check every lookup, so calling each of the accessors backed by a table with
+    // every input it can be given shows that none of them index out of bounds
+    #[test]
+    fn test_table_accessors_stay_in_bounds() {
+        crate::init();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let board = Game::new().board;
+        let mut zobrist = ZobristHash::uninit();
+
+        for square in Square::all() {
+            let _ = board.piece_at(square);
+            let _ = masks::adjacent_files(square);
+            let _ = tables::knight_attacks(square);
+            let _ = tables::king_attacks(square);
+            zobrist.set_en_passant(None, Some(square));
+
+            for other in Square::all() {
+                let _ = tables::between(square, other);
+            }
+
+            // Only the blockers on the square's rays affect the index, so a spread of random
+            // occupancies reaches indexes all over the table
+            for blockers in (0..1000)
+                .map(|_| Bitboard::new(rng.gen()))
+                .chain([Bitboard::EMPTY, Bitboard::FULL])
+            {
+                let _ = tables::rook_attacks(square, blockers);
+                let _ = tables::bishop_attacks(square, blockers);
+            }
+
+            for player in [Player::White, Player::Black] {
+                let _ = masks::front_span(player, square);
+                let _ = masks::passed_pawn_mask(player, square);
+                let _ = tables::pawn_attacks(square, player);
+
+                for kind in PieceKind::ALL {
+                    zobrist.toggle_piece_on_square(square, Piece::new(player, kind));
+                }
+            }
+        }
+
+        for player in [Player::White, Player::Black] {
+            for side in [CastleRightsSide::Kingside, CastleRightsSide::Queenside] {
+                zobrist.toggle_castle_rights(player, side);
+            }
+        }
+
+        for _ in 0..10_000 {
+            let _ = cuckoo::lookup(rng.gen());
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let table = [1, 2, 3];
+
+        // SAFETY: Both indexes are less than the length of the table
+        unsafe {
+            assert_eq!(*table.lookup(0), 1);
+            assert_eq!(*table.lookup(2), 3);
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "out of bounds")]
+    fn test_lookup_out_of_bounds_panics_in_debug_builds() {
+        let table = [1, 2, 3];
+        unsafe { table.lookup(3) };
+    }
+}
@@ -0,0 +1,117 @@
+use crate::chess::bitboard::Bitboard;
+use crate::chess::direction::Direction;
+use crate::chess::lookup::Lookup;
+use crate::chess::player::Player;
+use crate::chess::square::Square;
+
+static mut FRONT_SPANS: [[Bitboard; Square::N]; Player::N] =
+    [[Bitboard::EMPTY; Square::N]; Player::N];
+
+static mut PASSED_PAWN_MASKS: [[Bitboard; Square::N]; Player::N] =
+    [[Bitboard::EMPTY; Square::N]; Player::N];
+
+static mut ADJACENT_FILES: [Bitboard; Square::N] = [Bitboard::EMPTY; Square::N];
+
+// The squares in front of a square on the same file, from the given player's perspective
+pub fn front_span(player: Player, s: Square) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
+    // SAFETY: `Player::array_idx` is below `Player::N` and `Square::array_idx` is below `Square::N`
+    *unsafe { FRONT_SPANS.lookup(player.array_idx()).lookup(s.array_idx()) }
+}
+
+// The squares in front of a square on the same or adjacent files. If there are no enemy pawns
+// in this mask, a pawn on the square is passed.
+pub fn passed_pawn_mask(player: Player, s: Square) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
+    // SAFETY: `Player::array_idx` is below `Player::N` and `Square::array_idx` is below `Square::N`
+    *unsafe {
+        PASSED_PAWN_MASKS
+            .lookup(player.array_idx())
+            .lookup(s.array_idx())
+    }
+}
+
+// The files either side of a square, not including its own file. If there are no friendly
+// pawns in this mask, a pawn on the square is isolated.
+pub fn adjacent_files(s: Square) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
+    // SAFETY: `Square::array_idx` is below `Square::N`
+    *unsafe { ADJACENT_FILES.lookup(s.array_idx()) }
+}
+
+fn generate_front_span(player: Player, s: Square) -> Bitboard {
+    s.bb().span(Direction::forward(player))
+}
+
+fn generate_passed_pawn_mask(player: Player, s: Square) -> Bitboard {
+    let front_span = generate_front_span(player, s);
+    front_span.west() | front_span | front_span.east()
+}
+
+fn generate_adjacent_files(s: Square) -> Bitboard {
+    let file = s.bb().span(Direction::North) | s.bb() | s.bb().span(Direction::South);
+    file.west() | file.east()
+}
+
+pub fn init() {
+    for s in Bitboard::FULL {
+        unsafe {
+            for player in [Player::White, Player::Black] {
+                FRONT_SPANS[player.array_idx()][s.array_idx()] = generate_front_span(player, s);
+                PASSED_PAWN_MASKS[player.array_idx()][s.array_idx()] =
+                    generate_passed_pawn_mask(player, s);
+            }
+
+            ADJACENT_FILES[s.array_idx()] = generate_adjacent_files(s);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::bitboard::bitboards::*;
+    use crate::chess::square::squares::all::*;
+
+    #[test]
+    fn test_front_span() {
+        assert_eq!(
+            generate_front_span(Player::White, E4),
+            E5_BB | E6_BB | E7_BB | E8_BB
+        );
+        assert_eq!(
+            generate_front_span(Player::Black, E4),
+            E3_BB | E2_BB | E1_BB
+        );
+        assert_eq!(generate_front_span(Player::White, A8), Bitboard::EMPTY);
+    }
+
+    #[test]
+    fn test_passed_pawn_mask() {
+        assert_eq!(
+            generate_passed_pawn_mask(Player::White, A6),
+            A7_BB | A8_BB | B7_BB | B8_BB
+        );
+        assert_eq!(
+            generate_passed_pawn_mask(Player::Black, H3),
+            H2_BB | H1_BB | G2_BB | G1_BB
+        );
+    }
+
+    #[test]
+    fn test_adjacent_files() {
+        assert_eq!(generate_adjacent_files(A4), A_FILE.east());
+        assert_eq!(generate_adjacent_files(H4), H_FILE.west());
+        assert_eq!(generate_adjacent_files(D1), generate_adjacent_files(D8));
+        assert_eq!(generate_adjacent_files(D1).count(), 16);
+    }
+}
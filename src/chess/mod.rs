@@ -1,18 +1,38 @@
+use std::sync::Once;
+
 pub mod bitboard;
 pub mod board;
+pub mod cuckoo;
 pub mod direction;
 pub mod fen;
 pub mod game;
+pub mod lookup;
+pub mod masks;
 pub mod movegen;
 pub mod moves;
+pub mod notation;
 pub mod perft;
+// Only the annotate command reads and writes PGN
+#[cfg(feature = "cli")]
+pub mod pgn;
 pub mod piece;
 pub mod player;
 pub mod san;
 pub mod square;
 pub mod zobrist;
 
+static INIT: Once = Once::new();
+
+// The lookup tables are shared by everything in the process, so they're only ever initialised
+// once, however many times (and from however many threads) this is called.
 pub fn init() {
-    movegen::init();
-    zobrist::init();
+    INIT.call_once(|| {
+        movegen::init();
+        masks::init();
+        zobrist::init();
+    });
+}
+
+pub fn is_initialised() -> bool {
+    INIT.is_completed()
 }
@@ -25,6 +25,45 @@ pub fn generate_attackers_of(board: &Board, player: Player, square: Square) -> B
     attackers
 }
 
+// The opponent's sliders that attack the given square, where sliders are blocked by the pieces
+// in `occupied`.
+pub fn slider_attackers_of(
+    board: &Board,
+    player: Player,
+    square: Square,
+    occupied: Bitboard,
+) -> Bitboard {
+    let them = player.other();
+
+    (tables::bishop_attacks(square, occupied) & board.diagonal_sliders(them))
+        | (tables::rook_attacks(square, occupied) & board.orthogonal_sliders(them))
+}
+
+// All of the squares attacked by the opponent of the given player, where sliders are blocked
+// by the pieces in `occupied`.
+pub fn generate_attacked_squares(board: &Board, player: Player, occupied: Bitboard) -> Bitboard {
+    let them = player.other();
+
+    let pawn_pushes = board.pawns(them).forward(them);
+    let mut attacked = pawn_pushes.east() | pawn_pushes.west();
+
+    for knight in board.knights(them) {
+        attacked |= tables::knight_attacks(knight);
+    }
+
+    for diagonal_slider in board.diagonal_sliders(them) {
+        attacked |= tables::bishop_attacks(diagonal_slider, occupied);
+    }
+
+    for orthogonal_slider in board.orthogonal_sliders(them) {
+        attacked |= tables::rook_attacks(orthogonal_slider, occupied);
+    }
+
+    attacked |= tables::king_attacks(board.king(them).single());
+
+    attacked
+}
+
 pub fn all_attackers_of(board: &Board, square: Square, occupied: Bitboard) -> Bitboard {
     use Player::*;
 
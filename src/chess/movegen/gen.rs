@@ -1,6 +1,7 @@
 use crate::chess::bitboard::{bitboards, Bitboard};
 use crate::chess::movegen::{attackers, pins, tables};
 use crate::chess::moves::MoveList;
+use crate::chess::player::Player;
 use crate::chess::square::{squares, Square};
 use crate::chess::{game::Game, moves::Move, piece::PromotionPieceKind};
 
@@ -9,6 +10,13 @@ pub struct MovegenCache {
     check_mask: Bitboard,
     orthogonal_pins: Bitboard,
     diagonal_pins: Bitboard,
+    king_danger_squares: Bitboard,
+}
+
+impl Default for MovegenCache {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MovegenCache {
@@ -18,6 +26,7 @@ impl MovegenCache {
             check_mask: Bitboard::EMPTY,
             orthogonal_pins: Bitboard::EMPTY,
             diagonal_pins: Bitboard::EMPTY,
+            king_danger_squares: Bitboard::EMPTY,
         }
     }
 }
@@ -37,9 +46,16 @@ pub fn generate_captures(game: &Game, moves: &mut MoveList, movegencache: &mut M
     movegencache.checkers = checkers;
     let number_of_checkers = checkers.count();
 
+    // When calculating the squares the king can't move to, we need to remove our King from the board.
+    // If we don't, squares behind the king look safe (since they are blocked by the king)
+    // meaning we'd generate moves away from a slider while in check.
+    let king_danger_squares =
+        attackers::generate_attacked_squares(&game.board, game.player, all_pieces ^ king.bb());
+    movegencache.king_danger_squares = king_danger_squares;
+
     // If we're in check by more than one attacker, we can only get out of check via a king move
     if number_of_checkers > 1 {
-        generate_king_captures(moves, game, king, their_pieces);
+        generate_king_captures(moves, king, their_pieces, king_danger_squares);
         return;
     }
 
@@ -55,17 +71,30 @@ pub fn generate_captures(game: &Game, moves: &mut MoveList, movegencache: &mut M
     movegencache.orthogonal_pins = orthogonal_pins;
     movegencache.diagonal_pins = diagonal_pins;
 
-    generate_pawn_captures(
-        moves,
-        game,
-        game.board.pawns(game.player),
-        king,
-        their_pieces,
-        all_pieces,
-        check_mask,
-        orthogonal_pins,
-        diagonal_pins,
-    );
+    match game.player {
+        Player::White => generate_pawn_captures::<true>(
+            moves,
+            game,
+            game.board.pawns(Player::White),
+            king,
+            their_pieces,
+            all_pieces,
+            check_mask,
+            orthogonal_pins,
+            diagonal_pins,
+        ),
+        Player::Black => generate_pawn_captures::<false>(
+            moves,
+            game,
+            game.board.pawns(Player::Black),
+            king,
+            their_pieces,
+            all_pieces,
+            check_mask,
+            orthogonal_pins,
+            diagonal_pins,
+        ),
+    }
 
     generate_knight_captures(
         moves,
@@ -93,7 +122,7 @@ pub fn generate_captures(game: &Game, moves: &mut MoveList, movegencache: &mut M
         orthogonal_pins,
         diagonal_pins,
     );
-    generate_king_captures(moves, game, king, their_pieces);
+    generate_king_captures(moves, king, their_pieces, king_danger_squares);
 }
 
 pub fn generate_quiets(game: &Game, moves: &mut MoveList, movegencache: &MovegenCache) {
@@ -105,7 +134,7 @@ pub fn generate_quiets(game: &Game, moves: &mut MoveList, movegencache: &Movegen
 
     // If we're in check by more than one attacker, we can only get out of check via a king move
     if number_of_checkers > 1 {
-        generate_king_quiets(moves, game, king, all_pieces);
+        generate_king_quiets(moves, king, all_pieces, movegencache.king_danger_squares);
         return;
     }
 
@@ -114,15 +143,24 @@ pub fn generate_quiets(game: &Game, moves: &mut MoveList, movegencache: &Movegen
     let (orthogonal_pins, diagonal_pins) =
         (movegencache.orthogonal_pins, movegencache.diagonal_pins);
 
-    generate_pawn_quiets(
-        moves,
-        game,
-        game.board.pawns(game.player),
-        all_pieces,
-        check_mask,
-        orthogonal_pins,
-        diagonal_pins,
-    );
+    match game.player {
+        Player::White => generate_pawn_quiets::<true>(
+            moves,
+            game.board.pawns(Player::White),
+            all_pieces,
+            check_mask,
+            orthogonal_pins,
+            diagonal_pins,
+        ),
+        Player::Black => generate_pawn_quiets::<false>(
+            moves,
+            game.board.pawns(Player::Black),
+            all_pieces,
+            check_mask,
+            orthogonal_pins,
+            diagonal_pins,
+        ),
+    }
     generate_knight_quiets(
         moves,
         game.board.knights(game.player),
@@ -147,14 +185,117 @@ pub fn generate_quiets(game: &Game, moves: &mut MoveList, movegencache: &Movegen
         orthogonal_pins,
         diagonal_pins,
     );
-    generate_king_quiets(moves, game, king, all_pieces);
+    generate_king_quiets(moves, king, all_pieces, movegencache.king_danger_squares);
 
     if !checkers.any() {
         generate_castles(moves, game, all_pieces);
     }
 }
 
-fn generate_pawn_captures(
+// Generates all of the moves available in the position, without considering whether they leave our
+// king in check (apart from castling, which is only generated when it's legal). Moves generated
+// this way need to be filtered with `is_legal_after`.
+pub fn generate_pseudo_legal_moves(game: &Game, moves: &mut MoveList) {
+    let all_pieces = game.board.occupancy();
+    let their_pieces = game.board.occupancy_for(game.player.other());
+    let king = game.board.king(game.player).single();
+    let (check_mask, no_pins) = (Bitboard::FULL, Bitboard::EMPTY);
+
+    match game.player {
+        Player::White => {
+            let pawns = game.board.pawns(Player::White);
+            generate_pawn_captures::<true>(
+                moves,
+                game,
+                pawns,
+                king,
+                their_pieces,
+                all_pieces,
+                check_mask,
+                no_pins,
+                no_pins,
+            );
+            generate_pawn_quiets::<true>(moves, pawns, all_pieces, check_mask, no_pins, no_pins);
+        }
+        Player::Black => {
+            let pawns = game.board.pawns(Player::Black);
+            generate_pawn_captures::<false>(
+                moves,
+                game,
+                pawns,
+                king,
+                their_pieces,
+                all_pieces,
+                check_mask,
+                no_pins,
+                no_pins,
+            );
+            generate_pawn_quiets::<false>(moves, pawns, all_pieces, check_mask, no_pins, no_pins);
+        }
+    }
+
+    let knights = game.board.knights(game.player);
+    generate_knight_captures(moves, knights, their_pieces, check_mask, no_pins, no_pins);
+    generate_knight_quiets(moves, knights, all_pieces, check_mask, no_pins, no_pins);
+
+    let diagonal_sliders = game.board.diagonal_sliders(game.player);
+    generate_diagonal_slider_captures(
+        moves,
+        diagonal_sliders,
+        their_pieces,
+        all_pieces,
+        check_mask,
+        no_pins,
+        no_pins,
+    );
+    generate_diagonal_slider_quiets(
+        moves,
+        diagonal_sliders,
+        all_pieces,
+        check_mask,
+        no_pins,
+        no_pins,
+    );
+
+    let orthogonal_sliders = game.board.orthogonal_sliders(game.player);
+    generate_orthogonal_slider_captures(
+        moves,
+        orthogonal_sliders,
+        their_pieces,
+        all_pieces,
+        check_mask,
+        no_pins,
+        no_pins,
+    );
+    generate_orthogonal_slider_quiets(
+        moves,
+        orthogonal_sliders,
+        all_pieces,
+        check_mask,
+        no_pins,
+        no_pins,
+    );
+
+    generate_king_captures(moves, king, their_pieces, Bitboard::EMPTY);
+    generate_king_quiets(moves, king, all_pieces, Bitboard::EMPTY);
+
+    if !game.is_king_in_check() {
+        generate_castles(moves, game, all_pieces);
+    }
+}
+
+// Pawn movement depends on the player, so we generate a version of the pawn movegen for each
+// player. This way, the player is known at compile time and we avoid branching on it for every
+// pawn move.
+const fn player<const WHITE: bool>() -> Player {
+    if WHITE {
+        Player::White
+    } else {
+        Player::Black
+    }
+}
+
+fn generate_pawn_captures<const WHITE: bool>(
     moves: &mut MoveList,
     game: &Game,
     pawns: Bitboard,
@@ -165,6 +306,8 @@ fn generate_pawn_captures(
     orthogonal_pins: Bitboard,
     diagonal_pins: Bitboard,
 ) {
+    let player = const { player::<WHITE>() };
+
     // Pawns that are pinned orthogonally would reveal the king by capturing diagonally
     let can_capture_pawns = pawns & !orthogonal_pins;
 
@@ -173,18 +316,18 @@ fn generate_pawn_captures(
 
     // Pawns can move onto empty squares, as long as they block check if in check
     let available_move_squares = !all_pieces & check_mask;
-    let single_push_available_move_pawns = available_move_squares.backward(game.player);
+    let single_push_available_move_pawns = available_move_squares.backward(player);
 
     // Pawns can push once if they can move by pin rules, are not obstructed, and block check if in check
     let can_push_once_pawns = can_move_pawns & single_push_available_move_pawns;
 
     let capture_targets = their_pieces & check_mask;
 
-    let will_promote_rank = bitboards::pawn_back_rank(game.player.other());
+    let will_promote_rank = bitboards::pawn_back_rank(player.other());
 
     // Promotion capture: Pawns on the enemy's start rank will promote when capturing
     for pawn in can_capture_pawns & will_promote_rank {
-        let mut attacks = tables::pawn_attacks(pawn, game.player);
+        let mut attacks = tables::pawn_attacks(pawn, player);
 
         if diagonal_pins.contains(pawn) {
             attacks &= diagonal_pins;
@@ -216,7 +359,7 @@ fn generate_pawn_captures(
 
     // Promotion push: Pawns on the enemy's start rank will promote when pushing
     for pawn in can_push_once_pawns & will_promote_rank {
-        let target = pawn.forward(game.player);
+        let target = pawn.forward(player);
 
         // Pawns cannot push forward if they are pinned orthogonally
         // There's no 'moving along the pin ray' for these pieces, since the target square is empty
@@ -231,7 +374,7 @@ fn generate_pawn_captures(
 
     // Non-promoting captures: All pawns can capture diagonally
     for pawn in can_capture_pawns & !will_promote_rank {
-        let mut attacks = tables::pawn_attacks(pawn, game.player);
+        let mut attacks = tables::pawn_attacks(pawn, player);
 
         if diagonal_pins.contains(pawn) {
             attacks &= diagonal_pins;
@@ -244,31 +387,33 @@ fn generate_pawn_captures(
 
     // En-passant capture: Pawns either side of the en-passant pawn can capture
     if let Some(en_passant_target) = game.en_passant_target {
-        let captured_pawn = en_passant_target.backward(game.player);
+        let captured_pawn = en_passant_target.backward(player);
 
         if (check_mask & (en_passant_target.bb() | captured_pawn.bb())).any() {
             let potential_capturers =
-                can_capture_pawns & tables::pawn_attacks(en_passant_target, game.player.other());
+                can_capture_pawns & tables::pawn_attacks(en_passant_target, player.other());
 
             for potential_en_passant_capture_start in potential_capturers {
                 // Only consider this pawn if it is not pinned, or if it is pinned but captures along the pin ray
                 if !diagonal_pins.contains(potential_en_passant_capture_start)
                     || diagonal_pins.contains(en_passant_target)
                 {
-                    // We need to check that we do not reveal a check by making this en-passant capture
-                    let mut board_without_en_passant_participants = game.board.clone();
-                    board_without_en_passant_participants
-                        .remove_at(potential_en_passant_capture_start);
-                    board_without_en_passant_participants.remove_at(captured_pawn);
-
-                    let king_in_check = attackers::generate_attackers_of(
-                        &board_without_en_passant_participants,
-                        game.player,
+                    // We need to check that we do not reveal a check by making this en-passant capture.
+                    // Both pawns leave their squares at once, so this can reveal a slider attack on the
+                    // king that the usual pin detection doesn't catch.
+                    let occupancy_after_capture =
+                        (all_pieces ^ potential_en_passant_capture_start.bb() ^ captured_pawn.bb())
+                            | en_passant_target.bb();
+
+                    let reveals_check = attackers::slider_attackers_of(
+                        &game.board,
+                        player,
                         king,
+                        occupancy_after_capture,
                     )
                     .any();
 
-                    if !king_in_check {
+                    if !reveals_check {
                         moves.push(Move::en_passant(
                             potential_en_passant_capture_start,
                             en_passant_target,
@@ -280,30 +425,31 @@ fn generate_pawn_captures(
     }
 }
 
-fn generate_pawn_quiets(
+fn generate_pawn_quiets<const WHITE: bool>(
     moves: &mut MoveList,
-    game: &Game,
     pawns: Bitboard,
     all_pieces: Bitboard,
     check_mask: Bitboard,
     orthogonal_pins: Bitboard,
     diagonal_pins: Bitboard,
 ) {
+    let player = const { player::<WHITE>() };
+
     // Pawns that are pinned diagonally would reveal the king by moving forward
     let can_move_pawns = pawns & !diagonal_pins;
 
     // Pawns can move onto empty squares, as long as they block check if in check
     let available_move_squares = !all_pieces & check_mask;
-    let single_push_available_move_pawns = available_move_squares.backward(game.player);
+    let single_push_available_move_pawns = available_move_squares.backward(player);
 
     // Pawns can push once if they can move by pin rules, are not obstructed, and block check if in check
     let can_push_once_pawns = can_move_pawns & single_push_available_move_pawns;
 
-    let will_promote_rank = bitboards::pawn_back_rank(game.player.other());
+    let will_promote_rank = bitboards::pawn_back_rank(player.other());
 
     // Promotion push: Pawns on the enemy's start rank will promote when pushing
     for pawn in can_push_once_pawns & will_promote_rank {
-        let target = pawn.forward(game.player);
+        let target = pawn.forward(player);
 
         // Pawns cannot push forward if they are pinned orthogonally
         // There's no 'moving along the pin ray' for these pieces, since the target square is empty
@@ -327,11 +473,11 @@ fn generate_pawn_quiets(
         }
     }
 
-    let back_rank = bitboards::pawn_back_rank(game.player);
+    let back_rank = bitboards::pawn_back_rank(player);
 
     // Push: All pawns with an empty square in front of them can move forward
     for pawn in can_push_once_pawns & !will_promote_rank {
-        let forward_one = pawn.forward(game.player);
+        let forward_one = pawn.forward(player);
 
         // Pawns cannot push forward if they are pinned orthogonally, unless they're moving along the pin ray
         if !orthogonal_pins.contains(pawn) || orthogonal_pins.contains(forward_one) {
@@ -339,16 +485,16 @@ fn generate_pawn_quiets(
         }
     }
 
-    let double_push_blockers = all_pieces.backward(game.player);
+    let double_push_blockers = all_pieces.backward(player);
 
     let can_push_twice_pawns = can_move_pawns
         & back_rank
         & !double_push_blockers
-        & single_push_available_move_pawns.backward(game.player);
+        & single_push_available_move_pawns.backward(player);
 
     // Double push: All pawns on the start rank with empty squares in front of them can move forward two squares
     for pawn in can_push_twice_pawns {
-        let forward_two = pawn.forward(game.player).forward(game.player);
+        let forward_two = pawn.forward(player).forward(player);
 
         // Pawns cannot push forward if they are pinned orthogonally, unless they are moving along the pin ray
         if !orthogonal_pins.contains(pawn) || orthogonal_pins.contains(forward_two) {
@@ -491,39 +637,33 @@ fn generate_orthogonal_slider_quiets(
     }
 }
 
-fn generate_king_captures(moves: &mut MoveList, game: &Game, king: Square, their_pieces: Bitboard) {
-    let destinations = tables::king_attacks(king);
-
-    // When calculating the attacked squares, we need to remove our King from the board.
-    // If we don't, squares behind the king look safe (since they are blocked by the king)
-    // meaning we'd generate moves away from a slider while in check.
-    let mut board_without_king = game.board.clone();
-    board_without_king.remove_at(king);
+fn generate_king_captures(
+    moves: &mut MoveList,
+    king: Square,
+    their_pieces: Bitboard,
+    king_danger_squares: Bitboard,
+) {
+    let destinations = tables::king_attacks(king) & !king_danger_squares;
 
     for dst in destinations & their_pieces {
-        if attackers::generate_attackers_of(&board_without_king, game.player, dst).is_empty() {
-            moves.push(Move::capture(king, dst));
-        }
+        moves.push(Move::capture(king, dst));
     }
 }
 
-fn generate_king_quiets(moves: &mut MoveList, game: &Game, king: Square, all_pieces: Bitboard) {
-    let destinations = tables::king_attacks(king);
-
-    // When calculating the attacked squares, we need to remove our King from the board.
-    // If we don't, squares behind the king look safe (since they are blocked by the king)
-    // meaning we'd generate moves away from a slider while in check.
-    let mut board_without_king = game.board.clone();
-    board_without_king.remove_at(king);
+fn generate_king_quiets(
+    moves: &mut MoveList,
+    king: Square,
+    all_pieces: Bitboard,
+    king_danger_squares: Bitboard,
+) {
+    let destinations = tables::king_attacks(king) & !king_danger_squares;
 
     for dst in destinations & !all_pieces {
-        if attackers::generate_attackers_of(&board_without_king, game.player, dst).is_empty() {
-            moves.push(Move::quiet(king, dst));
-        }
+        moves.push(Move::quiet(king, dst));
     }
 }
 
-fn generate_castles(moves: &mut MoveList, game: &Game, all_pieces: Bitboard) {
+pub(super) fn generate_castles(moves: &mut MoveList, game: &Game, all_pieces: Bitboard) {
     let castle_rights_for_player = game.castle_rights.for_player(game.player);
 
     if castle_rights_for_player.king_side {
@@ -637,4 +777,28 @@ mod tests {
             (A5, B6),
         );
     }
+
+    #[test]
+    fn test_forbid_en_passant_revealed_check_from_other_side() {
+        should_not_allow_move("8/8/8/8/Q2pP2k/8/8/3K4 b - e3 0 1", (D4, E3));
+    }
+
+    #[test]
+    fn test_forbid_en_passant_revealed_diagonal_check() {
+        should_not_allow_move("8/k7/8/8/2pP4/8/8/6BK b - d3 0 1", (C4, D3));
+    }
+
+    #[test]
+    fn test_allow_en_passant_capturing_checking_pawn() {
+        should_allow_move("8/8/8/2k5/3Pp3/8/8/4K3 b - d3 0 1", (E4, D3));
+    }
+
+    #[test]
+    fn test_allow_en_passant_blocking_check() {
+        // The rook on a3 checks the king along the third rank, and capturing lands on d3
+        crate::init();
+        let fen = "8/8/8/8/3Pp3/R6k/8/4K3 b - d3 0 1";
+        assert!(Game::from_fen(fen).unwrap().is_king_in_check());
+        should_allow_move(fen, (E4, D3));
+    }
 }
@@ -0,0 +1,221 @@
+use crate::chess::bitboard::{bitboards, Bitboard};
+use crate::chess::game::Game;
+use crate::chess::movegen::{gen, tables};
+use crate::chess::moves::{Move, MoveList};
+use crate::chess::piece::PieceKind;
+
+// Checks whether a single move is legal in the given position, without generating every legal
+// move. This lets us validate moves that came from somewhere other than the movegen for this
+// position, e.g. a TT move that may be from a different position with the same hash.
+pub fn is_legal(game: &Game, mv: Move) -> bool {
+    let player = game.player;
+    let src = mv.src();
+    let dst = mv.dst();
+
+    let Some(piece) = game.board.piece_at(src) else {
+        return false;
+    };
+
+    if piece.player != player {
+        return false;
+    }
+
+    let all_pieces = game.board.occupancy();
+    let our_pieces = game.board.occupancy_for(player);
+    let their_pieces = game.board.occupancy_for(player.other());
+
+    if our_pieces.contains(dst) {
+        return false;
+    }
+
+    // Castling has its own rules about which squares need to be safe, so we check against the
+    // castling moves that would be generated for this position
+    if mv.is_castling() {
+        if piece.kind != PieceKind::King || game.is_king_in_check() {
+            return false;
+        }
+
+        let mut castles = MoveList::new();
+        gen::generate_castles(&mut castles, game, all_pieces);
+        return castles.contains(&mv);
+    }
+
+    let captured = if mv.is_en_passant() {
+        if piece.kind != PieceKind::Pawn || game.en_passant_target != Some(dst) {
+            return false;
+        }
+
+        dst.backward(player).bb()
+    } else {
+        dst.bb() & their_pieces
+    };
+
+    // The capture flag needs to match what's actually on the destination square
+    if mv.is_capture() != captured.any() {
+        return false;
+    }
+
+    let will_promote =
+        piece.kind == PieceKind::Pawn && bitboards::pawn_back_rank(player.other()).contains(src);
+
+    if mv.is_promotion() != will_promote {
+        return false;
+    }
+
+    let can_reach_dst = match piece.kind {
+        PieceKind::Pawn => {
+            if mv.is_capture() {
+                tables::pawn_attacks(src, player).contains(dst)
+            } else {
+                let single_push = src.bb().forward(player) & !all_pieces;
+                let double_push = (single_push & bitboards::pawn_back_rank(player).forward(player))
+                    .forward(player)
+                    & !all_pieces;
+
+                (single_push | double_push).contains(dst)
+            }
+        }
+        PieceKind::Knight => tables::knight_attacks(src).contains(dst),
+        PieceKind::Bishop => tables::bishop_attacks(src, all_pieces).contains(dst),
+        PieceKind::Rook => tables::rook_attacks(src, all_pieces).contains(dst),
+        PieceKind::Queen => (tables::bishop_attacks(src, all_pieces)
+            | tables::rook_attacks(src, all_pieces))
+        .contains(dst),
+        PieceKind::King => tables::king_attacks(src).contains(dst),
+    };
+
+    can_reach_dst && is_legal_after(game, mv)
+}
+
+// Checks whether a pseudo-legal move (e.g. one from `generate_pseudo_legal_moves`) would leave our
+// king in check. We look for attackers of the king on the board as it would be after the move,
+// ignoring any piece that has been captured.
+pub fn is_legal_after(game: &Game, mv: Move) -> bool {
+    // Pseudo-legal castling moves are only generated when they're legal
+    if mv.is_castling() {
+        return true;
+    }
+
+    let player = game.player;
+    let them = player.other();
+    let src = mv.src();
+    let dst = mv.dst();
+
+    let captured = if mv.is_en_passant() {
+        dst.backward(player).bb()
+    } else {
+        dst.bb() & game.board.occupancy_for(them)
+    };
+
+    let king = if game.board.king(player).contains(src) {
+        dst
+    } else {
+        game.board.king(player).single()
+    };
+
+    let occupied = (game.board.occupancy() ^ src.bb() ^ captured) | dst.bb();
+    let remaining = !captured;
+
+    let attackers = (tables::pawn_attacks(king, player) & game.board.pawns(them))
+        | (tables::knight_attacks(king) & game.board.knights(them))
+        | (tables::bishop_attacks(king, occupied) & game.board.diagonal_sliders(them))
+        | (tables::rook_attacks(king, occupied) & game.board.orthogonal_sliders(them))
+        | (tables::king_attacks(king) & game.board.king(them));
+
+    (attackers & remaining) == Bitboard::EMPTY
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::piece::PromotionPieceKind;
+    use crate::chess::square::Square;
+
+    // Every move we could construct between any two squares, including ones that make no sense.
+    // A move can't have the same source and destination square, as then A1 to A1 would be zero.
+    fn all_candidate_moves() -> Vec<Move> {
+        let mut moves = Vec::new();
+
+        for src in 0..64 {
+            for dst in 0..64 {
+                if src == dst {
+                    continue;
+                }
+
+                let (src, dst) = (Square::from_index(src), Square::from_index(dst));
+
+                moves.push(Move::quiet(src, dst));
+                moves.push(Move::capture(src, dst));
+                moves.push(Move::castles(src, dst));
+                moves.push(Move::en_passant(src, dst));
+
+                for promotion in [
+                    PromotionPieceKind::Knight,
+                    PromotionPieceKind::Bishop,
+                    PromotionPieceKind::Rook,
+                    PromotionPieceKind::Queen,
+                ] {
+                    moves.push(Move::quiet_promotion(src, dst, promotion));
+                    moves.push(Move::capture_promotion(src, dst, promotion));
+                }
+            }
+        }
+
+        moves
+    }
+
+    const POSITIONS: [&str; 12] = [
+        crate::chess::fen::START_POS,
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R b KQkq -",
+        "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+        "8/k7/8/8/2pP4/8/8/6BK b - d3 0 1",
+        "8/8/8/8/k2Pp2Q/8/8/3K4 b - d3 0 1",
+        "4k3/8/8/8/8/8/8/4K2R w K - 0 1",
+        "r3k2r/8/8/8/4q3/8/8/R3K2R w KQkq - 0 1",
+        "4k3/8/8/8/1b6/8/3N4/4K2R w K - 0 1",
+    ];
+
+    #[test]
+    fn test_is_legal_matches_movegen() {
+        crate::init();
+
+        let candidates = all_candidate_moves();
+
+        for fen in POSITIONS {
+            let game = Game::from_fen(fen).unwrap();
+            let legal_moves = game.moves();
+
+            for mv in &candidates {
+                assert_eq!(
+                    is_legal(&game, *mv),
+                    legal_moves.contains(mv),
+                    "{fen}: {mv:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_filtered_pseudo_legal_moves_match_movegen() {
+        crate::init();
+
+        for fen in POSITIONS {
+            let game = Game::from_fen(fen).unwrap();
+
+            let mut pseudo_legal_moves = MoveList::new();
+            gen::generate_pseudo_legal_moves(&game, &mut pseudo_legal_moves);
+            pseudo_legal_moves.retain(|mv| is_legal_after(&game, *mv));
+
+            let mut legal_moves = game.moves();
+
+            pseudo_legal_moves.sort_by_key(|mv| format!("{mv:?}"));
+            legal_moves.sort_by_key(|mv| format!("{mv:?}"));
+
+            assert_eq!(pseudo_legal_moves, legal_moves, "{fen}");
+        }
+    }
+}
@@ -1,10 +1,15 @@
 mod attackers;
 mod gen;
+mod legality;
 mod pins;
 pub mod tables;
 
 pub use attackers::{all_attackers_of, generate_attackers_of};
-pub use gen::{generate_captures, generate_legal_moves, generate_quiets, MovegenCache};
+pub use gen::{
+    generate_captures, generate_legal_moves, generate_pseudo_legal_moves, generate_quiets,
+    MovegenCache,
+};
+pub use legality::{is_legal, is_legal_after};
 
 pub fn init() {
     tables::init();
@@ -48,7 +48,7 @@ fn generate_sliding_attacks(
 
         // Until we're off the board
         while current_square.any() {
-            current_square = current_square.in_direction(*direction);
+            current_square = current_square.shift(*direction);
             attacks |= current_square;
 
             // Future squares blocked
@@ -66,7 +66,7 @@ pub fn generate_king_attacks(square: Square) -> Bitboard {
     let sq = square.bb();
 
     for direction in Direction::ALL {
-        attacks |= sq.in_direction(*direction);
+        attacks |= sq.shift(*direction);
     }
 
     attacks
@@ -1,3 +1,4 @@
+use crate::chess::lookup::Lookup;
 use crate::chess::{bitboard::Bitboard, square::Square};
 
 use super::attacks;
@@ -5,7 +6,12 @@ use super::attacks;
 static mut ATTACKS_TABLE: [Bitboard; Square::N] = [Bitboard::EMPTY; Square::N];
 
 pub fn king_attacks(s: Square) -> Bitboard {
-    *unsafe { ATTACKS_TABLE.get_unchecked(s.array_idx()) }
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
+    // SAFETY: `Square::array_idx` is below `Square::N`
+    *unsafe { ATTACKS_TABLE.lookup(s.array_idx()) }
 }
 
 pub fn init() {
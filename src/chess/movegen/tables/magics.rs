@@ -1,5 +1,6 @@
 use crate::chess::bitboard::bitboards;
 use crate::chess::direction::Direction;
+use crate::chess::lookup::Lookup;
 use crate::chess::{bitboard::Bitboard, square::Square};
 
 use super::attacks;
@@ -132,7 +133,7 @@ fn generate_sliding_occupancies(square: Square, directions: &[Direction]) -> Bit
         let mut sq = square.bb();
 
         while sq.any() {
-            sq = sq.in_direction(*direction) & !end_mask;
+            sq = sq.shift(*direction) & !end_mask;
             squares |= sq;
         }
     }
@@ -149,13 +150,25 @@ pub fn init() {
 }
 
 pub fn rook_attacks(s: Square, blockers: Bitboard) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
     let table_idx = table_index_rook(s, blockers);
-    *unsafe { ATTACKS_TABLE.get_unchecked(table_idx) }
+    // SAFETY: Only the relevant blockers affect the index, and `init` has already written to the
+    // index of every subset of them with bounds-checked indexing
+    *unsafe { ATTACKS_TABLE.lookup(table_idx) }
 }
 
 pub fn bishop_attacks(s: Square, blockers: Bitboard) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
     let table_idx = table_index_bishop(s, blockers);
-    *unsafe { ATTACKS_TABLE.get_unchecked(table_idx) }
+    // SAFETY: Only the relevant blockers affect the index, and `init` has already written to the
+    // index of every subset of them with bounds-checked indexing
+    *unsafe { ATTACKS_TABLE.lookup(table_idx) }
 }
 
 fn initialise_bishop_attacks() {
@@ -189,8 +202,9 @@ fn initialise_bishop_not_masks() {
 )]
 fn table_index_bishop(s: Square, blockers: Bitboard) -> usize {
     let square_idx = s.array_idx();
-    let (magic, index) = unsafe { DEFAULT_BISHOP_MAGICS.get_unchecked(square_idx) };
-    let not_mask = unsafe { BISHOP_NOT_MASKS.get_unchecked(square_idx) };
+    // SAFETY: `Square::array_idx` is below `Square::N`
+    let (magic, index) = unsafe { DEFAULT_BISHOP_MAGICS.lookup(square_idx) };
+    let not_mask = unsafe { BISHOP_NOT_MASKS.lookup(square_idx) };
 
     let relevant_occupancies = blockers | *not_mask;
     let mut occupancies_index_offset: u64 = relevant_occupancies.as_u64().wrapping_mul(*magic);
@@ -231,8 +245,9 @@ fn initialise_rook_not_masks() {
 fn table_index_rook(s: Square, blockers: Bitboard) -> usize {
     let square_idx = s.array_idx();
 
-    let (magic, index) = unsafe { DEFAULT_ROOK_MAGICS.get_unchecked(square_idx) };
-    let not_mask = unsafe { ROOK_NOT_MASKS.get_unchecked(square_idx) };
+    // SAFETY: `Square::array_idx` is below `Square::N`
+    let (magic, index) = unsafe { DEFAULT_ROOK_MAGICS.lookup(square_idx) };
+    let not_mask = unsafe { ROOK_NOT_MASKS.lookup(square_idx) };
 
     let relevant_occupancies = blockers | *not_mask;
     let mut occupancies_index_offset: u64 = relevant_occupancies.as_u64().wrapping_mul(*magic);
@@ -1,4 +1,4 @@
-mod attacks;
+pub mod attacks;
 mod between;
 mod king;
 mod knights;
@@ -1,3 +1,4 @@
+use crate::chess::lookup::Lookup;
 use crate::chess::{bitboard::Bitboard, player::Player, square::Square};
 
 use super::attacks;
@@ -6,10 +7,15 @@ static mut ATTACKS_TABLE: [[Bitboard; Square::N]; Player::N] =
     [[Bitboard::EMPTY; Square::N]; Player::N];
 
 pub fn pawn_attacks(s: Square, player: Player) -> Bitboard {
+    debug_assert!(
+        crate::chess::is_initialised(),
+        "Lookup tables were not initialised"
+    );
+    // SAFETY: `Player::array_idx` is below `Player::N` and `Square::array_idx` is below `Square::N`
     *unsafe {
         ATTACKS_TABLE
-            .get_unchecked(player.array_idx())
-            .get_unchecked(s.array_idx())
+            .lookup(player.array_idx())
+            .lookup(s.array_idx())
     }
 }
 
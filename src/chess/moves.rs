@@ -7,6 +7,13 @@ const MAX_LEGAL_MOVES: usize = 218;
 pub type MoveList = ArrayVec<Move, MAX_LEGAL_MOVES>;
 
 pub trait MoveListExt {
+    fn find_matching(
+        &self,
+        src: Square,
+        dst: Square,
+        promotion: Option<PromotionPieceKind>,
+    ) -> Option<Move>;
+
     fn expect_matching(
         &self,
         src: Square,
@@ -16,21 +23,25 @@ pub trait MoveListExt {
 }
 
 impl MoveListExt for MoveList {
+    fn find_matching(
+        &self,
+        src: Square,
+        dst: Square,
+        promotion: Option<PromotionPieceKind>,
+    ) -> Option<Move> {
+        self.iter()
+            .find(|mv| mv.src() == src && mv.dst() == dst && mv.promotion() == promotion)
+            .copied()
+    }
+
     fn expect_matching(
         &self,
         src: Square,
         dst: Square,
         promotion: Option<PromotionPieceKind>,
     ) -> Move {
-        for i in 0..self.len() {
-            let mv = *self.get(i).unwrap();
-
-            if mv.src() == src && mv.dst() == dst && mv.promotion() == promotion {
-                return mv;
-            }
-        }
-
-        panic!("Illegal move")
+        self.find_matching(src, dst, promotion)
+            .expect("Illegal move")
     }
 }
 
@@ -172,6 +183,25 @@ impl Move {
         self.0.get()
     }
 
+    // The raw encoding of the move, e.g. for writing it to a file
+    pub fn to_u16(self) -> u16 {
+        self.data()
+    }
+
+    // The inverse of `to_u16`, for data that may not have come from a valid move. Only the
+    // encoding is checked, so the move may still make no sense in any position.
+    pub fn from_u16(data: u16) -> Option<Self> {
+        let data = NonZeroU16::new(data)?;
+
+        // Outside of promotions, the second flag bit is unused so can't be set
+        let flags = (data.get() >> FLAGS_SHIFT) as u8;
+        if flags & PROMOTION_FLAG_BIT == 0 && flags & flag_bits(false, true) != 0 {
+            return None;
+        }
+
+        Some(Self(data))
+    }
+
     #[inline]
     pub fn src(self) -> Square {
         Square::from_index((self.data() & SRC_MASK) as u8)
@@ -192,7 +222,6 @@ impl Move {
         (self.data() & CAPTURE_BIT_MASK) == CAPTURE_BIT_MASK
     }
 
-    #[expect(unused, reason = "Not yet used")]
     #[inline]
     pub fn is_promotion(self) -> bool {
         (self.data() & PROMOTION_BIT_MASK) == PROMOTION_BIT_MASK
@@ -257,6 +286,34 @@ mod tests {
         assert_eq!(size_of::<Move>(), size_of::<Option<Move>>());
     }
 
+    #[test]
+    fn test_find_matching() {
+        crate::init();
+        let game = crate::chess::game::Game::new();
+
+        assert!(game.moves().find_matching(E2, E4, None).is_some());
+        assert!(game.moves().find_matching(E2, E5, None).is_none());
+        assert!(game
+            .moves()
+            .find_matching(E2, E4, Some(PromotionPieceKind::Queen))
+            .is_none());
+    }
+
+    #[test]
+    fn test_u16_round_trip() {
+        for mv in [
+            Move::quiet(E2, E4),
+            Move::castles(E1, G1),
+            Move::en_passant(D5, E6),
+            Move::capture_promotion(B7, A8, PromotionPieceKind::Knight),
+        ] {
+            assert_eq!(Move::from_u16(mv.to_u16()), Some(mv));
+        }
+
+        assert_eq!(Move::from_u16(0), None);
+        assert_eq!(Move::from_u16(0b1000_0000_0000_0001), None);
+    }
+
     #[test]
     fn test_quiet() {
         let mv = Move::quiet(A1, B1);
@@ -0,0 +1,172 @@
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::chess::piece::{PieceKind, PromotionPieceKind};
+use crate::chess::player::Player;
+use crate::chess::san;
+use crate::chess::square::squares;
+
+// The ways we can write moves for people to read, e.g. in the pretty output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    // Standard algebraic notation, e.g. 'Nf3'
+    San,
+    // Long algebraic notation, which always includes the source square, e.g. 'Ng1-f3'
+    Lan,
+    // SAN with unicode chess pieces instead of letters, e.g. '♘f3'
+    Figurine,
+}
+
+impl Notation {
+    pub const NAMES: &'static [&'static str] = &["SAN", "LAN", "Figurine"];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "san" => Some(Self::San),
+            "lan" => Some(Self::Lan),
+            "figurine" => Some(Self::Figurine),
+            _ => None,
+        }
+    }
+
+    pub fn format_move(self, game: &Game, mv: Move) -> String {
+        match self {
+            Self::San => san::format_move(game, mv),
+            Self::Lan => format_lan(game, mv),
+            Self::Figurine => format_figurine(game, mv),
+        }
+    }
+}
+
+fn format_lan(game: &Game, mv: Move) -> String {
+    let check_specifier = san::check_specifier(game, mv);
+
+    if mv.is_castling() {
+        let castle = if mv.dst() == squares::kingside_castle_dest(game.player) {
+            san::KINGSIDE_CASTLE
+        } else {
+            san::QUEENSIDE_CASTLE
+        };
+
+        return format!("{castle}{check_specifier}");
+    }
+
+    let piece = game.board.piece_at(mv.src()).unwrap();
+
+    let piece_identifier = match piece.kind {
+        PieceKind::Pawn => "",
+        PieceKind::Knight => "N",
+        PieceKind::Bishop => "B",
+        PieceKind::Rook => "R",
+        PieceKind::Queen => "Q",
+        PieceKind::King => "K",
+    };
+
+    let separator = if mv.is_capture() { san::CAPTURE } else { '-' };
+
+    let promotion = match mv.promotion() {
+        None => String::new(),
+        Some(p) => format!(
+            "{}{}",
+            san::PROMOTION,
+            match p {
+                PromotionPieceKind::Knight => 'N',
+                PromotionPieceKind::Bishop => 'B',
+                PromotionPieceKind::Rook => 'R',
+                PromotionPieceKind::Queen => 'Q',
+            }
+        ),
+    };
+
+    format!(
+        "{piece_identifier}{}{separator}{}{promotion}{check_specifier}",
+        mv.src().notation(),
+        mv.dst().notation()
+    )
+}
+
+// Pieces are only ever written as capital letters in SAN, so we can swap them out for the
+// figurines of the player making the move
+fn format_figurine(game: &Game, mv: Move) -> String {
+    san::format_move(game, mv)
+        .chars()
+        .map(|c| match (game.player, c) {
+            (Player::White, 'K') => '♔',
+            (Player::White, 'Q') => '♕',
+            (Player::White, 'R') => '♖',
+            (Player::White, 'B') => '♗',
+            (Player::White, 'N') => '♘',
+            (Player::Black, 'K') => '♚',
+            (Player::Black, 'Q') => '♛',
+            (Player::Black, 'R') => '♜',
+            (Player::Black, 'B') => '♝',
+            (Player::Black, 'N') => '♞',
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::moves::MoveListExt;
+    use crate::chess::square::squares::all::*;
+    use crate::chess::square::Square;
+
+    fn test_notation(
+        fen: &str,
+        mv: (Square, Square, Option<PromotionPieceKind>),
+        expected: [&str; 3],
+    ) {
+        crate::init();
+
+        let game = Game::from_fen(fen).unwrap();
+        let mv = game.moves().expect_matching(mv.0, mv.1, mv.2);
+
+        assert_eq!(
+            [Notation::San, Notation::Lan, Notation::Figurine].map(|n| n.format_move(&game, mv)),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_notations() {
+        let start_pos = crate::chess::fen::START_POS;
+
+        test_notation(start_pos, (E2, E4, None), ["e4", "e2-e4", "e4"]);
+        test_notation(start_pos, (G1, F3, None), ["Nf3", "Ng1-f3", "♘f3"]);
+
+        test_notation(
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 2",
+            (D8, D6, None),
+            ["Qd6", "Qd8-d6", "♛d6"],
+        );
+
+        test_notation(
+            "rnbqkbnr/ppp1pppp/8/3p4/4P3/8/PPPP1PPP/RNBQKBNR w KQkq d6 0 2",
+            (E4, D5, None),
+            ["exd5", "e4xd5", "exd5"],
+        );
+
+        test_notation(
+            "1n5k/P7/8/8/8/8/8/7K w - - 0 1",
+            (A7, B8, Some(PromotionPieceKind::Queen)),
+            ["axb8=Q+", "a7xb8=Q+", "axb8=♕+"],
+        );
+
+        test_notation(
+            "5k2/8/8/8/8/8/8/4K2R w K - 0 1",
+            (E1, G1, None),
+            ["O-O+", "O-O+", "O-O+"],
+        );
+    }
+
+    #[test]
+    fn test_from_name() {
+        for name in Notation::NAMES {
+            assert!(Notation::from_name(name).is_some());
+        }
+
+        assert_eq!(Notation::from_name("lan"), Some(Notation::Lan));
+        assert_eq!(Notation::from_name("Algebraic"), None);
+    }
+}
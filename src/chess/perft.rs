@@ -1,9 +1,9 @@
 use crate::chess::game::Game;
 use crate::chess::moves::Move;
 
-pub fn perft(depth: u8, game: &mut Game) -> usize {
+pub fn perft(depth: u8, game: &mut Game) -> u64 {
     if depth == 1 {
-        return game.moves().len();
+        return game.moves().len() as u64;
     }
 
     game.moves()
@@ -17,10 +17,10 @@ pub fn perft(depth: u8, game: &mut Game) -> usize {
         .sum()
 }
 
-pub fn perft_div(depth: u8, game: &mut Game) -> Vec<(Move, usize)> {
+pub fn perft_div(depth: u8, game: &mut Game) -> Vec<(Move, u64)> {
     let root_moves = game.moves().to_vec();
 
-    let mut perft_for_moves: Vec<(Move, usize)> = vec![];
+    let mut perft_for_moves: Vec<(Move, u64)> = vec![];
 
     if depth == 1 {
         for mv in root_moves {
@@ -0,0 +1,439 @@
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::chess::player::Player;
+use crate::chess::san;
+use std::fmt::Write;
+
+const RESULTS: [&str; 4] = ["1-0", "0-1", "1/2-1/2", "*"];
+
+// PGN export format asks for lines of movetext to be kept under 80 characters
+const MAX_LINE_LENGTH: usize = 79;
+
+// A game read from a PGN file. Only the main line is kept: comments, NAGs and variations are
+// skipped when reading.
+#[derive(Debug, Clone)]
+pub struct PgnGame {
+    pub tags: Vec<(String, String)>,
+    pub start: Game,
+    pub moves: Vec<Move>,
+    pub result: String,
+}
+
+impl PgnGame {
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        match self.tags.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => *existing = value.to_string(),
+            None => self.tags.push((name.to_string(), value.to_string())),
+        }
+    }
+}
+
+impl Default for PgnGame {
+    fn default() -> Self {
+        Self {
+            tags: Vec::new(),
+            start: Game::new(),
+            moves: Vec::new(),
+            result: "*".to_string(),
+        }
+    }
+}
+
+// Extra information to write after a move, e.g. '$2 {Mistake.}'
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Annotation {
+    pub nag: Option<u8>,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token {
+    Tag(String, String),
+    Move(String),
+    Result(String),
+}
+
+// Reads every game in the input
+pub fn parse(input: &str) -> Result<Vec<PgnGame>, String> {
+    let mut games = Vec::new();
+    let mut tags = Vec::new();
+    let mut moves = Vec::new();
+
+    for token in tokenize(input)? {
+        match token {
+            Token::Tag(name, value) => {
+                // A tag after some movetext means the previous game had no result
+                if !moves.is_empty() {
+                    games.push(build_game(
+                        std::mem::take(&mut tags),
+                        std::mem::take(&mut moves),
+                        "*".to_string(),
+                    )?);
+                }
+
+                tags.push((name, value));
+            }
+            Token::Move(mv) => moves.push(mv),
+            Token::Result(result) => {
+                games.push(build_game(
+                    std::mem::take(&mut tags),
+                    std::mem::take(&mut moves),
+                    result,
+                )?);
+            }
+        }
+    }
+
+    if !tags.is_empty() || !moves.is_empty() {
+        games.push(build_game(tags, moves, "*".to_string())?);
+    }
+
+    Ok(games)
+}
+
+fn build_game(
+    tags: Vec<(String, String)>,
+    moves: Vec<String>,
+    result: String,
+) -> Result<PgnGame, String> {
+    let start = match tags.iter().find(|(name, _)| name == "FEN") {
+        Some((_, fen)) => Game::from_fen(fen)?,
+        None => Game::new(),
+    };
+
+    let mut game = start.clone();
+    let mut parsed_moves = Vec::new();
+
+    for token in moves {
+        let mv = san::parse_move(&game, &token).map_err(|_| {
+            format!(
+                "Illegal move {token} in position {} (move {})",
+                game.to_fen(),
+                game.turn()
+            )
+        })?;
+
+        game.make_move(mv);
+        parsed_moves.push(mv);
+    }
+
+    Ok(PgnGame {
+        tags,
+        start,
+        moves: parsed_moves,
+        result,
+    })
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+    let mut variation_depth = 0_usize;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' => {
+                if !chars.by_ref().any(|c| c == '}') {
+                    return Err("Unterminated comment".to_string());
+                }
+            }
+            ';' => {
+                let _ = chars.by_ref().find(|&c| c == '\n');
+            }
+            '(' => variation_depth += 1,
+            ')' => variation_depth = variation_depth.saturating_sub(1),
+            '[' => {
+                let tag: String = chars.by_ref().take_while(|&c| c != ']').collect();
+                tokens.push(parse_tag(&tag)?);
+            }
+            c if c.is_whitespace() => {}
+            c => {
+                let mut word = String::from(c);
+
+                while let Some(&c) = chars.peek() {
+                    if c.is_whitespace() || "{};()[".contains(c) {
+                        break;
+                    }
+
+                    word.push(c);
+                    chars.next();
+                }
+
+                if variation_depth > 0 || word.starts_with('$') {
+                    continue;
+                }
+
+                if RESULTS.contains(&word.as_str()) {
+                    tokens.push(Token::Result(word));
+                    continue;
+                }
+
+                // Move numbers can be attached to the move, e.g. '1.e4' or '1...e5', and moves
+                // can have annotations attached, e.g. 'e4!?'
+                let mv = word
+                    .rsplit('.')
+                    .next()
+                    .unwrap_or_default()
+                    .trim_end_matches(['+', '#', '!', '?']);
+
+                // Some PGNs castle with zeros rather than the letter O
+                let mv = match mv {
+                    "0-0" => san::KINGSIDE_CASTLE,
+                    "0-0-0" => san::QUEENSIDE_CASTLE,
+                    mv => mv,
+                };
+
+                if !mv.is_empty() {
+                    tokens.push(Token::Move(mv.to_string()));
+                }
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+fn parse_tag(tag: &str) -> Result<Token, String> {
+    let (name, value) = tag
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(|| format!("Invalid tag: [{tag}]"))?;
+
+    let value = value
+        .trim()
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .ok_or_else(|| format!("Invalid tag: [{tag}]"))?;
+
+    Ok(Token::Tag(
+        name.to_string(),
+        value.replace("\\\"", "\"").replace("\\\\", "\\"),
+    ))
+}
+
+// Writes a game in PGN export format, with the annotations (if any) for each move after it
+pub fn write(game: &PgnGame, annotations: &[Annotation]) -> String {
+    let mut output = String::new();
+
+    for (name, value) in &game.tags {
+        let value = value.replace('\\', "\\\\").replace('"', "\\\"");
+        writeln!(output, "[{name} \"{value}\"]").unwrap();
+    }
+
+    if !game.tags.is_empty() {
+        output.push('\n');
+    }
+
+    let mut position = game.start.clone();
+    let mut tokens = Vec::new();
+    let mut needs_move_number = true;
+
+    for (i, &mv) in game.moves.iter().enumerate() {
+        if position.player == Player::White {
+            tokens.push(format!("{}.", position.turn()));
+        } else if needs_move_number {
+            tokens.push(format!("{}...", position.turn()));
+        }
+
+        tokens.push(san::format_move(&position, mv));
+        needs_move_number = false;
+
+        if let Some(annotation) = annotations.get(i) {
+            if let Some(nag) = annotation.nag {
+                tokens.push(format!("${nag}"));
+            }
+
+            // Black's next move needs its own move number once it's separated from White's
+            if let Some(comment) = &annotation.comment {
+                tokens.push(format!("{{{comment}}}"));
+                needs_move_number = true;
+            }
+        }
+
+        position.make_move(mv);
+    }
+
+    tokens.push(game.result.clone());
+
+    let mut line_length = 0;
+
+    for token in tokens {
+        if line_length > 0 && line_length + 1 + token.len() > MAX_LINE_LENGTH {
+            output.push('\n');
+            line_length = 0;
+        } else if line_length > 0 {
+            output.push(' ');
+            line_length += 1;
+        }
+
+        line_length += token.len();
+        output.push_str(&token);
+    }
+
+    output.push('\n');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GAME: &str = r#"[Event "Test"]
+[White "A \"quoted\" name"]
+[Black "Someone"]
+[Result "1-0"]
+
+1. e4 e5 {A comment (with brackets)} 2. Nf3 $1 Nc6 (2... d6 3. d4) 3.Bb5 a6?!
+; A line comment
+4. Ba4 Nf6 5. O-O 1-0
+"#;
+
+    #[test]
+    fn test_parse_game() {
+        crate::init();
+
+        let games = parse(GAME).unwrap();
+        assert_eq!(games.len(), 1);
+
+        let game = &games[0];
+        assert_eq!(
+            game.tags,
+            [
+                ("Event".to_string(), "Test".to_string()),
+                ("White".to_string(), "A \"quoted\" name".to_string()),
+                ("Black".to_string(), "Someone".to_string()),
+                ("Result".to_string(), "1-0".to_string()),
+            ]
+        );
+        assert_eq!(game.result, "1-0");
+
+        let moves: Vec<String> = game.moves.iter().map(|mv| format!("{mv:?}")).collect();
+        assert_eq!(
+            moves,
+            ["e2e4", "e7e5", "g1f3", "b8c6", "f1b5", "a7a6", "b5a4", "g8f6", "e1g1"]
+        );
+    }
+
+    #[test]
+    fn test_parse_multiple_games() {
+        crate::init();
+
+        let input = format!("{GAME}\n[Event \"Second\"]\n\n1. d4 d5 *\n\n1. c4 0-1\n");
+        let games = parse(&input).unwrap();
+
+        assert_eq!(games.len(), 3);
+        assert_eq!(games[1].tags, [("Event".to_string(), "Second".to_string())]);
+        assert_eq!(games[1].moves.len(), 2);
+        assert_eq!(games[1].result, "*");
+        assert!(games[2].tags.is_empty());
+        assert_eq!(games[2].result, "0-1");
+    }
+
+    #[test]
+    fn test_parse_from_fen() {
+        crate::init();
+
+        let games =
+            parse("[FEN \"4k3/8/8/8/8/8/8/R3K3 b - - 0 40\"]\n\n40... Kd7 41. Ra7+ *").unwrap();
+
+        assert_eq!(games[0].start.turn(), 40);
+        assert_eq!(games[0].moves.len(), 2);
+    }
+
+    #[test]
+    fn test_tokenize_move_numbers_and_castling() {
+        let tokens = tokenize("4.0-0 Nf6 5. d3 0-0-0+ 6...Kb8 *").unwrap();
+
+        assert_eq!(
+            tokens,
+            [
+                Token::Move("O-O".to_string()),
+                Token::Move("Nf6".to_string()),
+                Token::Move("d3".to_string()),
+                Token::Move("O-O-O".to_string()),
+                Token::Move("Kb8".to_string()),
+                Token::Result("*".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_castling_with_zeros() {
+        crate::init();
+
+        let games = parse("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4. 0-0 *").unwrap();
+        assert_eq!(format!("{:?}", games[0].moves.last().unwrap()), "e1g1");
+    }
+
+    #[test]
+    fn test_parse_illegal_move() {
+        crate::init();
+
+        assert!(parse("1. e4 e4 *").is_err());
+        assert!(parse("1. O-O *").is_err());
+        assert!(parse("[Event Test]\n1. e4 *").is_err());
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        crate::init();
+
+        let game = parse(GAME).unwrap().remove(0);
+        let written = write(&game, &[]);
+
+        assert!(written.starts_with("[Event \"Test\"]\n[White \"A \\\"quoted\\\" name\"]\n"));
+        assert!(written.ends_with("\n\n1. e4 e5 2. Nf3 Nc6 3. Bb5 a6 4. Ba4 Nf6 5. O-O 1-0\n"));
+
+        let reparsed = parse(&written).unwrap().remove(0);
+        assert_eq!(reparsed.tags, game.tags);
+        assert_eq!(reparsed.moves, game.moves);
+    }
+
+    #[test]
+    fn test_write_annotations() {
+        crate::init();
+
+        let game = parse("1. e4 e5 2. Qh5 *").unwrap().remove(0);
+        let annotations = [
+            Annotation {
+                nag: None,
+                comment: Some("[%eval 0.3]".to_string()),
+            },
+            Annotation::default(),
+            Annotation {
+                nag: Some(6),
+                comment: Some("Inaccuracy.".to_string()),
+            },
+        ];
+
+        assert_eq!(
+            write(&game, &annotations),
+            "1. e4 {[%eval 0.3]} 1... e5 2. Qh5 $6 {Inaccuracy.} *\n"
+        );
+    }
+
+    #[test]
+    fn test_write_wraps_long_lines() {
+        crate::init();
+
+        let mut game = PgnGame::default();
+        let mut position = game.start.clone();
+
+        for _ in 0..8 {
+            for mv in ["g1f3", "g8f6", "f3g1", "f6g8"] {
+                let mv = position
+                    .moves()
+                    .into_iter()
+                    .find(|m| format!("{m:?}") == mv)
+                    .unwrap();
+                position.make_move(mv);
+                game.moves.push(mv);
+            }
+        }
+
+        let written = write(&game, &[]);
+        assert!(written.lines().count() > 1);
+        assert!(written.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(parse(&written).unwrap()[0].moves, game.moves);
+    }
+}
@@ -1,14 +1,74 @@
 mod san_parser;
 mod san_writer;
 
-const KINGSIDE_CASTLE: &str = "O-O";
-const QUEENSIDE_CASTLE: &str = "O-O-O";
-const CAPTURE: char = 'x';
-const PROMOTION: char = '=';
+pub const KINGSIDE_CASTLE: &str = "O-O";
+pub const QUEENSIDE_CASTLE: &str = "O-O-O";
+pub const CAPTURE: char = 'x';
+pub const PROMOTION: char = '=';
 const CHECK: char = '+';
 const CHECKMATE: char = '#';
 
-#[expect(unused, reason = "Unused")]
 pub use san_parser::parse_move;
 
-pub use san_writer::format_move;
+pub use san_writer::{check_specifier, format_move};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::game::Game;
+    use crate::chess::moves::Move;
+    use rand::prelude::*;
+    use std::collections::HashSet;
+
+    // Every legal move in every position of some random games should format to a distinct SAN
+    // string, which parses back to the same move
+    #[test]
+    fn test_san_round_trip() {
+        crate::init();
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let mut moves_checked = 0;
+
+        for _ in 0..20 {
+            let mut game = Game::new();
+
+            for _ in 0..150 {
+                let moves = game.moves();
+                if moves.is_empty() {
+                    break;
+                }
+
+                let mut seen = HashSet::new();
+
+                for &mv in &moves {
+                    let san = format_move(&game, mv);
+                    assert!(seen.insert(san.clone()), "Ambiguous SAN: {san}");
+
+                    let parsed: Move = parse_move(&game, &san).unwrap();
+                    assert_eq!(parsed, mv, "{san} in {}", game.to_fen());
+
+                    let mut game_after_move = game.clone();
+                    game_after_move.make_move(mv);
+
+                    let expected_suffix = if !game_after_move.is_king_in_check() {
+                        None
+                    } else if game_after_move.moves().is_empty() {
+                        Some(CHECKMATE)
+                    } else {
+                        Some(CHECK)
+                    };
+
+                    assert_eq!(
+                        san.chars().last().filter(|c| "+#".contains(*c)),
+                        expected_suffix
+                    );
+                    moves_checked += 1;
+                }
+
+                game.make_move(*moves.choose(&mut rng).unwrap());
+            }
+        }
+
+        assert!(moves_checked > 10_000);
+    }
+}
@@ -29,7 +29,10 @@ pub enum ParseError {
     InvalidRank,
     InvalidPromotionPiece,
     InvalidAmbiguityResolution,
+    InvalidDestinationSquare,
     NoXInCaptureMove,
+    NoMatchingMove,
+    AmbiguousMove,
 }
 
 fn parse_ambiguity_resolution(chars: &[char]) -> Result<AmbiguityResolution, ParseError> {
@@ -62,31 +65,11 @@ fn parse_ambiguity_resolution(chars: &[char]) -> Result<AmbiguityResolution, Par
 }
 
 fn parse_file(c: char) -> Result<File, ParseError> {
-    Ok(match c {
-        'a' => File::A,
-        'b' => File::B,
-        'c' => File::C,
-        'd' => File::D,
-        'e' => File::E,
-        'f' => File::F,
-        'g' => File::G,
-        'h' => File::H,
-        _ => return Err(ParseError::InvalidFile),
-    })
+    File::from_char(c).ok_or(ParseError::InvalidFile)
 }
 
 fn parse_rank(c: char) -> Result<Rank, ParseError> {
-    Ok(match c {
-        '1' => Rank::R1,
-        '2' => Rank::R2,
-        '3' => Rank::R3,
-        '4' => Rank::R4,
-        '5' => Rank::R5,
-        '6' => Rank::R6,
-        '7' => Rank::R7,
-        '8' => Rank::R8,
-        _ => return Err(ParseError::InvalidRank),
-    })
+    Rank::from_char(c).ok_or(ParseError::InvalidRank)
 }
 
 fn parse_promotion_piece(piece: &str) -> Result<PromotionPieceKind, ParseError> {
@@ -116,6 +99,19 @@ fn parse_piece(c: char) -> Option<PieceKind> {
     }
 }
 
+fn single_source_square(squares: impl IntoIterator<Item = Square>) -> Result<Square, ParseError> {
+    let squares: HashSet<Square> = squares.into_iter().collect();
+    let mut squares = squares.into_iter();
+
+    let square = squares.next().ok_or(ParseError::NoMatchingMove)?;
+
+    if squares.next().is_some() {
+        return Err(ParseError::AmbiguousMove);
+    }
+
+    Ok(square)
+}
+
 fn parse_source_square(game: &Game, src: &str, dst: Square) -> Result<Square, ParseError> {
     let piece_moves: Vec<(PieceKind, Move)> = game
         .moves()
@@ -125,14 +121,12 @@ fn parse_source_square(game: &Game, src: &str, dst: Square) -> Result<Square, Pa
 
     // Pawn move
     if src.is_empty() {
-        let matching_source_squares: HashSet<Square> = piece_moves
-            .into_iter()
-            .filter(|&(piece, mv)| piece == PieceKind::Pawn && mv.dst() == dst)
-            .map(|(_, mv)| mv.src())
-            .collect();
-
-        assert_eq!(matching_source_squares.len(), 1);
-        return Ok(*matching_source_squares.iter().next().unwrap());
+        return single_source_square(
+            piece_moves
+                .into_iter()
+                .filter(|&(piece, mv)| piece == PieceKind::Pawn && mv.dst() == dst)
+                .map(|(_, mv)| mv.src()),
+        );
     }
 
     let src_chars: Vec<char> = src.chars().collect();
@@ -141,42 +135,49 @@ fn parse_source_square(game: &Game, src: &str, dst: Square) -> Result<Square, Pa
     if let Some(moved_piece) = parse_piece(*first_char) {
         let ambiguity_resolution = parse_ambiguity_resolution(rest)?;
 
-        let matching_source_squares: Vec<Square> = piece_moves
-            .into_iter()
-            .filter(|&(piece, mv)| {
-                piece == moved_piece && mv.dst() == dst && ambiguity_resolution.satisfied_by(mv)
-            })
-            .map(|(_, mv)| mv.src())
-            .collect();
-
-        assert_eq!(matching_source_squares.len(), 1);
-        return Ok(*matching_source_squares.first().unwrap());
+        return single_source_square(
+            piece_moves
+                .into_iter()
+                .filter(|&(piece, mv)| {
+                    piece == moved_piece && mv.dst() == dst && ambiguity_resolution.satisfied_by(mv)
+                })
+                .map(|(_, mv)| mv.src()),
+        );
     }
 
+    // Without a piece, this is a pawn capture (e.g. 'exd5')
     let ambiguity_resolution = parse_ambiguity_resolution(&src_chars)?;
 
-    let matching_source_squares: Vec<Square> = piece_moves
-        .into_iter()
-        .filter(|&(_, mv)| mv.dst() == dst && ambiguity_resolution.satisfied_by(mv))
-        .map(|(_, mv)| mv.src())
-        .collect();
-
-    assert_eq!(matching_source_squares.len(), 1);
-    Ok(*matching_source_squares.first().unwrap())
+    single_source_square(
+        piece_moves
+            .into_iter()
+            .filter(|&(piece, mv)| {
+                piece == PieceKind::Pawn && mv.dst() == dst && ambiguity_resolution.satisfied_by(mv)
+            })
+            .map(|(_, mv)| mv.src()),
+    )
 }
 
 fn parse_destination_square(sq: &str) -> Result<Square, ParseError> {
-    assert_eq!(sq.len(), 2);
-
     let mut chars = sq.chars();
-    let file = parse_file(chars.next().unwrap())?;
-    let rank = parse_rank(chars.next().unwrap())?;
 
-    Ok(Square::from_file_and_rank(file, rank))
+    let (Some(file), Some(rank), None) = (chars.next(), chars.next(), chars.next()) else {
+        return Err(ParseError::InvalidDestinationSquare);
+    };
+
+    Ok(Square::new(parse_file(file)?, parse_rank(rank)?))
 }
 
 fn parse_move_squares(game: &Game, mv: &str) -> Result<(Square, Square), ParseError> {
-    let (src, dst) = mv.split_at(mv.len() - 2);
+    // The destination square is always the last two characters
+    let split_idx = mv
+        .char_indices()
+        .rev()
+        .nth(1)
+        .map(|(idx, _)| idx)
+        .ok_or(ParseError::InvalidDestinationSquare)?;
+
+    let (src, dst) = mv.split_at(split_idx);
 
     let dst = parse_destination_square(dst)?;
     let src = parse_source_square(game, src, dst)?;
@@ -205,26 +206,32 @@ fn parse_squares(game: &Game, mv: &str) -> Result<(Square, Square), ParseError>
 }
 
 pub fn parse_move(game: &Game, mv: &str) -> Result<Move, ParseError> {
+    let mv = mv
+        .trim_end_matches(san::CHECK)
+        .trim_end_matches(san::CHECKMATE);
+
     if mv == san::KINGSIDE_CASTLE {
-        return Ok(game.moves().expect_matching(
-            squares::king_start(game.player),
-            squares::kingside_castle_dest(game.player),
-            None,
-        ));
+        return game
+            .moves()
+            .find_matching(
+                squares::king_start(game.player),
+                squares::kingside_castle_dest(game.player),
+                None,
+            )
+            .ok_or(ParseError::NoMatchingMove);
     }
 
     if mv == san::QUEENSIDE_CASTLE {
-        return Ok(game.moves().expect_matching(
-            squares::king_start(game.player),
-            squares::queenside_castle_dest(game.player),
-            None,
-        ));
+        return game
+            .moves()
+            .find_matching(
+                squares::king_start(game.player),
+                squares::queenside_castle_dest(game.player),
+                None,
+            )
+            .ok_or(ParseError::NoMatchingMove);
     }
 
-    let mv = mv
-        .trim_end_matches(san::CHECK)
-        .trim_end_matches(san::CHECKMATE);
-
     let (mv, promotion) = if mv.contains(san::PROMOTION) {
         let (rest, promotion_piece) = mv
             .split_once(san::PROMOTION)
@@ -237,7 +244,9 @@ pub fn parse_move(game: &Game, mv: &str) -> Result<Move, ParseError> {
 
     let (src, dst) = parse_squares(game, mv)?;
 
-    Ok(game.moves().expect_matching(src, dst, promotion))
+    game.moves()
+        .find_matching(src, dst, promotion)
+        .ok_or(ParseError::NoMatchingMove)
 }
 
 #[cfg(test)]
@@ -347,4 +356,18 @@ mod tests {
             "g8=Q+",
         );
     }
+
+    #[test]
+    fn san_illegal_moves_are_errors() {
+        crate::init();
+
+        let game = Game::from_fen(fen::START_POS).unwrap();
+
+        assert!(parse_move(&game, "e5").is_err());
+        assert!(parse_move(&game, "O-O").is_err());
+        assert!(parse_move(&game, "Qd4").is_err());
+        assert!(parse_move(&game, "N").is_err());
+        assert!(parse_move(&game, "").is_err());
+        assert!(parse_move(&game, "é4").is_err());
+    }
 }
@@ -12,29 +12,44 @@ enum AmbiguityResolution {
     Exact, // Two pieces on the same file or rank can move to the same square - specify the exact source square
 }
 
+// En passant captures are written like any other pawn capture (e.g. 'exd6'), without an 'e.p.'
+// suffix. The suffix is optional in SAN, and PGN readers don't expect it.
 pub fn format_move(game: &Game, mv: Move) -> String {
+    format!(
+        "{}{}",
+        format_move_without_check(game, mv),
+        check_specifier(game, mv)
+    )
+}
+
+// '+' if the move gives check, or '#' if it's checkmate
+pub fn check_specifier(game: &Game, mv: Move) -> String {
+    let mut game_after_move = game.clone();
+    game_after_move.make_move(mv);
+
+    if !game_after_move.is_king_in_check() {
+        String::new()
+    } else if game_after_move.moves().is_empty() {
+        san::CHECKMATE.to_string()
+    } else {
+        san::CHECK.to_string()
+    }
+}
+
+fn format_move_without_check(game: &Game, mv: Move) -> String {
     let from = mv.src();
     let to = mv.dst();
 
     let piece = game.board.piece_at(from).unwrap();
 
-    let king_start = squares::king_start(game.player);
-    if piece.kind == PieceKind::King && from == king_start {
-        let kingside_castle_dest = squares::kingside_castle_dest(game.player);
-        if to == kingside_castle_dest {
-            return san::KINGSIDE_CASTLE.to_string();
-        }
-
-        let queenside_castle_dest = squares::queenside_castle_dest(game.player);
-        if to == queenside_castle_dest {
-            return san::QUEENSIDE_CASTLE.to_string();
-        }
+    if mv.is_castling() {
+        return if to == squares::kingside_castle_dest(game.player) {
+            san::KINGSIDE_CASTLE.to_string()
+        } else {
+            san::QUEENSIDE_CASTLE.to_string()
+        };
     }
 
-    let mut game_after_move = game.clone();
-    game_after_move.make_move(mv);
-    let places_opponent_in_check = game_after_move.is_king_in_check();
-
     let ambiguity_resolution_required = required_ambiguity_resolution(game, mv);
 
     let piece_identifier: &'static str = match piece.kind {
@@ -81,13 +96,7 @@ pub fn format_move(game: &Game, mv: Move) -> String {
         ),
     };
 
-    let opponent_in_check_specifier = if places_opponent_in_check {
-        san::CHECK.to_string()
-    } else {
-        String::new()
-    };
-
-    format!("{piece_identifier}{ambiguity_resolution}{capture_x}{destination_notation}{promotion_specifier}{opponent_in_check_specifier}")
+    format!("{piece_identifier}{ambiguity_resolution}{capture_x}{destination_notation}{promotion_specifier}")
 }
 
 fn required_ambiguity_resolution(game: &Game, mv: Move) -> AmbiguityResolution {
@@ -125,11 +134,16 @@ fn required_ambiguity_resolution(game: &Game, mv: Move) -> AmbiguityResolution {
         .iter()
         .any(|m| m.src().rank() == mv.src().rank());
 
-    match (ambiguity_by_file, ambiguity_by_rank) {
-        (false, false) => AmbiguityResolution::None,
-        (true, false) => AmbiguityResolution::Rank,
-        (false, true) => AmbiguityResolution::File,
-        (true, true) => AmbiguityResolution::Exact,
+    // The file is preferred if it's enough to tell the moves apart, then the rank. If neither
+    // is enough on its own, we need both.
+    if potentially_ambiguous_moves.is_empty() {
+        AmbiguityResolution::None
+    } else if !ambiguity_by_file {
+        AmbiguityResolution::File
+    } else if !ambiguity_by_rank {
+        AmbiguityResolution::Rank
+    } else {
+        AmbiguityResolution::Exact
     }
 }
 
@@ -266,6 +280,25 @@ mod tests {
         test_san_string(fen, ambiguous_move, "Qh4e1");
     }
 
+    #[test]
+    fn san_file_preferred_when_file_and_rank_differ() {
+        crate::init();
+
+        let fen = "4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1";
+        test_san_string(fen, (B1, D2), "Nbd2");
+        test_san_string(fen, (F3, D2), "Nfd2");
+    }
+
+    #[test]
+    fn san_castling_with_check() {
+        test_san_string("5k2/8/8/8/8/8/8/4K2R w K - 0 1", (E1, G1), "O-O+");
+    }
+
+    #[test]
+    fn san_hash_for_checkmate() {
+        test_san_string("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1", (A1, A8), "Ra8#");
+    }
+
     #[test]
     fn san_castling() {
         let fen = "1k6/8/8/8/8/8/8/R3K2R w KQ - 0 1";
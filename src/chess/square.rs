@@ -1,4 +1,4 @@
-use crate::chess::bitboard::{bitboards, Bitboard};
+use crate::chess::bitboard::Bitboard;
 use crate::chess::player::Player;
 
 pub const FILES: [File; File::N] = [
@@ -59,6 +59,30 @@ impl File {
         self as u8
     }
 
+    pub const fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            'a' => Self::A,
+            'b' => Self::B,
+            'c' => Self::C,
+            'd' => Self::D,
+            'e' => Self::E,
+            'f' => Self::F,
+            'g' => Self::G,
+            'h' => Self::H,
+            _ => return None,
+        })
+    }
+
+    // The file `delta` files to the east (or west, if negative), if it's on the board
+    pub fn offset(self, delta: i8) -> Option<Self> {
+        let idx = self.idx().checked_add_signed(delta)?;
+        (idx < 8).then(|| Self::from_idx(idx))
+    }
+
+    pub const fn distance(self, other: Self) -> u8 {
+        self.idx().abs_diff(other.idx())
+    }
+
     pub const fn notation(self) -> &'static str {
         match self {
             Self::A => "a",
@@ -71,19 +95,6 @@ impl File {
             Self::H => "h",
         }
     }
-
-    pub fn bitboard(self) -> Bitboard {
-        match self {
-            Self::A => bitboards::A_FILE,
-            Self::B => bitboards::B_FILE,
-            Self::C => bitboards::C_FILE,
-            Self::D => bitboards::D_FILE,
-            Self::E => bitboards::E_FILE,
-            Self::F => bitboards::F_FILE,
-            Self::G => bitboards::G_FILE,
-            Self::H => bitboards::H_FILE,
-        }
-    }
 }
 
 impl std::fmt::Debug for File {
@@ -98,6 +109,19 @@ impl std::fmt::Display for File {
     }
 }
 
+impl std::str::FromStr for File {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next().and_then(Self::from_char), chars.next()) {
+            (Some(file), None) => Ok(file),
+            _ => Err(format!("Invalid file: {s}")),
+        }
+    }
+}
+
 pub const RANKS: [Rank; Rank::N] = [
     Rank::R1,
     Rank::R2,
@@ -157,9 +181,28 @@ impl Rank {
         self as u8
     }
 
-    #[inline(always)]
-    pub const fn array_idx(self) -> usize {
-        self as usize
+    pub const fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            '1' => Self::R1,
+            '2' => Self::R2,
+            '3' => Self::R3,
+            '4' => Self::R4,
+            '5' => Self::R5,
+            '6' => Self::R6,
+            '7' => Self::R7,
+            '8' => Self::R8,
+            _ => return None,
+        })
+    }
+
+    // The rank `delta` ranks to the north (or south, if negative), if it's on the board
+    pub fn offset(self, delta: i8) -> Option<Self> {
+        let idx = self.idx().checked_add_signed(delta)?;
+        (idx < 8).then(|| Self::from_idx(idx))
+    }
+
+    pub const fn distance(self, other: Self) -> u8 {
+        self.idx().abs_diff(other.idx())
     }
 
     pub const fn notation(self) -> &'static str {
@@ -188,16 +231,34 @@ impl std::fmt::Display for Rank {
     }
 }
 
+impl std::str::FromStr for Rank {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next().and_then(Self::from_char), chars.next()) {
+            (Some(rank), None) => Ok(rank),
+            _ => Err(format!("Invalid rank: {s}")),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub struct Square(u8);
 
 impl Square {
     pub const N: usize = 64;
 
-    pub const fn from_file_and_rank(file: File, rank: Rank) -> Self {
+    pub const fn new(file: File, rank: Rank) -> Self {
         Self::from_idxs(file.idx(), rank.idx())
     }
 
+    // All squares, from A1 to H8
+    pub fn all() -> impl DoubleEndedIterator<Item = Self> {
+        (0..64).map(Self::from_index)
+    }
+
     #[expect(
         clippy::cast_possible_truncation,
         reason = "At most 63 from .trailing_zeros() of a u64"
@@ -281,9 +342,26 @@ impl Square {
         Self(self.0 - 8)
     }
 
+    // The square `file_delta` files east and `rank_delta` ranks north, if it's on the board
+    pub fn offset(self, file_delta: i8, rank_delta: i8) -> Option<Self> {
+        Some(Self::new(
+            self.file().offset(file_delta)?,
+            self.rank().offset(rank_delta)?,
+        ))
+    }
+
+    // The number of king moves it would take to get from one square to the other
+    pub fn distance(self, other: Self) -> u8 {
+        self.file()
+            .distance(other.file())
+            .max(self.rank().distance(other.rank()))
+    }
+
+    pub fn manhattan_distance(self, other: Self) -> u8 {
+        self.file().distance(other.file()) + self.rank().distance(other.rank())
+    }
+
     #[inline(always)]
-    #[allow(clippy::allow_attributes, reason = "Only used in non-release mode")]
-    #[allow(unused, reason = "Only used in non-release mode")]
     pub fn relative_for(self, player: Player) -> Self {
         match player {
             Player::White => self,
@@ -304,6 +382,23 @@ impl std::fmt::Display for Square {
     }
 }
 
+impl std::str::FromStr for Square {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (
+            chars.next().and_then(File::from_char),
+            chars.next().and_then(Rank::from_char),
+            chars.next(),
+        ) {
+            (Some(file), Some(rank), None) => Ok(Self::new(file, rank)),
+            _ => Err(format!("Invalid square: {s}")),
+        }
+    }
+}
+
 #[cfg(test)]
 impl std::ops::BitOr for Square {
     type Output = Bitboard;
@@ -396,77 +491,77 @@ pub mod squares {
         use super::super::*;
 
         // For convenience
-        pub const A1: Square = Square::from_file_and_rank(File::A, Rank::R1);
-        pub const A2: Square = Square::from_file_and_rank(File::A, Rank::R2);
-        pub const A3: Square = Square::from_file_and_rank(File::A, Rank::R3);
-        pub const A4: Square = Square::from_file_and_rank(File::A, Rank::R4);
-        pub const A5: Square = Square::from_file_and_rank(File::A, Rank::R5);
-        pub const A6: Square = Square::from_file_and_rank(File::A, Rank::R6);
-        pub const A7: Square = Square::from_file_and_rank(File::A, Rank::R7);
-        pub const A8: Square = Square::from_file_and_rank(File::A, Rank::R8);
-
-        pub const B1: Square = Square::from_file_and_rank(File::B, Rank::R1);
-        pub const B2: Square = Square::from_file_and_rank(File::B, Rank::R2);
-        pub const B3: Square = Square::from_file_and_rank(File::B, Rank::R3);
-        pub const B4: Square = Square::from_file_and_rank(File::B, Rank::R4);
-        pub const B5: Square = Square::from_file_and_rank(File::B, Rank::R5);
-        pub const B6: Square = Square::from_file_and_rank(File::B, Rank::R6);
-        pub const B7: Square = Square::from_file_and_rank(File::B, Rank::R7);
-        pub const B8: Square = Square::from_file_and_rank(File::B, Rank::R8);
-
-        pub const C1: Square = Square::from_file_and_rank(File::C, Rank::R1);
-        pub const C2: Square = Square::from_file_and_rank(File::C, Rank::R2);
-        pub const C3: Square = Square::from_file_and_rank(File::C, Rank::R3);
-        pub const C4: Square = Square::from_file_and_rank(File::C, Rank::R4);
-        pub const C5: Square = Square::from_file_and_rank(File::C, Rank::R5);
-        pub const C6: Square = Square::from_file_and_rank(File::C, Rank::R6);
-        pub const C7: Square = Square::from_file_and_rank(File::C, Rank::R7);
-        pub const C8: Square = Square::from_file_and_rank(File::C, Rank::R8);
-
-        pub const D1: Square = Square::from_file_and_rank(File::D, Rank::R1);
-        pub const D2: Square = Square::from_file_and_rank(File::D, Rank::R2);
-        pub const D3: Square = Square::from_file_and_rank(File::D, Rank::R3);
-        pub const D4: Square = Square::from_file_and_rank(File::D, Rank::R4);
-        pub const D5: Square = Square::from_file_and_rank(File::D, Rank::R5);
-        pub const D6: Square = Square::from_file_and_rank(File::D, Rank::R6);
-        pub const D7: Square = Square::from_file_and_rank(File::D, Rank::R7);
-        pub const D8: Square = Square::from_file_and_rank(File::D, Rank::R8);
-
-        pub const E1: Square = Square::from_file_and_rank(File::E, Rank::R1);
-        pub const E2: Square = Square::from_file_and_rank(File::E, Rank::R2);
-        pub const E3: Square = Square::from_file_and_rank(File::E, Rank::R3);
-        pub const E4: Square = Square::from_file_and_rank(File::E, Rank::R4);
-        pub const E5: Square = Square::from_file_and_rank(File::E, Rank::R5);
-        pub const E6: Square = Square::from_file_and_rank(File::E, Rank::R6);
-        pub const E7: Square = Square::from_file_and_rank(File::E, Rank::R7);
-        pub const E8: Square = Square::from_file_and_rank(File::E, Rank::R8);
-
-        pub const F1: Square = Square::from_file_and_rank(File::F, Rank::R1);
-        pub const F2: Square = Square::from_file_and_rank(File::F, Rank::R2);
-        pub const F3: Square = Square::from_file_and_rank(File::F, Rank::R3);
-        pub const F4: Square = Square::from_file_and_rank(File::F, Rank::R4);
-        pub const F5: Square = Square::from_file_and_rank(File::F, Rank::R5);
-        pub const F6: Square = Square::from_file_and_rank(File::F, Rank::R6);
-        pub const F7: Square = Square::from_file_and_rank(File::F, Rank::R7);
-        pub const F8: Square = Square::from_file_and_rank(File::F, Rank::R8);
-
-        pub const G1: Square = Square::from_file_and_rank(File::G, Rank::R1);
-        pub const G2: Square = Square::from_file_and_rank(File::G, Rank::R2);
-        pub const G3: Square = Square::from_file_and_rank(File::G, Rank::R3);
-        pub const G4: Square = Square::from_file_and_rank(File::G, Rank::R4);
-        pub const G5: Square = Square::from_file_and_rank(File::G, Rank::R5);
-        pub const G6: Square = Square::from_file_and_rank(File::G, Rank::R6);
-        pub const G7: Square = Square::from_file_and_rank(File::G, Rank::R7);
-        pub const G8: Square = Square::from_file_and_rank(File::G, Rank::R8);
-
-        pub const H1: Square = Square::from_file_and_rank(File::H, Rank::R1);
-        pub const H2: Square = Square::from_file_and_rank(File::H, Rank::R2);
-        pub const H3: Square = Square::from_file_and_rank(File::H, Rank::R3);
-        pub const H4: Square = Square::from_file_and_rank(File::H, Rank::R4);
-        pub const H5: Square = Square::from_file_and_rank(File::H, Rank::R5);
-        pub const H6: Square = Square::from_file_and_rank(File::H, Rank::R6);
-        pub const H7: Square = Square::from_file_and_rank(File::H, Rank::R7);
-        pub const H8: Square = Square::from_file_and_rank(File::H, Rank::R8);
+        pub const A1: Square = Square::new(File::A, Rank::R1);
+        pub const A2: Square = Square::new(File::A, Rank::R2);
+        pub const A3: Square = Square::new(File::A, Rank::R3);
+        pub const A4: Square = Square::new(File::A, Rank::R4);
+        pub const A5: Square = Square::new(File::A, Rank::R5);
+        pub const A6: Square = Square::new(File::A, Rank::R6);
+        pub const A7: Square = Square::new(File::A, Rank::R7);
+        pub const A8: Square = Square::new(File::A, Rank::R8);
+
+        pub const B1: Square = Square::new(File::B, Rank::R1);
+        pub const B2: Square = Square::new(File::B, Rank::R2);
+        pub const B3: Square = Square::new(File::B, Rank::R3);
+        pub const B4: Square = Square::new(File::B, Rank::R4);
+        pub const B5: Square = Square::new(File::B, Rank::R5);
+        pub const B6: Square = Square::new(File::B, Rank::R6);
+        pub const B7: Square = Square::new(File::B, Rank::R7);
+        pub const B8: Square = Square::new(File::B, Rank::R8);
+
+        pub const C1: Square = Square::new(File::C, Rank::R1);
+        pub const C2: Square = Square::new(File::C, Rank::R2);
+        pub const C3: Square = Square::new(File::C, Rank::R3);
+        pub const C4: Square = Square::new(File::C, Rank::R4);
+        pub const C5: Square = Square::new(File::C, Rank::R5);
+        pub const C6: Square = Square::new(File::C, Rank::R6);
+        pub const C7: Square = Square::new(File::C, Rank::R7);
+        pub const C8: Square = Square::new(File::C, Rank::R8);
+
+        pub const D1: Square = Square::new(File::D, Rank::R1);
+        pub const D2: Square = Square::new(File::D, Rank::R2);
+        pub const D3: Square = Square::new(File::D, Rank::R3);
+        pub const D4: Square = Square::new(File::D, Rank::R4);
+        pub const D5: Square = Square::new(File::D, Rank::R5);
+        pub const D6: Square = Square::new(File::D, Rank::R6);
+        pub const D7: Square = Square::new(File::D, Rank::R7);
+        pub const D8: Square = Square::new(File::D, Rank::R8);
+
+        pub const E1: Square = Square::new(File::E, Rank::R1);
+        pub const E2: Square = Square::new(File::E, Rank::R2);
+        pub const E3: Square = Square::new(File::E, Rank::R3);
+        pub const E4: Square = Square::new(File::E, Rank::R4);
+        pub const E5: Square = Square::new(File::E, Rank::R5);
+        pub const E6: Square = Square::new(File::E, Rank::R6);
+        pub const E7: Square = Square::new(File::E, Rank::R7);
+        pub const E8: Square = Square::new(File::E, Rank::R8);
+
+        pub const F1: Square = Square::new(File::F, Rank::R1);
+        pub const F2: Square = Square::new(File::F, Rank::R2);
+        pub const F3: Square = Square::new(File::F, Rank::R3);
+        pub const F4: Square = Square::new(File::F, Rank::R4);
+        pub const F5: Square = Square::new(File::F, Rank::R5);
+        pub const F6: Square = Square::new(File::F, Rank::R6);
+        pub const F7: Square = Square::new(File::F, Rank::R7);
+        pub const F8: Square = Square::new(File::F, Rank::R8);
+
+        pub const G1: Square = Square::new(File::G, Rank::R1);
+        pub const G2: Square = Square::new(File::G, Rank::R2);
+        pub const G3: Square = Square::new(File::G, Rank::R3);
+        pub const G4: Square = Square::new(File::G, Rank::R4);
+        pub const G5: Square = Square::new(File::G, Rank::R5);
+        pub const G6: Square = Square::new(File::G, Rank::R6);
+        pub const G7: Square = Square::new(File::G, Rank::R7);
+        pub const G8: Square = Square::new(File::G, Rank::R8);
+
+        pub const H1: Square = Square::new(File::H, Rank::R1);
+        pub const H2: Square = Square::new(File::H, Rank::R2);
+        pub const H3: Square = Square::new(File::H, Rank::R3);
+        pub const H4: Square = Square::new(File::H, Rank::R4);
+        pub const H5: Square = Square::new(File::H, Rank::R5);
+        pub const H6: Square = Square::new(File::H, Rank::R6);
+        pub const H7: Square = Square::new(File::H, Rank::R7);
+        pub const H8: Square = Square::new(File::H, Rank::R8);
     }
 }
 
@@ -488,9 +583,59 @@ mod tests {
     }
 
     #[test]
-    fn square_from_file_and_rank() {
-        assert_eq!(Square::from_file_and_rank(File::A, Rank::R1), A1);
-        assert_eq!(Square::from_file_and_rank(File::H, Rank::R8), H8);
+    fn square_new() {
+        assert_eq!(Square::new(File::A, Rank::R1), A1);
+        assert_eq!(Square::new(File::H, Rank::R8), H8);
+    }
+
+    #[test]
+    fn square_from_str() {
+        assert_eq!("e4".parse::<Square>(), Ok(E4));
+        assert_eq!("h8".parse::<Square>(), Ok(H8));
+        assert!("e9".parse::<Square>().is_err());
+        assert!("i4".parse::<Square>().is_err());
+        assert!("e44".parse::<Square>().is_err());
+        assert!("".parse::<Square>().is_err());
+    }
+
+    #[test]
+    fn square_display_round_trips() {
+        for square in Square::all() {
+            assert_eq!(square.to_string().parse::<Square>(), Ok(square));
+        }
+    }
+
+    #[test]
+    fn square_all() {
+        assert_eq!(Square::all().count(), Square::N);
+        assert_eq!(Square::all().next(), Some(A1));
+        assert_eq!(Square::all().next_back(), Some(H8));
+    }
+
+    #[test]
+    fn square_offset() {
+        assert_eq!(E4.offset(1, 2), Some(F6));
+        assert_eq!(E4.offset(-4, -3), Some(A1));
+        assert_eq!(H8.offset(1, 0), None);
+        assert_eq!(A1.offset(0, -1), None);
+    }
+
+    #[test]
+    fn file_and_rank_offset() {
+        assert_eq!(File::A.offset(7), Some(File::H));
+        assert_eq!(File::A.offset(-1), None);
+        assert_eq!(Rank::R8.offset(-7), Some(Rank::R1));
+        assert_eq!(Rank::R8.offset(1), None);
+    }
+
+    #[test]
+    fn square_distance() {
+        assert_eq!(A1.distance(H8), 7);
+        assert_eq!(E4.distance(F6), 2);
+        assert_eq!(E4.distance(E4), 0);
+
+        assert_eq!(A1.manhattan_distance(H8), 14);
+        assert_eq!(E4.manhattan_distance(F6), 3);
     }
 
     #[test]
@@ -1,4 +1,5 @@
 use crate::chess::game::{CastleRightsSide, Game};
+use crate::chess::lookup::Lookup;
 use crate::chess::piece::{Piece, PieceKind};
 use crate::chess::player::Player;
 use crate::chess::square::Square;
@@ -50,8 +51,14 @@ mod components {
     pub static mut SIDE_TO_PLAY: ZobristComponent = 0;
 }
 
+// The components are generated from a fixed seed so that hashes are the same on every run, which
+// anything that stores hashes (saved TTs, the experience file) relies on. Changing the seed
+// regenerates every component, which invalidates those files and means the cuckoo tables need
+// regenerating.
+const SEED: u64 = 0;
+
 pub fn init() {
-    let mut random = StdRng::seed_from_u64(0);
+    let mut random = StdRng::seed_from_u64(SEED);
 
     for player in 0..Player::N {
         for square in 0..Square::N {
@@ -178,25 +185,30 @@ pub fn hash(game: &Game) -> ZobristHash {
 }
 
 fn piece_on_square(player: Player, piece: PieceKind, square: Square) -> ZobristComponent {
+    // SAFETY: `Player::array_idx`, `Square::array_idx` and `PieceKind::array_idx` are below
+    // `Player::N`, `Square::N` and `PieceKind::N` respectively
     *unsafe {
         components::PIECE_SQUARE
-            .get_unchecked(player.array_idx())
-            .get_unchecked(square.array_idx())
-            .get_unchecked(piece.array_idx())
+            .lookup(player.array_idx())
+            .lookup(square.array_idx())
+            .lookup(piece.array_idx())
     }
 }
 
 fn castle_rights(player: Player, side: CastleRightsSide) -> ZobristComponent {
+    // SAFETY: `Player::array_idx` is below `Player::N` and `CastleRightsSide::array_idx` is
+    // below `CastleRightsSide::N`
     *unsafe {
         components::CASTLING
-            .get_unchecked(player.array_idx())
-            .get_unchecked(side.array_idx())
+            .lookup(player.array_idx())
+            .lookup(side.array_idx())
     }
 }
 
 fn en_passant(square: Option<Square>) -> ZobristComponent {
     match square {
-        Some(s) => *unsafe { components::EN_PASSANT_SQUARE.get_unchecked(s.array_idx()) },
+        // SAFETY: `Square::array_idx` is below `Square::N`
+        Some(s) => *unsafe { components::EN_PASSANT_SQUARE.lookup(s.array_idx()) },
         None => unsafe { components::NO_EN_PASSANT_SQUARE },
     }
 }
@@ -204,3 +216,85 @@ fn en_passant(square: Option<Square>) -> ZobristComponent {
 fn side_to_play() -> ZobristComponent {
     unsafe { components::SIDE_TO_PLAY }
 }
+
+#[cfg(test)]
+fn all_components() -> Vec<ZobristComponent> {
+    let mut all = Vec::new();
+
+    unsafe {
+        all.extend(components::PIECE_SQUARE.iter().flatten().flatten());
+        all.extend(components::CASTLING.iter().flatten());
+        all.extend(components::EN_PASSANT_SQUARE);
+        all.push(components::NO_EN_PASSANT_SQUARE);
+        all.push(components::SIDE_TO_PLAY);
+    }
+
+    all
+}
+
+// Collisions are unavoidable, but bad components make them much more likely. Each component must
+// be non-zero and distinct, and no two components may XOR to a third, since then positions that
+// differ by just a couple of changes would share a hash.
+#[cfg(test)]
+fn verify(components: &[ZobristComponent]) -> Result<(), String> {
+    use std::collections::HashSet;
+
+    let distinct: HashSet<ZobristComponent> = components.iter().copied().collect();
+
+    if distinct.contains(&0) {
+        return Err("A component is zero".to_string());
+    }
+
+    if distinct.len() != components.len() {
+        return Err("Components are not distinct".to_string());
+    }
+
+    for (i, a) in components.iter().enumerate() {
+        for b in &components[i + 1..] {
+            if distinct.contains(&(a ^ b)) {
+                return Err(format!("{a:016x} ^ {b:016x} is also a component"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_init_can_be_called_repeatedly_from_many_threads() {
+        crate::init();
+        let hash_before = hash(&Game::new());
+
+        let threads: Vec<_> = (0..8).map(|_| std::thread::spawn(crate::init)).collect();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        assert_eq!(hash(&Game::new()), hash_before);
+    }
+
+    #[test]
+    fn test_components_are_valid() {
+        crate::init();
+        assert_eq!(verify(&all_components()), Ok(()));
+
+        assert!(verify(&[1, 2, 3]).is_err());
+        assert!(verify(&[1, 2, 2]).is_err());
+        assert!(verify(&[0, 1, 2]).is_err());
+    }
+
+    // Saved TTs and experience files hold hashes, so they'd silently stop matching if the
+    // components changed
+    #[test]
+    fn test_components_match_seed() {
+        crate::init();
+
+        assert_eq!(all_components().len(), 2 * 64 * 6 + 2 * 2 + 64 + 2);
+        assert_eq!(hash(&Game::new()), ZobristHash(0x7bc4_b33c_6a3b_ae8e));
+    }
+}
@@ -12,6 +12,13 @@ macro_rules! parameters {
             )*
         }
 
+        #[cfg(feature = "tuner")]
+        impl Default for Parameters {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
         #[cfg(feature = "tuner")]
         impl Parameters {
             pub fn new() -> Self {
@@ -96,6 +103,12 @@ macro_rules! parameters {
             )*
         }
 
+        impl Default for Trace {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
         impl Trace {
             #[cfg(feature = "tuner")]
             pub const SIZE: usize = size_of::<Self>() / size_of::<TraceComponent>();
@@ -109,24 +122,18 @@ macro_rules! parameters {
             }
 
             #[cfg(feature = "tuner")]
-            #[expect(unused_assignments, reason = "The final idx value will never be used")]
             #[expect(clippy::cast_precision_loss, reason = "known cast from i32 to f32")]
             pub fn non_zero_coefficients(&self) -> Vec<crate::utils::tuner::NonZeroCoefficient> {
                 let mut result = Vec::new();
-                let mut idx = 0;
+                let components = std::iter::empty()$(.chain(self.$param.iter()))*;
 
-                $(
-                    for (i, component) in self.$param.iter().enumerate() {
-                        let coefficient = component.0;
+                for (idx, component) in components.enumerate() {
+                    let coefficient = component.0;
 
-                        if coefficient != 0 {
-                            result
-                                .push(crate::utils::tuner::NonZeroCoefficient::new(idx + i, coefficient as f32));
-                        }
+                    if coefficient != 0 {
+                        result.push(crate::utils::tuner::NonZeroCoefficient::new(idx, coefficient as f32));
                     }
-
-                    idx += self.$param.len();
-                )*
+                }
 
                 result
             }
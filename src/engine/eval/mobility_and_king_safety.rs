@@ -4,7 +4,8 @@ use crate::chess::game::Game;
 use crate::chess::movegen::tables;
 use crate::chess::player::Player;
 use crate::engine::eval::params::{
-    ATTACKED_KING_SQUARES, BISHOP_MOBILITY, KNIGHT_MOBILITY, QUEEN_MOBILITY, ROOK_MOBILITY,
+    ATTACKED_KING_SQUARES, BISHOP_MOBILITY, KING_RING_ATTACKS, KNIGHT_MOBILITY, QUEEN_MOBILITY,
+    ROOK_MOBILITY,
 };
 
 fn mobility_and_opp_king_safety_for<const TRACE: bool>(
@@ -19,6 +20,9 @@ fn mobility_and_opp_king_safety_for<const TRACE: bool>(
     let their_pawn_attacks = their_pawns.west() | their_pawns.east();
     let mobility_safe_squares = !their_pawn_attacks;
 
+    let enemy_king = game.board.king(player.other()).single();
+    let enemy_king_surrounding_squares = tables::king_attacks(enemy_king);
+
     let mut attacked_squares = Bitboard::EMPTY;
 
     for p in game.board.knights(player) {
@@ -28,8 +32,13 @@ fn mobility_and_opp_king_safety_for<const TRACE: bool>(
         let mobility_squares = (moves & mobility_safe_squares).count() as usize;
         eval += KNIGHT_MOBILITY[mobility_squares];
 
+        // Each piece is weighted by how many squares around the enemy king it attacks
+        let king_ring_attacks = i32::from((moves & enemy_king_surrounding_squares).count());
+        eval += KING_RING_ATTACKS[0] * king_ring_attacks;
+
         if TRACE {
             trace.knight_mobility[mobility_squares].incr(player);
+            trace.king_ring_attacks[0].add(player, king_ring_attacks);
         }
     }
 
@@ -40,8 +49,12 @@ fn mobility_and_opp_king_safety_for<const TRACE: bool>(
         let mobility_squares = (moves & mobility_safe_squares).count() as usize;
         eval += BISHOP_MOBILITY[mobility_squares];
 
+        let king_ring_attacks = i32::from((moves & enemy_king_surrounding_squares).count());
+        eval += KING_RING_ATTACKS[1] * king_ring_attacks;
+
         if TRACE {
             trace.bishop_mobility[mobility_squares].incr(player);
+            trace.king_ring_attacks[1].add(player, king_ring_attacks);
         }
     }
 
@@ -52,8 +65,12 @@ fn mobility_and_opp_king_safety_for<const TRACE: bool>(
         let mobility_squares = (moves & mobility_safe_squares).count() as usize;
         eval += ROOK_MOBILITY[mobility_squares];
 
+        let king_ring_attacks = i32::from((moves & enemy_king_surrounding_squares).count());
+        eval += KING_RING_ATTACKS[2] * king_ring_attacks;
+
         if TRACE {
             trace.rook_mobility[mobility_squares].incr(player);
+            trace.king_ring_attacks[2].add(player, king_ring_attacks);
         }
     }
 
@@ -64,13 +81,15 @@ fn mobility_and_opp_king_safety_for<const TRACE: bool>(
         let mobility_squares = (moves & mobility_safe_squares).count() as usize;
         eval += QUEEN_MOBILITY[mobility_squares];
 
+        let king_ring_attacks = i32::from((moves & enemy_king_surrounding_squares).count());
+        eval += KING_RING_ATTACKS[3] * king_ring_attacks;
+
         if TRACE {
             trace.queen_mobility[mobility_squares].incr(player);
+            trace.king_ring_attacks[3].add(player, king_ring_attacks);
         }
     }
 
-    let enemy_king = game.board.king(player.other()).single();
-    let enemy_king_surrounding_squares = tables::king_attacks(enemy_king);
     let attacks_on_enemy_king =
         (attacked_squares & enemy_king_surrounding_squares).count() as usize;
 
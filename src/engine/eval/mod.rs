@@ -7,6 +7,7 @@ pub mod pawn_structure;
 mod phased_eval;
 pub mod piece_square_tables;
 mod player_eval;
+mod rooks;
 mod white_eval;
 
 use crate::chess::board::Board;
@@ -34,7 +35,10 @@ parameters!(
     (rook_mobility, 15, array, "ROOK_MOBILITY"),
     (queen_mobility, 28, array, "QUEEN_MOBILITY"),
     (attacked_king_squares, 9, array, "ATTACKED_KING_SQUARES"),
+    (king_ring_attacks, 4, array, "KING_RING_ATTACKS"),
     (bishop_pair, 1, single, "BISHOP_PAIR_BONUS"),
+    (rook_open_file, 1, single, "ROOK_OPEN_FILE_BONUS"),
+    (rook_semi_open_file, 1, single, "ROOK_SEMI_OPEN_FILE_BONUS"),
 );
 
 pub fn init() {
@@ -94,7 +98,8 @@ pub fn absolute_eval_with_trace<const TRACE: bool>(game: &Game, trace: &mut Trac
     let eval = game.incremental_eval.piece_square_tables
         + material::eval::<TRACE>(game, trace)
         + mobility_and_king_safety::eval::<TRACE>(game, trace)
-        + pawn_structure::eval::<TRACE>(game, trace);
+        + pawn_structure::eval::<TRACE>(game, trace)
+        + rooks::eval::<TRACE>(game, trace);
 
     eval.for_phase(game.incremental_eval.phase_value)
 }
@@ -185,4 +185,15 @@ pub const ATTACKED_KING_SQUARES: [PhasedEval; 9] = [
     s( -277,   -81),
 ];
 
+pub const KING_RING_ATTACKS: [PhasedEval; 4] = [
+    s(    0,     0),
+    s(    0,     0),
+    s(    0,     0),
+    s(    0,     0),
+];
+
 pub const BISHOP_PAIR_BONUS: PhasedEval = s(   31,    89);
+
+pub const ROOK_OPEN_FILE_BONUS: PhasedEval = s(    0,     0);
+
+pub const ROOK_SEMI_OPEN_FILE_BONUS: PhasedEval = s(    0,     0);
@@ -1,9 +1,11 @@
 use crate::chess::game::Game;
 
-use crate::chess::bitboard::{bitboards, Bitboard};
+use crate::chess::bitboard::Bitboard;
 use crate::chess::board::Board;
+use crate::chess::lookup::Lookup;
+use crate::chess::masks;
 use crate::chess::player::{ByPlayer, Player};
-use crate::chess::square::{Rank, Square};
+use crate::chess::square::Square;
 use crate::engine::eval::params::PieceSquareTableDefinition;
 use crate::engine::eval::piece_square_tables::{flatten, flip, negate, PieceSquareTable};
 use crate::engine::eval::{params, PhasedEval, Trace, TraceComponentIncr};
@@ -14,9 +16,6 @@ pub fn eval<const TRACE: bool>(game: &Game, trace: &mut Trace) -> PhasedEval {
     eval
 }
 
-static mut ENEMY_PASSED_PAWN_MASKS: [[Bitboard; Square::N]; Player::N] =
-    [[Bitboard::EMPTY; Square::N]; Player::N];
-
 static mut PASSED_PAWN_PST: [[PhasedEval; Square::N]; Player::N] =
     [[PhasedEval::ZERO; Square::N]; Player::N];
 
@@ -28,57 +27,12 @@ pub fn black_pst(def: PieceSquareTableDefinition) -> PieceSquareTable {
     negate(flatten(def))
 }
 
-fn generate_passed_pawn_mask(player: Player, square: Square) -> Bitboard {
-    let our_bank_rank = bitboards::back_rank(player);
-    let their_pawn_back_rank = bitboards::pawn_back_rank(player.other());
-
-    // Pawns cannot be on our back rank
-    if (square.bb() & our_bank_rank).any() {
-        return Bitboard::EMPTY;
-    }
-
-    // Pawns on their pawn rank cannot be blocked, as pawns cannot be on their back rank
-    if (square.bb() & their_pawn_back_rank).any() {
-        return Bitboard::EMPTY;
-    }
-
-    let file = square.file().bitboard();
-    let file_left = file.west();
-    let file_right = file.east();
-
-    let relevant_files = file_left | file | file_right;
-
-    let rank = square.rank();
-    let mut relevant_ranks = Bitboard::FULL;
-
-    let back_rank_idx = match player {
-        Player::White => Rank::R1,
-        Player::Black => Rank::R8,
-    };
-
-    let distance_from_back_rank = back_rank_idx.array_idx().abs_diff(rank.array_idx());
-
-    for _ in 0..=distance_from_back_rank {
-        relevant_ranks = relevant_ranks.forward(player);
-    }
-
-    let enemy_pawns_mask = relevant_files & relevant_ranks;
-    enemy_pawns_mask
-}
-
-fn enemy_passed_pawn_mask(player: Player, square: Square) -> Bitboard {
-    *unsafe {
-        ENEMY_PASSED_PAWN_MASKS
-            .get_unchecked(player.array_idx())
-            .get_unchecked(square.array_idx())
-    }
-}
-
 fn pst_value(player: Player, square: Square) -> PhasedEval {
+    // SAFETY: `Player::array_idx` is below `Player::N` and `Square::array_idx` is below `Square::N`
     *unsafe {
         PASSED_PAWN_PST
-            .get_unchecked(player.array_idx())
-            .get_unchecked(square.array_idx())
+            .lookup(player.array_idx())
+            .lookup(square.array_idx())
     }
 }
 
@@ -97,7 +51,7 @@ pub fn eval_passed_pawns_by_player(board: &Board) -> ByPlayer<PhasedEval> {
 }
 
 pub fn is_passed(pawn: Square, player: Player, their_pawns: Bitboard) -> bool {
-    (enemy_passed_pawn_mask(player, pawn) & their_pawns).is_empty()
+    (masks::passed_pawn_mask(player, pawn) & their_pawns).is_empty()
 }
 
 fn calculate_passed_pawn_bonus<const TRACE: bool>(
@@ -124,15 +78,6 @@ fn calculate_passed_pawn_bonus<const TRACE: bool>(
 }
 
 pub fn init() {
-    for player in [Player::White, Player::Black] {
-        for square in Bitboard::FULL {
-            let mask = generate_passed_pawn_mask(player, square);
-            unsafe {
-                ENEMY_PASSED_PAWN_MASKS[player.array_idx()][square.array_idx()] = mask;
-            }
-        }
-    }
-
     unsafe {
         PASSED_PAWN_PST[Player::White.array_idx()] = white_pst(params::PASSED_PAWNS);
         PASSED_PAWN_PST[Player::Black.array_idx()] = black_pst(params::PASSED_PAWNS);
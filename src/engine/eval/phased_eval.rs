@@ -77,6 +77,14 @@ impl std::ops::SubAssign for PhasedEval {
     }
 }
 
+impl std::ops::Mul<i32> for PhasedEval {
+    type Output = Self;
+
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
 impl std::ops::Neg for PhasedEval {
     type Output = Self;
 
@@ -97,9 +105,7 @@ pub fn piece_phase_value_contribution(kind: PieceKind) -> i16 {
 pub fn phase_value(board: &Board) -> i16 {
     let mut v = 0;
 
-    for idx in 0..Square::N {
-        let square = Square::from_array_index(idx);
-
+    for square in Square::all() {
         let maybe_piece = board.piece_at(square);
 
         if let Some(piece) = maybe_piece {
@@ -130,4 +136,17 @@ mod tests {
         assert_eq!(phased_eval.midgame().0, 2456);
         assert_eq!(phased_eval.endgame().0, 2393);
     }
+
+    #[test]
+    fn test_multiplying_scales_midgame_and_endgame_vals() {
+        let phased_eval = PhasedEval::new(12, -7) * 3;
+
+        assert_eq!(phased_eval.midgame().0, 36);
+        assert_eq!(phased_eval.endgame().0, -21);
+
+        let phased_eval = PhasedEval::new(-5, 9) * -2;
+
+        assert_eq!(phased_eval.midgame().0, 10);
+        assert_eq!(phased_eval.endgame().0, -18);
+    }
 }
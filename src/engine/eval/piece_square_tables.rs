@@ -89,6 +89,10 @@ pub fn init() {
 
 #[inline(always)]
 pub fn piece_contributions(square: Square, piece: Piece) -> PhasedEval {
+    debug_assert!(
+        crate::engine::is_initialised(),
+        "Eval and search tables were not initialised"
+    );
     // Safe as idx is guaranteed to be in bounds - we have length 64 arrays and are
     // generating idx from Square
     unsafe { TABLES[piece.player.array_idx()][piece.kind.array_idx()][square.array_idx()] }
@@ -97,9 +101,7 @@ pub fn piece_contributions(square: Square, piece: Piece) -> PhasedEval {
 pub fn eval(board: &Board) -> PhasedEval {
     let mut eval = PhasedEval::ZERO;
 
-    for idx in 0..Square::N {
-        let square = Square::from_array_index(idx);
-
+    for square in Square::all() {
         let maybe_piece = board.piece_at(square);
 
         if let Some(piece) = maybe_piece {
@@ -114,9 +116,7 @@ pub fn eval_by_player(board: &Board) -> ByPlayer<PhasedEval> {
     let mut white_eval = PhasedEval::ZERO;
     let mut black_eval = PhasedEval::ZERO;
 
-    for idx in 0..Square::N {
-        let square = Square::from_array_index(idx);
-
+    for square in Square::all() {
         let maybe_piece = board.piece_at(square);
 
         if let Some(piece) = maybe_piece {
@@ -22,7 +22,10 @@ impl Eval {
 
     const MATE: i16 = 32000;
     const MATED: i16 = -Self::MATE;
-    const MATE_THRESHOLD: i16 = Self::MATE - 100;
+
+    // Mates can be found anywhere up to the maximum search depth (255 plies), so every score within
+    // that distance of MATE is a mate score. Anything closer to zero is a centipawn score.
+    const MATE_THRESHOLD: i16 = Self::MATE - 256;
     const MATED_THRESHOLD: i16 = -Self::MATE_THRESHOLD;
 
     pub const fn new(eval: i16) -> Self {
@@ -37,6 +40,16 @@ impl Eval {
         Self(-Self::MATE + i16::from(ply))
     }
 
+    pub const fn is_mate_score(self) -> bool {
+        self.0 > Self::MATE_THRESHOLD || self.0 < Self::MATED_THRESHOLD
+    }
+
+    // The score in centipawns, kept out of the range used for mate scores so that it can't be
+    // mistaken for one when reported
+    pub fn to_centipawns_clamped(self) -> i16 {
+        self.0.clamp(Self::MATED_THRESHOLD, Self::MATE_THRESHOLD)
+    }
+
     pub fn is_mate_in_moves(self) -> Option<i16> {
         if self.0 > Self::MATE_THRESHOLD {
             return Some((Self::MATE - self.0 + 1) / 2);
@@ -60,11 +73,11 @@ impl Eval {
         let mut adjusted_value = self.0;
 
         if adjusted_value > Self::MATE_THRESHOLD {
-            adjusted_value += i16::from(plies);
+            adjusted_value = Self::shift_mate_score(adjusted_value, i16::from(plies));
         }
 
         if adjusted_value < Self::MATED_THRESHOLD {
-            adjusted_value -= i16::from(plies);
+            adjusted_value = Self::shift_mate_score(adjusted_value, -i16::from(plies));
         }
 
         Self(adjusted_value)
@@ -74,17 +87,27 @@ impl Eval {
         let mut adjusted_value = self.0;
 
         if adjusted_value > Self::MATE_THRESHOLD {
-            adjusted_value -= i16::from(plies);
+            adjusted_value = Self::shift_mate_score(adjusted_value, -i16::from(plies));
         }
 
         if adjusted_value < Self::MATED_THRESHOLD {
-            adjusted_value += i16::from(plies);
+            adjusted_value = Self::shift_mate_score(adjusted_value, i16::from(plies));
         }
 
         Self(adjusted_value)
     }
 
-    #[expect(unused, reason = "Unused")]
+    // Scores in the TT can be a little beyond MATE (e.g. a bound from the search window that was
+    // stored deeper in the tree), but never so far that adjusting them should overflow
+    fn shift_mate_score(value: i16, plies: i16) -> i16 {
+        debug_assert!(
+            value.checked_add(plies).is_some(),
+            "Mate score {value} overflowed when adjusted by {plies} plies"
+        );
+
+        value.saturating_add(plies)
+    }
+
     pub fn to_white_eval(self, player: Player) -> WhiteEval {
         match player {
             Player::White => WhiteEval(self.0),
@@ -104,13 +127,13 @@ impl std::ops::Add for Eval {
     type Output = Self;
 
     fn add(self, rhs: Self) -> Self::Output {
-        Self(self.0 + rhs.0)
+        Self(self.0.saturating_add(rhs.0))
     }
 }
 
 impl std::ops::AddAssign for Eval {
     fn add_assign(&mut self, rhs: Self) {
-        self.0 += rhs.0;
+        self.0 = self.0.saturating_add(rhs.0);
     }
 }
 
@@ -118,13 +141,13 @@ impl std::ops::Sub for Eval {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
-        Self(self.0 - rhs.0)
+        Self(self.0.saturating_sub(rhs.0))
     }
 }
 
 impl std::ops::SubAssign for Eval {
     fn sub_assign(&mut self, rhs: Self) {
-        self.0 -= rhs.0;
+        self.0 = self.0.saturating_sub(rhs.0);
     }
 }
 
@@ -132,7 +155,7 @@ impl std::ops::Mul<i16> for Eval {
     type Output = Self;
 
     fn mul(self, rhs: i16) -> Self::Output {
-        Self(self.0 * rhs)
+        Self(self.0.saturating_mul(rhs))
     }
 }
 
@@ -148,6 +171,42 @@ impl Div<i16> for Eval {
     type Output = Self;
 
     fn div(self, rhs: i16) -> Self::Output {
-        Self(self.0 / rhs)
+        Self(self.0.saturating_div(rhs))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic_saturates() {
+        assert_eq!(Eval::MAX + Eval(1), Eval::MAX);
+        assert_eq!(Eval::MIN - Eval(1), Eval::MIN);
+        assert_eq!(-Eval::MIN, Eval::MAX);
+        assert_eq!(Eval(20000) * 2, Eval::MAX);
+        assert_eq!(Eval::MIN / -1, Eval::MAX);
+
+        let mut eval = Eval::MAX;
+        eval += Eval(1);
+        assert_eq!(eval, Eval::MAX);
+    }
+
+    #[test]
+    fn test_mate_scores_at_any_depth() {
+        for plies in [1, 100, 200, u8::MAX] {
+            assert!(Eval::mate_in(plies).is_mate_score());
+            assert!(Eval::mated_in(plies).is_mate_score());
+        }
+
+        assert_eq!(Eval::mate_in(u8::MAX).is_mate_in_moves(), Some(128));
+        assert!(!Eval(3000).is_mate_score());
+    }
+
+    #[test]
+    fn test_centipawns_clamped_out_of_mate_range() {
+        assert_eq!(Eval(150).to_centipawns_clamped(), 150);
+        assert!(!Eval(Eval::MAX.to_centipawns_clamped()).is_mate_score());
+        assert!(!Eval(Eval::MIN.to_centipawns_clamped()).is_mate_score());
     }
 }
@@ -0,0 +1,66 @@
+use crate::chess::game::Game;
+use crate::chess::masks;
+use crate::chess::player::Player;
+use crate::engine::eval::params::{ROOK_OPEN_FILE_BONUS, ROOK_SEMI_OPEN_FILE_BONUS};
+use crate::engine::eval::{PhasedEval, Trace, TraceComponentIncr};
+
+fn rook_files_eval_for<const TRACE: bool>(
+    game: &Game,
+    player: Player,
+    trace: &mut Trace,
+) -> PhasedEval {
+    let mut eval = PhasedEval::ZERO;
+
+    let our_pawns = game.board.pawns(player);
+    let their_pawns = game.board.pawns(player.other());
+
+    for rook in game.board.rooks(player) {
+        // A rook's own square can't contain a pawn, so the two spans cover its whole file
+        let file = masks::front_span(player, rook) | masks::front_span(player.other(), rook);
+
+        if (file & our_pawns).any() {
+            continue;
+        }
+
+        if (file & their_pawns).any() {
+            eval += ROOK_SEMI_OPEN_FILE_BONUS;
+
+            if TRACE {
+                trace.rook_semi_open_file.incr(player);
+            }
+        } else {
+            eval += ROOK_OPEN_FILE_BONUS;
+
+            if TRACE {
+                trace.rook_open_file.incr(player);
+            }
+        }
+    }
+
+    eval
+}
+
+pub fn eval<const TRACE: bool>(game: &Game, trace: &mut Trace) -> PhasedEval {
+    rook_files_eval_for::<TRACE>(game, Player::White, trace)
+        - rook_files_eval_for::<TRACE>(game, Player::Black, trace)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rook_files_are_traced() {
+        crate::init();
+
+        // White's a1 rook is on an open file and its h1 rook is blocked by its own pawn. Black's
+        // d8 rook is on a file with only a white pawn.
+        let game = Game::from_fen("3rk3/8/8/8/8/3P4/7P/R3K2R w KQ - 0 1").unwrap();
+
+        let mut trace = Trace::new();
+        eval::<true>(&game, &mut trace);
+
+        assert_eq!(trace.rook_open_file[0].0, 1);
+        assert_eq!(trace.rook_semi_open_file[0].0, -1);
+    }
+}
@@ -0,0 +1,409 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::engine::options::EngineOptions;
+use crate::engine::search::move_overhead::MoveOverheadCalibration;
+use crate::engine::search::move_picker::{self, PickedFrom};
+use crate::engine::search::time_control::{Control, TimeStrategy};
+use crate::engine::search::transposition::SearchTranspositionTableData;
+use crate::engine::search::{
+    self, NullReporter, PersistentState, Reporter, SearchContext, SearchRestrictions, SearchResult,
+    SearchScore, TimeControl,
+};
+use crate::engine::tablebases::TablebaseProbe;
+use crate::engine::transposition_table::TranspositionTableEntry;
+use crate::engine::uci::options;
+use crate::engine::util::sync::LockLatch;
+
+// A single instance of the engine: a position, its options and the state that's kept between
+// searches (e.g. the TT). Any number of them can be used to play separate games at the same time.
+// Instances share the precomputed lookup tables, and the syzygy tablebases: these are loaded into
+// global state by Fathom, so setting SyzygyPath on one instance changes it for all of them.
+pub struct Engine {
+    pub game: Game,
+    pub options: EngineOptions,
+
+    persistent_state: Arc<Mutex<PersistentState>>,
+    control: Option<Control>,
+    is_stopped: Arc<LockLatch>,
+
+    // Kept outside of the persistent state so that it isn't reset between games, and can be
+    // updated when a search finishes without waiting for the search thread to let go of the TT
+    move_overhead_calibration: Arc<Mutex<MoveOverheadCalibration>>,
+}
+
+impl Engine {
+    pub fn new(options: EngineOptions) -> Self {
+        Self {
+            game: Game::new(),
+            persistent_state: Arc::new(Mutex::new(PersistentState::new(options.hash_size))),
+            options,
+
+            control: None,
+            is_stopped: Arc::new(LockLatch::new()),
+            move_overhead_calibration: Arc::new(Mutex::new(MoveOverheadCalibration::new())),
+        }
+    }
+
+    // Sets an option by its UCI name, e.g. "Hash". Returns a warning if the value was adjusted.
+    pub fn set_option(&mut self, name: &str, value: &str) -> Result<Option<String>, String> {
+        let Some(option) = options::find(name) else {
+            return Err(format!("Unknown option: {name}"));
+        };
+
+        (option.set)(&mut self.options, &self.persistent_state, value)
+            .map_err(|e| format!("Unable to set {}: {e}", option.name))
+    }
+
+    pub fn new_game(&mut self) -> Result<(), String> {
+        self.game = Game::new();
+        self.is_stopped.reset();
+
+        let mut persistent_state_handle = self.persistent_state.lock().unwrap();
+        persistent_state_handle.reset();
+
+        // The previous game is over, so keep anything we learned from it
+        persistent_state_handle.experience.save()
+    }
+
+    // Writes the experience file, if there is one. This happens at the start of each new game,
+    // but also needs to happen before the engine exits.
+    pub fn save_experience(&self) -> Result<(), String> {
+        self.idle_persistent_state()?.experience.save()
+    }
+
+    // Searches the current position on another thread. The reporter is told about the search's
+    // progress, and `Reporter::search_complete` is called on the search thread when it finishes.
+    pub fn start_search<R: Reporter + 'static>(
+        &mut self,
+        time_control: &TimeControl,
+        search_restrictions: SearchRestrictions,
+        reporter: R,
+    ) -> JoinHandle<SearchResult> {
+        let started_at = Instant::now();
+        let mut game = self.game.clone();
+        let mut options = self.options.clone();
+
+        {
+            let clocks = match time_control {
+                TimeControl::Clocks(clocks) => Some(clocks),
+                TimeControl::ExactTime(_) | TimeControl::Infinite => None,
+            };
+
+            let mut calibration = self.move_overhead_calibration.lock().unwrap();
+            calibration.start_move(&game, clocks);
+
+            if options.auto_move_overhead {
+                if let Some(measured) = calibration.move_overhead() {
+                    let measured = usize::try_from(measured.as_millis()).unwrap_or(usize::MAX);
+                    options.move_overhead = options.move_overhead.max(measured);
+                }
+            }
+        }
+
+        let (mut time_strategy, control) = TimeStrategy::new(&game, time_control, &options);
+        let watchdog = control.start_watchdog(&time_strategy);
+        self.control = Some(control);
+        self.is_stopped.reset();
+
+        let persistent_state = self.persistent_state.clone();
+        let is_stopped = self.is_stopped.clone();
+        let move_overhead_calibration = self.move_overhead_calibration.clone();
+
+        std::thread::spawn(move || {
+            let mut persistent_state_handle = persistent_state.lock().unwrap();
+
+            let result = search::search(
+                &mut game,
+                &mut persistent_state_handle,
+                &mut time_strategy,
+                &search_restrictions,
+                &options,
+                &reporter,
+            );
+
+            if let Some(watchdog) = watchdog {
+                watchdog.finish();
+            }
+
+            reporter.search_complete(&game, &persistent_state_handle, &result);
+            move_overhead_calibration
+                .lock()
+                .unwrap()
+                .finish_move(started_at.elapsed());
+
+            // Anything waiting for the search to stop will want the persistent state next
+            drop(persistent_state_handle);
+            is_stopped.set();
+
+            result
+        })
+    }
+
+    // Finds the best few moves in the current position, best first, by searching again with the
+    // moves we've already found excluded. The time is split evenly between the searches.
+    pub fn hint(
+        &self,
+        time: Duration,
+        number_of_moves: usize,
+    ) -> Result<Vec<(Move, SearchScore)>, String> {
+        let mut persistent_state_handle = self.idle_persistent_state()?;
+
+        let mut game = self.game.clone();
+        let number_of_moves = number_of_moves.min(game.moves().len());
+
+        if number_of_moves == 0 {
+            return Ok(Vec::new());
+        }
+
+        let time_per_search = time / u32::try_from(number_of_moves).unwrap();
+        let mut search_restrictions = SearchRestrictions::default();
+        let mut hints = Vec::new();
+
+        for _ in 0..number_of_moves {
+            let (mut time_strategy, _) = TimeStrategy::new(
+                &game,
+                &TimeControl::ExactTime(time_per_search),
+                &self.options,
+            );
+
+            let result = search::search(
+                &mut game,
+                &mut persistent_state_handle,
+                &mut time_strategy,
+                &search_restrictions,
+                &self.options,
+                &NullReporter,
+            );
+
+            search_restrictions.excluded_moves.push(result.best_move);
+            hints.push((result.best_move, result.score));
+        }
+
+        Ok(hints)
+    }
+
+    // The legal moves in the order the move picker would try them at the root of a search, with
+    // where each one came from. Killers and counter moves only exist during a search, so none are
+    // used here.
+    pub fn picked_moves(&self) -> Result<Vec<(Move, PickedFrom)>, String> {
+        let mut persistent_state_handle = self.idle_persistent_state()?;
+
+        let tt_move = persistent_state_handle
+            .tt
+            .get(&self.game.zobrist)
+            .and_then(|entry| entry.best_move);
+
+        let (mut time_strategy, _) =
+            TimeStrategy::new(&self.game, &TimeControl::Infinite, &self.options);
+        let search_restrictions = SearchRestrictions::default();
+        let ctx = SearchContext::new(
+            &mut persistent_state_handle,
+            &mut time_strategy,
+            &self.options,
+            &search_restrictions,
+        );
+
+        Ok(move_picker::picked_moves(&self.game, &ctx, 0, tt_move))
+    }
+
+    pub fn probe_tablebases(&self) -> Result<TablebaseProbe, String> {
+        let mut persistent_state_handle = self.idle_persistent_state()?;
+        let tablebase = &mut persistent_state_handle.tablebase;
+
+        Ok(TablebaseProbe {
+            n_men: tablebase.n_men(),
+            wdl: tablebase.wdl(&self.game),
+            dtz: tablebase.dtz(&self.game),
+        })
+    }
+
+    // The TT's current generation, and the entries in the TT bucket for the current position
+    pub fn hash_entries(
+        &self,
+    ) -> Result<
+        (
+            u8,
+            Vec<TranspositionTableEntry<SearchTranspositionTableData>>,
+        ),
+        String,
+    > {
+        let persistent_state_handle = self.idle_persistent_state()?;
+        let tt = &persistent_state_handle.tt;
+
+        Ok((
+            tt.generation,
+            tt.bucket_entries(&self.game.zobrist).cloned().collect(),
+        ))
+    }
+
+    // Saves the TT to a file, returning the number of entries that were saved
+    pub fn save_hash(&self, path: &Path) -> Result<usize, String> {
+        let persistent_state_handle = self.idle_persistent_state()?;
+
+        let file =
+            File::create(path).map_err(|e| format!("Unable to create {}: {e}", path.display()))?;
+
+        persistent_state_handle.tt.save(&mut BufWriter::new(file))
+    }
+
+    // Replaces the TT with one saved by `save_hash`, returning the number of entries loaded
+    pub fn load_hash(&self, path: &Path) -> Result<usize, String> {
+        let mut persistent_state_handle = self.idle_persistent_state()?;
+
+        let file =
+            File::open(path).map_err(|e| format!("Unable to open {}: {e}", path.display()))?;
+
+        persistent_state_handle.tt.load(&mut BufReader::new(file))
+    }
+
+    // A running search holds on to the persistent state until it finishes, which may be never
+    // for an infinite search, so we don't wait for it
+    fn idle_persistent_state(&self) -> Result<MutexGuard<'_, PersistentState>, String> {
+        self.persistent_state
+            .try_lock()
+            .map_err(|_| "Unable to access the hash while searching".to_string())
+    }
+
+    // Stops the current search, if there is one, and waits for it to finish
+    pub fn stop(&mut self) {
+        if let Some(control) = self.control.take() {
+            control.stop();
+            self.is_stopped.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::temp_file::TempFile;
+
+    #[test]
+    fn test_engines_can_search_concurrently() {
+        crate::init();
+
+        let fens = [
+            "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
+            "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - -",
+            "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        ];
+
+        let mut engines: Vec<Engine> = fens
+            .iter()
+            .map(|fen| {
+                let mut engine = Engine::new(EngineOptions {
+                    hash_size: 1,
+                    ..EngineOptions::default()
+                });
+                engine.game = Game::from_fen(fen).unwrap();
+                engine
+            })
+            .collect();
+
+        let searches: Vec<JoinHandle<SearchResult>> = engines
+            .iter_mut()
+            .map(|engine| {
+                engine.start_search(
+                    &TimeControl::Infinite,
+                    SearchRestrictions {
+                        depth: Some(5),
+                        ..SearchRestrictions::default()
+                    },
+                    NullReporter,
+                )
+            })
+            .collect();
+
+        for (engine, search) in engines.iter().zip(searches) {
+            let result = search.join().unwrap();
+            assert!(engine.game.moves().contains(&result.best_move));
+        }
+    }
+
+    #[test]
+    fn test_saved_hash_can_be_loaded() {
+        crate::init();
+
+        let file = TempFile::new("hash");
+
+        let mut engine = Engine::new(EngineOptions {
+            hash_size: 1,
+            ..EngineOptions::default()
+        });
+
+        let result = engine
+            .start_search(
+                &TimeControl::Infinite,
+                SearchRestrictions {
+                    depth: Some(5),
+                    ..SearchRestrictions::default()
+                },
+                NullReporter,
+            )
+            .join()
+            .unwrap();
+
+        let saved = engine.save_hash(file.path()).unwrap();
+        assert!(saved > 0);
+
+        let loaded_engine = Engine::new(EngineOptions {
+            hash_size: 1,
+            ..EngineOptions::default()
+        });
+
+        assert_eq!(loaded_engine.load_hash(file.path()), Ok(saved));
+
+        let persistent_state = loaded_engine.persistent_state.lock().unwrap();
+        let entry = persistent_state.tt.get(&engine.game.zobrist).unwrap();
+        assert_eq!(entry.best_move, Some(result.best_move));
+    }
+
+    #[test]
+    fn test_hint_returns_distinct_moves() {
+        crate::init();
+
+        let mut engine = Engine::new(EngineOptions {
+            hash_size: 1,
+            ..EngineOptions::default()
+        });
+        engine.game = Game::from_fen("6k1/5ppp/8/8/8/8/5PPP/R5K1 w - - 0 1").unwrap();
+
+        let hints = engine.hint(Duration::from_millis(300), 3).unwrap();
+        assert_eq!(hints.len(), 3);
+
+        let (best_move, best_score) = hints[0];
+        assert_eq!(format!("{best_move:?}"), "a1a8");
+        assert_eq!(best_score, SearchScore::Mate(1));
+
+        for (i, (mv, _)) in hints.iter().enumerate() {
+            assert!(engine.game.moves().contains(mv));
+            assert!(!hints[i + 1..].iter().any(|(other, _)| other == mv));
+        }
+
+        // There are only as many hints as there are legal moves
+        engine.game = Game::from_fen("k7/2K5/8/8/8/8/8/1R6 b - - 0 1").unwrap();
+        assert_eq!(engine.hint(Duration::from_millis(30), 3).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_loading_an_invalid_hash_file_fails() {
+        crate::init();
+
+        let file = TempFile::new("not-hash");
+        std::fs::write(file.path(), "not a hash file").unwrap();
+
+        let engine = Engine::new(EngineOptions {
+            hash_size: 1,
+            ..EngineOptions::default()
+        });
+
+        assert!(engine.load_hash(file.path()).is_err());
+    }
+}
@@ -1,15 +1,27 @@
+use std::sync::Once;
+
 pub mod eval;
+pub mod handle;
 pub mod options;
 pub mod uci;
 pub mod util;
 
 pub mod see;
+pub mod selftest;
 
 pub mod search;
 mod tablebases;
 pub mod transposition_table;
 
+static INIT: Once = Once::new();
+
 pub fn init() {
-    eval::init();
-    search::init();
+    INIT.call_once(|| {
+        eval::init();
+        search::init();
+    });
+}
+
+pub fn is_initialised() -> bool {
+    INIT.is_completed()
 }
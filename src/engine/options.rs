@@ -1,11 +1,32 @@
+use crate::chess::notation::Notation;
+
 pub mod defaults {
+    use crate::chess::notation::Notation;
+
     pub const HASH_SIZE: usize = 256;
+    pub const MAX_HASH_SIZE: usize = 1024;
     pub const THREADS: usize = 1;
+    // The search is single-threaded
+    pub const MAX_THREADS: usize = 1;
     pub const MOVE_OVERHEAD: usize = 0;
+    pub const AUTO_MOVE_OVERHEAD: bool = false;
     pub const SYZYGY_PATH: Option<String> = None;
+    pub const DRAW_DITHER: bool = true;
+    pub const EXTEND_PV_FROM_TT: bool = true;
+    pub const ANALYSE_MODE: bool = false;
+    pub const TWOFOLD_REPETITION: bool = true;
+    pub const EXPERIENCE_FILE: Option<String> = None;
+    pub const PREFERRED_OPENINGS: Option<String> = None;
+    pub const NOTATION: Notation = Notation::San;
+    pub const MIN_REPORT_DEPTH: usize = 0;
+    pub const REPORT_INTERVAL: usize = 0;
 }
 
 #[derive(Debug, Clone)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "Each bool is an independent UCI check option"
+)]
 pub struct EngineOptions {
     pub hash_size: usize,
     pub threads: usize,
@@ -13,7 +34,46 @@ pub struct EngineOptions {
     // Account for the possibility that there's some overhead making the move
     // e.g. sending the best move over the internet.
     pub move_overhead: usize,
+
+    // Measure the overhead from how much time goes missing from our clock between moves, and use
+    // it instead of `move_overhead` when it's larger
+    pub auto_move_overhead: bool,
+
     pub syzygy_path: Option<String>,
+
+    // Randomise draw scores slightly and avoid draws when we're ahead, to stop the
+    // engine shuffling pieces around in positions it should be winning.
+    pub draw_dither: bool,
+
+    // Fill out PVs that were cut short by TT cutoffs using the best moves stored in the TT.
+    // These moves may be stale, so this can be disabled.
+    pub extend_pv_from_tt: bool,
+
+    // Set by the GUI when the engine is being used for analysis rather than playing a game.
+    // We always search (rather than playing tablebase moves instantly) and don't avoid draws,
+    // so that the reported scores and lines are unbiased.
+    pub analyse_mode: bool,
+
+    // Treat a position that repeats one from after the root of the search as a draw, rather than
+    // waiting for a threefold repetition. Repetitions of positions from before the root always
+    // need to be threefold.
+    pub twofold_repetition: bool,
+
+    // A file to remember the results of searches in, so that they can be reused when the same
+    // positions come up in later games
+    pub experience_file: Option<String>,
+
+    // A file of opening lines that we try to stay inside for the first few moves of a game
+    pub preferred_openings: Option<String>,
+
+    // How moves are written in the pretty output
+    pub notation: Notation,
+
+    // Search progress is only reported from this depth onwards, and at most once every
+    // `report_interval` milliseconds (0 to report every iteration). The last iteration is always
+    // reported before the best move, so GUIs still see the final score and PV.
+    pub min_report_depth: usize,
+    pub report_interval: usize,
 }
 
 impl Default for EngineOptions {
@@ -22,7 +82,17 @@ impl Default for EngineOptions {
             hash_size: defaults::HASH_SIZE,
             threads: defaults::THREADS,
             move_overhead: defaults::MOVE_OVERHEAD,
+            auto_move_overhead: defaults::AUTO_MOVE_OVERHEAD,
             syzygy_path: defaults::SYZYGY_PATH,
+            draw_dither: defaults::DRAW_DITHER,
+            extend_pv_from_tt: defaults::EXTEND_PV_FROM_TT,
+            analyse_mode: defaults::ANALYSE_MODE,
+            twofold_repetition: defaults::TWOFOLD_REPETITION,
+            experience_file: defaults::EXPERIENCE_FILE,
+            preferred_openings: defaults::PREFERRED_OPENINGS,
+            notation: defaults::NOTATION,
+            min_report_depth: defaults::MIN_REPORT_DEPTH,
+            report_interval: defaults::REPORT_INTERVAL,
         }
     }
 }
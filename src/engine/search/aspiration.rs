@@ -52,6 +52,16 @@ impl Window {
     }
 }
 
+// Scores far from zero and scores from shallow searches are less stable between iterations, so
+// they start with a wider window
+fn initial_window_width(eval: Eval, depth: u8) -> Eval {
+    let score_widening = eval.0.unsigned_abs() / params::ASPIRATION_WINDOW_SCORE_DIVISOR;
+    let depth_widening = params::ASPIRATION_WINDOW_DEPTH_WIDENING
+        * i16::from(params::ASPIRATION_WINDOW_DEPTH_WIDENING_LIMIT.saturating_sub(depth));
+
+    params::ASPIRATION_WINDOW_SIZE + Eval(i16::try_from(score_widening).unwrap() + depth_widening)
+}
+
 pub fn aspiration_search(
     game: &mut Game,
     depth: u8,
@@ -59,10 +69,13 @@ pub fn aspiration_search(
     pv: &mut PrincipalVariation,
     ctx: &mut SearchContext<'_>,
 ) -> Result<Eval, ()> {
-    let mut window = if depth < params::ASPIRATION_MIN_DEPTH {
-        Window::no_window()
-    } else {
-        Window::around(eval.unwrap(), params::ASPIRATION_WINDOW_SIZE)
+    // Mate scores (including tablebase wins and losses) jump by large amounts between
+    // iterations, so a window around them would just keep failing
+    let mut window = match eval {
+        Some(eval) if depth >= params::ASPIRATION_MIN_DEPTH && !eval.is_mate_score() => {
+            Window::around(eval, initial_window_width(eval, depth))
+        }
+        _ => Window::no_window(),
     };
 
     loop {
@@ -79,3 +92,28 @@ pub fn aspiration_search(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_initial_window_width() {
+        let deep = params::ASPIRATION_WINDOW_DEPTH_WIDENING_LIMIT;
+
+        assert_eq!(
+            initial_window_width(Eval(0), deep),
+            params::ASPIRATION_WINDOW_SIZE
+        );
+        assert!(initial_window_width(Eval(400), deep) > initial_window_width(Eval(0), deep));
+        assert_eq!(
+            initial_window_width(Eval(-400), deep),
+            initial_window_width(Eval(400), deep)
+        );
+        assert!(initial_window_width(Eval(0), 5) > initial_window_width(Eval(0), deep));
+        assert_eq!(
+            initial_window_width(Eval(0), deep + 10),
+            initial_window_width(Eval(0), deep)
+        );
+    }
+}
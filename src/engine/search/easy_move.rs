@@ -0,0 +1,50 @@
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::engine::eval::Eval;
+use crate::engine::search::negamax::negamax;
+use crate::engine::search::principal_variation::PrincipalVariation;
+
+use super::{params, SearchContext};
+
+// Whether the best root move is so much better than the alternatives that searching for longer is
+// unlikely to change our mind, e.g. a recapture. We check with a reduced depth, null window search
+// of every other root move against a bound some way below the best move's score: if none of them
+// can reach it, the best move is easy.
+pub fn is_easy_move(
+    game: &mut Game,
+    best_move: Move,
+    score: Eval,
+    depth: u8,
+    ctx: &mut SearchContext<'_>,
+) -> Result<bool, ()> {
+    let threshold = score - params::EASY_MOVE_MARGIN;
+    let verification_depth = depth / 2;
+
+    let other_moves: Vec<Move> = game
+        .moves()
+        .into_iter()
+        .filter(|mv| *mv != best_move && !ctx.search_restrictions.excluded_moves.contains(mv))
+        .collect();
+
+    for mv in other_moves {
+        game.make_move(mv);
+
+        let move_score = -negamax(
+            game,
+            -threshold,
+            -threshold + Eval(1),
+            verification_depth - 1,
+            1,
+            &mut PrincipalVariation::new(),
+            ctx,
+        )?;
+
+        game.undo_move();
+
+        if move_score >= threshold {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
@@ -0,0 +1,268 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::engine::eval;
+use crate::engine::eval::Eval;
+use crate::engine::search::transposition::{
+    BoundAndAge, NodeBound, SearchTranspositionTable, SearchTranspositionTableData,
+};
+
+// The result of a search from a previous game
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ExperienceEntry {
+    best_move: Move,
+    score: Eval,
+    depth: u8,
+}
+
+// Root positions we've searched before, along with what we found, so that we don't have to start
+// from scratch when we see them again in a later game. The entries are kept in a file between
+// runs of the engine, which is only read and written if one has been set.
+pub struct Experience {
+    path: Option<PathBuf>,
+    entries: HashMap<u64, ExperienceEntry>,
+}
+
+impl Experience {
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            entries: HashMap::new(),
+        }
+    }
+
+    // Loads the entries from the given file, which doesn't need to exist yet. An empty path
+    // disables the experience file. Lines that can't be parsed are skipped, and reported in the
+    // returned warning. If the file can't be read, the current file and entries are kept.
+    pub fn set_path(&mut self, path: &str) -> Result<Option<String>, String> {
+        if path.is_empty() || path == "<empty>" {
+            self.path = None;
+            self.entries.clear();
+            return Ok(None);
+        }
+
+        let path = PathBuf::from(path);
+        let mut entries = HashMap::new();
+        let mut invalid_lines = 0;
+
+        if path.exists() {
+            let contents = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+            for line in contents.lines() {
+                match parse_entry(line) {
+                    Some((key, entry)) => {
+                        entries.insert(key, entry);
+                    }
+                    None => invalid_lines += 1,
+                }
+            }
+        }
+
+        let warning = (invalid_lines > 0).then(|| {
+            format!(
+                "Skipped {invalid_lines} invalid experience entries in {}",
+                path.display()
+            )
+        });
+
+        self.entries = entries;
+        self.path = Some(path);
+        Ok(warning)
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.path.is_some()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut contents = String::new();
+
+        for (key, entry) in &self.entries {
+            writeln!(
+                contents,
+                "{key:016x} {} {} {}",
+                entry.best_move.to_u16(),
+                entry.score.0,
+                entry.depth
+            )
+            .unwrap();
+        }
+
+        std::fs::write(path, contents)
+            .map_err(|e| format!("Unable to write {}: {e}", path.display()))
+    }
+
+    // Remember the result of a completed search from the given root. The TT entry for the root is
+    // always from the last completed iteration, so it's the most accurate result we have.
+    pub fn record(&mut self, game: &Game, tt: &SearchTranspositionTable) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let Some(tt_entry) = tt.get(&game.zobrist) else {
+            return;
+        };
+
+        let (NodeBound::Exact, Some(best_move)) = (tt_entry.bound(), tt_entry.best_move) else {
+            return;
+        };
+
+        // Only replace what we knew before if we've searched the position more deeply
+        if self
+            .entries
+            .get(&game.zobrist.0)
+            .is_some_and(|existing| existing.depth > tt_entry.depth)
+        {
+            return;
+        }
+
+        self.entries.insert(
+            game.zobrist.0,
+            ExperienceEntry {
+                best_move,
+                score: tt_entry.eval,
+                depth: tt_entry.depth,
+            },
+        );
+    }
+
+    // If we've searched this root before, put what we found into the TT so that the previous best
+    // move is searched first. We never take TT cutoffs at the root, so the score only affects
+    // positions that transpose back into this one.
+    pub fn seed(&self, game: &Game, tt: &mut SearchTranspositionTable) {
+        let Some(entry) = self.entries.get(&game.zobrist.0) else {
+            return;
+        };
+
+        // Anything already in the TT is at least as up to date as what we remember
+        if tt.get(&game.zobrist).is_some() {
+            return;
+        }
+
+        // Entries can collide, so check the move actually makes sense here
+        if !game.moves().contains(&entry.best_move) {
+            return;
+        }
+
+        tt.insert(
+            &game.zobrist,
+            SearchTranspositionTableData {
+                bound_and_age: BoundAndAge::new(NodeBound::Exact, tt.generation),
+                eval: entry.score,
+                static_eval: eval::eval(game),
+                depth: entry.depth,
+                best_move: Some(entry.best_move),
+            },
+        );
+    }
+}
+
+fn parse_entry(line: &str) -> Option<(u64, ExperienceEntry)> {
+    let mut parts = line.split_whitespace();
+
+    let key = u64::from_str_radix(parts.next()?, 16).ok()?;
+    let best_move = Move::from_u16(parts.next()?.parse().ok()?)?;
+    let score = Eval(parts.next()?.parse().ok()?);
+    let depth = parts.next()?.parse().ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((
+        key,
+        ExperienceEntry {
+            best_move,
+            score,
+            depth,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::temp_file::TempFile;
+
+    #[test]
+    fn test_experience_is_saved_and_seeds_the_tt() {
+        crate::init();
+
+        let file = TempFile::new("experience");
+        let game = Game::new();
+        let best_move = game.moves()[0];
+
+        let mut tt = SearchTranspositionTable::new(1);
+        tt.insert(
+            &game.zobrist,
+            SearchTranspositionTableData {
+                bound_and_age: BoundAndAge::new(NodeBound::Exact, 0),
+                eval: Eval(35),
+                static_eval: Eval(10),
+                depth: 12,
+                best_move: Some(best_move),
+            },
+        );
+
+        let mut experience = Experience::new();
+        experience.set_path(file.path_str()).unwrap();
+        experience.record(&game, &tt);
+        experience.save().unwrap();
+
+        let mut loaded_experience = Experience::new();
+        loaded_experience.set_path(file.path_str()).unwrap();
+
+        let mut tt = SearchTranspositionTable::new(1);
+        loaded_experience.seed(&game, &mut tt);
+
+        let entry = tt.get(&game.zobrist).unwrap();
+        assert_eq!(entry.best_move, Some(best_move));
+        assert_eq!(entry.eval, Eval(35));
+        assert_eq!(entry.depth, 12);
+    }
+
+    #[test]
+    fn test_invalid_entries_are_skipped() {
+        let file = TempFile::new("experience-invalid");
+        std::fs::write(file.path(), "463b96181691fc9c 796 35 12\nnot an entry\n").unwrap();
+
+        let mut experience = Experience::new();
+        let warning = experience.set_path(file.path_str()).unwrap();
+
+        assert!(warning.unwrap().contains("Skipped 1 invalid"));
+        assert_eq!(experience.entries.len(), 1);
+        assert_eq!(experience.path.as_deref(), Some(file.path()));
+    }
+
+    #[test]
+    fn test_unreadable_file_keeps_the_current_state() {
+        let file = TempFile::new("experience-current");
+        std::fs::write(file.path(), "463b96181691fc9c 796 35 12\n").unwrap();
+
+        let mut experience = Experience::new();
+        experience.set_path(file.path_str()).unwrap();
+
+        // A directory exists but can't be read as a file
+        let directory = std::env::temp_dir();
+        assert!(experience.set_path(directory.to_str().unwrap()).is_err());
+
+        assert_eq!(experience.entries.len(), 1);
+        assert_eq!(experience.path.as_deref(), Some(file.path()));
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        assert!(parse_entry("463b96181691fc9c 796 35 12").is_some());
+        assert!(parse_entry("463b96181691fc9c 0 35 12").is_none());
+        assert!(parse_entry("463b96181691fc9c 796 35").is_none());
+        assert!(parse_entry("463b96181691fc9c 796 35 12 1").is_none());
+    }
+}
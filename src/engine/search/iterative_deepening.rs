@@ -1,10 +1,11 @@
 use crate::chess::game::Game;
-use crate::chess::moves::Move;
 use crate::engine::eval::Eval;
 use crate::engine::search::aspiration::aspiration_search;
+use crate::engine::search::easy_move::is_easy_move;
 use crate::engine::search::principal_variation::PrincipalVariation;
 use crate::engine::search::{
-    Reporter, SearchContext, SearchInfo, SearchScore, SearchStats, MAX_SEARCH_DEPTH,
+    params, Reporter, SearchContext, SearchInfo, SearchIteration, SearchScore, SearchStats,
+    MAX_SEARCH_DEPTH,
 };
 use crate::engine::util;
 
@@ -12,52 +13,141 @@ pub fn search(
     game: &mut Game,
     ctx: &mut SearchContext<'_>,
     pv: &mut PrincipalVariation,
-    reporter: &mut impl Reporter,
-) -> Option<Move> {
-    let mut best_move: Option<Move> = None;
+    reporter: &impl Reporter,
+) -> Option<SearchInfo> {
+    let mut last_info: Option<SearchInfo> = None;
     let mut overall_eval: Option<Eval> = None;
 
     let max_search_depth = ctx.search_restrictions.depth.unwrap_or(MAX_SEARCH_DEPTH);
     ctx.max_depth_reached = 0;
 
+    // The move the previous search expected to be played here, e.g. our ponder move
+    let expected_move = ctx.tt.get(&game.zobrist).and_then(|entry| entry.best_move);
+    let mut best_move_stable_iterations = 0;
+    let mut easy_move_checked = false;
+
     for depth in 1..=max_search_depth {
-        if !ctx.time_control.should_start_new_search(depth) {
+        if !ctx.should_start_new_search(depth) {
             break;
         }
 
-        let Ok(eval) = aspiration_search(game, depth, overall_eval, pv, ctx) else {
+        // Search into a fresh PV for each iteration. If the search is aborted partway through
+        // an iteration, the moves it found haven't been searched to completion at this depth, so
+        // we throw them away and keep the PV from the last completed iteration.
+        let mut iteration_pv = PrincipalVariation::new();
+        trace!(Iteration { depth });
+
+        let Ok(eval) = aspiration_search(game, depth, overall_eval, &mut iteration_pv, ctx) else {
             break;
         };
 
+        if pv.first().is_some() && pv.first() == iteration_pv.first() {
+            best_move_stable_iterations += 1;
+        } else {
+            best_move_stable_iterations = 0;
+        }
+
+        *pv = iteration_pv;
+
         let score = if let Some(nmoves) = eval.is_mate_in_moves() {
             SearchScore::Mate(nmoves)
         } else {
-            SearchScore::Centipawns(eval.0)
+            SearchScore::Centipawns(eval.to_centipawns_clamped())
         };
 
-        best_move = Some(*pv.first().unwrap());
         overall_eval = Some(eval);
 
-        reporter.report_search_progress(
-            game,
-            SearchInfo {
-                depth,
-                seldepth: ctx.max_depth_reached,
-                score,
-                pv: pv.clone(),
-                hashfull: ctx.tt.occupancy(),
-                stats: SearchStats {
-                    time: ctx.time_control.elapsed(),
-                    nodes: ctx.nodes_visited,
-                    nodes_per_second: util::metrics::nodes_per_second(
-                        ctx.nodes_visited,
-                        ctx.time_control.elapsed(),
-                    ),
-                    tbhits: ctx.tbhits,
-                },
+        ctx.iterations.push(SearchIteration {
+            depth,
+            time: ctx.time_control.elapsed(),
+            nodes: ctx.nodes_visited,
+        });
+
+        let mut reported_pv = if ctx.options.extend_pv_from_tt {
+            extend_pv_from_tt(game, pv, depth, ctx)
+        } else {
+            pv.clone()
+        };
+
+        // GUIs will reject PVs containing illegal moves, so make sure we never send one.
+        // This should never happen, so it indicates a bug in the search.
+        let pv_is_legal = reported_pv.truncate_at_illegal_move(game);
+        debug_assert!(pv_is_legal, "Search produced an illegal PV");
+
+        let info = SearchInfo {
+            depth,
+            seldepth: ctx.max_depth_reached,
+            score,
+            pv: reported_pv,
+            hashfull: ctx.tt.occupancy(),
+            stats: SearchStats {
+                time: ctx.time_control.elapsed(),
+                nodes: ctx.nodes_visited,
+                nodes_per_second: util::metrics::nodes_per_second(
+                    ctx.nodes_visited,
+                    ctx.time_control.elapsed(),
+                ),
+                tbhits: ctx.tbhits,
+                tb_cache_hits: ctx.tablebase.cache_hits(),
             },
-        );
+        };
+
+        reporter.report_search_progress(game, info.clone());
+        last_info = Some(info);
+
+        // If the best move has been the same for a while, it's what we expected to play, and it's
+        // clearly better than everything else, there's little point using the rest of our time.
+        // The check costs a search of every other root move, so it's only done once.
+        let Some(best_move) = pv.first().copied() else {
+            continue;
+        };
+
+        if !easy_move_checked
+            && depth >= params::EASY_MOVE_MIN_DEPTH
+            && best_move_stable_iterations >= params::EASY_MOVE_STABLE_ITERATIONS
+            && expected_move == Some(best_move)
+            && !eval.is_mate_score()
+            && ctx
+                .time_control
+                .used_fraction_of_soft_stop(params::EASY_MOVE_TIME_FRACTION)
+        {
+            easy_move_checked = true;
+
+            // An aborted check means we're out of time anyway
+            if is_easy_move(game, best_move, eval, depth, ctx).unwrap_or(true) {
+                break;
+            }
+        }
+    }
+
+    last_info
+}
+
+// TT cutoffs in PV nodes can leave us with a PV that's shorter than the depth we searched to.
+// We can fill the rest of it in using the best moves stored in the TT.
+fn extend_pv_from_tt(
+    game: &mut Game,
+    pv: &PrincipalVariation,
+    depth: u8,
+    ctx: &SearchContext<'_>,
+) -> PrincipalVariation {
+    let mut extended_pv = pv.clone();
+
+    if pv.len() >= depth {
+        return extended_pv;
+    }
+
+    for mv in pv.clone() {
+        game.make_move(mv);
+    }
+
+    for mv in ctx.tt.line_from(game, depth - pv.len()) {
+        extended_pv.append(mv);
+    }
+
+    for _ in 0..pv.len() {
+        game.undo_move();
     }
 
-    best_move
+    extended_pv
 }
@@ -1,64 +1,127 @@
 use crate::chess::game::Game;
 use crate::chess::moves::Move;
+use crate::chess::player::Player;
+use crate::engine::eval;
 use crate::engine::eval::Eval;
 use crate::engine::options::EngineOptions;
+use crate::engine::search::experience::Experience;
 use crate::engine::search::move_picker::MovePicker;
 use crate::engine::search::principal_variation::PrincipalVariation;
+use crate::engine::search::repertoire::Repertoire;
 use crate::engine::search::tables::{CountermoveTable, HistoryTable, KillersTable};
 use crate::engine::search::time_control::TimeStrategy;
 use crate::engine::search::transposition::SearchTranspositionTable;
 use crate::engine::tablebases::{Tablebase, Wdl};
 use crate::engine::util;
+use std::any::Any;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Mutex, MutexGuard, PoisonError};
 use std::time::Duration;
 
+// Records an event in the search trace. Without the 'trace' feature, this compiles to nothing.
+macro_rules! trace {
+    ($($event:tt)*) => {
+        #[cfg(feature = "trace")]
+        $crate::engine::search::trace::record($crate::engine::search::trace::Event::$($event)*);
+    };
+}
+
 mod aspiration;
+mod easy_move;
+mod experience;
 mod iterative_deepening;
 mod move_ordering;
+pub mod move_overhead;
 pub mod move_picker;
 mod negamax;
-mod principal_variation;
+pub mod principal_variation;
 mod quiescence;
+mod repertoire;
 mod tables;
 pub mod time_control;
+#[cfg(feature = "trace")]
+pub mod trace;
 pub mod transposition;
 
 const MAX_SEARCH_DEPTH: u8 = u8::MAX;
 const MAX_SEARCH_DEPTH_SIZE: usize = MAX_SEARCH_DEPTH as usize;
 
 mod params {
+    use std::time::Duration;
+
     use crate::engine::eval::Eval;
 
-    pub const CHECK_TERMINATION_NODE_FREQUENCY: u64 = 10000;
+    // We check the clock roughly once per interval, based on how quickly we're searching. This
+    // stops us overshooting very short searches without spending time on checks in longer ones.
+    pub const CHECK_TERMINATION_INTERVAL: Duration = Duration::from_millis(1);
+    pub const MIN_CHECK_TERMINATION_NODE_FREQUENCY: u64 = 256;
+    pub const MAX_CHECK_TERMINATION_NODE_FREQUENCY: u64 = 10000;
 
     pub const ASPIRATION_MIN_DEPTH: u8 = 5;
     pub const ASPIRATION_WINDOW_SIZE: Eval = Eval::new(25);
+    pub const ASPIRATION_WINDOW_SCORE_DIVISOR: u16 = 16;
+    pub const ASPIRATION_WINDOW_DEPTH_WIDENING: i16 = 4;
+    pub const ASPIRATION_WINDOW_DEPTH_WIDENING_LIMIT: u8 = 12;
 
     pub const NULL_MOVE_PRUNING_DEPTH_LIMIT: u8 = 3;
     pub const NULL_MOVE_PRUNING_DEPTH_REDUCTION: u8 = 2;
+    pub const NULL_MOVE_VERIFICATION_DEPTH: u8 = 12;
+
+    pub const DELTA_PRUNING_MARGIN: Eval = Eval::new(200);
 
     pub const FUTILITY_PRUNE_DEPTH: u8 = 1;
     pub const FUTILITY_PRUNE_MAX_MOVE_VALUE: Eval = Eval::new(135);
 
-    pub const REVERSE_FUTILITY_PRUNE_DEPTH: u8 = 4;
-    pub const REVERSE_FUTILITY_PRUNE_MARGIN_PER_PLY: Eval = Eval::new(150);
+    pub const REVERSE_FUTILITY_PRUNE_DEPTH: u8 = 8;
+    pub const REVERSE_FUTILITY_PRUNE_MARGIN_PER_PLY: Eval = Eval::new(90);
+    pub const REVERSE_FUTILITY_PRUNE_IMPROVING_MARGIN: Eval = Eval::new(70);
+
+    pub const CHECK_EXTENSION: u8 = 1;
+    pub const PAWN_PUSH_TO_SEVENTH_EXTENSION: u8 = 1;
+    pub const MAX_EXTENSIONS_PER_BRANCH: u8 = 16;
+
+    pub const DRAW_AVOIDANCE_THRESHOLD: Eval = Eval::new(100);
+    pub const DRAW_AVOIDANCE_PENALTY: Eval = Eval::new(20);
+
+    pub const REFUTATION_LINE_LENGTH: u8 = 4;
+
+    pub const TT_CUTOFF_HALFMOVE_CLOCK_LIMIT: u32 = 90;
+    pub const TT_REPLACEMENT_AGE_WEIGHT: i32 = 4;
+    pub const TT_REPLACEMENT_EXACT_BONUS: i32 = 2;
+    pub const FIFTY_MOVE_SCALING_START: u32 = 20;
+
+    pub const PREFERRED_OPENINGS_MAX_PLIES: u32 = 20;
+    pub const PREFERRED_OPENINGS_BONUS: Eval = Eval::new(15);
 
     pub const LMR_DEPTH: u8 = 3;
     pub const LMR_MOVE_THRESHOLD: usize = 3;
 
-    pub const HISTORY_DECAY_FACTOR: i32 = 8;
+    pub const HISTORY_MAX_VALUE: i32 = 16384;
+    pub const HISTORY_MAX_BONUS: i32 = 1200;
 
     pub const MAX_TIME_PER_MOVE: f32 = 0.5;
     pub const INCREMENT_TO_USE: f32 = 0.5;
     pub const BASE_TIME_PER_MOVE: f32 = 0.033;
 
     pub const SOFT_TIME_MULTIPLIER: f32 = 0.75;
+
+    pub const EASY_MOVE_MIN_DEPTH: u8 = 8;
+    pub const EASY_MOVE_STABLE_ITERATIONS: u8 = 4;
+    pub const EASY_MOVE_MARGIN: Eval = Eval::new(150);
+    pub const EASY_MOVE_TIME_FRACTION: f32 = 0.3;
     pub const HARD_TIME_MULTIPLIER: f32 = 3.00;
+
+    pub const MOVE_OVERHEAD_CALIBRATION_SAMPLES: usize = 8;
+    pub const MOVE_OVERHEAD_CALIBRATION_MIN_SAMPLES: usize = 3;
+    pub const MAX_CALIBRATED_MOVE_OVERHEAD: Duration = Duration::from_secs(1);
 }
 
 pub struct PersistentState {
     pub tt: SearchTranspositionTable,
     pub history_table: HistoryTable,
     pub tablebase: Tablebase,
+    pub experience: Experience,
+    pub repertoire: Repertoire,
 }
 
 impl PersistentState {
@@ -67,6 +130,8 @@ impl PersistentState {
             tt: SearchTranspositionTable::new(tt_size_mb),
             history_table: HistoryTable::new(),
             tablebase: Tablebase::new(),
+            experience: Experience::new(),
+            repertoire: Repertoire::new(),
         }
     }
 
@@ -79,21 +144,33 @@ impl PersistentState {
 pub struct SearchContext<'s> {
     pub tt: &'s mut SearchTranspositionTable,
     pub tablebase: &'s mut Tablebase,
+    pub experience: &'s mut Experience,
+    pub repertoire: &'s Repertoire,
 
     pub history_table: &'s mut HistoryTable,
 
     pub time_control: &'s mut TimeStrategy,
 
-    #[expect(unused, reason = "No options currently used in search")]
     pub options: &'s EngineOptions,
     pub search_restrictions: &'s SearchRestrictions,
 
     pub killer_moves: KillersTable,
     pub countermove_table: CountermoveTable,
 
+    extensions: u8,
+    draw_avoider: Option<Player>,
+    probe_tablebases: bool,
+    // While verifying a null move cutoff, the player who played the null move can't play another
+    // until we're this many plies from the root
+    null_move_verification: Option<(Player, u8)>,
+    // The static eval of each position on the current branch, or None if the player was in check
+    static_evals: [Option<Eval>; MAX_SEARCH_DEPTH_SIZE],
     nodes_visited: u64,
     max_depth_reached: u8,
     tbhits: u64,
+    tt_probes: u64,
+    tt_hits: u64,
+    iterations: Vec<SearchIteration>,
 }
 
 impl<'s> SearchContext<'s> {
@@ -106,6 +183,8 @@ impl<'s> SearchContext<'s> {
         Self {
             tt: &mut persistent_state.tt,
             tablebase: &mut persistent_state.tablebase,
+            experience: &mut persistent_state.experience,
+            repertoire: &persistent_state.repertoire,
 
             history_table: &mut persistent_state.history_table,
 
@@ -117,22 +196,84 @@ impl<'s> SearchContext<'s> {
             killer_moves: KillersTable::new(),
             countermove_table: CountermoveTable::new(),
 
+            extensions: 0,
+            draw_avoider: None,
+            probe_tablebases: true,
+            null_move_verification: None,
+            static_evals: [None; MAX_SEARCH_DEPTH_SIZE],
             max_depth_reached: 0,
             nodes_visited: 0,
             tbhits: 0,
+            tt_probes: 0,
+            tt_hits: 0,
+            iterations: Vec::new(),
         }
     }
+
+    pub fn should_start_new_search(&self, depth: u8) -> bool {
+        if depth > 1 && self.node_limit_reached() {
+            return false;
+        }
+
+        self.time_control.should_start_new_search(depth)
+    }
+
+    // Whichever of the node limit and the time control is reached first stops the search
+    pub fn should_stop(&mut self) -> bool {
+        self.node_limit_reached() || self.time_control.should_stop(self.nodes_visited)
+    }
+
+    fn node_limit_reached(&self) -> bool {
+        self.search_restrictions
+            .nodes
+            .is_some_and(|nodes| self.nodes_visited >= nodes)
+    }
+
+    pub fn is_draw_by_repetition(&self, game: &Game, plies: u8) -> bool {
+        let recent_plies = if self.options.twofold_repetition {
+            usize::from(plies)
+        } else {
+            0
+        };
+
+        game.is_draw_by_repetition(recent_plies)
+    }
+
+    pub fn draw_score(&self, game: &Game) -> Eval {
+        if !self.options.draw_dither {
+            return Eval::DRAW;
+        }
+
+        // Vary the draw score very slightly between nodes, so that the search doesn't consider
+        // every drawing line to be equal and keep picking the same repetition.
+        let dither = if self.nodes_visited & 0x2 == 0 {
+            Eval(-1)
+        } else {
+            Eval(1)
+        };
+
+        let avoidance = match self.draw_avoider {
+            Some(player) if player == game.player => -params::DRAW_AVOIDANCE_PENALTY,
+            Some(_) => params::DRAW_AVOIDANCE_PENALTY,
+            None => Eval::DRAW,
+        };
+
+        dither + avoidance
+    }
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum SearchScore {
     Centipawns(i16),
     Mate(i16),
 }
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct SearchRestrictions {
     pub depth: Option<u8>,
+    pub nodes: Option<u64>,
+    // Root moves that won't be searched, e.g. to find the next best move once we know the best
+    pub excluded_moves: Vec<Move>,
 }
 
 #[derive(Debug, Clone)]
@@ -149,8 +290,11 @@ pub struct Clocks {
     pub white_increment: Option<Duration>,
     pub black_increment: Option<Duration>,
     pub moves_to_go: Option<u32>,
+    // A 'movetime' sent alongside the clocks caps the time we'll use
+    pub move_time: Option<Duration>,
 }
 
+#[derive(Clone)]
 pub struct SearchInfo {
     pub depth: u8,
     pub seldepth: u8,
@@ -160,19 +304,85 @@ pub struct SearchInfo {
     pub hashfull: usize,
 }
 
+#[derive(Debug, Clone)]
 pub struct SearchStats {
     pub time: Duration,
     pub nodes: u64,
     pub nodes_per_second: u64,
     pub tbhits: u64,
+    // How many of the tablebase hits were answered from the tablebase cache
+    pub tb_cache_hits: u64,
+}
+
+// The time and node counts are totals since the start of the search, not just for the iteration
+#[derive(Debug, Clone)]
+pub struct SearchIteration {
+    pub depth: u8,
+    pub time: Duration,
+    pub nodes: u64,
 }
 
-pub trait Reporter {
+pub struct SearchResult {
+    pub best_move: Move,
+    pub score: SearchScore,
+    pub pv: PrincipalVariation,
+    pub stats: SearchStats,
+
+    // Each iterative deepening iteration that completed, in order of depth
+    pub iterations: Vec<SearchIteration>,
+}
+
+impl SearchResult {
+    // How many times more nodes the last iteration needed than the one before it
+    #[expect(clippy::cast_precision_loss, reason = "This is only an estimate")]
+    pub fn branching_factor(&self) -> Option<f32> {
+        let [.., before_previous, previous, last] = self.iterations.as_slice() else {
+            return None;
+        };
+
+        let last_nodes = last.nodes - previous.nodes;
+        let previous_nodes = previous.nodes - before_previous.nodes;
+
+        if previous_nodes == 0 {
+            return None;
+        }
+
+        Some(last_nodes as f32 / previous_nodes as f32)
+    }
+
+    // The branching factor of a uniform tree as deep as the last iteration with as many nodes as
+    // the whole search, i.e. how much each extra ply cost on average
+    #[expect(clippy::cast_precision_loss, reason = "This is only an estimate")]
+    pub fn effective_branching_factor(&self) -> Option<f32> {
+        let last = self.iterations.last()?;
+
+        if last.depth == 0 {
+            return None;
+        }
+
+        Some((last.nodes as f32).powf(1.0 / f32::from(last.depth)))
+    }
+}
+
+// Reporters only take `&self`, so that a single reporter can be shared between several search
+// threads and forward what they find to one place. Any state a reporter keeps between calls needs
+// to be behind a lock or an atomic.
+pub trait Reporter: Send + Sync {
     fn generic_report(&self, s: &str);
 
-    fn report_search_progress(&mut self, game: &Game, progress: SearchInfo);
+    fn report_search_progress(&self, game: &Game, progress: SearchInfo);
 
     fn best_move(&self, game: &Game, mv: Move);
+
+    // Called once a search started by `Engine::start_search` has finished, on the search thread
+    fn search_complete(
+        &self,
+        game: &Game,
+        _persistent_state: &PersistentState,
+        result: &SearchResult,
+    ) {
+        self.best_move(game, result.best_move);
+    }
 }
 
 pub struct NullReporter;
@@ -180,44 +390,116 @@ pub struct NullReporter;
 impl Reporter for NullReporter {
     fn generic_report(&self, _: &str) {}
 
-    fn report_search_progress(&mut self, _: &Game, _: SearchInfo) {}
+    fn report_search_progress(&self, _: &Game, _: SearchInfo) {}
 
     fn best_move(&self, _: &Game, _: Move) {}
 }
 
-pub struct CapturingReporter {
-    pub score: Option<SearchScore>,
-    pub nodes: u64,
+// Forwards what several search threads report on the same position to a single reporter (e.g. the
+// UCI one), one call at a time so that their output isn't interleaved. Progress is only forwarded
+// when it's deeper than anything forwarded so far, and only the first best move (or completed
+// search) is forwarded, so the GUI sees one search and gets one 'bestmove'.
+pub struct AggregatingReporter<R: Reporter> {
+    inner: R,
+    state: Mutex<AggregatorState>,
 }
 
-impl CapturingReporter {
-    pub fn new() -> Self {
+#[derive(Default)]
+struct AggregatorState {
+    depth: Option<u8>,
+    best_move_sent: bool,
+}
+
+impl<R: Reporter> AggregatingReporter<R> {
+    pub fn new(inner: R) -> Self {
         Self {
-            score: None,
-            nodes: 0,
+            inner,
+            state: Mutex::new(AggregatorState::default()),
         }
     }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    fn state(&self) -> MutexGuard<'_, AggregatorState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
 }
 
-impl Reporter for CapturingReporter {
-    fn generic_report(&self, _: &str) {}
+impl<R: Reporter> Reporter for AggregatingReporter<R> {
+    fn generic_report(&self, s: &str) {
+        let _state = self.state();
+        self.inner.generic_report(s);
+    }
+
+    fn report_search_progress(&self, game: &Game, progress: SearchInfo) {
+        let mut state = self.state();
 
-    fn report_search_progress(&mut self, _: &Game, stats: SearchInfo) {
-        self.score = Some(stats.score);
-        self.nodes = stats.stats.nodes;
+        if state.depth.is_some_and(|depth| progress.depth <= depth) {
+            return;
+        }
+
+        state.depth = Some(progress.depth);
+        self.inner.report_search_progress(game, progress);
     }
 
-    fn best_move(&self, _: &Game, _: Move) {}
+    fn best_move(&self, game: &Game, mv: Move) {
+        let mut state = self.state();
+
+        if !std::mem::replace(&mut state.best_move_sent, true) {
+            self.inner.best_move(game, mv);
+        }
+    }
+
+    fn search_complete(
+        &self,
+        game: &Game,
+        persistent_state: &PersistentState,
+        result: &SearchResult,
+    ) {
+        let mut state = self.state();
+
+        if !std::mem::replace(&mut state.best_move_sent, true) {
+            self.inner.search_complete(game, persistent_state, result);
+        }
+    }
 }
 
+// The search is done on the given game, which is back in the root position once it returns
 pub fn search(
-    game: &Game,
+    game: &mut Game,
     persistent_state: &mut PersistentState,
     time_strategy: &mut TimeStrategy,
     search_restrictions: &SearchRestrictions,
     options: &EngineOptions,
-    reporter: &mut impl Reporter,
-) -> Move {
+    reporter: &impl Reporter,
+) -> SearchResult {
+    // When analysing, we still search tablebase positions so that we report real lines. The
+    // tablebase is still probed within the search, so the scores will reflect the TB result.
+    let tablebase_moves = if options.analyse_mode || !search_restrictions.excluded_moves.is_empty()
+    {
+        None
+    } else {
+        persistent_state.tablebase.preserving_root_moves(game)
+    };
+
+    // Rather than playing the move with the best DTZ, which can be an unnatural move, we search
+    // the moves that keep the tablebase result and let the search pick the most practical one
+    let tablebase_restrictions;
+    let search_restrictions = match &tablebase_moves {
+        Some(moves) if moves.len() > 1 => {
+            let mut restrictions = search_restrictions.clone();
+            restrictions
+                .excluded_moves
+                .extend(game.moves().into_iter().filter(|mv| !moves.contains(mv)));
+
+            tablebase_restrictions = restrictions;
+            &tablebase_restrictions
+        }
+        _ => search_restrictions,
+    };
+
     let mut ctx = SearchContext::new(
         persistent_state,
         time_strategy,
@@ -225,68 +507,272 @@ pub fn search(
         search_restrictions,
     );
 
+    // The moves we're searching all have the same tablebase result, so probing within the
+    // search would give them all the same score
+    if tablebase_moves.is_some() {
+        ctx.probe_tablebases = false;
+    }
+
     ctx.tt.new_generation();
-    ctx.history_table.decay(params::HISTORY_DECAY_FACTOR);
+    ctx.tablebase.reset_stats();
+    ctx.experience.seed(game, ctx.tt);
+
+    trace!(SearchStarted { fen: game.to_fen() });
+
+    // If one side is clearly ahead, they should try to avoid drawing lines. When analysing, we
+    // want draws to be scored as draws.
+    let root_eval = eval::eval(game);
+    if !options.analyse_mode {
+        if root_eval > params::DRAW_AVOIDANCE_THRESHOLD {
+            ctx.draw_avoider = Some(game.player);
+        } else if root_eval < -params::DRAW_AVOIDANCE_THRESHOLD {
+            ctx.draw_avoider = Some(game.player.other());
+        }
+    }
 
     let mut pv = PrincipalVariation::new();
 
-    let tablebase_result = ctx.tablebase.best_move(game);
-    if let Some(mv) = tablebase_result {
-        let (pv, score) = get_tablebase_pv(game, &ctx);
+    // With only one move that keeps the result, there's nothing to search
+    if let Some(&[mv]) = tablebase_moves.as_deref() {
+        let (pv, score) = get_tablebase_pv(game, &mut ctx);
 
         let depth = pv.len();
 
-        reporter.report_search_progress(
-            game,
-            SearchInfo {
-                depth,
-                seldepth: depth,
-                score,
-                pv,
-                hashfull: persistent_state.tt.occupancy(),
-                stats: SearchStats {
-                    time: time_strategy.elapsed(),
-                    nodes: u64::from(depth),
-                    nodes_per_second: util::metrics::nodes_per_second(
-                        u64::from(depth),
-                        time_strategy.elapsed(),
-                    ),
-                    tbhits: 1,
-                },
+        let info = SearchInfo {
+            depth,
+            seldepth: depth,
+            score,
+            pv,
+            hashfull: ctx.tt.occupancy(),
+            stats: SearchStats {
+                time: ctx.time_control.elapsed(),
+                nodes: u64::from(depth),
+                nodes_per_second: util::metrics::nodes_per_second(
+                    u64::from(depth),
+                    ctx.time_control.elapsed(),
+                ),
+                tbhits: 1,
+                tb_cache_hits: ctx.tablebase.cache_hits(),
             },
-        );
+        };
+
+        reporter.report_search_progress(game, info.clone());
 
-        return mv;
+        return SearchResult {
+            best_move: mv,
+            score: info.score,
+            pv: info.pv,
+            stats: info.stats,
+            iterations: Vec::new(),
+        };
     }
 
-    iterative_deepening::search(
-        // Give the search its own copy of the game so we don't get one returned in a dirty state
-        // when the search aborts.
-        &mut game.clone(),
-        &mut ctx,
-        &mut pv,
-        reporter,
-    );
+    // A panic during the search would take the whole engine down and lose the game on time, so
+    // we catch it and carry on with the best move we have. The PV is only replaced once an
+    // iteration completes, so it's still usable.
+    let root_history_len = game.history.len();
+    let root_fen = game.to_fen();
+
+    let search_result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        iterative_deepening::search(game, &mut ctx, &mut pv, reporter)
+    }));
+
+    let last_info = match search_result {
+        Ok(last_info) => {
+            // An aborted search returns straight away, leaving its moves on the board
+            undo_to_root(game, root_history_len);
+            last_info
+        }
+        Err(panic) => {
+            reporter.generic_report(&format!(
+                "info string Search panicked: {}",
+                panic_message(panic.as_ref())
+            ));
+
+            // We could have panicked halfway through making a move, so we can't rely on being
+            // able to undo our way back to the root
+            rebuild_root(game, &root_fen, root_history_len);
+            None
+        }
+    };
+
+    ctx.tt.record_probes(ctx.tt_probes, ctx.tt_hits);
+
+    let best_move = pv
+        .first()
+        .copied()
+        .unwrap_or_else(|| panic_move(game, &ctx));
+    let iterations = std::mem::take(&mut ctx.iterations);
+
+    if let Some(info) = last_info {
+        ctx.experience.record(game, ctx.tt);
+
+        return SearchResult {
+            best_move,
+            score: info.score,
+            pv: info.pv,
+            stats: info.stats,
+            iterations,
+        };
+    }
 
-    let best_move = pv.first().copied();
+    // We didn't manage to complete a single iteration, so all we have to go on is the static eval
+    let mut pv = PrincipalVariation::new();
+    pv.append(best_move);
 
-    best_move.unwrap_or_else(|| panic_move(game, &ctx))
+    SearchResult {
+        best_move,
+        score: SearchScore::Centipawns(root_eval.to_centipawns_clamped()),
+        pv,
+        stats: SearchStats {
+            time: ctx.time_control.elapsed(),
+            nodes: ctx.nodes_visited,
+            nodes_per_second: util::metrics::nodes_per_second(
+                ctx.nodes_visited,
+                ctx.time_control.elapsed(),
+            ),
+            tbhits: ctx.tbhits,
+            tb_cache_hits: ctx.tablebase.cache_hits(),
+        },
+        iterations,
+    }
 }
 
 pub fn init() {
     tables::init();
 }
 
+// The static eval doesn't know about the fifty move rule, so once the halfmove clock passes a
+// threshold we scale it linearly towards a draw. This encourages the engine to make progress
+// (with a capture or pawn move) long before the rule actually applies.
+pub fn fifty_move_scaled_eval(eval: Eval, halfmove_clock: u32) -> Eval {
+    if halfmove_clock <= params::FIFTY_MOVE_SCALING_START {
+        return eval;
+    }
+
+    let remaining = i64::from(100_u32.saturating_sub(halfmove_clock));
+    let scaling_range = i64::from(100 - params::FIFTY_MOVE_SCALING_START);
+    let scaled = i64::from(eval.0) * remaining / scaling_range;
+
+    Eval(i16::try_from(scaled).unwrap())
+}
+
+pub struct QuiescenceResult {
+    pub static_eval: Eval,
+    pub score: Eval,
+    pub line: PrincipalVariation,
+    pub nodes: u64,
+}
+
+// Runs only the quiescence search from the given position, for 'd qsearch'. It gets a TT of its
+// own, so nothing from earlier searches affects the result, and the line of captures it settled on
+// can be read back from the TT afterwards.
+pub fn quiescence_only(game: &Game, options: &EngineOptions) -> QuiescenceResult {
+    let mut game = game.clone();
+    let mut persistent_state = PersistentState::new(1);
+    let (mut time_strategy, _) = TimeStrategy::new(&game, &TimeControl::Infinite, options);
+    let search_restrictions = SearchRestrictions::default();
+
+    let mut ctx = SearchContext::new(
+        &mut persistent_state,
+        &mut time_strategy,
+        options,
+        &search_restrictions,
+    );
+
+    let score = quiescence::quiescence(&mut game, Eval::MIN, Eval::MAX, 0, &mut ctx)
+        .expect("An infinite search should never be stopped");
+    let nodes = ctx.nodes_visited;
+
+    QuiescenceResult {
+        static_eval: eval::eval(&game),
+        score,
+        line: persistent_state.tt.line_from(&mut game, MAX_SEARCH_DEPTH),
+        nodes,
+    }
+}
+
+// Reconstruct the lines that refute each root move other than the best move, using the
+// results of the previous search stored in the TT. Each line starts with the refuted move.
+pub fn refutations(
+    game: &Game,
+    persistent_state: &PersistentState,
+    best_move: Move,
+) -> Vec<PrincipalVariation> {
+    let mut refutations = Vec::new();
+    let mut game = game.clone();
+
+    for mv in game.moves() {
+        if mv == best_move {
+            continue;
+        }
+
+        game.make_move(mv);
+        let refutation_line = persistent_state
+            .tt
+            .line_from(&mut game, params::REFUTATION_LINE_LENGTH);
+        game.undo_move();
+
+        if !refutation_line.is_empty() {
+            let mut refutation = PrincipalVariation::new();
+            refutation.push(mv, &refutation_line);
+            refutations.push(refutation);
+        }
+    }
+
+    refutations
+}
+
+// Undo the moves (including null moves) that the search left on the board
+fn undo_to_root(game: &mut Game, root_history_len: usize) {
+    while game.history.len() > root_history_len {
+        if game.history.last().is_some_and(|h| h.mv.is_some()) {
+            game.undo_move();
+        } else {
+            game.undo_null_move();
+        }
+    }
+}
+
+// The history before the root is never touched by the search, so we can keep it for repetition
+// detection in later searches
+fn rebuild_root(game: &mut Game, root_fen: &str, root_history_len: usize) {
+    let mut history = std::mem::take(&mut game.history);
+    let mut repetition_keys = std::mem::take(&mut game.repetition_keys);
+    history.truncate(root_history_len);
+    repetition_keys.truncate(root_history_len);
+
+    *game = Game::from_fen(root_fen).unwrap();
+    game.history = history;
+    game.repetition_keys = repetition_keys;
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        return message;
+    }
+
+    panic
+        .downcast_ref::<String>()
+        .map_or("Unknown panic", String::as_str)
+}
+
 // If we have so little time to search that we couldn't determine a best move, we'll need to spend
 // a bit of extra time so that we still make a move.
 // Rather than returning a random move, we return the first move that is returned after move ordering
 fn panic_move(game: &Game, ctx: &SearchContext<'_>) -> Move {
     let mut move_picker = MovePicker::new(None);
 
-    move_picker.next(game, ctx, 0).unwrap()
+    loop {
+        let mv = move_picker.next(game, ctx, 0).unwrap();
+
+        if !ctx.search_restrictions.excluded_moves.contains(&mv) {
+            return mv;
+        }
+    }
 }
 
-fn get_tablebase_pv(game: &Game, ctx: &SearchContext<'_>) -> (PrincipalVariation, SearchScore) {
+fn get_tablebase_pv(game: &Game, ctx: &mut SearchContext<'_>) -> (PrincipalVariation, SearchScore) {
     let mut game = game.clone();
     let player = game.player;
 
@@ -343,3 +829,21 @@ fn get_tablebase_pv(game: &Game, ctx: &SearchContext<'_>) -> (PrincipalVariation
         }),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::square::squares::all::*;
+
+    #[test]
+    fn test_quiescence_only_follows_captures() {
+        crate::init();
+
+        let game = Game::from_fen("4k3/8/8/3n4/4P3/8/8/4K3 w - - 0 1").unwrap();
+        let result = quiescence_only(&game, &EngineOptions::default());
+
+        assert_eq!(result.line.first(), Some(&Move::capture(E4, D5)));
+        assert!(result.score > result.static_eval);
+        assert!(result.nodes > 1);
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::chess::game::Game;
+use crate::chess::player::Player;
+use crate::engine::search::{params, Clocks};
+
+// Our clock as the GUI told us it was at the start of one of our moves
+struct TimedMove {
+    player: Player,
+    plies: u32,
+    clock: Duration,
+    increment: Duration,
+    moves_to_go: Option<u32>,
+    // How long it took between us receiving 'go' and sending 'bestmove'
+    time_used: Option<Duration>,
+}
+
+// Measures how much time the GUI takes off our clock beyond the time we spend between receiving
+// 'go' and sending 'bestmove', e.g. because moves are sent over a slow network. When we're told
+// our clock at the start of our next move, anything missing from it beyond the time we used (and
+// the increment we were given) was lost in transit.
+pub struct MoveOverheadCalibration {
+    last_move: Option<TimedMove>,
+    samples: VecDeque<Duration>,
+}
+
+impl Default for MoveOverheadCalibration {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MoveOverheadCalibration {
+    pub const fn new() -> Self {
+        Self {
+            last_move: None,
+            samples: VecDeque::new(),
+        }
+    }
+
+    // Called when we're asked to search. Searches without clocks can't be measured, and break up
+    // the sequence of moves that can be.
+    pub fn start_move(&mut self, game: &Game, clocks: Option<&Clocks>) {
+        let Some(clocks) = clocks else {
+            self.last_move = None;
+            return;
+        };
+
+        let (clock, increment) = match game.player {
+            Player::White => (clocks.white_clock, clocks.white_increment),
+            Player::Black => (clocks.black_clock, clocks.black_increment),
+        };
+
+        let Some(clock) = clock else {
+            self.last_move = None;
+            return;
+        };
+
+        let this_move = TimedMove {
+            player: game.player,
+            plies: game.plies,
+            clock,
+            increment: increment.unwrap_or_default(),
+            moves_to_go: clocks.moves_to_go,
+            time_used: None,
+        };
+
+        if let Some(lag) = self
+            .last_move
+            .as_ref()
+            .and_then(|last_move| Self::lag_since(last_move, &this_move))
+        {
+            if self.samples.len() == params::MOVE_OVERHEAD_CALIBRATION_SAMPLES {
+                self.samples.pop_front();
+            }
+            self.samples.push_back(lag);
+        }
+
+        self.last_move = Some(this_move);
+    }
+
+    // Called once we've sent 'bestmove'
+    pub fn finish_move(&mut self, time_used: Duration) {
+        if let Some(last_move) = self.last_move.as_mut() {
+            last_move.time_used = Some(time_used);
+        }
+    }
+
+    fn lag_since(last_move: &TimedMove, this_move: &TimedMove) -> Option<Duration> {
+        let time_used = last_move.time_used?;
+
+        // Only our very next move in the same game can be compared, and not across a time control
+        // where time gets added to the clock
+        let is_next_move =
+            this_move.player == last_move.player && this_move.plies == last_move.plies + 2;
+        let crossed_time_control = last_move.moves_to_go == Some(1);
+
+        if !is_next_move || crossed_time_control {
+            return None;
+        }
+
+        let expected_clock = (last_move.clock + last_move.increment).saturating_sub(time_used);
+        Some(expected_clock.saturating_sub(this_move.clock))
+    }
+
+    // The overhead to allow for, once we've seen enough moves to trust the measurements. We use
+    // the largest lag we've seen recently, as one slow move costs more than several fast ones
+    // save.
+    pub fn move_overhead(&self) -> Option<Duration> {
+        if self.samples.len() < params::MOVE_OVERHEAD_CALIBRATION_MIN_SAMPLES {
+            return None;
+        }
+
+        self.samples
+            .iter()
+            .max()
+            .map(|lag| (*lag).min(params::MAX_CALIBRATED_MOVE_OVERHEAD))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clocks(white_ms: u64, increment_ms: u64) -> Clocks {
+        Clocks {
+            white_clock: Some(Duration::from_millis(white_ms)),
+            black_clock: Some(Duration::from_secs(10)),
+            white_increment: Some(Duration::from_millis(increment_ms)),
+            black_increment: Some(Duration::from_millis(increment_ms)),
+            moves_to_go: None,
+            move_time: None,
+        }
+    }
+
+    #[test]
+    fn test_measures_lag() {
+        crate::init();
+
+        let mut calibration = MoveOverheadCalibration::new();
+        let mut game = Game::new();
+        let mut white_clock = 10_000;
+
+        for lag in [30, 50, 40] {
+            calibration.start_move(&game, Some(&clocks(white_clock, 100)));
+            assert!(calibration.move_overhead().is_none());
+
+            calibration.finish_move(Duration::from_millis(500));
+            white_clock = white_clock + 100 - 500 - lag;
+
+            // The measurement only needs to know that it's our next move
+            game.plies += 2;
+        }
+
+        calibration.start_move(&game, Some(&clocks(white_clock, 100)));
+        assert_eq!(calibration.move_overhead(), Some(Duration::from_millis(50)));
+    }
+
+    #[test]
+    fn test_searches_without_clocks_are_not_measured() {
+        crate::init();
+
+        let mut calibration = MoveOverheadCalibration::new();
+        let mut game = Game::new();
+
+        for _ in 0..5 {
+            calibration.start_move(&game, Some(&clocks(10_000, 0)));
+            calibration.finish_move(Duration::from_millis(100));
+            game.plies += 2;
+
+            calibration.start_move(&game, None);
+            calibration.finish_move(Duration::from_millis(100));
+            game.plies += 2;
+        }
+
+        assert!(calibration.move_overhead().is_none());
+    }
+}
@@ -7,6 +7,78 @@ use crate::engine::search::{move_ordering, SearchContext};
 
 const MAX_MOVES: usize = u8::MAX as usize;
 
+// Where a move came from in the move picker, and the score it was ordered by (if any), so that
+// move ordering can be inspected with 'd moves'
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum PickedFrom {
+    BestMove,
+    GoodCapture(i32),
+    BadCapture(i32),
+    Killer1,
+    Killer2,
+    CounterMove,
+    Quiet(i32),
+}
+
+impl std::fmt::Display for PickedFrom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BestMove => write!(f, "best move"),
+            Self::GoodCapture(score) => write!(f, "good capture  {score}"),
+            Self::BadCapture(score) => write!(f, "bad capture   {score}"),
+            Self::Killer1 => write!(f, "killer 1"),
+            Self::Killer2 => write!(f, "killer 2"),
+            Self::CounterMove => write!(f, "counter move"),
+            Self::Quiet(score) => write!(f, "quiet         {score}"),
+        }
+    }
+}
+
+// Runs a move picker to completion, returning the moves in the order they were picked along with
+// the stage each one came from. This mirrors the stages in `MovePicker::next` rather than being
+// part of it, so that the search doesn't pay for keeping track.
+pub fn picked_moves(
+    game: &Game,
+    ctx: &SearchContext<'_>,
+    plies: u8,
+    previous_best_move: Option<Move>,
+) -> Vec<(Move, PickedFrom)> {
+    let mut tactical_moves = MoveList::new();
+    movegen::generate_captures(game, &mut tactical_moves, &mut MovegenCache::new());
+
+    let previous_move = game.history.last().and_then(|h| h.mv);
+    let counter_move = previous_move.and_then(|mv| ctx.countermove_table.get(game.player, mv));
+
+    let mut move_picker = MovePicker::new(previous_best_move);
+    let mut moves = Vec::new();
+
+    while let Some(mv) = move_picker.next(game, ctx, plies) {
+        let picked_from = if Some(mv) == previous_best_move {
+            PickedFrom::BestMove
+        } else if tactical_moves.contains(&mv) {
+            let score = score_tactical(game, mv);
+
+            if score >= move_ordering::GOOD_CAPTURE_SCORE {
+                PickedFrom::GoodCapture(score)
+            } else {
+                PickedFrom::BadCapture(score)
+            }
+        } else if ctx.killer_moves.get_0(plies) == Some(mv) {
+            PickedFrom::Killer1
+        } else if ctx.killer_moves.get_1(plies) == Some(mv) {
+            PickedFrom::Killer2
+        } else if counter_move == Some(mv) {
+            PickedFrom::CounterMove
+        } else {
+            PickedFrom::Quiet(score_quiet(game, mv, ctx.history_table))
+        };
+
+        moves.push((mv, picked_from));
+    }
+
+    moves
+}
+
 #[derive(Eq, PartialEq)]
 enum GenStage {
     BestMove,
@@ -76,7 +148,13 @@ impl MovePicker {
             self.stage = GenCaptures;
 
             if let Some(previous_best_move) = self.previous_best_move {
-                return Some(previous_best_move);
+                // The best move comes from the TT, so it could be from a different position
+                // with the same hash. If so, we treat it as if we had no best move.
+                if game.is_legal(previous_best_move) {
+                    return Some(previous_best_move);
+                }
+
+                self.previous_best_move = None;
             }
         }
 
@@ -443,4 +521,37 @@ mod tests {
 
         assert_eq!(moves.len(), 4);
     }
+
+    #[test]
+    fn test_picked_moves() {
+        crate::init();
+
+        let game =
+            Game::from_fen("rnbqkb1r/ppp1pppp/5n2/3p4/4P3/2N5/PPPP1PPP/R1BQKBNR w KQkq - 0 3")
+                .unwrap();
+
+        let mut persistent_state = PersistentState::new(16);
+        let options = EngineOptions::default();
+        let (mut time_strategy, _) = TimeStrategy::new(&game, &TimeControl::Infinite, &options);
+        let search_restrictions = SearchRestrictions::default();
+        let mut ctx = SearchContext::new(
+            &mut persistent_state,
+            &mut time_strategy,
+            &options,
+            &search_restrictions,
+        );
+
+        ctx.killer_moves.try_push(0, Move::quiet(G1, F3));
+
+        let moves = picked_moves(&game, &ctx, 0, Some(Move::quiet(D2, D4)));
+
+        assert_eq!(moves.len(), 33);
+        assert_eq!(moves[0], (Move::quiet(D2, D4), PickedFrom::BestMove));
+        assert!(matches!(moves[1].1, PickedFrom::GoodCapture(_)));
+        assert!(moves.contains(&(Move::quiet(G1, F3), PickedFrom::Killer1)));
+        assert!(moves
+            .iter()
+            .skip_while(|(_, picked_from)| !matches!(picked_from, PickedFrom::Quiet(_)))
+            .all(|(_, picked_from)| matches!(picked_from, PickedFrom::Quiet(_))));
+    }
 }
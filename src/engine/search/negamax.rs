@@ -1,13 +1,15 @@
-use super::{params, SearchContext, MAX_SEARCH_DEPTH};
+use super::{fifty_move_scaled_eval, params, SearchContext, MAX_SEARCH_DEPTH};
 use crate::chess::game::Game;
-use crate::chess::moves::Move;
+use crate::chess::moves::MoveList;
+use crate::chess::piece::PieceKind;
+use crate::chess::square::Rank;
 use crate::engine::eval;
 use crate::engine::eval::Eval;
 use crate::engine::search::move_picker::MovePicker;
 use crate::engine::search::principal_variation::PrincipalVariation;
 use crate::engine::search::quiescence::quiescence;
 use crate::engine::search::tables::lmr_table::lmr_reduction;
-use crate::engine::search::transposition::{NodeBound, SearchTranspositionTableData};
+use crate::engine::search::transposition::{BoundAndAge, NodeBound, SearchTranspositionTableData};
 use crate::engine::tablebases::Wdl;
 use std::cmp::max;
 
@@ -31,6 +33,24 @@ impl DepthReduction {
     }
 }
 
+// Depths for child nodes are worked out as i16 so that extensions and reductions can't overflow
+// (e.g. an extension when searching to the maximum depth), then clamped to a depth we can search
+fn clamp_depth(depth: i16) -> u8 {
+    u8::try_from(depth.clamp(0, i16::from(MAX_SEARCH_DEPTH))).unwrap()
+}
+
+// The margin grows with depth, since the deeper the search, the further the real score could end
+// up from the static eval
+fn reverse_futility_margin(depth: u8, improving: bool) -> Eval {
+    let margin = params::REVERSE_FUTILITY_PRUNE_MARGIN_PER_PLY * i16::from(depth);
+
+    if improving {
+        margin - params::REVERSE_FUTILITY_PRUNE_IMPROVING_MARGIN
+    } else {
+        margin
+    }
+}
+
 pub fn negamax(
     game: &mut Game,
     mut alpha: Eval,
@@ -45,25 +65,31 @@ pub fn negamax(
 
     // Check periodically to see if we're out of time. If we are, we shouldn't continue the search
     // so we return Err to signal to the caller that the search did not complete.
-    if ctx.time_control.should_stop(ctx.nodes_visited) {
+    if ctx.should_stop() {
         return Err(());
     }
 
     ctx.max_depth_reached = ctx.max_depth_reached.max(plies);
 
+    if plies == MAX_SEARCH_DEPTH {
+        return Ok(eval::eval(game));
+    }
+
     if !is_root
-        && (game.is_repeated_position()
+        && (ctx.is_draw_by_repetition(game, plies)
             || game.is_stalemate_by_fifty_move_rule()
             || game.is_stalemate_by_insufficient_material())
     {
-        return Ok(Eval::DRAW);
+        return Ok(ctx.draw_score(game));
     }
 
-    // Check extension: If we're about to finish searching, but we are in check, we
-    // should keep going.
+    // If we're about to finish searching, but we are in check, we should keep going:
+    // quiescence search doesn't consider evasions, so we'd get a meaningless score.
+    // Moves that give check are normally extended before we get here, so this only applies
+    // once we've run out of extensions on this branch.
     let in_check = game.is_king_in_check();
-    if in_check && depth < MAX_SEARCH_DEPTH {
-        depth += 1;
+    if in_check && depth == 0 {
+        depth = 1;
     }
 
     if depth == 0 {
@@ -74,24 +100,64 @@ pub fn negamax(
         ctx.nodes_visited += 1;
     }
 
-    let mut previous_best_move: Option<Move> = None;
-
-    if let Some(tt_entry) = ctx.tt.get(&game.zobrist) {
-        if !is_root && !is_pv && tt_entry.depth >= depth {
+    trace!(Node {
+        plies,
+        depth,
+        alpha,
+        beta,
+        hash: game.zobrist.0
+    });
+
+    let tt_entry = ctx.tt.get(&game.zobrist);
+    ctx.tt_probes += 1;
+    ctx.tt_hits += u64::from(tt_entry.is_some());
+
+    let (previous_best_move, tt_static_eval) = if let Some(tt_entry) = tt_entry {
+        // Scores in the TT don't know how close we are to a draw by the fifty move rule,
+        // so once we get close we can't trust them to cut off the search.
+        if !is_root
+            && !is_pv
+            && tt_entry.depth >= depth
+            && game.halfmove_clock < params::TT_CUTOFF_HALFMOVE_CLOCK_LIMIT
+        {
             let tt_score = tt_entry.eval.with_mate_distance_from_root(plies);
 
-            match tt_entry.bound {
-                NodeBound::Exact => return Ok(tt_score),
-                NodeBound::Upper if tt_entry.eval <= alpha => return Ok(tt_score),
-                NodeBound::Lower if tt_entry.eval >= beta => return Ok(tt_score),
-                _ => {}
+            let is_cutoff = match tt_entry.bound() {
+                NodeBound::Exact => true,
+                NodeBound::Upper => tt_entry.eval <= alpha,
+                NodeBound::Lower => tt_entry.eval >= beta,
+            };
+
+            if is_cutoff {
+                trace!(TtCutoff {
+                    plies,
+                    score: tt_score
+                });
+                return Ok(tt_score);
             }
         }
 
-        previous_best_move = tt_entry.best_move;
-    }
+        (tt_entry.best_move, Some(tt_entry.static_eval))
+    } else {
+        (None, None)
+    };
 
-    let tb_cardinality = ctx.tablebase.n_men();
+    // The static eval stored in the TT is independent of the halfmove clock, so we scale it after
+    // looking it up
+    let static_eval = tt_static_eval.unwrap_or_else(|| eval::eval(game));
+    let eval = fifty_move_scaled_eval(static_eval, game.halfmove_clock);
+
+    // We're 'improving' if our eval is better than it was on our previous move. If so, we can be
+    // more confident that a high eval will hold up.
+    ctx.static_evals[usize::from(plies)] = if in_check { None } else { Some(eval) };
+    let improving = plies >= 2
+        && ctx.static_evals[usize::from(plies - 2)].is_some_and(|previous| eval > previous);
+
+    let tb_cardinality = if ctx.probe_tablebases {
+        ctx.tablebase.n_men()
+    } else {
+        0
+    };
     if !is_root && tb_cardinality > 0 {
         let piece_count = game.board.occupancy().count();
 
@@ -116,10 +182,10 @@ pub fn negamax(
                     || (tb_bound == NodeBound::Upper && score <= alpha)
                 {
                     let tt_data = SearchTranspositionTableData {
-                        bound: tb_bound,
+                        bound_and_age: BoundAndAge::new(tb_bound, ctx.tt.generation),
                         eval: score,
+                        static_eval,
                         best_move: None,
-                        age: ctx.tt.generation,
                         depth,
                     };
 
@@ -135,13 +201,12 @@ pub fn negamax(
         }
     }
 
-    let eval = eval::eval(game);
-
     if !is_root && !is_pv && !in_check {
         // Reverse futility pruning
         if depth <= params::REVERSE_FUTILITY_PRUNE_DEPTH
-            && eval - params::REVERSE_FUTILITY_PRUNE_MARGIN_PER_PLY * i16::from(depth) > beta
+            && eval - reverse_futility_margin(depth, improving) > beta
         {
+            trace!(ReverseFutilityPrune { plies, eval });
             return Ok(beta);
         }
 
@@ -150,14 +215,25 @@ pub fn negamax(
             && eval >= beta
             // Don't let a player play a null move in response to a null move
             && game.history.last().map_or(true, |m| m.mv.is_some())
+            // Without pieces, we're likely to be in zugzwang, where passing would be better
+            // than any real move
+            && game.board.has_non_pawn_material(game.player)
+            && !matches!(
+                ctx.null_move_verification,
+                Some((player, min_plies)) if player == game.player && plies < min_plies
+            )
         {
+            let reduced_depth = clamp_depth(
+                i16::from(depth) - 1 - i16::from(params::NULL_MOVE_PRUNING_DEPTH_REDUCTION),
+            );
+
             game.make_null_move();
 
             let null_score = -negamax(
                 game,
                 -beta,
                 -beta + Eval(1),
-                depth - 1 - params::NULL_MOVE_PRUNING_DEPTH_REDUCTION,
+                reduced_depth,
                 plies + 1,
                 &mut PrincipalVariation::new(),
                 ctx,
@@ -166,7 +242,43 @@ pub fn negamax(
             game.undo_null_move();
 
             if null_score >= beta {
-                return Ok(null_score);
+                if depth < params::NULL_MOVE_VERIFICATION_DEPTH
+                    || ctx.null_move_verification.is_some()
+                {
+                    trace!(NullMovePrune {
+                        plies,
+                        score: null_score
+                    });
+                    return Ok(null_score);
+                }
+
+                // At high depths, a wrong cutoff (e.g. in zugzwang) throws away a lot of work, so
+                // we check it with a reduced search of our own moves in which we can't play
+                // another null move for a while
+                ctx.null_move_verification = Some((
+                    game.player,
+                    plies.saturating_add(reduced_depth - reduced_depth / 4),
+                ));
+
+                let verification_score = negamax(
+                    game,
+                    beta - Eval(1),
+                    beta,
+                    reduced_depth,
+                    plies,
+                    &mut PrincipalVariation::new(),
+                    ctx,
+                );
+
+                ctx.null_move_verification = None;
+
+                if verification_score? >= beta {
+                    trace!(NullMovePrune {
+                        plies,
+                        score: null_score
+                    });
+                    return Ok(null_score);
+                }
             }
         }
     }
@@ -175,11 +287,26 @@ pub fn negamax(
     let mut best_move = None;
     let mut best_eval = Eval::MIN;
 
+    // At the root, we prefer to stay inside the repertoire we've been given (if any) so we try
+    // those moves first if we don't have anything better to go on
+    let preferred_moves = if is_root {
+        ctx.repertoire.preferred_moves(game).to_vec()
+    } else {
+        Vec::new()
+    };
+
+    let previous_best_move = previous_best_move.or_else(|| preferred_moves.first().copied());
+
     let mut moves = MovePicker::new(previous_best_move);
     let mut number_of_legal_moves = 0;
     let mut node_pv = PrincipalVariation::new();
+    let mut quiets_tried = MoveList::new();
 
     while let Some(mv) = moves.next(game, ctx, plies) {
+        if is_root && ctx.search_restrictions.excluded_moves.contains(&mv) {
+            continue;
+        }
+
         node_pv.clear();
 
         // Futility pruning
@@ -190,14 +317,54 @@ pub fn negamax(
             && depth <= params::FUTILITY_PRUNE_DEPTH
             && eval + params::FUTILITY_PRUNE_MAX_MOVE_VALUE < alpha
         {
+            trace!(FutilityPrune { plies, mv });
             continue;
         }
 
+        let is_pawn_push_to_seventh = game.board.piece_at(mv.src()).unwrap().kind
+            == PieceKind::Pawn
+            && mv.dst().relative_for(game.player).rank() == Rank::R7;
+
         game.make_move(mv);
         number_of_legal_moves += 1;
 
-        let move_score = if number_of_legal_moves == 1 {
-            -negamax(game, -beta, -alpha, depth - 1, plies + 1, &mut node_pv, ctx)?
+        // Extensions: Search interesting moves a little deeper. We limit the number of
+        // extensions applied on a single branch so that the search can't explode.
+        let extension = if ctx.extensions >= params::MAX_EXTENSIONS_PER_BRANCH {
+            0
+        } else if game.is_king_in_check() {
+            params::CHECK_EXTENSION
+        } else if is_pawn_push_to_seventh {
+            params::PAWN_PUSH_TO_SEVENTH_EXTENSION
+        } else {
+            0
+        };
+
+        let new_depth = i16::from(depth) - 1 + i16::from(extension);
+        ctx.extensions += extension;
+
+        // Moves inside the repertoire get a small bonus. We shift the window we search the move
+        // with rather than just adding to its score, otherwise a move that failed low could
+        // look like it raised alpha.
+        let bonus = if preferred_moves.contains(&mv) {
+            params::PREFERRED_OPENINGS_BONUS
+        } else {
+            Eval(0)
+        };
+
+        let move_alpha = alpha - bonus;
+        let move_beta = beta - bonus;
+
+        let mut move_score = if number_of_legal_moves == 1 {
+            -negamax(
+                game,
+                -move_beta,
+                -move_alpha,
+                clamp_depth(new_depth),
+                plies + 1,
+                &mut node_pv,
+                ctx,
+            )?
         } else {
             let reduction = if depth >= params::LMR_DEPTH
                 && number_of_legal_moves >= params::LMR_MOVE_THRESHOLD
@@ -216,9 +383,9 @@ pub fn negamax(
             // We search them with a reduced window to prove that they are at least worse.
             let pvs_score = -negamax(
                 game,
-                -alpha - Eval(1),
-                -alpha,
-                depth.saturating_sub(reduction),
+                -move_alpha - Eval(1),
+                -move_alpha,
+                clamp_depth(new_depth + 1 - i16::from(reduction)),
                 plies + 1,
                 &mut node_pv,
                 ctx,
@@ -226,15 +393,34 @@ pub fn negamax(
 
             // Turns out the move we just searched could be better than our current PV, so we re-search
             // with the normal alpha/beta bounds.
-            if pvs_score > alpha && pvs_score < beta {
-                -negamax(game, -beta, -alpha, depth - 1, plies + 1, &mut node_pv, ctx)?
+            if pvs_score > move_alpha && pvs_score < move_beta {
+                -negamax(
+                    game,
+                    -move_beta,
+                    -move_alpha,
+                    clamp_depth(new_depth),
+                    plies + 1,
+                    &mut node_pv,
+                    ctx,
+                )?
             } else {
                 pvs_score
             }
         };
 
+        // Mate scores are exact distances to mate, so the bonus would turn them into nonsense
+        // (or push them past MATE)
+        if !move_score.is_mate_score() {
+            move_score += bonus;
+        }
+
+        ctx.extensions -= extension;
         game.undo_move();
 
+        if !mv.is_capture() {
+            quiets_tried.push(mv);
+        }
+
         if move_score > best_eval {
             best_move = Some(mv);
             best_eval = move_score;
@@ -275,18 +461,49 @@ pub fn negamax(
             }
 
             ctx.history_table.add_bonus_for(game.player, mv, depth);
+
+            // Any quiet moves we tried before this one failed to cause a cutoff, so we
+            // penalise them to try them later next time.
+            for quiet in quiets_tried.iter().filter(|&&q| q != mv) {
+                ctx.history_table.add_malus_for(game.player, *quiet, depth);
+            }
         }
     }
 
-    let tt_data = SearchTranspositionTableData {
-        bound: tt_node_bound,
-        eval: best_eval.with_mate_distance_from_position(plies),
-        best_move,
-        age: ctx.tt.generation,
-        depth,
-    };
+    // With some root moves excluded, the result isn't the real value of the root position, so
+    // we don't want it to replace what we know about the position in the TT
+    if !is_root || ctx.search_restrictions.excluded_moves.is_empty() {
+        let tt_data = SearchTranspositionTableData {
+            bound_and_age: BoundAndAge::new(tt_node_bound, ctx.tt.generation),
+            eval: best_eval.with_mate_distance_from_position(plies),
+            static_eval,
+            best_move,
+            depth,
+        };
 
-    ctx.tt.insert(&game.zobrist, tt_data);
+        ctx.tt.insert(&game.zobrist, tt_data);
+    }
 
     Ok(best_eval)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reverse_futility_margin() {
+        assert!(reverse_futility_margin(2, false) > reverse_futility_margin(1, false));
+        assert!(reverse_futility_margin(4, true) < reverse_futility_margin(4, false));
+    }
+
+    #[test]
+    fn test_clamp_depth() {
+        assert_eq!(clamp_depth(5), 5);
+        assert_eq!(clamp_depth(-2), 0);
+        assert_eq!(
+            clamp_depth(i16::from(MAX_SEARCH_DEPTH) + 1),
+            MAX_SEARCH_DEPTH
+        );
+    }
+}
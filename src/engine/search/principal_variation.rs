@@ -1,3 +1,4 @@
+use crate::chess::game::Game;
 use crate::chess::moves::Move;
 use crate::engine::search::MAX_SEARCH_DEPTH_SIZE;
 use arrayvec::ArrayVec;
@@ -5,6 +6,12 @@ use arrayvec::ArrayVec;
 #[derive(Clone)]
 pub struct PrincipalVariation(ArrayVec<Move, MAX_SEARCH_DEPTH_SIZE>);
 
+impl Default for PrincipalVariation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl PrincipalVariation {
     #[inline]
     pub const fn new() -> Self {
@@ -30,6 +37,11 @@ impl PrincipalVariation {
         self.0.push(mv);
     }
 
+    #[inline]
+    pub fn get(&self, idx: usize) -> Option<Move> {
+        self.0.get(idx).copied()
+    }
+
     #[inline]
     pub fn first(&self) -> Option<&Move> {
         self.0.first()
@@ -38,6 +50,34 @@ impl PrincipalVariation {
     pub fn len(&self) -> u8 {
         u8::try_from(self.0.len()).unwrap()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    // Replay the PV from the given position, dropping everything from the first illegal move
+    // onwards. Returns whether the whole PV was legal. The moves are undone afterwards, so the
+    // game is left as it was.
+    pub fn truncate_at_illegal_move(&mut self, game: &mut Game) -> bool {
+        let mut legal_moves = self.0.len();
+
+        for (i, mv) in self.0.iter().enumerate() {
+            if !game.moves().contains(mv) {
+                legal_moves = i;
+                break;
+            }
+
+            game.make_move(*mv);
+        }
+
+        for _ in 0..legal_moves {
+            game.undo_move();
+        }
+
+        let is_legal = legal_moves == self.0.len();
+        self.0.truncate(legal_moves);
+        is_legal
+    }
 }
 
 impl IntoIterator for PrincipalVariation {
@@ -78,4 +118,20 @@ mod tests {
         assert_eq!(pv_4.0.get(1).unwrap().src(), C1);
         assert_eq!(pv_4.0.get(2).unwrap().src(), A1);
     }
+
+    #[test]
+    fn test_illegal_moves_are_truncated() {
+        crate::init();
+        let mut game = Game::new();
+
+        let mut pv = PrincipalVariation::new();
+        pv.append(Move::quiet(E2, E4));
+        pv.append(Move::quiet(E7, E5));
+        pv.append(Move::quiet(E4, E5));
+        pv.append(Move::quiet(G1, F3));
+
+        assert!(!pv.truncate_at_illegal_move(&mut game));
+        assert_eq!(pv.len(), 2);
+        assert_eq!(game.zobrist, Game::new().zobrist);
+    }
 }
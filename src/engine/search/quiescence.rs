@@ -1,9 +1,12 @@
 use crate::chess::game::Game;
+use crate::chess::piece::PieceKind;
 use crate::engine::eval;
 use crate::engine::eval::Eval;
 use crate::engine::search::move_picker::MovePicker;
+use crate::engine::search::transposition::{BoundAndAge, NodeBound, SearchTranspositionTableData};
+use crate::engine::see::piece_value;
 
-use super::{SearchContext, MAX_SEARCH_DEPTH};
+use super::{fifty_move_scaled_eval, params, SearchContext, MAX_SEARCH_DEPTH};
 
 pub fn quiescence(
     game: &mut Game,
@@ -15,24 +18,63 @@ pub fn quiescence(
     ctx.max_depth_reached = ctx.max_depth_reached.max(plies);
     ctx.nodes_visited += 1;
 
+    trace!(QuiescenceNode {
+        plies,
+        alpha,
+        beta,
+        hash: game.zobrist.0
+    });
+
     if plies == MAX_SEARCH_DEPTH {
         return Ok(eval::eval(game));
     }
 
-    if game.is_repeated_position()
+    if ctx.is_draw_by_repetition(game, plies)
         || game.is_stalemate_by_fifty_move_rule()
         || game.is_stalemate_by_insufficient_material()
     {
-        return Ok(Eval::DRAW);
+        return Ok(ctx.draw_score(game));
     }
 
     // Check periodically to see if we're out of time. If we are, we shouldn't continue the search
     // so we return Err to signal to the caller that the search did not complete.
-    if ctx.time_control.should_stop(ctx.nodes_visited) {
+    if ctx.should_stop() {
         return Err(());
     }
 
-    let eval = eval::eval(game);
+    // Any result from the TT is at least as deep as a quiescence search, so we can use it
+    // unless we're close to a draw by the fifty move rule, which the stored score doesn't know about.
+    let tt_entry = ctx.tt.get(&game.zobrist);
+    ctx.tt_probes += 1;
+    ctx.tt_hits += u64::from(tt_entry.is_some());
+
+    let tt_static_eval = if let Some(tt_entry) = tt_entry {
+        if game.halfmove_clock < params::TT_CUTOFF_HALFMOVE_CLOCK_LIMIT {
+            let tt_score = tt_entry.eval.with_mate_distance_from_root(plies);
+
+            let is_cutoff = match tt_entry.bound() {
+                NodeBound::Exact => true,
+                NodeBound::Upper => tt_score <= alpha,
+                NodeBound::Lower => tt_score >= beta,
+            };
+
+            if is_cutoff {
+                trace!(TtCutoff {
+                    plies,
+                    score: tt_score
+                });
+                return Ok(tt_score);
+            }
+        }
+
+        Some(tt_entry.static_eval)
+    } else {
+        None
+    };
+
+    let original_alpha = alpha;
+    let static_eval = tt_static_eval.unwrap_or_else(|| eval::eval(game));
+    let eval = fifty_move_scaled_eval(static_eval, game.halfmove_clock);
 
     if eval >= beta {
         return Ok(eval);
@@ -43,9 +85,25 @@ pub fn quiescence(
     }
 
     let mut best_eval = eval;
+    let mut best_move = None;
 
     let mut moves = MovePicker::new_loud();
     while let Some(mv) = moves.next(game, ctx, plies) {
+        // Delta pruning: If winning the captured piece (plus a safety margin) still isn't
+        // enough to raise alpha, there's no point searching the capture.
+        if !mv.is_promotion() {
+            let captured_piece = if mv.is_en_passant() {
+                PieceKind::Pawn
+            } else {
+                game.board.piece_at(mv.dst()).unwrap().kind
+            };
+
+            if eval + piece_value(captured_piece) + params::DELTA_PRUNING_MARGIN < alpha {
+                trace!(DeltaPrune { plies, mv });
+                continue;
+            }
+        }
+
         game.make_move(mv);
 
         let move_score = -quiescence(game, -beta, -alpha, plies + 1, ctx)?;
@@ -54,6 +112,7 @@ pub fn quiescence(
 
         if move_score > best_eval {
             best_eval = move_score;
+            best_move = Some(mv);
         }
 
         // Cutoff: This move is so good that our opponent won't let it be played.
@@ -66,5 +125,23 @@ pub fn quiescence(
         }
     }
 
+    let tt_node_bound = if best_eval >= beta {
+        NodeBound::Lower
+    } else if best_eval > original_alpha {
+        NodeBound::Exact
+    } else {
+        NodeBound::Upper
+    };
+
+    let tt_data = SearchTranspositionTableData {
+        bound_and_age: BoundAndAge::new(tt_node_bound, ctx.tt.generation),
+        eval: best_eval.with_mate_distance_from_position(plies),
+        static_eval,
+        best_move,
+        depth: 0,
+    };
+
+    ctx.tt.insert(&game.zobrist, tt_data);
+
     Ok(best_eval)
 }
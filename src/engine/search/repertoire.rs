@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::chess::san;
+use crate::engine::search::params;
+
+// A set of opening lines we'd like to play, e.g. to practise against specific openings. Lines are
+// read from a file with one line per row, either as PGN movetext from the starting position:
+//
+//   1. e4 e5 2. Nf3 Nc6 3. Bb5
+//
+// or as an EPD position with the moves to play from it:
+//
+//   r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq - bm Bb5 Bc4;
+//
+// Moves can be given in SAN or UCI notation.
+pub struct Repertoire {
+    moves: HashMap<u64, Vec<Move>>,
+}
+
+impl Repertoire {
+    pub fn new() -> Self {
+        Self {
+            moves: HashMap::new(),
+        }
+    }
+
+    // An empty path clears the repertoire
+    pub fn set_path(&mut self, path: &str) -> Result<(), String> {
+        let mut repertoire = Self::new();
+
+        if !path.is_empty() && path != "<empty>" {
+            let contents =
+                std::fs::read_to_string(path).map_err(|e| format!("Unable to read {path}: {e}"))?;
+
+            for (line_number, line) in contents.lines().enumerate() {
+                repertoire
+                    .add_line(line)
+                    .map_err(|e| format!("{e} on line {}", line_number + 1))?;
+            }
+        }
+
+        *self = repertoire;
+        Ok(())
+    }
+
+    // The moves from this position that stay inside the repertoire. We only stick to the
+    // repertoire for the start of the game, so this is empty after the first few moves.
+    pub fn preferred_moves(&self, game: &Game) -> &[Move] {
+        if game.plies >= params::PREFERRED_OPENINGS_MAX_PLIES {
+            return &[];
+        }
+
+        self.moves.get(&game.zobrist.0).map_or(&[], Vec::as_slice)
+    }
+
+    fn add_line(&mut self, line: &str) -> Result<(), String> {
+        let line = line.trim();
+
+        // Skip blank lines, PGN tags and comments
+        if line.is_empty() || line.starts_with('[') || line.starts_with(';') {
+            return Ok(());
+        }
+
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+
+        if tokens[0].contains('/') {
+            return self.add_epd(&tokens);
+        }
+
+        let mut game = Game::new();
+
+        for token in tokens {
+            if is_move_number_or_result(token) {
+                continue;
+            }
+
+            // Move numbers can be attached to the move, e.g. '1.e4' or '1...e5'
+            let token = token.rsplit('.').next().unwrap_or_default();
+
+            if game.plies >= params::PREFERRED_OPENINGS_MAX_PLIES {
+                break;
+            }
+
+            let mv = find_move(&game, token).ok_or_else(|| format!("Illegal move {token}"))?;
+            self.add_move(&game, mv);
+            game.make_move(mv);
+        }
+
+        Ok(())
+    }
+
+    fn add_epd(&mut self, tokens: &[&str]) -> Result<(), String> {
+        if tokens.len() < 4 {
+            return Err("Invalid EPD".to_string());
+        }
+
+        let game = Game::from_fen(&tokens[..4].join(" "))?;
+        let operations = tokens[4..].join(" ");
+
+        for operation in operations.split(';') {
+            let mut operands = operation.split_whitespace();
+
+            if operands.next() != Some("bm") {
+                continue;
+            }
+
+            for token in operands {
+                let mv = find_move(&game, token).ok_or_else(|| format!("Illegal move {token}"))?;
+                self.add_move(&game, mv);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn add_move(&mut self, game: &Game, mv: Move) {
+        let moves = self.moves.entry(game.zobrist.0).or_default();
+
+        if !moves.contains(&mv) {
+            moves.push(mv);
+        }
+    }
+}
+
+fn is_move_number_or_result(token: &str) -> bool {
+    token.chars().all(|c| c.is_ascii_digit() || c == '.')
+        || ["1-0", "0-1", "1/2-1/2", "*"].contains(&token)
+}
+
+// Moves can be given in SAN or in UCI notation, e.g. in an EPD 'bm' operation
+fn find_move(game: &Game, token: &str) -> Option<Move> {
+    let token = match token.trim_end_matches(['+', '#', '!', '?']) {
+        // Castling is sometimes written with zeros rather than the letter O
+        "0-0" => san::KINGSIDE_CASTLE,
+        "0-0-0" => san::QUEENSIDE_CASTLE,
+        token => token,
+    };
+
+    san::parse_move(game, token).ok().or_else(|| {
+        game.moves()
+            .into_iter()
+            .find(|&mv| format!("{mv:?}") == token)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::moves::MoveListExt;
+    use crate::chess::square::squares::all::*;
+    use crate::engine::options::EngineOptions;
+    use crate::engine::search::time_control::TimeStrategy;
+    use crate::engine::search::{
+        search, NullReporter, PersistentState, SearchRestrictions, SearchScore, TimeControl,
+    };
+
+    #[test]
+    fn test_pgn_lines() {
+        crate::init();
+
+        let mut repertoire = Repertoire::new();
+        repertoire.add_line("1. e4 e5 2.Nf3 Nc6 3. Bb5 *").unwrap();
+        repertoire.add_line("1. e4 c5 2. c3").unwrap();
+
+        let mut game = Game::new();
+        assert_eq!(
+            repertoire.preferred_moves(&game),
+            &[game.moves().expect_matching(E2, E4, None)]
+        );
+
+        game.make_move(game.moves().expect_matching(E2, E4, None));
+        assert_eq!(repertoire.preferred_moves(&game).len(), 2);
+
+        game.make_move(game.moves().expect_matching(D7, D5, None));
+        assert!(repertoire.preferred_moves(&game).is_empty());
+    }
+
+    #[test]
+    fn test_epd_lines() {
+        crate::init();
+
+        let fen = "r1bqkbnr/pppp1ppp/2n5/4p3/4P3/5N2/PPPP1PPP/RNBQKB1R w KQkq -";
+
+        let mut repertoire = Repertoire::new();
+        repertoire
+            .add_line(&format!("{fen} bm Bb5 f1c4; id \"Spanish or Italian\";"))
+            .unwrap();
+
+        let game = Game::from_fen(fen).unwrap();
+        assert_eq!(
+            repertoire.preferred_moves(&game),
+            &[
+                game.moves().expect_matching(F1, B5, None),
+                game.moves().expect_matching(F1, C4, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_castling_with_zeros() {
+        crate::init();
+
+        let mut repertoire = Repertoire::new();
+        repertoire
+            .add_line("1. e4 e5 2. Nf3 Nc6 3. Bc4 Bc5 4.0-0")
+            .unwrap();
+
+        let game =
+            Game::from_fen("r1bqk1nr/pppp1ppp/2n5/2b1p3/2B1P3/5N2/PPPP1PPP/RNBQK2R w KQkq - 4 4")
+                .unwrap();
+        assert_eq!(
+            repertoire.preferred_moves(&game),
+            &[game.moves().expect_matching(E1, G1, None)]
+        );
+    }
+
+    #[test]
+    fn test_illegal_moves_are_rejected() {
+        crate::init();
+
+        let mut repertoire = Repertoire::new();
+        assert!(repertoire.add_line("1. e4 e4").is_err());
+        assert!(repertoire.add_line("1. Ke2 Nxe4").is_err());
+    }
+
+    #[test]
+    fn test_preferred_mate_keeps_its_mate_score() {
+        crate::init();
+
+        let fen = "6k1/5ppp/8/8/8/8/8/R5K1 w - -";
+        let mut game = Game::from_fen(fen).unwrap();
+
+        let mut persistent_state = PersistentState::new(16);
+        persistent_state
+            .repertoire
+            .add_line(&format!("{fen} bm Ra8#;"))
+            .unwrap();
+
+        let (mut time_strategy, _) =
+            TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+        let result = search(
+            &mut game,
+            &mut persistent_state,
+            &mut time_strategy,
+            &SearchRestrictions {
+                depth: Some(4),
+                ..SearchRestrictions::default()
+            },
+            &EngineOptions::default(),
+            &NullReporter,
+        );
+
+        assert_eq!(result.best_move, Move::quiet(A1, A8));
+        assert_eq!(result.score, SearchScore::Mate(1));
+    }
+}
@@ -7,7 +7,7 @@ pub fn init() {
 use crate::chess::moves::Move;
 use crate::chess::player::Player;
 use crate::chess::square::Square;
-use crate::engine::search::{move_ordering, MAX_SEARCH_DEPTH_SIZE};
+use crate::engine::search::{params, MAX_SEARCH_DEPTH_SIZE};
 
 pub struct KillersTable([[Option<Move>; 2]; MAX_SEARCH_DEPTH_SIZE]);
 
@@ -61,7 +61,7 @@ impl HistoryTable {
     fn bonus(depth: u8) -> i32 {
         let depthi32 = i32::from(depth);
 
-        depthi32 * depthi32
+        std::cmp::min(depthi32 * depthi32, params::HISTORY_MAX_BONUS)
     }
 
     pub fn get(&self, player: Player, mv: Move) -> i32 {
@@ -69,21 +69,20 @@ impl HistoryTable {
     }
 
     pub fn add_bonus_for(&mut self, player: Player, mv: Move, depth: u8) {
-        let bonus = Self::bonus(depth);
-        let existing_score = self.get(player, mv);
-        let new_score = std::cmp::min(existing_score + bonus, move_ordering::HISTORY_MAX_SCORE);
+        self.update(player, mv, Self::bonus(depth));
+    }
 
-        self.0[player.array_idx()][mv.src().array_idx()][mv.dst().array_idx()] = new_score;
+    pub fn add_malus_for(&mut self, player: Player, mv: Move, depth: u8) {
+        self.update(player, mv, -Self::bonus(depth));
     }
 
-    pub fn decay(&mut self, decay_factor: i32) {
-        for from_square in 0..Square::N {
-            for to_square in 0..Square::N {
-                for player in 0..Player::N {
-                    self.0[player][from_square][to_square] /= decay_factor;
-                }
-            }
-        }
+    // 'History gravity': the closer an entry is to the maximum value, the smaller the effect of
+    // further bonuses. This keeps all entries within [-HISTORY_MAX_VALUE, HISTORY_MAX_VALUE]
+    // without needing to periodically decay the table.
+    fn update(&mut self, player: Player, mv: Move, bonus: i32) {
+        let entry = &mut self.0[player.array_idx()][mv.src().array_idx()][mv.dst().array_idx()];
+
+        *entry += bonus - *entry * bonus.abs() / params::HISTORY_MAX_VALUE;
     }
 }
 
@@ -103,3 +102,27 @@ impl CountermoveTable {
         self.0[player.array_idx()][previous_move.src().array_idx()][previous_move.dst().array_idx()]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chess::square::squares::all::*;
+
+    #[test]
+    fn test_history_stays_within_bounds() {
+        let mut history = HistoryTable::new();
+        let mv = Move::quiet(E2, E4);
+
+        for _ in 0..10_000 {
+            history.add_bonus_for(Player::White, mv, 20);
+        }
+
+        assert!(history.get(Player::White, mv) <= params::HISTORY_MAX_VALUE);
+
+        for _ in 0..10_000 {
+            history.add_malus_for(Player::White, mv, 20);
+        }
+
+        assert!(history.get(Player::White, mv) >= -params::HISTORY_MAX_VALUE);
+    }
+}
@@ -4,6 +4,10 @@ const LMR_BASE: f32 = 0.75;
 const LMR_FACTOR: f32 = 2.25;
 
 pub fn lmr_reduction(depth: u8, move_count: usize) -> u8 {
+    debug_assert!(
+        crate::engine::is_initialised(),
+        "Eval and search tables were not initialised"
+    );
     let depth = depth as usize;
     unsafe { LMR_TABLE[depth.min(63)][move_count.min(63)] }
 }
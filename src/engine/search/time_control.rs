@@ -1,5 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::thread::JoinHandle;
 use std::time::{Duration, Instant};
 
 use crate::chess::game::Game;
@@ -19,6 +20,7 @@ pub struct TimeStrategy {
     force_stop: Arc<AtomicBool>,
 }
 
+#[derive(Clone)]
 pub struct Control {
     force_stop: Arc<AtomicBool>,
 }
@@ -27,6 +29,52 @@ impl Control {
     pub fn stop(&self) {
         self.force_stop.store(true, Ordering::Relaxed);
     }
+
+    // Stops the search once its hard time limit has passed, even if the search thread itself
+    // hasn't checked the clock, e.g. because it's stuck probing tablebases on a slow disk. The
+    // returned watchdog should be finished when the search ends.
+    pub fn start_watchdog(&self, time_strategy: &TimeStrategy) -> Option<Watchdog> {
+        let hard_stop = time_strategy.hard_stop()?;
+
+        let control = self.clone();
+        let started_at = time_strategy.started_at;
+        let is_finished = Arc::new(AtomicBool::new(false));
+        let thread_is_finished = is_finished.clone();
+
+        let thread = std::thread::spawn(move || loop {
+            if thread_is_finished.load(Ordering::Relaxed) {
+                return;
+            }
+
+            let remaining = hard_stop.saturating_sub(started_at.elapsed());
+            if remaining.is_zero() {
+                control.stop();
+                return;
+            }
+
+            // Woken early by `Watchdog::finish`, or possibly spuriously
+            std::thread::park_timeout(remaining);
+        });
+
+        Some(Watchdog {
+            is_finished,
+            thread,
+        })
+    }
+}
+
+pub struct Watchdog {
+    is_finished: Arc<AtomicBool>,
+    thread: JoinHandle<()>,
+}
+
+impl Watchdog {
+    // Wakes the watchdog thread so it exits straight away instead of waiting for the hard limit
+    pub fn finish(self) {
+        self.is_finished.store(true, Ordering::Relaxed);
+        self.thread.thread().unpark();
+        self.thread.join().unwrap();
+    }
 }
 
 impl TimeStrategy {
@@ -78,6 +126,11 @@ impl TimeStrategy {
                     base_time.mul_f32(params::HARD_TIME_MULTIPLIER),
                     max_time_per_move,
                 );
+
+                if let Some(move_time) = clocks.move_time {
+                    soft_stop = soft_stop.min(move_time);
+                    hard_stop = hard_stop.min(move_time);
+                }
             }
         };
 
@@ -94,7 +147,7 @@ impl TimeStrategy {
             soft_stop,
             hard_stop,
 
-            next_check_at: params::CHECK_TERMINATION_NODE_FREQUENCY,
+            next_check_at: params::MIN_CHECK_TERMINATION_NODE_FREQUENCY,
 
             force_stop,
         };
@@ -122,25 +175,166 @@ impl TimeStrategy {
         }
     }
 
-    pub fn should_stop(&mut self, nodes_visited: u64) -> bool {
-        if nodes_visited < self.next_check_at {
-            return false;
+    // Whether we've used the given fraction of the time we'd normally spend on this move. This only
+    // applies when playing with a clock: otherwise we've been told how long to search for.
+    pub fn used_fraction_of_soft_stop(&self, fraction: f32) -> bool {
+        match self.time_control {
+            TimeControl::Clocks(_) => self.elapsed() >= self.soft_stop.mul_f32(fraction),
+            TimeControl::ExactTime(_) | TimeControl::Infinite => false,
         }
+    }
 
+    // The stop flag is cheap to check, so we look at it on every node. Checking the clock is
+    // more expensive, so we only do it every so often.
+    pub fn should_stop(&mut self, nodes_visited: u64) -> bool {
         if self.is_force_stopped() {
             return true;
         }
 
-        self.next_check_at = nodes_visited + params::CHECK_TERMINATION_NODE_FREQUENCY;
+        if nodes_visited < self.next_check_at {
+            return false;
+        }
+
+        let elapsed = self.elapsed();
+        self.next_check_at = nodes_visited + Self::check_frequency(nodes_visited, elapsed);
 
         match self.time_control {
-            TimeControl::Clocks(_) => self.elapsed() > self.hard_stop,
-            TimeControl::ExactTime(time) => self.elapsed() > time,
+            TimeControl::Clocks(_) => elapsed > self.hard_stop,
+            TimeControl::ExactTime(time) => elapsed > time,
             TimeControl::Infinite => false,
         }
     }
 
+    // How many nodes we expect to search in `CHECK_TERMINATION_INTERVAL` at our current speed
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "The result is clamped to a small number of nodes"
+    )]
+    fn check_frequency(nodes_visited: u64, elapsed: Duration) -> u64 {
+        let elapsed_micros = elapsed.as_micros().max(1);
+        let interval_micros = params::CHECK_TERMINATION_INTERVAL.as_micros();
+
+        let nodes_per_interval = u128::from(nodes_visited) * interval_micros / elapsed_micros;
+
+        nodes_per_interval.clamp(
+            u128::from(params::MIN_CHECK_TERMINATION_NODE_FREQUENCY),
+            u128::from(params::MAX_CHECK_TERMINATION_NODE_FREQUENCY),
+        ) as u64
+    }
+
+    // The time after which the search must stop, if there is one
+    fn hard_stop(&self) -> Option<Duration> {
+        match self.time_control {
+            TimeControl::Clocks(_) => Some(self.hard_stop),
+            TimeControl::ExactTime(time) => Some(time),
+            TimeControl::Infinite => None,
+        }
+    }
+
     fn is_force_stopped(&self) -> bool {
         self.force_stop.load(Ordering::Relaxed)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::search::Clocks;
+
+    #[test]
+    fn test_check_frequency_adapts_to_speed() {
+        let slow = TimeStrategy::check_frequency(1000, Duration::from_millis(100));
+        let fast = TimeStrategy::check_frequency(5_000_000, Duration::from_secs(1));
+        let very_fast = TimeStrategy::check_frequency(100_000_000, Duration::from_secs(1));
+
+        assert_eq!(slow, params::MIN_CHECK_TERMINATION_NODE_FREQUENCY);
+        assert_eq!(fast, 5000);
+        assert_eq!(very_fast, params::MAX_CHECK_TERMINATION_NODE_FREQUENCY);
+    }
+
+    #[test]
+    fn test_move_time_caps_clocks() {
+        crate::init();
+
+        let clocks = Clocks {
+            white_clock: Some(Duration::from_secs(60)),
+            black_clock: Some(Duration::from_secs(60)),
+            white_increment: None,
+            black_increment: None,
+            moves_to_go: None,
+            move_time: Some(Duration::from_millis(100)),
+        };
+
+        let (time_strategy, _) = TimeStrategy::new(
+            &Game::new(),
+            &TimeControl::Clocks(clocks),
+            &EngineOptions::default(),
+        );
+
+        assert_eq!(time_strategy.soft_stop, Duration::from_millis(100));
+        assert_eq!(time_strategy.hard_stop(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn test_used_fraction_of_soft_stop() {
+        crate::init();
+
+        let clocks = Clocks {
+            white_clock: Some(Duration::from_secs(60)),
+            black_clock: Some(Duration::from_secs(60)),
+            white_increment: None,
+            black_increment: None,
+            moves_to_go: None,
+            move_time: None,
+        };
+
+        let (time_strategy, _) = TimeStrategy::new(
+            &Game::new(),
+            &TimeControl::Clocks(clocks),
+            &EngineOptions::default(),
+        );
+
+        assert!(time_strategy.used_fraction_of_soft_stop(0.0));
+        assert!(!time_strategy.used_fraction_of_soft_stop(1.0));
+
+        // Without a clock, there's no time of our own to save
+        let (time_strategy, _) = TimeStrategy::new(
+            &Game::new(),
+            &TimeControl::ExactTime(Duration::from_secs(10)),
+            &EngineOptions::default(),
+        );
+
+        assert!(!time_strategy.used_fraction_of_soft_stop(0.0));
+    }
+
+    #[test]
+    fn test_watchdog_stops_search() {
+        crate::init();
+
+        let time_control = TimeControl::ExactTime(Duration::from_millis(10));
+        let (mut time_strategy, control) =
+            TimeStrategy::new(&Game::new(), &time_control, &EngineOptions::default());
+
+        let watchdog = control.start_watchdog(&time_strategy).unwrap();
+        assert!(!time_strategy.should_stop(0));
+
+        std::thread::sleep(Duration::from_millis(100));
+        assert!(time_strategy.should_stop(0));
+        watchdog.finish();
+    }
+
+    #[test]
+    fn test_watchdog_finishes_early() {
+        crate::init();
+
+        let time_control = TimeControl::ExactTime(Duration::from_secs(60));
+        let (mut time_strategy, control) =
+            TimeStrategy::new(&Game::new(), &time_control, &EngineOptions::default());
+
+        let started_at = Instant::now();
+        control.start_watchdog(&time_strategy).unwrap().finish();
+
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+        assert!(!time_strategy.should_stop(0));
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::VecDeque;
+use std::fmt::Write;
+use std::sync::{Mutex, PoisonError};
+
+use crate::chess::moves::Move;
+use crate::engine::eval::Eval;
+
+// How many of the most recent events we keep. Older events are thrown away as new ones come in.
+const TRACE_LENGTH: usize = 4096;
+
+static TRACE: Mutex<TraceBuffer> = Mutex::new(TraceBuffer::new());
+
+// Something that happened during a search, kept so that we can see what the search was doing
+// shortly before a crash
+#[derive(Debug, Clone)]
+pub enum Event {
+    SearchStarted {
+        fen: String,
+    },
+    Iteration {
+        depth: u8,
+    },
+    Node {
+        plies: u8,
+        depth: u8,
+        alpha: Eval,
+        beta: Eval,
+        hash: u64,
+    },
+    QuiescenceNode {
+        plies: u8,
+        alpha: Eval,
+        beta: Eval,
+        hash: u64,
+    },
+    TtCutoff {
+        plies: u8,
+        score: Eval,
+    },
+    ReverseFutilityPrune {
+        plies: u8,
+        eval: Eval,
+    },
+    NullMovePrune {
+        plies: u8,
+        score: Eval,
+    },
+    FutilityPrune {
+        plies: u8,
+        mv: Move,
+    },
+    DeltaPrune {
+        plies: u8,
+        mv: Move,
+    },
+}
+
+struct TraceBuffer {
+    events: VecDeque<Event>,
+}
+
+impl TraceBuffer {
+    const fn new() -> Self {
+        Self {
+            events: VecDeque::new(),
+        }
+    }
+
+    fn push(&mut self, event: Event) {
+        if self.events.len() == TRACE_LENGTH {
+            self.events.pop_front();
+        }
+
+        self.events.push_back(event);
+    }
+}
+
+// Events are recorded with the `trace!` macro, which compiles to nothing unless the 'trace'
+// feature is enabled
+pub fn record(event: Event) {
+    TRACE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(event);
+}
+
+// The recorded events, oldest first, one per line
+pub fn dump() -> String {
+    let trace = TRACE.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut output = String::new();
+
+    writeln!(output, "Last {} search events:", trace.events.len()).unwrap();
+
+    for event in &trace.events {
+        writeln!(output, "  {event:?}").unwrap();
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_buffer_keeps_most_recent_events() {
+        let mut buffer = TraceBuffer::new();
+
+        for depth in 0..=u8::MAX {
+            for _ in 0..20 {
+                buffer.push(Event::Iteration { depth });
+            }
+        }
+
+        assert_eq!(buffer.events.len(), TRACE_LENGTH);
+        assert!(matches!(
+            buffer.events.back(),
+            Some(Event::Iteration { depth: u8::MAX })
+        ));
+        assert!(matches!(
+            buffer.events.front(),
+            Some(Event::Iteration { depth: 51 })
+        ));
+    }
+}
@@ -1,27 +1,79 @@
+use super::params;
+use crate::chess::game::Game;
 use crate::chess::moves::Move;
+use crate::chess::zobrist::ZobristHash;
 use crate::engine::eval::Eval;
-use crate::engine::transposition_table::{TTOverwriteable, TranspositionTable};
+use crate::engine::search::principal_variation::PrincipalVariation;
+use crate::engine::transposition_table::{
+    TTOverwriteable, TranspositionTable, TranspositionTableEntry, GENERATIONS,
+};
+use std::io::{Read, Write};
+use std::num::NonZeroU8;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub enum NodeBound {
     Exact,
     Upper,
     Lower,
 }
 
+// The bound and the age share a byte so that entries fit in 16 bytes. The bound goes in the low
+// two bits and is never stored as zero, which leaves `Option` a niche to use for empty entries.
+#[derive(Debug, Clone, Copy)]
+pub struct BoundAndAge(NonZeroU8);
+
+impl BoundAndAge {
+    pub const fn new(bound: NodeBound, age: u8) -> Self {
+        let bound = match bound {
+            NodeBound::Exact => 1,
+            NodeBound::Upper => 2,
+            NodeBound::Lower => 3,
+        };
+
+        Self(NonZeroU8::new(bound | (age % GENERATIONS) << 2).unwrap())
+    }
+
+    pub const fn bound(self) -> NodeBound {
+        match self.0.get() & 0b11 {
+            1 => NodeBound::Exact,
+            2 => NodeBound::Upper,
+            _ => NodeBound::Lower,
+        }
+    }
+
+    pub const fn age(self) -> u8 {
+        self.0.get() >> 2
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SearchTranspositionTableData {
-    pub bound: NodeBound,
+    pub bound_and_age: BoundAndAge,
     pub eval: Eval,
+    pub static_eval: Eval,
     pub depth: u8,
-    pub age: u8,
     pub best_move: Option<Move>,
 }
 
+impl SearchTranspositionTableData {
+    pub const fn bound(&self) -> NodeBound {
+        self.bound_and_age.bound()
+    }
+
+    pub const fn age(&self) -> u8 {
+        self.bound_and_age.age()
+    }
+
+    // How many generations ago the entry was written, allowing for the generation wrapping around
+    pub const fn generations_old(&self, generation: u8) -> u8 {
+        (generation + GENERATIONS - self.age()) % GENERATIONS
+    }
+}
+
 impl TTOverwriteable for SearchTranspositionTableData {
     fn should_overwrite_with(&self, new: &Self) -> bool {
         // Always prioritise results from new searches
-        if new.age != self.age {
+        if new.age() != self.age() {
             return true;
         }
 
@@ -31,18 +83,218 @@ impl TTOverwriteable for SearchTranspositionTableData {
             return true;
         }
 
+        // Quiescence search results are much less valuable than main search results, so
+        // don't let them replace results from the main search
+        if new.depth == 0 && self.depth > 0 {
+            return false;
+        }
+
         // If the new node is exact, always store it
-        if new.bound == NodeBound::Exact {
+        if new.bound() == NodeBound::Exact {
             return true;
         }
 
         // Don't overwrite exact nodes
-        self.bound != NodeBound::Exact
+        self.bound() != NodeBound::Exact
+    }
+
+    // Deeper results save more work, but results from earlier searches are less likely to be
+    // useful again the older they get
+    fn replacement_priority(&self, generation: u8) -> i32 {
+        let age = i32::from(self.generations_old(generation));
+        let exact_bonus = if self.bound() == NodeBound::Exact {
+            params::TT_REPLACEMENT_EXACT_BONUS
+        } else {
+            0
+        };
+
+        i32::from(self.depth) + exact_bonus - age * params::TT_REPLACEMENT_AGE_WEIGHT
+    }
+
+    fn generation(&self) -> u8 {
+        self.age()
     }
 }
 
 pub type SearchTranspositionTable = TranspositionTable<SearchTranspositionTableData>;
 
+impl SearchTranspositionTable {
+    // Follow the best moves stored in the TT from the given position to reconstruct the line
+    // the search expects to be played. Since TT entries can be overwritten or collide, we
+    // check that each move is legal before following it. The moves are undone afterwards, so
+    // the game is left as it was.
+    pub fn line_from(&self, game: &mut Game, max_length: u8) -> PrincipalVariation {
+        let mut line = PrincipalVariation::new();
+
+        while line.len() < max_length {
+            let Some(mv) = self.get(&game.zobrist).and_then(|entry| entry.best_move) else {
+                break;
+            };
+
+            if !game.moves().contains(&mv) {
+                break;
+            }
+
+            line.append(mv);
+            game.make_move(mv);
+
+            // Stop once we get into a cycle, otherwise we'd keep following it forever
+            if game.is_repeated_position() {
+                break;
+            }
+        }
+
+        for _ in 0..line.len() {
+            game.undo_move();
+        }
+
+        line
+    }
+}
+
+// Saved hash files start with this header, followed by each of the entries in the table.
+// The version needs to be bumped whenever the layout of the file changes.
+const HASH_FILE_MAGIC: &[u8; 8] = b"TCHRNTT\0";
+const HASH_FILE_VERSION: u32 = 1;
+
+// The hashes in a saved file are only meaningful if they were generated with the same zobrist
+// keys as ours, so we store the hash of a known position and check it matches when loading.
+fn zobrist_check() -> u64 {
+    Game::new().zobrist.0
+}
+
+fn write_bytes(writer: &mut impl Write, bytes: &[u8]) -> Result<(), String> {
+    writer
+        .write_all(bytes)
+        .map_err(|e| format!("Unable to write hash file: {e}"))
+}
+
+fn read_bytes<const N: usize>(reader: &mut impl Read) -> Result<[u8; N], String> {
+    let mut bytes = [0; N];
+    reader
+        .read_exact(&mut bytes)
+        .map_err(|e| format!("Unable to read hash file: {e}"))?;
+    Ok(bytes)
+}
+
+fn write_entry(
+    writer: &mut impl Write,
+    entry: &TranspositionTableEntry<SearchTranspositionTableData>,
+) -> Result<(), String> {
+    let data = &entry.data;
+
+    let bound: u8 = match data.bound() {
+        NodeBound::Exact => 0,
+        NodeBound::Upper => 1,
+        NodeBound::Lower => 2,
+    };
+
+    write_bytes(writer, &entry.key.0.to_le_bytes())?;
+    write_bytes(writer, &[bound, data.depth, data.age()])?;
+    write_bytes(writer, &data.eval.0.to_le_bytes())?;
+    write_bytes(writer, &data.static_eval.0.to_le_bytes())?;
+    write_bytes(
+        writer,
+        &data.best_move.map_or(0, Move::to_u16).to_le_bytes(),
+    )
+}
+
+fn read_entry(
+    reader: &mut impl Read,
+) -> Result<TranspositionTableEntry<SearchTranspositionTableData>, String> {
+    let key = ZobristHash(u64::from_le_bytes(read_bytes(reader)?));
+    let [bound, depth, age] = read_bytes(reader)?;
+    let eval = Eval(i16::from_le_bytes(read_bytes(reader)?));
+    let static_eval = Eval(i16::from_le_bytes(read_bytes(reader)?));
+    let best_move = u16::from_le_bytes(read_bytes(reader)?);
+
+    let bound = match bound {
+        0 => NodeBound::Exact,
+        1 => NodeBound::Upper,
+        2 => NodeBound::Lower,
+        _ => return Err(format!("Invalid bound in hash file: {bound}")),
+    };
+
+    let best_move = match best_move {
+        0 => None,
+        _ => Some(
+            Move::from_u16(best_move)
+                .ok_or_else(|| format!("Invalid move in hash file: {best_move}"))?,
+        ),
+    };
+
+    Ok(TranspositionTableEntry {
+        key,
+        data: SearchTranspositionTableData {
+            bound_and_age: BoundAndAge::new(bound, age),
+            eval,
+            static_eval,
+            depth,
+            best_move,
+        },
+    })
+}
+
+impl SearchTranspositionTable {
+    // Writes every entry in the table so that a long analysis can be picked up again later.
+    // Returns the number of entries that were written.
+    pub fn save(&self, writer: &mut impl Write) -> Result<usize, String> {
+        let number_of_entries = self.entries().count();
+
+        write_bytes(writer, HASH_FILE_MAGIC)?;
+        write_bytes(writer, &HASH_FILE_VERSION.to_le_bytes())?;
+        write_bytes(writer, &zobrist_check().to_le_bytes())?;
+        write_bytes(writer, &[self.generation])?;
+        write_bytes(writer, &(number_of_entries as u64).to_le_bytes())?;
+
+        for entry in self.entries() {
+            write_entry(writer, entry)?;
+        }
+
+        writer
+            .flush()
+            .map_err(|e| format!("Unable to write hash file: {e}"))?;
+
+        Ok(number_of_entries)
+    }
+
+    // Replaces the contents of the table with the entries from a saved hash file. The table
+    // doesn't need to be the same size as the one that was saved, but if it's smaller, some
+    // entries may be lost. The table is left untouched if the file can't be read.
+    pub fn load(&mut self, reader: &mut impl Read) -> Result<usize, String> {
+        if read_bytes::<8>(reader)? != *HASH_FILE_MAGIC {
+            return Err("Not a hash file".to_string());
+        }
+
+        let version = u32::from_le_bytes(read_bytes(reader)?);
+        if version != HASH_FILE_VERSION {
+            return Err(format!(
+                "Unsupported hash file version: {version} (expected {HASH_FILE_VERSION})"
+            ));
+        }
+
+        if u64::from_le_bytes(read_bytes(reader)?) != zobrist_check() {
+            return Err("Hash file was created with different zobrist keys".to_string());
+        }
+
+        let [generation] = read_bytes(reader)?;
+        let number_of_entries = u64::from_le_bytes(read_bytes(reader)?);
+
+        let entries = (0..number_of_entries)
+            .map(|_| read_entry(reader))
+            .collect::<Result<Vec<_>, String>>()?;
+
+        self.reset();
+        self.generation = generation % GENERATIONS;
+
+        for entry in entries {
+            self.insert(&entry.key, entry.data);
+        }
+
+        Ok(self.occupied)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -55,6 +307,19 @@ mod tests {
             std::mem::size_of::<TranspositionTableEntry<SearchTranspositionTableData>>(),
             16
         );
+
+        // Empty slots shouldn't take up any more space
+        assert_eq!(
+            std::mem::size_of::<Option<TranspositionTableEntry<SearchTranspositionTableData>>>(),
+            16
+        );
+    }
+
+    // The table is shared between search threads
+    #[test]
+    fn assert_tt_is_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<SearchTranspositionTable>();
     }
 
     #[test]
@@ -63,5 +328,6 @@ mod tests {
             transposition_table::calculate_number_of_entries::<SearchTranspositionTableData>(256);
 
         assert_eq!(number_of_entries, 16_777_216);
+        assert_eq!(number_of_entries % transposition_table::BUCKET_SIZE, 0);
     }
 }
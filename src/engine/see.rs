@@ -2,10 +2,11 @@ use crate::chess::game::Game;
 use crate::chess::movegen;
 use crate::chess::movegen::tables;
 use crate::chess::moves::Move;
-use crate::chess::piece::PieceKind;
+use crate::chess::piece::{PieceKind, PromotionPieceKind};
+use crate::chess::square::Square;
 use crate::engine::eval::Eval;
 
-fn piece_value(kind: PieceKind) -> Eval {
+pub fn piece_value(kind: PieceKind) -> Eval {
     use PieceKind::*;
 
     Eval(match kind {
@@ -135,6 +136,124 @@ pub fn see(game: &Game, mv: Move, threshold: Eval) -> bool {
     score >= Eval(0)
 }
 
+// One capture in an exchange, with the material balance afterwards for the player who started it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExchangeCapture {
+    pub src: Square,
+    pub attacker: PieceKind,
+    pub balance: Eval,
+}
+
+// The captures SEE considers for a move, played out in full: each side keeps recapturing with its
+// least valuable attacker until it runs out (or would have to capture into check with its king).
+// Unlike `see`, this doesn't stop as soon as the result is known, so that the whole sequence can be
+// shown by 'd see'.
+pub fn exchange_sequence(game: &Game, mv: Move) -> Vec<ExchangeCapture> {
+    let board = &game.board;
+    let to = mv.dst();
+    let moved_piece = board.piece_at(mv.src()).unwrap();
+
+    let mut balance = match board.piece_at(to) {
+        Some(piece) => piece_value(piece.kind),
+        None if mv.is_en_passant() => piece_value(PieceKind::Pawn),
+        None => Eval(0),
+    };
+
+    if let Some(promotion_piece) = mv.promotion() {
+        balance += piece_value(promotion_piece.piece()) - piece_value(PieceKind::Pawn);
+    }
+
+    let mut occupied = (board.occupancy() ^ mv.src().bb()) | to.bb();
+    if mv.is_en_passant() {
+        occupied ^= game.en_passant_target.unwrap().bb();
+    }
+
+    let mut captures = vec![ExchangeCapture {
+        src: mv.src(),
+        attacker: moved_piece.kind,
+        balance,
+    }];
+
+    let mut victim = mv
+        .promotion()
+        .map_or(moved_piece.kind, PromotionPieceKind::piece);
+    let mut color = game.player;
+
+    loop {
+        color = color.other();
+
+        // Recalculating the attackers each time picks up sliders behind the pieces that have
+        // already captured
+        let attackers = movegen::all_attackers_of(board, to, occupied) & occupied;
+        let my_attackers = attackers & board.occupancy_for(color);
+
+        let Some((attacker_sq, attacker)) = PieceKind::ALL.into_iter().find_map(|kind| {
+            let squares = my_attackers & board.pieces_of_kind(kind, color);
+            squares.any().then(|| (squares.lsb().single(), kind))
+        }) else {
+            break;
+        };
+
+        if attacker == PieceKind::King && (attackers & board.occupancy_for(color.other())).any() {
+            break;
+        }
+
+        occupied ^= attacker_sq.bb();
+
+        if color == game.player {
+            balance += piece_value(victim);
+        } else {
+            balance -= piece_value(victim);
+        }
+
+        captures.push(ExchangeCapture {
+            src: attacker_sq,
+            attacker,
+            balance,
+        });
+
+        victim = attacker;
+    }
+
+    captures
+}
+
+// The result of an exchange when each side can stop capturing whenever continuing would be worse
+// for them
+pub fn exchange_value(captures: &[ExchangeCapture]) -> Eval {
+    let mut value = captures.last().unwrap().balance;
+
+    for (i, capture) in captures.iter().enumerate().rev().skip(1) {
+        // The capture after this one was made by the opponent when `i` is even
+        value = if i % 2 == 0 {
+            value.min(capture.balance)
+        } else {
+            value.max(capture.balance)
+        };
+    }
+
+    value
+}
+
+// The largest threshold that `see` passes for the move, which is what SEE thinks the exchange is
+// worth
+pub fn see_value(game: &Game, mv: Move) -> Eval {
+    let king_value = i32::from(piece_value(PieceKind::King).0);
+    let (mut low, mut high) = (-2 * king_value, 2 * king_value);
+
+    while low < high {
+        let mid = low + (high - low + 1) / 2;
+
+        if see(game, mv, Eval(i16::try_from(mid).unwrap())) {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Eval(i16::try_from(low).unwrap())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -298,4 +417,40 @@ mod tests {
             assert_eq!(see(&game, *mv, Eval(threshold)), result);
         }
     }
+
+    #[test]
+    fn test_exchange_sequence() {
+        crate::init();
+
+        // Rxe5, dxe5 (defended by the d6 pawn): we win a knight but lose the rook
+        let game = Game::from_fen("4k3/8/3p4/4n3/8/8/8/4R1K1 w - - 0 1").unwrap();
+        let mv = game.moves().expect_matching(E1, E5, None);
+
+        let captures = exchange_sequence(&game, mv);
+
+        assert_eq!(captures.len(), 2);
+        assert_eq!(captures[0].attacker, PieceKind::Rook);
+        assert_eq!(captures[0].balance, Eval(300));
+        assert_eq!(captures[1].src, D6);
+        assert_eq!(captures[1].attacker, PieceKind::Pawn);
+        assert_eq!(captures[1].balance, Eval(-200));
+
+        assert_eq!(exchange_value(&captures), Eval(-200));
+        assert_eq!(see_value(&game, mv), Eval(-200));
+    }
+
+    #[test]
+    fn test_exchange_value_stops_when_recapturing_loses() {
+        crate::init();
+
+        // Nxd5 wins a pawn, but Qxd5 would lose the queen to exd5, so black stops
+        let game = Game::from_fen("3qk3/8/8/3p4/4P3/2N5/8/4K3 w - - 0 1").unwrap();
+        let mv = game.moves().expect_matching(C3, D5, None);
+
+        let captures = exchange_sequence(&game, mv);
+
+        assert_eq!(captures.len(), 3);
+        assert_eq!(exchange_value(&captures), Eval(100));
+        assert_eq!(see_value(&game, mv), Eval(100));
+    }
 }
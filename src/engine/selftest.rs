@@ -0,0 +1,131 @@
+use crate::chess::game::Game;
+use crate::chess::perft::perft;
+use crate::chess::zobrist;
+use crate::engine::eval;
+
+// Positions with well-known perft results, from https://www.chessprogramming.org/Perft_Results
+const PERFT_POSITIONS: [(&str, u8, u64); 5] = [
+    (
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        4,
+        197_281,
+    ),
+    (
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        3,
+        97_862,
+    ),
+    ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 4, 43_238),
+    (
+        "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+        3,
+        9_467,
+    ),
+    (
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+        3,
+        62_379,
+    ),
+];
+
+// The depth to play out the perft positions to while checking the zobrist hash
+const ZOBRIST_DEPTH: u8 = 2;
+
+// The hand-crafted eval of some positions from the side to move's point of view. These need
+// updating whenever the eval changes, which `test_selftest_passes` will point out.
+const EVAL_POSITIONS: [(&str, i16); 4] = [
+    (
+        "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+        0,
+    ),
+    (
+        "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+        46,
+    ),
+    ("8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1", 52),
+    (
+        "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R b KQ - 1 8",
+        -79,
+    ),
+];
+
+// Quick checks that the move generator, zobrist hashing and eval give the results they should,
+// for verifying a build (e.g. one that's been cross-compiled). Returns whether all of the checks
+// passed.
+pub fn selftest() -> bool {
+    let mut passed = true;
+
+    for (fen, depth, expected) in PERFT_POSITIONS {
+        let mut game = Game::from_fen(fen).unwrap();
+        let nodes = perft(depth, &mut game);
+        passed &= report(
+            nodes == expected,
+            &format!("perft {depth} {fen}: {nodes} (expected {expected})"),
+        );
+    }
+
+    for (fen, _, _) in PERFT_POSITIONS {
+        let mut game = Game::from_fen(fen).unwrap();
+        let mismatches = zobrist_mismatches(ZOBRIST_DEPTH, &mut game);
+        passed &= report(
+            mismatches == 0,
+            &format!("zobrist {fen}: {mismatches} mismatches"),
+        );
+    }
+
+    for (fen, expected) in EVAL_POSITIONS {
+        let game = Game::from_fen(fen).unwrap();
+        let eval = eval::eval(&game).0;
+        passed &= report(
+            eval == expected,
+            &format!("eval {fen}: {eval} (expected {expected})"),
+        );
+    }
+
+    if passed {
+        println!("selftest passed");
+    } else {
+        println!("selftest failed");
+    }
+
+    passed
+}
+
+fn report(passed: bool, description: &str) -> bool {
+    println!("{} {description}", if passed { "ok  " } else { "FAIL" });
+    passed
+}
+
+// The number of positions within the given depth whose incrementally updated zobrist hash
+// doesn't match one computed from scratch, including after undoing moves
+fn zobrist_mismatches(depth: u8, game: &mut Game) -> u64 {
+    let mut mismatches = u64::from(game.zobrist != zobrist::hash(game));
+
+    if depth == 0 {
+        return mismatches;
+    }
+
+    for mv in game.moves() {
+        let before = game.zobrist.clone();
+
+        game.make_move(mv);
+        mismatches += zobrist_mismatches(depth - 1, game);
+        game.undo_move();
+
+        mismatches += u64::from(game.zobrist != before);
+    }
+
+    mismatches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_selftest_passes() {
+        crate::init();
+
+        assert!(selftest());
+    }
+}
@@ -1,147 +1,335 @@
 use crate::chess::game::Game;
-use crate::chess::moves::{Move, MoveListExt};
-use crate::chess::piece::PromotionPieceKind;
+use crate::chess::moves::Move;
+use crate::chess::piece::PieceKind;
 use crate::chess::player::Player;
-use crate::chess::square::Square;
-use std::ffi::{c_uint, CString};
-use std::ptr;
-
-#[allow(
-    unused,
-    non_camel_case_types,
-    non_upper_case_globals,
-    non_snake_case,
-    clippy::allow_attributes,
-    clippy::allow_attributes_without_reason,
-    clippy::unreadable_literal
-)]
-mod bindings;
+use crate::chess::zobrist::ZobristHash;
+use std::path::{Path, PathBuf};
 
+// Probing is done through Fathom, which is C code, so it's left out of builds without the
+// 'syzygy' feature
+#[cfg(feature = "syzygy")]
+mod probe;
+
+// Without the 'syzygy' feature, tablebases can't be loaded, so nothing is ever probed
+#[cfg(not(feature = "syzygy"))]
+mod probe {
+    use super::Wdl;
+    use crate::chess::game::Game;
+    use crate::chess::moves::Move;
+
+    pub fn init(_: &str) -> Result<(), String> {
+        Err("This build doesn't support tablebases".to_string())
+    }
+
+    pub fn largest() -> u8 {
+        0
+    }
+
+    pub fn wdl(_: &Game) -> Option<Wdl> {
+        None
+    }
+
+    pub fn best_move(_: &Game) -> Option<Move> {
+        None
+    }
+
+    pub fn dtz(_: &Game) -> Option<u32> {
+        None
+    }
+
+    pub fn root_moves(_: &Game) -> Option<Vec<(Move, u32)>> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Wdl {
     Win,
     Draw,
     Loss,
 }
 
+// The largest syzygy tablebases that exist
+pub const MAX_MEN: u8 = 7;
+
+// Fathom splits SyzygyPath into directories the same way
+#[cfg(not(windows))]
+const PATH_SEPARATOR: char = ':';
+#[cfg(windows)]
+const PATH_SEPARATOR: char = ';';
+
+// The name of the table for the material in the position, e.g. 'KRPvKR'. Tables are named with
+// the stronger side first (the one with more pieces, or the more valuable pieces), whichever side
+// it is and whichever side is to move.
+pub fn table_name(game: &Game) -> String {
+    let side = |player: Player| -> String {
+        [
+            (PieceKind::King, 'K'),
+            (PieceKind::Queen, 'Q'),
+            (PieceKind::Rook, 'R'),
+            (PieceKind::Bishop, 'B'),
+            (PieceKind::Knight, 'N'),
+            (PieceKind::Pawn, 'P'),
+        ]
+        .into_iter()
+        .flat_map(|(kind, c)| {
+            let count = game.board.pieces_of_kind(kind, player).count();
+            std::iter::repeat_n(c, usize::from(count))
+        })
+        .collect()
+    };
+
+    let white = side(Player::White);
+    let black = side(Player::Black);
+
+    // 'KQRBNP' are in descending order of value, so comparing the positions of the pieces in that
+    // string tells us which side is stronger when both have the same number of pieces
+    let strength = |pieces: &str| -> (usize, Vec<std::cmp::Reverse<usize>>) {
+        (
+            pieces.len(),
+            pieces
+                .chars()
+                .map(|c| std::cmp::Reverse("KQRBNP".find(c).unwrap()))
+                .collect(),
+        )
+    };
+
+    if strength(&black) > strength(&white) {
+        format!("{black}v{white}")
+    } else {
+        format!("{white}v{black}")
+    }
+}
+
+// The tables needed to probe the position: the table for its own material, and the tables for
+// the material left after each capture or promotion, which the probe looks into as well
+pub fn tables_needed(game: &Game) -> Vec<String> {
+    let mut game = game.clone();
+    let mut tables = vec![table_name(&game)];
+
+    for mv in game.moves() {
+        if !mv.is_capture() && !mv.is_promotion() {
+            continue;
+        }
+
+        game.make_move(mv);
+        let table = table_name(&game);
+        game.undo_move();
+
+        // Positions with only the kings left are always drawn, so don't have a table
+        if table != "KvK" && !tables.contains(&table) {
+            tables.push(table);
+        }
+    }
+
+    tables
+}
+
+// The WDL and DTZ files for the table which can be found in the given SyzygyPath
+pub fn find_table_files(syzygy_path: &str, table: &str) -> Vec<PathBuf> {
+    syzygy_path
+        .split(PATH_SEPARATOR)
+        .filter(|dir| !dir.is_empty())
+        .flat_map(|dir| {
+            ["rtbw", "rtbz"].map(|extension| Path::new(dir).join(format!("{table}.{extension}")))
+        })
+        .filter(|path| path.is_file())
+        .collect()
+}
+
+// What the tablebases say about a position, for 'd probe'
+pub struct TablebaseProbe {
+    pub n_men: u8,
+    pub wdl: Option<Wdl>,
+    pub dtz: Option<u32>,
+}
+
+// The number of WDL results we remember. Probing the tablebases means going through the FFI and
+// possibly reading from disk, and the search tends to probe the same endgame positions many times.
+const WDL_CACHE_SIZE: usize = 1 << 16;
+
+// A small always-replace cache of WDL results, indexed by zobrist hash
+struct WdlCache {
+    entries: Vec<Option<(u64, Wdl)>>,
+}
+
+impl WdlCache {
+    fn new() -> Self {
+        Self {
+            entries: vec![None; WDL_CACHE_SIZE],
+        }
+    }
+
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "The hash is reduced to an index into the cache"
+    )]
+    const fn index(key: &ZobristHash) -> usize {
+        key.0 as usize % WDL_CACHE_SIZE
+    }
+
+    fn get(&self, key: &ZobristHash) -> Option<Wdl> {
+        match self.entries[Self::index(key)] {
+            Some((entry_key, wdl)) if entry_key == key.0 => Some(wdl),
+            _ => None,
+        }
+    }
+
+    fn insert(&mut self, key: &ZobristHash, wdl: Wdl) {
+        self.entries[Self::index(key)] = Some((key.0, wdl));
+    }
+
+    fn clear(&mut self) {
+        self.entries.fill(None);
+    }
+}
+
 pub struct Tablebase {
     is_enabled: bool,
+    cache: WdlCache,
+    cache_hits: u64,
 }
 
 impl Tablebase {
     pub fn new() -> Self {
-        Self { is_enabled: false }
+        Self {
+            is_enabled: false,
+            cache: WdlCache::new(),
+            cache_hits: 0,
+        }
+    }
+
+    // How many WDL probes since the last `reset_stats` were answered from the cache
+    pub const fn cache_hits(&self) -> u64 {
+        self.cache_hits
+    }
+
+    pub fn reset_stats(&mut self) {
+        self.cache_hits = 0;
     }
 
-    #[expect(
-        clippy::cast_possible_truncation,
-        reason = "n_men will be at most 7 as these are the largest syzygy tablebases"
-    )]
     pub fn n_men(&self) -> u8 {
         if !self.is_enabled {
             return 0;
         }
 
-        unsafe { bindings::TB_LARGEST as u8 }
+        probe::largest()
     }
 
-    pub fn set_paths(&mut self, path: &str) {
-        let path = CString::new(path).unwrap();
-        let was_set = unsafe { bindings::tb_init(path.as_ptr()) };
-        let n_men = unsafe { bindings::TB_LARGEST as usize };
-
-        assert!(
-            was_set && n_men != 0,
-            "Invalid tablebase path: {}",
-            path.to_str().unwrap_or_default()
-        );
+    // The tablebases themselves are shared by the whole process, so this changes the tablebases
+    // used by every engine instance which has tablebases enabled, not just this one
+    pub fn set_paths(&mut self, path: &str) -> Result<(), String> {
+        probe::init(path)?;
 
+        // Results from the old tablebases might not match the new ones
+        self.cache.clear();
         self.is_enabled = true;
+
+        Ok(())
     }
 
-    pub fn wdl(&self, game: &Game) -> Option<Wdl> {
+    pub fn wdl(&mut self, game: &Game) -> Option<Wdl> {
         if !self.is_enabled {
             return None;
         }
 
-        unsafe {
-            let wdl = bindings::tb_probe_wdl(
-                game.board.occupancy_for(Player::White).as_u64(),
-                game.board.occupancy_for(Player::Black).as_u64(),
-                game.board.all_kings().as_u64(),
-                game.board.all_queens().as_u64(),
-                game.board.all_rooks().as_u64(),
-                game.board.all_bishops().as_u64(),
-                game.board.all_knights().as_u64(),
-                game.board.all_pawns().as_u64(),
-                0,
-                0,
-                0,
-                game.player == Player::White,
-            );
-
-            Self::to_wdl(wdl)
+        if let Some(wdl) = self.cache.get(&game.zobrist) {
+            self.cache_hits += 1;
+            return Some(wdl);
         }
+
+        let wdl = probe::wdl(game)?;
+        self.cache.insert(&game.zobrist, wdl);
+        Some(wdl)
     }
 
-    #[rustfmt::skip]
     pub fn best_move(&self, game: &Game) -> Option<Move> {
         if !self.is_enabled {
             return None;
         }
 
-        unsafe {
-            let result = bindings::tb_probe_root(
-                game.board.occupancy_for(Player::White).as_u64(),
-                game.board.occupancy_for(Player::Black).as_u64(),
-                game.board.all_kings().as_u64(),
-                game.board.all_queens().as_u64(),
-                game.board.all_rooks().as_u64(),
-                game.board.all_bishops().as_u64(),
-                game.board.all_knights().as_u64(),
-                game.board.all_pawns().as_u64(),
-                game.halfmove_clock,
-                0,
-                0,
-                game.player == Player::White,
-                ptr::null_mut(),
-            );
-
-            if result == bindings::TB_RESULT_FAILED {
-                return None;
-            }
-
-            // let wdl_bits = result & bindings::TB_RESULT_WDL_MASK >> bindings::TB_RESULT_WDL_SHIFT;
-            // let dtz_bits = (result & bindings::TB_RESULT_DTZ_MASK) >> bindings::TB_RESULT_DTZ_SHIFT;
-            let from_bits =(result & bindings::TB_RESULT_FROM_MASK) >> bindings::TB_RESULT_FROM_SHIFT;
-            let to_bits = (result & bindings::TB_RESULT_TO_MASK) >> bindings::TB_RESULT_TO_SHIFT;
-            let promotion_bits = (result & bindings::TB_RESULT_PROMOTES_MASK) >> bindings::TB_RESULT_PROMOTES_SHIFT;
-
-            let from = Square::from_index(from_bits as u8);
-            let to = Square::from_index(to_bits as u8);
-
-            let promotion = match promotion_bits {
-                bindings::TB_PROMOTES_QUEEN => Some(PromotionPieceKind::Queen),
-                bindings::TB_PROMOTES_ROOK => Some(PromotionPieceKind::Rook),
-                bindings::TB_PROMOTES_BISHOP => Some(PromotionPieceKind::Bishop),
-                bindings::TB_PROMOTES_KNIGHT => Some(PromotionPieceKind::Knight),
-                _ => None,
-            };
-
-            let matching_move = game.moves().expect_matching(from, to, promotion);
-
-            Some(matching_move)
-        }
+        probe::best_move(game)
     }
 
-    fn to_wdl(outcome: c_uint) -> Option<Wdl> {
-        use Wdl::*;
+    pub fn dtz(&self, game: &Game) -> Option<u32> {
+        if !self.is_enabled {
+            return None;
+        }
+
+        probe::dtz(game)
+    }
 
-        match outcome {
-            bindings::TB_WIN => Some(Win),
-            bindings::TB_LOSS => Some(Loss),
-            bindings::TB_DRAW | bindings::TB_CURSED_WIN | bindings::TB_BLESSED_LOSS => Some(Draw),
-            bindings::TB_RESULT_FAILED => None,
-            _ => unreachable!(),
+    // The root moves which keep the best result available, taking the fifty move rule into
+    // account. Returns None if the position isn't in the tablebases.
+    pub fn preserving_root_moves(&self, game: &Game) -> Option<Vec<Move>> {
+        if !self.is_enabled {
+            return None;
         }
+
+        let moves = probe::root_moves(game)?;
+        let best_rank = moves.iter().map(|&(_, rank)| rank).max()?;
+
+        Some(
+            moves
+                .into_iter()
+                .filter(|&(_, rank)| rank == best_rank)
+                .map(|(mv, _)| mv)
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_name() {
+        crate::init();
+
+        let table = |fen: &str| table_name(&Game::from_fen(fen).unwrap());
+
+        assert_eq!(table("8/8/8/8/8/2k5/8/K1Q5 w - - 0 1"), "KQvK");
+        assert_eq!(table("8/8/8/8/8/2k5/8/K1q5 w - - 0 1"), "KQvK");
+        assert_eq!(table("8/8/3r4/8/8/2k5/P7/K1Q5 b - - 0 1"), "KQPvKR");
+        assert_eq!(table("8/8/3R4/8/8/2k5/p7/K1q5 w - - 0 1"), "KQPvKR");
+        assert_eq!(table("8/8/3r4/8/8/2k5/8/K1N5 w - - 0 1"), "KRvKN");
+
+        // The side with more pieces comes first, even if it has less material
+        assert_eq!(table("8/8/3r4/8/8/2k5/8/K1NB4 w - - 0 1"), "KBNvKR");
+        assert_eq!(table("8/8/3R4/8/8/2k5/8/K1nb4 w - - 0 1"), "KBNvKR");
+
+        // With the same number of pieces, the side with the more valuable pieces comes first
+        assert_eq!(table("8/8/3r4/8/8/2k5/8/K1B5 w - - 0 1"), "KRvKB");
+        assert_eq!(table("8/8/3rb3/8/8/2k5/8/K1NR4 w - - 0 1"), "KRBvKRN");
+        assert_eq!(table("8/8/3RB3/8/8/2k5/8/K1nr4 w - - 0 1"), "KRBvKRN");
+    }
+
+    #[test]
+    fn test_tables_needed() {
+        crate::init();
+
+        let game = Game::from_fen("8/8/8/3r4/8/2k5/8/K2Q4 w - - 0 1").unwrap();
+        assert_eq!(tables_needed(&game), vec!["KQvKR", "KQvK"]);
+    }
+
+    #[test]
+    fn test_wdl_cache() {
+        let mut cache = WdlCache::new();
+        let key = ZobristHash(0x1234_5678);
+        let colliding_key = ZobristHash(0x1234_5678 + WDL_CACHE_SIZE as u64);
+
+        assert_eq!(cache.get(&key), None);
+
+        cache.insert(&key, Wdl::Win);
+        assert_eq!(cache.get(&key), Some(Wdl::Win));
+        assert_eq!(cache.get(&colliding_key), None);
+
+        cache.insert(&colliding_key, Wdl::Loss);
+        assert_eq!(cache.get(&colliding_key), Some(Wdl::Loss));
+        assert_eq!(cache.get(&key), None);
+
+        cache.clear();
+        assert_eq!(cache.get(&colliding_key), None);
     }
 }
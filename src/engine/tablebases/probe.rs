@@ -0,0 +1,165 @@
+use super::Wdl;
+use crate::chess::game::Game;
+use crate::chess::moves::{Move, MoveListExt};
+use crate::chess::piece::PromotionPieceKind;
+use crate::chess::player::Player;
+use crate::chess::square::Square;
+use std::ffi::{c_uint, CString};
+use std::ptr;
+use std::sync::{PoisonError, RwLock};
+
+#[allow(
+    unused,
+    non_camel_case_types,
+    non_upper_case_globals,
+    non_snake_case,
+    clippy::allow_attributes,
+    clippy::allow_attributes_without_reason,
+    clippy::unreadable_literal
+)]
+mod bindings;
+
+// Fathom keeps the loaded tablebases in global state, so they're shared by every engine instance
+// in the process. Loading them frees the old ones, which mustn't happen while another thread is
+// probing, so probes hold a read lock and loading holds the write lock.
+static TABLEBASES: RwLock<()> = RwLock::new(());
+
+pub fn init(path: &str) -> Result<(), String> {
+    let c_path = CString::new(path).map_err(|_| format!("Invalid tablebase path: {path}"))?;
+
+    let _tablebases = TABLEBASES.write().unwrap_or_else(PoisonError::into_inner);
+    let was_set = unsafe { bindings::tb_init(c_path.as_ptr()) };
+
+    if !was_set || unsafe { bindings::TB_LARGEST } == 0 {
+        return Err(format!("Invalid tablebase path: {path}"));
+    }
+
+    Ok(())
+}
+
+#[expect(
+    clippy::cast_possible_truncation,
+    reason = "n_men will be at most 7 as these are the largest syzygy tablebases"
+)]
+pub fn largest() -> u8 {
+    let _tablebases = TABLEBASES.read().unwrap_or_else(PoisonError::into_inner);
+    unsafe { bindings::TB_LARGEST as u8 }
+}
+
+pub fn wdl(game: &Game) -> Option<Wdl> {
+    let _tablebases = TABLEBASES.read().unwrap_or_else(PoisonError::into_inner);
+
+    unsafe {
+        let wdl = bindings::tb_probe_wdl(
+            game.board.occupancy_for(Player::White).as_u64(),
+            game.board.occupancy_for(Player::Black).as_u64(),
+            game.board.all_kings().as_u64(),
+            game.board.all_queens().as_u64(),
+            game.board.all_rooks().as_u64(),
+            game.board.all_bishops().as_u64(),
+            game.board.all_knights().as_u64(),
+            game.board.all_pawns().as_u64(),
+            0,
+            0,
+            0,
+            game.player == Player::White,
+        );
+
+        to_wdl(wdl)
+    }
+}
+
+pub fn best_move(game: &Game) -> Option<Move> {
+    let result = unsafe { probe_root(game, ptr::null_mut()) };
+
+    if result == bindings::TB_RESULT_FAILED {
+        return None;
+    }
+
+    Some(result_move(game, result))
+}
+
+// The distance to zeroing the halfmove clock (by a capture or pawn move) with best play
+pub fn dtz(game: &Game) -> Option<u32> {
+    let result = unsafe { probe_root(game, ptr::null_mut()) };
+
+    if result == bindings::TB_RESULT_FAILED {
+        return None;
+    }
+
+    Some((result & bindings::TB_RESULT_DTZ_MASK) >> bindings::TB_RESULT_DTZ_SHIFT)
+}
+
+// Every root move, with a rank for its WDL result from the side to move's point of view. A
+// higher rank is better, so a cursed win ranks above a draw but below a win.
+pub fn root_moves(game: &Game) -> Option<Vec<(Move, u32)>> {
+    let mut results = [bindings::TB_RESULT_FAILED; bindings::TB_MAX_MOVES as usize];
+    let result = unsafe { probe_root(game, results.as_mut_ptr()) };
+
+    if result == bindings::TB_RESULT_FAILED {
+        return None;
+    }
+
+    Some(
+        results
+            .into_iter()
+            .take_while(|&r| r != bindings::TB_RESULT_FAILED)
+            .map(|r| {
+                let rank = (r & bindings::TB_RESULT_WDL_MASK) >> bindings::TB_RESULT_WDL_SHIFT;
+                (result_move(game, r), rank)
+            })
+            .collect(),
+    )
+}
+
+unsafe fn probe_root(game: &Game, results: *mut c_uint) -> c_uint {
+    let _tablebases = TABLEBASES.read().unwrap_or_else(PoisonError::into_inner);
+
+    bindings::tb_probe_root(
+        game.board.occupancy_for(Player::White).as_u64(),
+        game.board.occupancy_for(Player::Black).as_u64(),
+        game.board.all_kings().as_u64(),
+        game.board.all_queens().as_u64(),
+        game.board.all_rooks().as_u64(),
+        game.board.all_bishops().as_u64(),
+        game.board.all_knights().as_u64(),
+        game.board.all_pawns().as_u64(),
+        game.halfmove_clock,
+        0,
+        0,
+        game.player == Player::White,
+        results,
+    )
+}
+
+#[rustfmt::skip]
+fn result_move(game: &Game, result: c_uint) -> Move {
+    let from_bits = (result & bindings::TB_RESULT_FROM_MASK) >> bindings::TB_RESULT_FROM_SHIFT;
+    let to_bits = (result & bindings::TB_RESULT_TO_MASK) >> bindings::TB_RESULT_TO_SHIFT;
+    let promotion_bits = (result & bindings::TB_RESULT_PROMOTES_MASK) >> bindings::TB_RESULT_PROMOTES_SHIFT;
+
+    let from = Square::from_index(from_bits as u8);
+    let to = Square::from_index(to_bits as u8);
+
+    let promotion = match promotion_bits {
+        bindings::TB_PROMOTES_QUEEN => Some(PromotionPieceKind::Queen),
+        bindings::TB_PROMOTES_ROOK => Some(PromotionPieceKind::Rook),
+        bindings::TB_PROMOTES_BISHOP => Some(PromotionPieceKind::Bishop),
+        bindings::TB_PROMOTES_KNIGHT => Some(PromotionPieceKind::Knight),
+        _ => None,
+    };
+
+    game.moves().expect_matching(from, to, promotion)
+}
+
+fn to_wdl(outcome: c_uint) -> Option<Wdl> {
+    use Wdl::*;
+
+    match outcome {
+        bindings::TB_WIN => Some(Win),
+        bindings::TB_LOSS => Some(Loss),
+        bindings::TB_DRAW | bindings::TB_CURSED_WIN | bindings::TB_BLESSED_LOSS => Some(Draw),
+        bindings::TB_RESULT_FAILED => None,
+        _ => unreachable!(),
+    }
+}
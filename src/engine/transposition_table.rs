@@ -1,7 +1,26 @@
 use crate::chess::zobrist::ZobristHash;
 
+// Each position can be stored in any of the slots in its bucket, so a position that hashes to the
+// same place as another doesn't necessarily have to throw it away
+pub const BUCKET_SIZE: usize = 4;
+
+// Entries only have room for 6 bits of the generation, so it wraps around sooner than a u8 would
+pub const GENERATIONS: u8 = 64;
+
 pub trait TTOverwriteable {
+    // Whether a new result for the same position should replace this one
     fn should_overwrite_with(&self, new: &Self) -> bool;
+
+    // How much we'd like to keep this entry when a different position needs a slot in its bucket.
+    // The entry with the lowest priority is replaced. Tables that don't age their entries can
+    // leave this and `generation` as they are.
+    fn replacement_priority(&self, _generation: u8) -> i32 {
+        0
+    }
+
+    fn generation(&self) -> u8 {
+        0
+    }
 }
 
 pub struct TranspositionTable<T: Clone + TTOverwriteable> {
@@ -9,6 +28,48 @@ pub struct TranspositionTable<T: Clone + TTOverwriteable> {
     pub generation: u8,
     pub occupied: usize,
     size: usize,
+    stats: TTStats,
+}
+
+// Counters for how well the table is working, reset with each new generation. Writes are counted
+// by the table itself, but probes are counted by the search and added with `record_probes`, so
+// that `get` doesn't need to write to the table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TTStats {
+    pub probes: u64,
+    pub hits: u64,
+    pub writes: u64,
+    // Writes that threw away an entry for a different position
+    pub replacements: u64,
+    // Replacements that threw away an entry written during the current generation
+    pub current_generation_replacements: u64,
+    // Writes for a position already in the table that were rejected in favour of the existing entry
+    pub rejected_writes: u64,
+}
+
+impl std::ops::AddAssign for TTStats {
+    fn add_assign(&mut self, rhs: Self) {
+        self.probes += rhs.probes;
+        self.hits += rhs.hits;
+        self.writes += rhs.writes;
+        self.replacements += rhs.replacements;
+        self.current_generation_replacements += rhs.current_generation_replacements;
+        self.rejected_writes += rhs.rejected_writes;
+    }
+}
+
+impl TTStats {
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "This is just for reporting, so a loss of precision is fine"
+    )]
+    pub fn hit_rate(&self) -> f64 {
+        if self.probes == 0 {
+            return 0.0;
+        }
+
+        self.hits as f64 / self.probes as f64
+    }
 }
 
 #[derive(Clone)]
@@ -20,7 +81,8 @@ pub struct TranspositionTableEntry<T: Clone + TTOverwriteable> {
 pub fn calculate_number_of_entries<T: Clone + TTOverwriteable>(size_mb: usize) -> usize {
     let size_of_entry = std::mem::size_of::<TranspositionTableEntry<T>>();
     let total_size_in_bytes = size_mb * 1024 * 1024;
-    total_size_in_bytes / size_of_entry
+    let number_of_buckets = (total_size_in_bytes / size_of_entry / BUCKET_SIZE).max(1);
+    number_of_buckets * BUCKET_SIZE
 }
 
 impl<T: Clone + TTOverwriteable> TranspositionTable<T> {
@@ -30,6 +92,7 @@ impl<T: Clone + TTOverwriteable> TranspositionTable<T> {
             size: 0,
             occupied: 0,
             generation: 0,
+            stats: TTStats::default(),
         };
 
         tt.resize(size_mb);
@@ -43,6 +106,7 @@ impl<T: Clone + TTOverwriteable> TranspositionTable<T> {
 
         self.generation = 0;
         self.occupied = 0;
+        self.stats = TTStats::default();
     }
 
     pub fn resize(&mut self, size_mb: usize) {
@@ -60,17 +124,30 @@ impl<T: Clone + TTOverwriteable> TranspositionTable<T> {
         self.generation = 0;
     }
 
+    // Entries remember the generation they were written in, so it's fine for this to wrap around
+    // in a long session
     pub fn new_generation(&mut self) {
-        self.generation += 1;
+        self.generation = (self.generation + 1) % GENERATIONS;
+        self.stats = TTStats::default();
+    }
+
+    pub fn stats(&self) -> TTStats {
+        self.stats
+    }
+
+    pub fn record_probes(&mut self, probes: u64, hits: u64) {
+        self.stats.probes += probes;
+        self.stats.hits += hits;
     }
 
     #[expect(
         clippy::cast_possible_truncation,
         reason = "The truncation is intended to get an index"
     )]
-    fn get_entry_idx(&self, key: &ZobristHash) -> usize {
+    fn get_bucket(&self, key: &ZobristHash) -> std::ops::Range<usize> {
         // PERF: There's likely a more performant way to do this
-        key.0 as usize % self.data.len()
+        let start = key.0 as usize % (self.data.len() / BUCKET_SIZE) * BUCKET_SIZE;
+        start..start + BUCKET_SIZE
     }
 
     #[expect(
@@ -86,40 +163,75 @@ impl<T: Clone + TTOverwriteable> TranspositionTable<T> {
     }
 
     pub fn insert(&mut self, key: &ZobristHash, data: T) {
-        let idx = self.get_entry_idx(key);
-
-        // !: We know the exact size of the table and will always access within the bounds.
-        unsafe {
-            if let Some(existing_data) = self.data.get_unchecked(idx) {
-                if existing_data.data.should_overwrite_with(&data) {
-                    self.data[idx] = Some(TranspositionTableEntry {
-                        key: key.clone(),
-                        data,
-                    });
-                }
+        let bucket = self.get_bucket(key);
+        let stats = &mut self.stats;
+        stats.writes += 1;
+
+        // If the position is already in the bucket, it's up to the existing entry whether it's
+        // replaced
+        if let Some(existing) = self.data[bucket.clone()]
+            .iter_mut()
+            .flatten()
+            .find(|entry| entry.key == *key)
+        {
+            if existing.data.should_overwrite_with(&data) {
+                existing.data = data;
             } else {
-                self.occupied += 1;
-
-                self.data[idx] = Some(TranspositionTableEntry {
-                    key: key.clone(),
-                    data,
-                });
+                stats.rejected_writes += 1;
             }
+
+            return;
         }
-    }
 
-    pub fn get(&self, key: &ZobristHash) -> Option<&T> {
-        let idx = self.get_entry_idx(key);
-
-        // !: We know the exact size of the table and will always access within the bounds.
-        unsafe {
-            if let Some(entry) = self.data.get_unchecked(idx) {
-                if entry.key == *key {
-                    return Some(&entry.data);
-                }
-            }
+        let new_entry = Some(TranspositionTableEntry {
+            key: key.clone(),
+            data,
+        });
+
+        if let Some(empty) = self.data[bucket.clone()].iter_mut().find(|e| e.is_none()) {
+            *empty = new_entry;
+            self.occupied += 1;
+            return;
+        }
+
+        let generation = self.generation;
+        let victim = self.data[bucket]
+            .iter_mut()
+            .min_by_key(|entry| {
+                entry
+                    .as_ref()
+                    .map_or(i32::MIN, |e| e.data.replacement_priority(generation))
+            })
+            .unwrap();
+
+        stats.replacements += 1;
+        if victim
+            .as_ref()
+            .is_some_and(|e| e.data.generation() == generation)
+        {
+            stats.current_generation_replacements += 1;
         }
 
-        None
+        *victim = new_entry;
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = &TranspositionTableEntry<T>> {
+        self.data.iter().flatten()
+    }
+
+    // The entries sharing a bucket with the key, which compete with it for space
+    pub fn bucket_entries(
+        &self,
+        key: &ZobristHash,
+    ) -> impl Iterator<Item = &TranspositionTableEntry<T>> {
+        self.data[self.get_bucket(key)].iter().flatten()
+    }
+
+    pub fn get(&self, key: &ZobristHash) -> Option<&T> {
+        self.data[self.get_bucket(key)]
+            .iter()
+            .flatten()
+            .find(|entry| entry.key == *key)
+            .map(|entry| &entry.data)
     }
 }
@@ -2,11 +2,16 @@
 // https://github.com/lynx-chess/Lynx/blob/main/src/Lynx/Bench.cs
 // https://github.com/JacquesRW/akimbo/blob/main/resources/fens.txt
 
+use std::time::{Duration, Instant};
+
 use crate::chess::game::Game;
+use crate::chess::movegen;
+use crate::chess::moves::MoveList;
 use crate::engine::options::EngineOptions;
 use crate::engine::search;
 use crate::engine::search::time_control::TimeStrategy;
-use crate::engine::search::{CapturingReporter, PersistentState, SearchRestrictions, TimeControl};
+use crate::engine::search::{NullReporter, PersistentState, SearchRestrictions, TimeControl};
+use crate::engine::transposition_table::TTStats;
 
 const POSITIONS: [&str; 87] = [
     "r3k2r/2pb1ppp/2pp1q2/p7/1nP1B3/1P2P3/P2N1PPP/R2QK2R w KQkq a6 0 14",
@@ -98,30 +103,206 @@ const POSITIONS: [&str; 87] = [
     "r3k2r/ppp2ppp/n7/1N1p4/Bb6/8/PPPP1PPP/RNBQ1RK1 w - - 2 1", // Double check B and N, no castling rights
 ];
 
-pub fn bench(depth: u8) -> u64 {
+// The depth searched by the 'bench' command
+pub const BENCH_DEPTH: u8 = 10;
+
+// The number of nodes searched by 'bench'. This only changes when the search does, so tools (e.g.
+// OpenBench or release scripts) can compare it to check that a change is functionally equivalent.
+pub fn signature() -> u64 {
+    bench(BENCH_DEPTH).nodes
+}
+
+pub struct BenchPosition {
+    pub fen: &'static str,
+    pub depth: u8,
+    pub time_to_depth: Duration,
+    pub effective_branching_factor: Option<f32>,
+}
+
+pub struct BenchResult {
+    // The number of nodes searched, which is the signature when searched to `BENCH_DEPTH`
+    pub nodes: u64,
+    pub positions: Vec<BenchPosition>,
+}
+
+impl BenchResult {
+    // Node counts only say how big the tree was, whereas the branching factor says how well it was
+    // pruned, independently of how deep the search got
+    #[expect(clippy::cast_precision_loss, reason = "This is only an average")]
+    pub fn average_effective_branching_factor(&self) -> Option<f32> {
+        let branching_factors: Vec<f32> = self
+            .positions
+            .iter()
+            .filter_map(|position| position.effective_branching_factor)
+            .collect();
+
+        if branching_factors.is_empty() {
+            return None;
+        }
+
+        Some(branching_factors.iter().sum::<f32>() / branching_factors.len() as f32)
+    }
+}
+
+pub fn bench(depth: u8) -> BenchResult {
     let mut nodes = 0;
+    let mut positions = Vec::with_capacity(POSITIONS.len());
 
     for position in POSITIONS {
-        let mut bench_reporter = CapturingReporter::new();
-        let game = Game::from_fen(position).unwrap();
+        let mut game = Game::from_fen(position).unwrap();
 
         let mut persistent_state = PersistentState::new(16);
         let options = EngineOptions::default();
 
         let (mut time_strategy, _) = TimeStrategy::new(&game, &TimeControl::Infinite, &options);
-        let search_restrictions = SearchRestrictions { depth: Some(depth) };
+        let search_restrictions = SearchRestrictions {
+            depth: Some(depth),
+            ..SearchRestrictions::default()
+        };
 
-        let _ = search::search(
-            &game,
+        let result = search::search(
+            &mut game,
             &mut persistent_state,
             &mut time_strategy,
             &search_restrictions,
             &options,
-            &mut bench_reporter,
+            &NullReporter,
         );
 
-        nodes += bench_reporter.nodes;
+        nodes += result.stats.nodes;
+
+        // The search can finish early, e.g. when it finds a forced mate
+        let last_iteration = result.iterations.last();
+        positions.push(BenchPosition {
+            fen: position,
+            depth: last_iteration.map_or(0, |iteration| iteration.depth),
+            time_to_depth: last_iteration.map_or(Duration::ZERO, |iteration| iteration.time),
+            effective_branching_factor: result.effective_branching_factor(),
+        });
+    }
+
+    BenchResult { nodes, positions }
+}
+
+// Search each of the bench positions in turn with one small table, as if they were the moves of a
+// game, so that the table fills up and the replacement policy has to choose what to keep. Returns
+// the TT counters summed over every search.
+pub fn bench_tt(depth: u8, hash_size_mb: usize) -> TTStats {
+    let mut persistent_state = PersistentState::new(hash_size_mb);
+    let options = EngineOptions::default();
+    let mut stats = TTStats::default();
+
+    for position in POSITIONS {
+        let mut game = Game::from_fen(position).unwrap();
+
+        let (mut time_strategy, _) = TimeStrategy::new(&game, &TimeControl::Infinite, &options);
+        let search_restrictions = SearchRestrictions {
+            depth: Some(depth),
+            ..SearchRestrictions::default()
+        };
+
+        search::search(
+            &mut game,
+            &mut persistent_state,
+            &mut time_strategy,
+            &search_restrictions,
+            &options,
+            &NullReporter,
+        );
+
+        stats += persistent_state.tt.stats();
+    }
+
+    stats
+}
+
+pub struct MovegenBenchResult {
+    pub nodes: u64,
+    pub legal_time: Duration,
+    pub pseudo_legal_time: Duration,
+}
+
+// Compare generating strictly legal moves with generating pseudo-legal moves and filtering out
+// the illegal ones, by running perft on each of the bench positions using each approach.
+pub fn bench_movegen(depth: u8) -> MovegenBenchResult {
+    let mut nodes = 0;
+    let mut legal_time = Duration::ZERO;
+    let mut pseudo_legal_time = Duration::ZERO;
+
+    for position in POSITIONS {
+        let mut game = Game::from_fen(position).unwrap();
+
+        let started_at = Instant::now();
+        let legal_nodes = perft::<false>(depth, &mut game);
+        legal_time += started_at.elapsed();
+
+        let started_at = Instant::now();
+        let pseudo_legal_nodes = perft::<true>(depth, &mut game);
+        pseudo_legal_time += started_at.elapsed();
+
+        assert_eq!(
+            legal_nodes, pseudo_legal_nodes,
+            "Pseudo-legal movegen disagrees with legal movegen for {position}"
+        );
+
+        nodes += legal_nodes;
+    }
+
+    MovegenBenchResult {
+        nodes,
+        legal_time,
+        pseudo_legal_time,
+    }
+}
+
+fn perft<const PSEUDO_LEGAL: bool>(depth: u8, game: &mut Game) -> u64 {
+    if depth == 0 {
+        return 1;
+    }
+
+    let mut moves = MoveList::new();
+
+    if PSEUDO_LEGAL {
+        movegen::generate_pseudo_legal_moves(game, &mut moves);
+    } else {
+        movegen::generate_legal_moves(game, &mut moves);
+    }
+
+    let mut nodes = 0;
+
+    for mv in moves {
+        if PSEUDO_LEGAL && !movegen::is_legal_after(game, mv) {
+            continue;
+        }
+
+        game.make_move(mv);
+        nodes += perft::<PSEUDO_LEGAL>(depth - 1, game);
+        game.undo_move();
     }
 
     nodes
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bench_is_deterministic() {
+        crate::init();
+
+        assert_eq!(bench(3).nodes, bench(3).nodes);
+    }
+
+    #[test]
+    fn test_bench_tt_fills_table() {
+        crate::init();
+
+        let stats = bench_tt(5, 1);
+
+        assert!(stats.replacements > 0);
+        assert!(stats.current_generation_replacements <= stats.replacements);
+        assert!(stats.hits <= stats.probes);
+        assert!(stats.hit_rate() > 0.05);
+    }
+}
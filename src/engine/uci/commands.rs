@@ -1,4 +1,4 @@
-use crate::engine::uci::UciMove;
+use crate::engine::uci::{PositionMove, UciMove};
 use std::time::Duration;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -7,7 +7,7 @@ pub enum Position {
     StartPos,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct GoCmdArguments {
     pub ponder: bool,
     pub wtime: Option<Duration>,
@@ -16,7 +16,7 @@ pub struct GoCmdArguments {
     pub binc: Option<Duration>,
     pub movestogo: Option<u32>,
     pub depth: Option<u8>,
-    pub nodes: Option<u32>,
+    pub nodes: Option<u64>,
     pub movetime: Option<Duration>,
     pub infinite: bool,
 }
@@ -27,8 +27,18 @@ pub enum DebugCommand {
     SetPosition { position: String },
     Perft { depth: u8 },
     PerftDiv { depth: u8 },
-    Move { moves: Vec<UciMove> },
+    Move { moves: Vec<PositionMove> },
     Eval,
+    SaveHash { path: String },
+    LoadHash { path: String },
+    Hint { time: Duration },
+    DumpTrace,
+    History,
+    Moves,
+    See { mv: UciMove },
+    QSearch,
+    Probe,
+    HashKey,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -40,15 +50,18 @@ pub enum UciCommand {
         name: String,
         value: String,
     },
+    Register,
     UciNewGame,
     Position {
         position: Position,
-        moves: Vec<UciMove>,
+        moves: Vec<PositionMove>,
     },
     Go(GoCmdArguments),
     D(DebugCommand),
     Stop,
     PonderHit,
     Bench,
+    BenchMovegen,
+    BenchTt,
     Quit,
 }
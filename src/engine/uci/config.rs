@@ -0,0 +1,155 @@
+//! Options can be set in a `tcheran.toml` file next to the engine binary, so that they don't
+//! need to be sent with `setoption` at the start of every session.
+//!
+//! Only a small subset of TOML is supported: one `name = value` pair per line, with optional
+//! quotes around names and values, and `#` comments. The only escapes allowed in quoted strings
+//! are `\\` and `\"`.
+//!
+//! ```toml
+//! Hash = 512
+//! SyzygyPath = "/path/to/syzygy"
+//! "Move Overhead" = 50
+//! ```
+
+use std::path::PathBuf;
+
+const CONFIG_FILE_NAME: &str = "tcheran.toml";
+
+pub fn config_file_path() -> Option<PathBuf> {
+    let current_exe = std::env::current_exe().ok()?;
+    Some(current_exe.with_file_name(CONFIG_FILE_NAME))
+}
+
+pub fn read_config_file() -> Result<Vec<(String, String)>, String> {
+    let Some(path) = config_file_path() else {
+        return Ok(Vec::new());
+    };
+
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let contents = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Unable to read {}: {e}", path.display()))?;
+
+    parse(&contents).map_err(|e| format!("Unable to parse {}: {e}", path.display()))
+}
+
+pub fn parse(contents: &str) -> Result<Vec<(String, String)>, String> {
+    let mut options = Vec::new();
+
+    for (line_number, line) in contents.lines().enumerate() {
+        let line = strip_comment(line).trim();
+
+        if line.is_empty() {
+            continue;
+        }
+
+        let Some((name, value)) = line.split_once('=') else {
+            return Err(format!("line {}: expected 'name = value'", line_number + 1));
+        };
+
+        let unquote =
+            |s: &str| unquote(s.trim()).map_err(|e| format!("line {}: {e}", line_number + 1));
+
+        let name = unquote(name)?;
+        let value = unquote(value)?;
+
+        if name.is_empty() {
+            return Err(format!("line {}: missing option name", line_number + 1));
+        }
+
+        options.push((name, value));
+    }
+
+    Ok(options)
+}
+
+// Comments start with a '#', as long as it's not inside a quoted string
+fn strip_comment(line: &str) -> &str {
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+            continue;
+        }
+
+        match c {
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            '#' if !in_quotes => return &line[..i],
+            _ => {}
+        }
+    }
+
+    line
+}
+
+// Escapes other than '\\' and '\"' are rejected rather than passed through, since a Windows path
+// like "C:\tb" would otherwise be read differently to how TOML reads it
+fn unquote(s: &str) -> Result<String, String> {
+    let Some(s) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) else {
+        return Ok(s.to_string());
+    };
+
+    let mut unquoted = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some(c @ ('\\' | '"')) => unquoted.push(c),
+            Some(c) => return Err(format!("unsupported escape '\\{c}'")),
+            None => return Err("unterminated string".to_string()),
+        }
+    }
+
+    Ok(unquoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        let config = r#"
+            # Engine configuration
+            Hash = 512
+            "Move Overhead" = 50 # For slow connections
+            SyzygyPath = "/path/to/#syzygy"
+        "#;
+
+        assert_eq!(
+            parse(config).unwrap(),
+            vec![
+                ("Hash".to_string(), "512".to_string()),
+                ("Move Overhead".to_string(), "50".to_string()),
+                ("SyzygyPath".to_string(), "/path/to/#syzygy".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_config_escapes() {
+        assert_eq!(
+            parse(r#"SyzygyPath = "C:\\tb\\\"syzygy\"" # Comment"#).unwrap(),
+            vec![("SyzygyPath".to_string(), r#"C:\tb\"syzygy""#.to_string())]
+        );
+
+        assert!(parse(r#"SyzygyPath = "C:\tb""#).is_err());
+        assert!(parse(r#"SyzygyPath = "C:\\tb\""#).is_err());
+    }
+
+    #[test]
+    fn test_parse_config_invalid_line() {
+        assert!(parse("Hash 512").is_err());
+        assert!(parse("= 512").is_err());
+    }
+}
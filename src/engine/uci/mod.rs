@@ -1,16 +1,19 @@
 //! Implementation of the Universal Chess Interface (UCI) protocol
 
 use std::io::{BufRead, IsTerminal};
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
+use crate::chess::fen::{self, FenFormat};
 use crate::chess::moves::{Move, MoveListExt};
-use crate::chess::{perft, san};
+use crate::chess::notation::Notation;
+use crate::chess::perft;
 
+use crate::engine::handle::Engine;
 use crate::engine::options::EngineOptions;
-use crate::engine::{eval, search, uci, util};
-use crate::uci::commands::DebugCommand;
-use crate::uci::options::UciOption;
+use crate::engine::uci::commands::DebugCommand;
+use crate::engine::{eval, search, see, tablebases, util};
 use crate::ENGINE_NAME;
 
 use self::responses::{InfoFields, InfoScore};
@@ -19,29 +22,92 @@ use self::{
     responses::{IdParam, UciResponse},
 };
 
-mod bench;
+pub mod bench;
 pub mod commands;
+mod config;
 mod r#move;
-mod options;
+pub mod options;
 pub mod parser;
 pub mod responses;
 
-use crate::chess::game::Game;
+use crate::chess::game::{Game, Outcome};
 use crate::chess::player::Player;
-use crate::engine::search::time_control::{Control, TimeStrategy};
+use crate::engine::search::principal_variation::PrincipalVariation;
+use crate::engine::search::transposition::SearchTranspositionTableData;
 use crate::engine::search::{
-    Clocks, PersistentState, Reporter, SearchRestrictions, SearchScore, TimeControl,
+    Clocks, PersistentState, Reporter, SearchRestrictions, SearchResult, SearchScore, TimeControl,
 };
-use crate::engine::uci::bench::bench;
-use crate::engine::util::sync::LockLatch;
-pub use r#move::UciMove;
+use crate::engine::uci::bench::{bench_movegen, bench_tt};
+pub use r#move::{PositionMove, UciMove};
+
+// The number of moves suggested by 'd hint'
+const HINT_MOVES: usize = 3;
+
+// Decides which search progress reports are sent, so that very fast searches don't spend time
+// (or log space) writing out every iteration. Reports that are held back are remembered so that
+// the last one can be sent when the search finishes.
+#[derive(Default)]
+pub struct ProgressFilter {
+    min_depth: u8,
+    interval: Duration,
+    state: Mutex<ProgressFilterState>,
+}
+
+#[derive(Default)]
+struct ProgressFilterState {
+    last_reported_at: Option<Duration>,
+    held_back: Option<search::SearchInfo>,
+}
+
+impl ProgressFilter {
+    pub fn new(min_depth: usize, interval: Duration) -> Self {
+        Self {
+            min_depth: u8::try_from(min_depth).unwrap_or(u8::MAX),
+            interval,
+            state: Mutex::default(),
+        }
+    }
+
+    fn should_report(&self, progress: &search::SearchInfo) -> bool {
+        let mut state = self.state.lock().unwrap();
+
+        let too_shallow = progress.depth < self.min_depth;
+        let too_soon = state
+            .last_reported_at
+            .is_some_and(|last| progress.stats.time < last + self.interval);
+
+        if too_shallow || too_soon {
+            state.held_back = Some(progress.clone());
+            return false;
+        }
+
+        state.last_reported_at = Some(progress.stats.time);
+        state.held_back = None;
+        true
+    }
+
+    fn take_held_back(&self) -> Option<search::SearchInfo> {
+        self.state.lock().unwrap().held_back.take()
+    }
+}
 
 #[derive(Clone)]
 pub struct UciReporter {
     pub pretty_output: bool,
+    pub debug: bool,
+    pub notation: Notation,
+    pub progress_filter: Arc<ProgressFilter>,
 }
 
 impl UciReporter {
+    fn report_progress(&self, game: &Game, progress: &search::SearchInfo) {
+        if self.pretty_output {
+            self.pretty_report_search_progress(game, progress);
+        } else {
+            Self::uci_report_search_progress(progress);
+        }
+    }
+
     fn uci_report_search_progress(progress: &search::SearchInfo) {
         let score = match progress.score {
             SearchScore::Centipawns(cp) => InfoScore::Centipawns(cp),
@@ -69,12 +135,37 @@ impl UciReporter {
         }));
     }
 
+    fn pretty_score(score: SearchScore) -> colored::ColoredString {
+        use colored::Colorize;
+
+        match score {
+            SearchScore::Centipawns(cp) => {
+                let friendly_score = format!("{:+.2}", f64::from(cp) / 100.0);
+
+                match cp {
+                    i16::MIN..=-11 => friendly_score.red(),
+                    -10..=10 => friendly_score.white(),
+                    11..=i16::MAX => friendly_score.green(),
+                }
+            }
+            SearchScore::Mate(plies) => {
+                let friendly_mate = format!("M{}", plies.abs());
+
+                match plies {
+                    i16::MIN..=-1 => friendly_mate.red(),
+                    1..=i16::MAX => friendly_mate.green(),
+                    0 => unreachable!(),
+                }
+            }
+        }
+    }
+
     // Inspired by Simbelmyne's lovely search output
     #[expect(
         clippy::cast_precision_loss,
         reason = "Various approximate calculations"
     )]
-    fn pretty_report_search_progress(game: &Game, progress: &search::SearchInfo) {
+    fn pretty_report_search_progress(&self, game: &Game, progress: &search::SearchInfo) {
         use colored::Colorize;
 
         let mut game = game.clone();
@@ -82,29 +173,7 @@ impl UciReporter {
         print!(" {:>3}", progress.depth);
         print!("{}", format!("/{:<3}", progress.seldepth).bright_black());
 
-        print!(
-            " {:>7}",
-            match progress.score {
-                SearchScore::Centipawns(cp) => {
-                    let friendly_score = format!("{:+.2}", f64::from(cp) / 100.0);
-
-                    match cp {
-                        i16::MIN..=-11 => friendly_score.red(),
-                        -10..=10 => friendly_score.white(),
-                        11..=i16::MAX => friendly_score.green(),
-                    }
-                }
-                SearchScore::Mate(plies) => {
-                    let friendly_mate = format!("M{}", plies.abs());
-
-                    match plies {
-                        i16::MIN..=-1 => friendly_mate.red(),
-                        1..=i16::MAX => friendly_mate.green(),
-                        0 => unreachable!(),
-                    }
-                }
-            }
-        );
+        print!(" {:>7}", Self::pretty_score(progress.score));
 
         print!(
             "  {:>6}",
@@ -136,7 +205,7 @@ impl UciReporter {
 
         print!("  ");
         for mv in progress.pv.clone() {
-            let san_mv = san::format_move(&game, mv);
+            let san_mv = self.notation.format_move(&game, mv);
 
             print!(
                 " {}",
@@ -152,15 +221,33 @@ impl UciReporter {
         println!();
     }
 
-    fn uci_best_move(mv: Move) {
+    fn uci_best_move(mv: Move, ponder: Option<Move>) {
         send_response(&UciResponse::BestMove {
-            mv: mv.into(),
-            ponder: None,
+            mv: Some(mv.into()),
+            ponder: ponder.map(Into::into),
         });
     }
 
-    fn pretty_best_move(game: &Game, mv: Move) {
-        println!("bestmove {}", san::format_move(game, mv));
+    fn pretty_best_move(&self, game: &Game, mv: Move) {
+        println!("bestmove {}", self.notation.format_move(game, mv));
+    }
+
+    fn refutation(&self, game: &Game, refutation: PrincipalVariation) {
+        if self.pretty_output {
+            let mut game = game.clone();
+
+            print!("refutation");
+            for mv in refutation {
+                print!(" {}", self.notation.format_move(&game, mv));
+                game.make_move(mv);
+            }
+            println!();
+        } else {
+            send_response(&UciResponse::Info(InfoFields {
+                refutation: Some(refutation.into_iter().map(Into::into).collect()),
+                ..Default::default()
+            }));
+        }
     }
 }
 
@@ -169,32 +256,91 @@ impl Reporter for UciReporter {
         println!("{s}");
     }
 
-    fn report_search_progress(&mut self, game: &Game, progress: search::SearchInfo) {
+    fn report_search_progress(&self, game: &Game, progress: search::SearchInfo) {
+        if self.progress_filter.should_report(&progress) {
+            self.report_progress(game, &progress);
+        }
+    }
+
+    fn best_move(&self, game: &Game, mv: Move) {
         if self.pretty_output {
-            Self::pretty_report_search_progress(game, &progress);
+            self.pretty_best_move(game, mv);
         } else {
-            Self::uci_report_search_progress(&progress);
+            Self::uci_best_move(mv, None);
         }
     }
 
-    fn best_move(&self, game: &Game, mv: Move) {
+    fn search_complete(
+        &self,
+        game: &Game,
+        persistent_state: &PersistentState,
+        result: &SearchResult,
+    ) {
+        if let Some(progress) = self.progress_filter.take_held_back() {
+            self.report_progress(game, &progress);
+        }
+
+        if self.debug {
+            for refutation in search::refutations(game, persistent_state, result.best_move) {
+                self.refutation(game, refutation);
+            }
+
+            let tt_stats = persistent_state.tt.stats();
+            send_response(&UciResponse::Info(InfoFields {
+                string: Some(format!(
+                    "tt hits {:.1}% of {} probes, {} writes, {} replacements ({} from this search), {} rejected",
+                    tt_stats.hit_rate() * 100.0,
+                    tt_stats.probes,
+                    tt_stats.writes,
+                    tt_stats.replacements,
+                    tt_stats.current_generation_replacements,
+                    tt_stats.rejected_writes
+                )),
+                ..Default::default()
+            }));
+
+            if result.stats.tbhits > 0 {
+                send_response(&UciResponse::Info(InfoFields {
+                    string: Some(format!(
+                        "tbhits {} ({} from cache)",
+                        result.stats.tbhits, result.stats.tb_cache_hits
+                    )),
+                    ..Default::default()
+                }));
+            }
+        }
+
         if self.pretty_output {
-            Self::pretty_best_move(game, mv);
+            use colored::Colorize;
+
+            println!(
+                "bestmove {} {}",
+                self.notation.format_move(game, result.best_move),
+                Self::pretty_score(result.score)
+            );
+
+            if let (Some(last_iteration), Some(branching_factor)) =
+                (result.iterations.last(), result.branching_factor())
+            {
+                println!(
+                    "{}",
+                    format!(
+                        "depth {} in {:.2?}, {} nodes, branching factor {branching_factor:.2}",
+                        last_iteration.depth, last_iteration.time, result.stats.nodes
+                    )
+                    .bright_black()
+                );
+            }
         } else {
-            Self::uci_best_move(mv);
+            // The second move of the PV is the reply we expect, which GUIs can use to ponder
+            Self::uci_best_move(result.best_move, result.pv.get(1));
         }
     }
 }
 
 pub struct Uci {
-    control: Option<Control>,
-    is_stopped: Arc<LockLatch>,
+    engine: Engine,
     reporter: UciReporter,
-    debug: bool,
-    game: Game,
-    options: EngineOptions,
-
-    persistent_state: Arc<Mutex<PersistentState>>,
 
     // If we're running without using stdin (i.e. passing the UCI commands as command line
     // args) then we need to block on anything taking place on other threads, otherwise we'll
@@ -206,7 +352,7 @@ impl Uci {
     fn execute(&mut self, cmd: &UciCommand) -> Result<ExecuteResult, String> {
         match cmd {
             UciCommand::Uci => {
-                self.game = Game::new();
+                self.engine.game = Game::new();
 
                 let version = crate::engine_version();
                 send_response(&UciResponse::Id(IdParam::Name(format!(
@@ -214,270 +360,537 @@ impl Uci {
                 ))));
                 send_response(&UciResponse::Id(IdParam::Author("Jonathan Gilchrist")));
 
+                for line in crate::build_info() {
+                    send_response(&UciResponse::Info(InfoFields {
+                        string: Some(line),
+                        ..Default::default()
+                    }));
+                }
+
+                // Make it obvious when someone is testing or benchmarking the wrong binary
+                if crate::is_development_build() {
+                    send_response(&UciResponse::Info(InfoFields {
+                        string: Some("warning: development build, reduced strength".to_owned()),
+                        ..Default::default()
+                    }));
+                }
+
                 // Options
-                send_response(&UciResponse::option::<uci::options::HashOption>());
-                send_response(&UciResponse::option::<uci::options::ThreadsOption>());
-                send_response(&UciResponse::option::<uci::options::MoveOverheadOption>());
-                send_response(&UciResponse::option::<uci::options::SyzygyPath>());
+                for option in options::OPTIONS {
+                    send_response(&UciResponse::option(option));
+                }
 
                 send_response(&UciResponse::UciOk);
             }
             UciCommand::Debug(on) => {
-                self.debug = *on;
+                self.reporter.debug = *on;
             }
             UciCommand::IsReady => send_response(&UciResponse::ReadyOk),
-            UciCommand::SetOption { name, value } => {
-                match name.as_str() {
-                    options::HashOption::NAME => {
-                        let new_size = options::HashOption::set(&mut self.options, value)?;
-
-                        if let Ok(mut tt_handle) = self.persistent_state.try_lock() {
-                            tt_handle.tt.resize(new_size);
-                        } else {
-                            self.reporter
-                                .generic_report("error: Unable to change TT size during search");
-                        }
-
-                        Ok(())
-                    }
-                    options::ThreadsOption::NAME => {
-                        options::ThreadsOption::set(&mut self.options, value)
-                    }
-                    options::MoveOverheadOption::NAME => {
-                        options::MoveOverheadOption::set(&mut self.options, value)
-                    }
-                    options::SyzygyPath::NAME => {
-                        let syzygy_path = options::SyzygyPath::set(&mut self.options, value);
-
-                        if let Ok(mut state_handle) = self.persistent_state.try_lock() {
-                            state_handle.tablebase.set_paths(&syzygy_path);
-                        } else {
-                            self.reporter
-                                .generic_report("error: Unable to change SyzygyPath during search");
-                        }
-
-                        Ok(())
-                    }
-                    _ => return Err(format!("Unknown option: {name}")),
+            UciCommand::SetOption { name, value } => match self.engine.set_option(name, value) {
+                Ok(Some(warning)) => {
+                    self.reporter
+                        .generic_report(&format!("info string {warning}"));
                 }
-                .map_err(|e| format!("Unable to set {name}: {e:?}"))?;
-            }
-            UciCommand::UciNewGame => {
-                self.game = Game::new();
-                self.is_stopped.reset();
-
-                let mut persistent_state_handle = self.persistent_state.lock().unwrap();
-                persistent_state_handle.reset();
-            }
+                Ok(None) => {}
+                Err(e) => eprintln!("error: {e}"),
+            },
+            UciCommand::UciNewGame => self.engine.new_game()?,
             UciCommand::Position { position, moves } => {
                 let mut game = match position {
                     commands::Position::StartPos => Game::new(),
                     commands::Position::Fen(fen) => Game::from_fen(fen)?,
                 };
 
+                // If any of the moves are illegal, we keep the previous position rather than
+                // ending up with a position that's out of sync with the GUI
                 for mv in moves {
-                    let matching_move = game.moves().expect_matching(mv.src, mv.dst, mv.promotion);
-                    game.make_move(matching_move);
+                    play_uci_move(&mut game, *mv)?;
                 }
 
-                self.game = game;
+                self.engine.game = game;
             }
-            UciCommand::Go(GoCmdArguments {
-                ponder: _,
-                wtime,
-                btime,
-                winc,
-                binc,
-                movestogo,
-                depth,
-                nodes: _,
-                movetime,
-                infinite: _,
-            }) => {
-                let game = self.game.clone();
-                let options = self.options.clone();
-                let mut reporter = self.reporter.clone();
-
-                let clocks = Clocks {
-                    white_clock: *wtime,
-                    black_clock: *btime,
-                    white_increment: *winc,
-                    black_increment: *binc,
-                    moves_to_go: *movestogo,
-                };
-
-                let mut time_control = TimeControl::Infinite;
-
-                if let Some(move_time) = movetime {
-                    time_control = TimeControl::ExactTime(*move_time);
-                }
-
-                if wtime.is_some() || btime.is_some() {
-                    time_control = TimeControl::Clocks(clocks);
+            UciCommand::Go(args) => {
+                // There's nothing to search if there are no legal moves, so we tell the GUI
+                // the game is over rather than searching
+                if let Some(outcome @ (Outcome::Checkmate { .. } | Outcome::Stalemate)) =
+                    self.engine.game.outcome()
+                {
+                    send_response(&UciResponse::Info(InfoFields {
+                        string: Some(format!("game over: {outcome}")),
+                        ..Default::default()
+                    }));
+                    send_response(&UciResponse::BestMove {
+                        mv: None,
+                        ponder: None,
+                    });
+
+                    return Ok(ExecuteResult::KeepGoing);
                 }
 
-                let (mut time_strategy, control) =
-                    TimeStrategy::new(&self.game, &time_control, &options);
-
-                self.control = Some(control);
-
-                let search_restrictions = SearchRestrictions { depth: *depth };
-
-                let persistent_state = self.persistent_state.clone();
-                let is_stopped = self.is_stopped.clone();
+                let time_control = go_time_control(args);
+                let search_restrictions = go_search_restrictions(args);
 
-                let join_handle = std::thread::spawn(move || {
-                    let mut persistent_state_handle = persistent_state.lock().unwrap();
-
-                    let best_move = search::search(
-                        &game,
-                        &mut persistent_state_handle,
-                        &mut time_strategy,
-                        &search_restrictions,
-                        &options,
-                        &mut reporter,
-                    );
+                let reporter = UciReporter {
+                    notation: self.engine.options.notation,
+                    progress_filter: Arc::new(ProgressFilter::new(
+                        self.engine.options.min_report_depth,
+                        Duration::from_millis(self.engine.options.report_interval as u64),
+                    )),
+                    ..self.reporter.clone()
+                };
 
-                    reporter.best_move(&game, best_move);
-                    is_stopped.set();
-                });
+                let join_handle =
+                    self.engine
+                        .start_search(&time_control, search_restrictions, reporter);
 
                 if self.block_on_threads {
                     join_handle.join().unwrap();
                 }
             }
-            UciCommand::Stop => {
-                if let Some(c) = self.control.as_mut() {
-                    c.stop();
-                    self.is_stopped.wait();
-                }
-
-                self.control = None;
-            }
+            UciCommand::Stop => self.engine.stop(),
+            // Debug commands are for people rather than GUIs, so their output goes to stderr where
+            // it can't be mistaken for part of the protocol
             UciCommand::D(debug_cmd) => match debug_cmd {
                 DebugCommand::PrintPosition => {
-                    println!("{:?}", self.game.board);
-                    println!("FEN: {}", self.game.to_fen());
-                    println!();
+                    if self.reporter.pretty_output {
+                        eprintln!("{:#}", self.engine.game);
+                    } else {
+                        eprintln!("{}", self.engine.game);
+                    }
+                    eprintln!("FEN: {}", self.engine.game.to_fen());
+                    eprintln!(
+                        "Shredder-FEN: {}",
+                        fen::write(&self.engine.game, FenFormat::Shredder)
+                    );
+                    eprintln!("EPD: {}", fen::write(&self.engine.game, FenFormat::Epd));
+                    eprintln!();
                 }
                 DebugCommand::SetPosition { position } => match position.as_str() {
                     "kiwipete" => {
-                        self.game = Game::from_fen(
+                        self.engine.game = Game::from_fen(
                             "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq -",
                         )
                         .unwrap();
 
-                        println!("{:?}", self.game.board);
+                        eprintln!("{}", self.engine.game);
                     }
                     _ => return Err("Unknown debug position".to_owned()),
                 },
                 DebugCommand::Move { moves } => {
-                    for mv in moves {
-                        let matching_move =
-                            self.game
-                                .moves()
-                                .expect_matching(mv.src, mv.dst, mv.promotion);
+                    let mut game = self.engine.game.clone();
 
-                        self.game.make_move(matching_move);
+                    for mv in moves {
+                        play_uci_move(&mut game, *mv)?;
                     }
 
-                    println!("{:?}", self.game.board);
-                    println!("FEN: {}", crate::chess::fen::write(&self.game));
-                    println!();
+                    self.engine.game = game;
+
+                    if self.reporter.pretty_output {
+                        eprintln!("{:#}", self.engine.game);
+                    } else {
+                        eprintln!("{}", self.engine.game);
+                    }
+                    eprintln!("FEN: {}", self.engine.game.to_fen());
+                    eprintln!();
                 }
                 DebugCommand::Perft { depth } => {
                     let started_at = Instant::now();
-                    let result = perft::perft(*depth, &mut self.game);
+                    let result = perft::perft(*depth, &mut self.engine.game);
                     let time_taken = started_at.elapsed();
 
-                    let nodes_per_second =
-                        util::metrics::nodes_per_second(u64::try_from(result).unwrap(), time_taken);
+                    let nodes_per_second = util::metrics::nodes_per_second(result, time_taken);
 
-                    println!("positions: {result}");
-                    println!("time taken: {time_taken:?}");
-                    println!("nps: {nodes_per_second:?}");
-                    println!();
+                    eprintln!("positions: {result}");
+                    eprintln!("time taken: {time_taken:?}");
+                    eprintln!("nps: {nodes_per_second:?}");
+                    eprintln!();
                 }
                 DebugCommand::PerftDiv { depth } => {
-                    let result = perft::perft_div(*depth, &mut self.game);
+                    let result = perft::perft_div(*depth, &mut self.engine.game);
                     let mut total = 0;
 
                     for (mv, number_for_mv) in result {
-                        println!("{mv:?}: {number_for_mv}");
+                        eprintln!("{mv:?}: {number_for_mv}");
                         total += number_for_mv;
                     }
 
-                    println!("total: {total}");
-                    println!();
+                    eprintln!("total: {total}");
+                    eprintln!();
                 }
                 #[rustfmt::skip]
                 DebugCommand::Eval => {
-                    let eval_components = eval::eval_components(&self.game);
+                    let eval_components = eval::eval_components(&self.engine.game);
 
-                    println!("Phase value: {}", eval_components.phase_value);
-                    println!();
+                    eprintln!("Phase value: {}", eval_components.phase_value);
+                    eprintln!();
 
-                    println!("                         Midgame     Endgame    Actual");
+                    eprintln!("                         Midgame     Endgame    Actual");
 
                     let pst = eval_components.piece_square;
-                    println!("Piece square tables:");
-                    println!("  White:                 {}       {}         {}", pst.phased_player_eval.white().midgame(), pst.phased_player_eval.white().endgame(), pst.player_eval.white());
-                    println!("  Black:                 {}       {}         {}", pst.phased_player_eval.black().midgame(), pst.phased_player_eval.black().endgame(), pst.player_eval.black());
-                    println!("  Total:                                        {}", pst.eval);
-                    println!();
+                    eprintln!("Piece square tables:");
+                    eprintln!("  White:                 {}       {}         {}", pst.phased_player_eval.white().midgame(), pst.phased_player_eval.white().endgame(), pst.player_eval.white());
+                    eprintln!("  Black:                 {}       {}         {}", pst.phased_player_eval.black().midgame(), pst.phased_player_eval.black().endgame(), pst.player_eval.black());
+                    eprintln!("  Total:                                        {}", pst.eval);
+                    eprintln!();
 
                     let passed_pawns = eval_components.passed_pawns;
-                    println!("Passed pawns:");
-                    println!("  White:                 {}       {}         {}", passed_pawns.phased_player_eval.white().midgame(), passed_pawns.phased_player_eval.white().endgame(), passed_pawns.player_eval.white());
-                    println!("  Black:                 {}       {}         {}", passed_pawns.phased_player_eval.black().midgame(), passed_pawns.phased_player_eval.black().endgame(), passed_pawns.player_eval.black());
-                    println!("  Total:                                        {}", passed_pawns.eval);
-                    println!();
+                    eprintln!("Passed pawns:");
+                    eprintln!("  White:                 {}       {}         {}", passed_pawns.phased_player_eval.white().midgame(), passed_pawns.phased_player_eval.white().endgame(), passed_pawns.player_eval.white());
+                    eprintln!("  Black:                 {}       {}         {}", passed_pawns.phased_player_eval.black().midgame(), passed_pawns.phased_player_eval.black().endgame(), passed_pawns.player_eval.black());
+                    eprintln!("  Total:                                        {}", passed_pawns.eval);
+                    eprintln!();
+
+                    eprintln!("Eval: {}", eval_components.eval);
+                }
+                DebugCommand::SaveHash { path } => {
+                    let entries = self.engine.save_hash(Path::new(path))?;
+                    eprintln!("Saved {entries} hash entries to {path}");
+                }
+                DebugCommand::LoadHash { path } => {
+                    let entries = self.engine.load_hash(Path::new(path))?;
+                    eprintln!("Loaded {entries} hash entries from {path}");
+                }
+                DebugCommand::Hint { time } => {
+                    let hints = self.engine.hint(*time, HINT_MOVES)?;
+
+                    for (i, (mv, score)) in hints.into_iter().enumerate() {
+                        eprintln!(
+                            "{}. {:<8} {}",
+                            i + 1,
+                            self.engine
+                                .options
+                                .notation
+                                .format_move(&self.engine.game, mv),
+                            UciReporter::pretty_score(score)
+                        );
+                    }
+
+                    eprintln!();
+                }
+                DebugCommand::DumpTrace => {
+                    if !cfg!(feature = "trace") {
+                        return Err("The engine was built without the 'trace' feature".to_owned());
+                    }
+
+                    #[cfg(feature = "trace")]
+                    eprint!("{}", search::trace::dump());
+                }
+                DebugCommand::History => {
+                    let game = &self.engine.game;
+                    eprintln!("Start: {}", game.start_position().to_fen());
+
+                    for played in game.played_moves() {
+                        let number = match played.player {
+                            Player::White => format!("{}.", played.turn),
+                            Player::Black => format!("{}...", played.turn),
+                        };
+
+                        eprintln!("{number:>6} {:<8} {}", played.san, played.fen);
+                    }
+
+                    eprintln!();
+                }
+                DebugCommand::See { mv } => {
+                    let game = &self.engine.game;
+                    let mv = find_uci_move(game, *mv)?;
+
+                    if !mv.is_capture() && !mv.is_promotion() {
+                        return Err(format!("{mv:?} is not a capture or a promotion"));
+                    }
+
+                    let captures = see::exchange_sequence(game, mv);
+
+                    // SEE doesn't check whether recaptures are legal, so once one isn't we can't
+                    // show the rest of the sequence in the chosen notation
+                    let mut exchange_game = Some(game.clone());
+
+                    for (i, capture) in captures.iter().enumerate() {
+                        let capture_move = exchange_game.as_ref().and_then(|g| {
+                            g.moves()
+                                .into_iter()
+                                .find(|m| m.src() == capture.src && m.dst() == mv.dst())
+                        });
+
+                        let notation = if let (Some(exchange_game), Some(m)) =
+                            (exchange_game.as_mut(), capture_move)
+                        {
+                            let notation =
+                                self.engine.options.notation.format_move(exchange_game, m);
+                            exchange_game.make_move(m);
+                            notation
+                        } else {
+                            exchange_game = None;
+                            format!("{}{}", capture.src.notation(), mv.dst().notation())
+                        };
+
+                        eprintln!("{:>3}. {notation:<8} {:+}", i + 1, capture.balance.0);
+                    }
+
+                    eprintln!("Exchange value: {}", see::exchange_value(&captures).0);
+                    eprintln!("SEE value: {}", see::see_value(game, mv).0);
+                    eprintln!();
+                }
+                DebugCommand::QSearch => {
+                    let game = &self.engine.game;
+
+                    // Quiescence search only looks at captures, so it can't tell us anything
+                    // useful when we need to get out of check
+                    if game.is_king_in_check() {
+                        return Err("Quiescence search can't be run while in check".to_owned());
+                    }
+
+                    let result = search::quiescence_only(game, &self.engine.options);
+
+                    let mut line_game = game.clone();
+                    let line: Vec<String> = result
+                        .line
+                        .into_iter()
+                        .map(|mv| {
+                            let notation = self.engine.options.notation.format_move(&line_game, mv);
+                            line_game.make_move(mv);
+                            notation
+                        })
+                        .collect();
+
+                    eprintln!("Static eval: {}", result.static_eval.0);
+                    eprintln!("QSearch score: {}", result.score.0);
+                    eprintln!("Nodes: {}", result.nodes);
+                    eprintln!("Line: {}", line.join(" "));
+                    eprintln!();
+                }
+                DebugCommand::Probe => {
+                    let game = &self.engine.game;
+                    let probe = self.engine.probe_tablebases()?;
+                    let pieces = game.board.occupancy().count();
+
+                    if probe.n_men == 0 {
+                        eprintln!("Tablebases: not loaded");
+                    } else {
+                        eprintln!("Tablebases: up to {} men", probe.n_men);
+                    }
+
+                    eprintln!("Pieces: {pieces}");
+
+                    match probe.wdl {
+                        Some(wdl) => eprintln!("WDL: {wdl:?} for {:?}", game.player),
+                        None => eprintln!("WDL: unknown"),
+                    }
+
+                    match probe.dtz {
+                        Some(dtz) => eprintln!("DTZ: {dtz}"),
+                        None => eprintln!("DTZ: unknown"),
+                    }
+
+                    // Tables are only looked for when the position could be in them, as there
+                    // are a lot of tables needed for positions with lots of captures available
+                    if pieces <= tablebases::MAX_MEN {
+                        let syzygy_path = self.engine.options.syzygy_path.as_deref();
+
+                        eprintln!("Tables:");
+
+                        for table in tablebases::tables_needed(game) {
+                            let files = syzygy_path
+                                .map(|path| tablebases::find_table_files(path, &table))
+                                .unwrap_or_default();
+
+                            if files.is_empty() {
+                                eprintln!("  {table:<8} not found");
+                            } else {
+                                for file in files {
+                                    eprintln!("  {table:<8} {}", file.display());
+                                }
+                            }
+                        }
+                    }
+
+                    eprintln!();
+                }
+                DebugCommand::HashKey => {
+                    let game = &self.engine.game;
+                    let (generation, entries) = self.engine.hash_entries()?;
+
+                    eprintln!("Hash: {:#018x}", game.zobrist.0);
+                    eprintln!("Generation: {generation}");
+
+                    let describe = |data: &SearchTranspositionTableData| {
+                        let score = match data.eval.is_mate_in_moves() {
+                            Some(moves) => format!("mate {moves}"),
+                            None => format!("cp {}", data.eval.0),
+                        };
+
+                        // The move might not be legal here if it's from a different position
+                        let mv = match data.best_move {
+                            Some(mv) if game.moves().contains(&mv) => {
+                                self.engine.options.notation.format_move(game, mv)
+                            }
+                            Some(mv) => format!("{mv:?} (illegal)"),
+                            None => "none".to_owned(),
+                        };
+
+                        format!(
+                            "depth {} bound {:?} score {score} static eval {} move {mv} age {} ({} generations old)",
+                            data.depth,
+                            data.bound(),
+                            data.static_eval.0,
+                            data.age(),
+                            data.generations_old(generation)
+                        )
+                    };
+
+                    match entries.iter().find(|entry| entry.key == game.zobrist) {
+                        Some(entry) => eprintln!("Entry: {}", describe(&entry.data)),
+                        None => eprintln!("Entry: none"),
+                    }
+
+                    // Other positions stored in the same bucket, which this one has to compete
+                    // with for space
+                    let others: Vec<_> = entries
+                        .iter()
+                        .filter(|entry| entry.key != game.zobrist)
+                        .collect();
+
+                    if !others.is_empty() {
+                        eprintln!("Same bucket:");
+
+                        for entry in others {
+                            eprintln!("  {:#018x} {}", entry.key.0, describe(&entry.data));
+                        }
+                    }
+
+                    eprintln!();
+                }
+                DebugCommand::Moves => {
+                    let game = &self.engine.game;
+
+                    for (i, (mv, picked_from)) in
+                        self.engine.picked_moves()?.into_iter().enumerate()
+                    {
+                        eprintln!(
+                            "{:>3}. {:<8} {picked_from}",
+                            i + 1,
+                            self.engine.options.notation.format_move(game, mv)
+                        );
+                    }
 
-                    println!("Eval: {}", eval_components.eval);
+                    eprintln!();
                 }
             },
-            UciCommand::PonderHit => {}
+            // The engine doesn't need registering, so we never ask the GUI for it (and never send
+            // 'copyprotection' or 'registration' messages). Some GUIs send it anyway.
+            UciCommand::PonderHit | UciCommand::Register => {}
             // For OpenBench to understand NPS values for different workers
             UciCommand::Bench => {
                 let started_at = Instant::now();
-                let nodes = bench(10);
+                let result = bench::bench(bench::BENCH_DEPTH);
                 let time_taken = started_at.elapsed();
 
-                let nps = util::metrics::nodes_per_second(nodes, time_taken);
+                // Tools like OpenBench look for the line with the node count and NPS, so nothing
+                // else should mention them
+                for (i, position) in result.positions.iter().enumerate() {
+                    let branching_factor = position
+                        .effective_branching_factor
+                        .map_or_else(|| "-".to_owned(), |ebf| format!("{ebf:.2}"));
+
+                    println!(
+                        "{:>2}. depth {} in {:.2?}, effective branching factor {branching_factor} ({})",
+                        i + 1,
+                        position.depth,
+                        position.time_to_depth,
+                        position.fen
+                    );
+                }
+
+                if let Some(ebf) = result.average_effective_branching_factor() {
+                    println!("average effective branching factor {ebf:.2}");
+                }
 
-                println!("{nodes} nodes {nps} nps");
+                let nps = util::metrics::nodes_per_second(result.nodes, time_taken);
+
+                println!("{} nodes {nps} nps", result.nodes);
+            }
+            UciCommand::BenchMovegen => {
+                let result = bench_movegen(3);
+
+                for (name, time_taken) in [
+                    ("legal", result.legal_time),
+                    ("pseudo-legal", result.pseudo_legal_time),
+                ] {
+                    let nps = util::metrics::nodes_per_second(result.nodes, time_taken);
+                    println!("{name}: {} nodes {nps} nps ({time_taken:?})", result.nodes);
+                }
+            }
+            UciCommand::BenchTt => {
+                let stats = bench_tt(bench::BENCH_DEPTH, 1);
+
+                println!(
+                    "hit rate {:.2}% ({} hits, {} probes)",
+                    stats.hit_rate() * 100.0,
+                    stats.hits,
+                    stats.probes
+                );
+                println!(
+                    "{} writes, {} replacements ({} from the same search), {} rejected",
+                    stats.writes,
+                    stats.replacements,
+                    stats.current_generation_replacements,
+                    stats.rejected_writes
+                );
+            }
+            UciCommand::Quit => {
+                self.engine.stop();
+
+                // We're exiting either way, so a failure to save is only worth reporting
+                if let Err(e) = self.engine.save_experience() {
+                    eprintln!("Error: {e}");
+                }
+
+                return Ok(ExecuteResult::Exit);
             }
-            UciCommand::Quit => return Ok(ExecuteResult::Exit),
         }
 
         Ok(ExecuteResult::KeepGoing)
     }
 
-    fn run_line(&mut self, line: &str) -> Result<bool, String> {
-        let command = parser::parse(line);
-
-        match command {
-            Ok(ref c) => {
-                let execute_result = self.execute(c)?;
+    // Errors from bad input are reported but never stop the main loop - a GUI sending a line
+    // we don't understand shouldn't take the engine down with it.
+    fn run_line(&mut self, line: &str) -> bool {
+        if line.trim().is_empty() {
+            return true;
+        }
 
-                if execute_result == ExecuteResult::Exit {
-                    return Ok(false);
-                }
-            }
+        match parser::parse(line) {
+            Ok(ref c) => match self.execute(c) {
+                Ok(ExecuteResult::Exit) => return false,
+                Ok(ExecuteResult::KeepGoing) => {}
+                Err(e) => eprintln!("Error: {e}"),
+            },
             Err(e) => {
                 eprintln!("{e}");
             }
         }
 
-        Ok(true)
+        true
     }
 
     fn main_loop_stdin(&mut self) -> Result<(), String> {
-        let stdin_lines = std::io::stdin().lock().lines();
+        let mut stdin = std::io::stdin().lock();
+        let mut line = Vec::new();
+
+        loop {
+            line.clear();
+
+            let bytes_read = stdin
+                .read_until(b'\n', &mut line)
+                .map_err(|e| format!("Unable to read from stdin: {e}"))?;
 
-        for line in stdin_lines {
-            let line = line.unwrap();
-            let should_continue = self.run_line(&line).map_err(|e| format!("Error: {e}"))?;
+            if bytes_read == 0 {
+                break;
+            }
+
+            // Lines which aren't valid UTF-8 are still passed through so that any valid
+            // tokens in them can be picked up
+            let line = String::from_utf8_lossy(&line);
+            let should_continue = self.run_line(line.trim_end_matches(['\r', '\n']));
 
             if !should_continue {
                 break;
@@ -487,22 +900,23 @@ impl Uci {
         Ok(())
     }
 
-    fn main_loop_args(&mut self, lines: Vec<String>) -> Result<(), String> {
+    fn main_loop_args(&mut self, lines: Vec<String>) {
         for line in lines {
-            let should_continue = self.run_line(&line)?;
+            let should_continue = self.run_line(&line);
 
             if !should_continue {
                 break;
             }
         }
-
-        Ok(())
     }
 
     fn main_loop(&mut self, uci_input_mode: UciInputMode) -> Result<(), String> {
         match uci_input_mode {
             UciInputMode::Stdin => self.main_loop_stdin(),
-            UciInputMode::Commands(cmds) => self.main_loop_args(cmds),
+            UciInputMode::Commands(cmds) => {
+                self.main_loop_args(cmds);
+                Ok(())
+            }
         }
     }
 }
@@ -513,6 +927,61 @@ enum ExecuteResult {
     Exit,
 }
 
+fn play_uci_move(game: &mut Game, mv: PositionMove) -> Result<(), String> {
+    match mv {
+        PositionMove::Move(mv) => game.make_move(find_uci_move(game, mv)?),
+        PositionMove::Null => {
+            // Passing while in check would leave the king en prise, which the search can't handle
+            if game.is_king_in_check() {
+                return Err(format!(
+                    "Null move {mv} is illegal while in check in position {}",
+                    game.to_fen()
+                ));
+            }
+
+            game.make_null_move();
+        }
+    }
+
+    Ok(())
+}
+
+fn find_uci_move(game: &Game, mv: UciMove) -> Result<Move, String> {
+    game.moves()
+        .find_matching(mv.src, mv.dst, mv.promotion)
+        .ok_or_else(|| format!("Illegal move {mv} in position {}", game.to_fen()))
+}
+
+// Every limit given to 'go' applies at once, so the search stops at whichever is reached first.
+// If we're given both the clocks and a 'movetime', we use the clocks but never think for longer
+// than the movetime.
+fn go_time_control(args: &GoCmdArguments) -> TimeControl {
+    if args.wtime.is_some() || args.btime.is_some() {
+        TimeControl::Clocks(Clocks {
+            white_clock: args.wtime,
+            black_clock: args.btime,
+            white_increment: args.winc,
+            black_increment: args.binc,
+            moves_to_go: args.movestogo,
+            move_time: args.movetime,
+        })
+    } else if let Some(move_time) = args.movetime {
+        TimeControl::ExactTime(move_time)
+    } else {
+        TimeControl::Infinite
+    }
+}
+
+fn go_search_restrictions(args: &GoCmdArguments) -> SearchRestrictions {
+    SearchRestrictions {
+        depth: args.depth,
+        nodes: args.nodes,
+        ..SearchRestrictions::default()
+    }
+}
+
+// Only protocol responses (and the output of commands like 'bench' that tools parse) are written
+// to stdout. Diagnostics go to stderr so that they can't corrupt the stream a GUI is reading.
 fn send_response(response: &UciResponse) {
     println!("{response}");
 }
@@ -530,20 +999,22 @@ pub enum UciInputMode {
     Stdin,
 }
 
-pub fn uci(uci_input_mode: UciInputMode) -> Result<(), String> {
-    let options = EngineOptions::default();
+// The command line flags that can be used to set options, e.g. '--hash'
+pub fn option_flags() -> impl Iterator<Item = &'static str> {
+    options::OPTIONS.iter().map(|option| option.flag)
+}
 
+// `option_flags` are (flag, value) pairs passed on the command line, which are applied
+// on top of any options set in the config file.
+pub fn uci(uci_input_mode: UciInputMode, option_flags: &[(String, String)]) -> Result<(), String> {
     let mut uci = Uci {
-        control: None,
-        is_stopped: Arc::new(LockLatch::new()),
+        engine: Engine::new(EngineOptions::default()),
         reporter: UciReporter {
             pretty_output: std::io::stdin().is_terminal(),
+            debug: false,
+            notation: Notation::San,
+            progress_filter: Arc::default(),
         },
-        debug: false,
-        persistent_state: Arc::new(Mutex::new(PersistentState::new(options.hash_size))),
-
-        game: Game::new(),
-        options,
 
         block_on_threads: match uci_input_mode {
             UciInputMode::Stdin => false,
@@ -551,5 +1022,167 @@ pub fn uci(uci_input_mode: UciInputMode) -> Result<(), String> {
         },
     };
 
+    match config::read_config_file() {
+        Ok(config_options) => {
+            for (name, value) in config_options {
+                match uci.engine.set_option(&name, &value) {
+                    Ok(Some(warning)) => eprintln!("warning: {warning}"),
+                    Ok(None) => {}
+                    Err(e) => eprintln!("error: {e}"),
+                }
+            }
+        }
+        Err(e) => eprintln!("error: {e}"),
+    }
+
+    for (flag, value) in option_flags {
+        let option =
+            options::find_by_flag(flag).ok_or_else(|| format!("Unknown option: --{flag}"))?;
+
+        if let Some(warning) = uci.engine.set_option(option.name, value)? {
+            eprintln!("warning: {warning}");
+        }
+    }
+
     uci.main_loop(uci_input_mode)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::search::NullReporter;
+
+    fn go(input: &str) -> GoCmdArguments {
+        match parser::parse(input).unwrap() {
+            UciCommand::Go(args) => args,
+            _ => panic!("Expected a go command"),
+        }
+    }
+
+    #[test]
+    fn test_go_depth_and_movetime() {
+        let args = go("go depth 30 movetime 100");
+
+        assert!(matches!(
+            go_time_control(&args),
+            TimeControl::ExactTime(time) if time == Duration::from_millis(100)
+        ));
+        assert_eq!(go_search_restrictions(&args).depth, Some(30));
+    }
+
+    #[test]
+    fn test_go_nodes_and_clocks() {
+        let args = go("go nodes 1e6 wtime 60000 btime 60000 movetime 500");
+
+        let TimeControl::Clocks(clocks) = go_time_control(&args) else {
+            panic!("Expected the clocks to be used");
+        };
+        assert_eq!(clocks.white_clock, Some(Duration::from_secs(60)));
+        assert_eq!(clocks.move_time, Some(Duration::from_millis(500)));
+
+        let search_restrictions = go_search_restrictions(&args);
+        assert_eq!(search_restrictions.nodes, Some(1_000_000));
+        assert_eq!(search_restrictions.depth, None);
+    }
+
+    #[test]
+    fn test_a1a1_is_not_a_null_move() {
+        crate::init();
+
+        let UciCommand::Position { moves, .. } =
+            parser::parse("position startpos moves a1a1").unwrap()
+        else {
+            panic!("Expected position command");
+        };
+
+        let mut game = Game::new();
+        assert!(play_uci_move(&mut game, moves[0]).is_err());
+        assert_eq!(game.player, Player::White);
+    }
+
+    #[test]
+    fn test_null_move_is_rejected_in_check() {
+        crate::init();
+
+        let mut game = Game::from_fen("4k3/8/8/8/8/8/4q3/4K3 w - - 0 1").unwrap();
+        assert!(play_uci_move(&mut game, PositionMove::Null).is_err());
+        assert_eq!(game.to_fen(), "4k3/8/8/8/8/8/4q3/4K3 w - - 0 1");
+
+        let mut game = Game::from_fen("4k3/8/8/8/8/8/8/4K3 w - - 0 1").unwrap();
+        assert!(play_uci_move(&mut game, PositionMove::Null).is_ok());
+        assert_eq!(game.player, Player::Black);
+    }
+
+    #[test]
+    fn test_go_depth_and_movetime_stops_at_movetime() {
+        crate::init();
+
+        let mut engine = Engine::new(EngineOptions::default());
+        let args = go("go depth 30 movetime 100");
+
+        let started_at = Instant::now();
+        let result = engine
+            .start_search(
+                &go_time_control(&args),
+                go_search_restrictions(&args),
+                NullReporter,
+            )
+            .join()
+            .unwrap();
+
+        assert!(started_at.elapsed() < Duration::from_secs(1));
+        assert!(result.iterations.last().unwrap().depth < 30);
+    }
+
+    fn progress(depth: u8, time_ms: u64) -> search::SearchInfo {
+        search::SearchInfo {
+            depth,
+            seldepth: depth,
+            score: SearchScore::Centipawns(0),
+            stats: search::SearchStats {
+                time: Duration::from_millis(time_ms),
+                nodes: 0,
+                nodes_per_second: 0,
+                tbhits: 0,
+                tb_cache_hits: 0,
+            },
+            pv: PrincipalVariation::new(),
+            hashfull: 0,
+        }
+    }
+
+    #[test]
+    fn test_progress_filter_min_depth() {
+        let filter = ProgressFilter::new(5, Duration::ZERO);
+
+        assert!(!filter.should_report(&progress(4, 0)));
+        assert_eq!(filter.take_held_back().unwrap().depth, 4);
+        assert!(filter.take_held_back().is_none());
+
+        assert!(filter.should_report(&progress(5, 1)));
+        assert!(filter.take_held_back().is_none());
+    }
+
+    #[test]
+    fn test_progress_filter_interval() {
+        let filter = ProgressFilter::new(0, Duration::from_millis(100));
+
+        assert!(filter.should_report(&progress(1, 0)));
+        assert!(!filter.should_report(&progress(2, 50)));
+        assert!(!filter.should_report(&progress(3, 99)));
+        assert!(filter.should_report(&progress(4, 100)));
+        assert!(!filter.should_report(&progress(5, 150)));
+
+        assert_eq!(filter.take_held_back().unwrap().depth, 5);
+    }
+
+    #[test]
+    fn test_progress_filter_reports_everything_by_default() {
+        let filter = ProgressFilter::default();
+
+        for depth in 1..10 {
+            assert!(filter.should_report(&progress(depth, 0)));
+        }
+        assert!(filter.take_held_back().is_none());
+    }
+}
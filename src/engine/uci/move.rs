@@ -49,3 +49,32 @@ impl From<Move> for UciMove {
         }
     }
 }
+
+// A move to play from a position. Some GUIs send '0000' as a null move, e.g. when a line in an
+// analysis tree passes, which isn't a move from one square to another.
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum PositionMove {
+    Move(UciMove),
+    Null,
+}
+
+impl PositionMove {
+    pub fn notation(self) -> String {
+        match self {
+            Self::Move(mv) => mv.notation(),
+            Self::Null => "0000".to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Debug for PositionMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.notation())
+    }
+}
+
+impl std::fmt::Display for PositionMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.notation())
+    }
+}
@@ -1,7 +1,11 @@
-use crate::engine::options::EngineOptions;
+use std::sync::Mutex;
 
-#[derive(Debug)]
-#[expect(unused, reason = "Not all UCI option types are used by this engine")]
+use crate::chess::notation::Notation;
+use crate::engine::options::{defaults, EngineOptions};
+use crate::engine::search::PersistentState;
+use crate::engine::util::system;
+
+#[derive(Debug, Clone)]
 pub enum UciOptionType {
     Check {
         default: bool,
@@ -13,7 +17,7 @@ pub enum UciOptionType {
     },
     Combo {
         default: &'static str,
-        values: Vec<&'static str>,
+        values: &'static [&'static str],
     },
     String {
         default: &'static str,
@@ -21,82 +25,365 @@ pub enum UciOptionType {
     Button,
 }
 
-pub trait UciOption {
-    const NAME: &'static str;
-    const DEF: UciOptionType;
+// Some options need to change the engine's state as well as its options, e.g. resizing the TT
+// when the hash size changes, so they're given access to both. If the value had to be adjusted
+// (e.g. to fit in the memory we have), a warning is returned to pass on to the user.
+type SetOptionFn = fn(
+    options: &mut EngineOptions,
+    state: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String>;
+
+pub struct UciOption {
+    pub name: &'static str,
+    // Options can also be passed as command line flags, e.g. '--hash 512'
+    pub flag: &'static str,
+    pub def: UciOptionType,
+    pub set: SetOptionFn,
 }
 
-pub struct HashOption;
+pub const OPTIONS: &[UciOption] = &[
+    UciOption {
+        name: "Hash",
+        flag: "hash",
+        def: UciOptionType::Spin {
+            default: defaults::HASH_SIZE,
+            min: 0,
+            max: defaults::MAX_HASH_SIZE,
+        },
+        set: set_hash,
+    },
+    UciOption {
+        name: "Threads",
+        flag: "threads",
+        def: UciOptionType::Spin {
+            default: defaults::THREADS,
+            min: 0,
+            max: defaults::MAX_THREADS,
+        },
+        set: set_threads,
+    },
+    UciOption {
+        name: "Move Overhead",
+        flag: "move-overhead",
+        def: UciOptionType::Spin {
+            default: defaults::MOVE_OVERHEAD,
+            min: 0,
+            max: 1000,
+        },
+        set: |options, _, value| {
+            options.move_overhead = parse_spin(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "AutoMoveOverhead",
+        flag: "auto-move-overhead",
+        def: UciOptionType::Check {
+            default: defaults::AUTO_MOVE_OVERHEAD,
+        },
+        set: |options, _, value| {
+            options.auto_move_overhead = parse_check(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "SyzygyPath",
+        flag: "syzygy-path",
+        def: UciOptionType::String { default: "" },
+        set: set_syzygy_path,
+    },
+    UciOption {
+        name: "DrawDither",
+        flag: "draw-dither",
+        def: UciOptionType::Check {
+            default: defaults::DRAW_DITHER,
+        },
+        set: |options, _, value| {
+            options.draw_dither = parse_check(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "ExtendPVFromTT",
+        flag: "extend-pv-from-tt",
+        def: UciOptionType::Check {
+            default: defaults::EXTEND_PV_FROM_TT,
+        },
+        set: |options, _, value| {
+            options.extend_pv_from_tt = parse_check(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "UCI_AnalyseMode",
+        flag: "analyse-mode",
+        def: UciOptionType::Check {
+            default: defaults::ANALYSE_MODE,
+        },
+        set: |options, _, value| {
+            options.analyse_mode = parse_check(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "TwofoldRepetition",
+        flag: "twofold-repetition",
+        def: UciOptionType::Check {
+            default: defaults::TWOFOLD_REPETITION,
+        },
+        set: |options, _, value| {
+            options.twofold_repetition = parse_check(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "Experience",
+        flag: "experience",
+        def: UciOptionType::String { default: "" },
+        set: set_experience,
+    },
+    UciOption {
+        name: "PreferredOpenings",
+        flag: "preferred-openings",
+        def: UciOptionType::String { default: "" },
+        set: set_preferred_openings,
+    },
+    UciOption {
+        name: "Notation",
+        flag: "notation",
+        def: UciOptionType::Combo {
+            default: "SAN",
+            values: Notation::NAMES,
+        },
+        set: |options, _, value| {
+            options.notation = Notation::from_name(value).ok_or("Invalid value")?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "MinReportDepth",
+        flag: "min-report-depth",
+        def: UciOptionType::Spin {
+            default: defaults::MIN_REPORT_DEPTH,
+            min: 0,
+            max: 255,
+        },
+        set: |options, _, value| {
+            options.min_report_depth = parse_spin(value)?;
+            Ok(None)
+        },
+    },
+    UciOption {
+        name: "ReportInterval",
+        flag: "report-interval",
+        def: UciOptionType::Spin {
+            default: defaults::REPORT_INTERVAL,
+            min: 0,
+            max: 60000,
+        },
+        set: |options, _, value| {
+            options.report_interval = parse_spin(value)?;
+            Ok(None)
+        },
+    },
+];
 
-impl UciOption for HashOption {
-    const NAME: &'static str = "Hash";
-    const DEF: UciOptionType = UciOptionType::Spin {
-        default: crate::engine::options::defaults::HASH_SIZE,
-        min: 0,
-        max: 1024,
-    };
+// Option names are case-insensitive
+pub fn find(name: &str) -> Option<&'static UciOption> {
+    OPTIONS
+        .iter()
+        .find(|option| option.name.eq_ignore_ascii_case(name))
 }
 
-impl HashOption {
-    pub fn set(options: &mut EngineOptions, value: &str) -> Result<usize, String> {
-        let hash_size = value.parse::<usize>().map_err(|_| "Invalid value")?;
+pub fn find_by_flag(flag: &str) -> Option<&'static UciOption> {
+    OPTIONS.iter().find(|option| option.flag == flag)
+}
 
-        options.hash_size = hash_size;
-        Ok(hash_size)
-    }
+fn parse_spin(value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| "Invalid value".to_owned())
 }
 
-pub struct ThreadsOption;
+fn parse_check(value: &str) -> Result<bool, String> {
+    value
+        .parse::<bool>()
+        .map_err(|_| "Invalid value".to_owned())
+}
+
+fn set_hash(
+    options: &mut EngineOptions,
+    state: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String> {
+    let requested = parse_spin(value)?;
+    let (hash_size, warning) = hash_size(requested, system::available_memory_mb());
 
-impl UciOption for ThreadsOption {
-    const NAME: &'static str = "Threads";
-    const DEF: UciOptionType = UciOptionType::Spin {
-        default: crate::engine::options::defaults::THREADS,
-        min: 1,
-        max: 1,
+    let Ok(mut state_handle) = state.try_lock() else {
+        return Err("Unable to change TT size during search".to_owned());
     };
+
+    options.hash_size = hash_size;
+    state_handle.tt.resize(hash_size);
+    Ok(warning)
 }
 
-impl ThreadsOption {
-    pub fn set(options: &mut EngineOptions, value: &str) -> Result<(), String> {
-        let threads = value.parse::<usize>().map_err(|_| "Invalid value")?;
+// 0 picks a size based on the memory that's available. The table never takes more than half of
+// the available memory, so that the rest of the system has room and we don't start swapping.
+fn hash_size(requested: usize, available_memory: Option<usize>) -> (usize, Option<String>) {
+    let max_hash_size = available_memory.map(|available| (available / 2).max(1));
+
+    if requested == 0 {
+        let hash_size = max_hash_size.map_or(defaults::HASH_SIZE, |max_hash_size| {
+            max_hash_size.min(defaults::MAX_HASH_SIZE)
+        });
+
+        return (hash_size, Some(format!("Hash set to {hash_size} MB")));
+    }
 
-        options.threads = threads;
-        Ok(())
+    match (available_memory, max_hash_size) {
+        (Some(available), Some(max_hash_size)) if requested > max_hash_size => (
+            max_hash_size,
+            Some(format!(
+                "Hash of {requested} MB is more than half of the {available} MB of available memory, using {max_hash_size} MB"
+            )),
+        ),
+        _ => (requested, None),
     }
 }
 
-pub struct MoveOverheadOption;
+// 0 picks the number of threads based on the number of cores
+fn set_threads(
+    options: &mut EngineOptions,
+    _: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String> {
+    let requested = parse_spin(value)?;
+    let cores = system::logical_cores();
+    let max_threads = cores.min(defaults::MAX_THREADS);
 
-impl UciOption for MoveOverheadOption {
-    const NAME: &'static str = "Move Overhead";
-    const DEF: UciOptionType = UciOptionType::Spin {
-        default: crate::engine::options::defaults::MOVE_OVERHEAD,
-        min: 0,
-        max: 1000,
+    let (threads, warning) = if requested == 0 {
+        (max_threads, Some(format!("Threads set to {max_threads}")))
+    } else if requested > max_threads {
+        (
+            max_threads,
+            Some(format!(
+                "Threads of {requested} is more than the {max_threads} that can be used, using {max_threads}"
+            )),
+        )
+    } else {
+        (requested, None)
     };
+
+    options.threads = threads;
+    Ok(warning)
 }
 
-impl MoveOverheadOption {
-    pub fn set(options: &mut EngineOptions, value: &str) -> Result<(), String> {
-        let move_overhead = value.parse::<usize>().map_err(|_| "Invalid value")?;
+fn set_syzygy_path(
+    options: &mut EngineOptions,
+    state: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String> {
+    let Ok(mut state_handle) = state.try_lock() else {
+        return Err("Unable to change SyzygyPath during search".to_owned());
+    };
 
-        options.move_overhead = move_overhead;
-        Ok(())
-    }
+    state_handle.tablebase.set_paths(value)?;
+    options.syzygy_path = Some(value.to_string());
+    Ok(None)
 }
 
-pub struct SyzygyPath;
+fn set_experience(
+    options: &mut EngineOptions,
+    state: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String> {
+    let Ok(mut state_handle) = state.try_lock() else {
+        return Err("Unable to change Experience during search".to_owned());
+    };
 
-impl UciOption for SyzygyPath {
-    const NAME: &'static str = "SyzygyPath";
-    const DEF: UciOptionType = UciOptionType::String { default: "" };
+    // Keep what we've learned so far before switching to another file
+    state_handle.experience.save()?;
+    let warning = state_handle.experience.set_path(value)?;
+
+    options.experience_file = Some(value.to_string());
+    Ok(warning)
 }
 
-impl SyzygyPath {
-    pub fn set(options: &mut EngineOptions, value: &str) -> String {
-        let path = value.to_string();
-        options.syzygy_path = Some(path.clone());
-        path
+fn set_preferred_openings(
+    options: &mut EngineOptions,
+    state: &Mutex<PersistentState>,
+    value: &str,
+) -> Result<Option<String>, String> {
+    let Ok(mut state_handle) = state.try_lock() else {
+        return Err("Unable to change PreferredOpenings during search".to_owned());
+    };
+
+    state_handle.repertoire.set_path(value)?;
+
+    options.preferred_openings = Some(value.to_string());
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_option_names_are_unique() {
+        for (i, option) in OPTIONS.iter().enumerate() {
+            assert!(OPTIONS[i + 1..]
+                .iter()
+                .all(|other| !other.name.eq_ignore_ascii_case(option.name)
+                    && other.flag != option.flag));
+        }
+    }
+
+    #[test]
+    fn test_threads_are_capped() {
+        let mut options = EngineOptions::default();
+        let state = Mutex::new(PersistentState::new(1));
+
+        assert!(set_threads(&mut options, &state, "0").unwrap().is_some());
+        assert_eq!(options.threads, defaults::MAX_THREADS);
+
+        assert!(set_threads(&mut options, &state, "1024").unwrap().is_some());
+        assert_eq!(options.threads, defaults::MAX_THREADS);
+
+        assert!(set_threads(&mut options, &state, "1").unwrap().is_none());
+        assert_eq!(options.threads, 1);
+    }
+
+    #[test]
+    fn test_auto_hash_size() {
+        assert_eq!(hash_size(0, Some(8000)).0, defaults::MAX_HASH_SIZE);
+        assert_eq!(hash_size(0, Some(1000)).0, 500);
+        assert_eq!(hash_size(0, Some(1)).0, 1);
+        assert_eq!(hash_size(0, None).0, defaults::HASH_SIZE);
+        assert!(hash_size(0, Some(1000)).1.is_some());
+
+        let mut options = EngineOptions::default();
+        let state = Mutex::new(PersistentState::new(1));
+        assert!(set_hash(&mut options, &state, "auto").is_err());
+    }
+
+    #[test]
+    fn test_hash_size_is_capped() {
+        assert_eq!(hash_size(400, Some(1000)), (400, None));
+        assert_eq!(hash_size(500, Some(1000)), (500, None));
+
+        let (size, warning) = hash_size(800, Some(1000));
+        assert_eq!(size, 500);
+        assert!(warning.unwrap().contains("half of the 1000 MB"));
+
+        assert_eq!(hash_size(4096, None), (4096, None));
+    }
+
+    #[test]
+    fn test_find_option_ignores_case() {
+        assert_eq!(find("hash").unwrap().name, "Hash");
+        assert_eq!(find("move overhead").unwrap().name, "Move Overhead");
+        assert!(find("NotAnOption").is_none());
     }
 }
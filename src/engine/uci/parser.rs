@@ -2,81 +2,52 @@ use crate::chess::{
     piece::PromotionPieceKind,
     square::{File, Rank, Square},
 };
-use crate::engine::uci::UciMove;
-use crate::uci::commands::{DebugCommand, Position};
+use crate::engine::uci::commands::{DebugCommand, Position};
+use crate::engine::uci::{PositionMove, UciMove};
 use nom::bytes::complete::take_until;
 use nom::character::complete::alpha1;
 use nom::combinator::rest;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{one_of, space0, space1},
-    combinator::{eof, map, opt, value},
+    character::complete::{anychar, one_of, space0, space1},
+    combinator::{eof, map, map_opt, opt, value, verify},
     error::ParseError,
-    multi::{fold_many0, separated_list1},
+    multi::separated_list1,
     sequence::{pair, preceded, tuple},
     IResult, Parser,
 };
+use std::iter::Peekable;
+use std::str::FromStr;
 use std::time::Duration;
 
 use super::commands::{GoCmdArguments, UciCommand};
 
-// FIXME: Don't accept `isreadymorechars` as `IsReady`
-
 fn boolean(input: &str) -> IResult<&str, bool> {
     alt((value(true, tag("on")), value(false, tag("off"))))(input)
 }
 
 fn uci_file(input: &str) -> IResult<&str, File> {
-    let (input, file) = one_of("abcdefgh")(input)?;
-
-    Ok((
-        input,
-        match file {
-            'a' => File::A,
-            'b' => File::B,
-            'c' => File::C,
-            'd' => File::D,
-            'e' => File::E,
-            'f' => File::F,
-            'g' => File::G,
-            'h' => File::H,
-            _ => unreachable!(),
-        },
-    ))
+    map_opt(anychar, File::from_char)(input)
 }
 
 fn uci_rank(input: &str) -> IResult<&str, Rank> {
-    let (input, rank) = one_of("12345678")(input)?;
-
-    Ok((
-        input,
-        match rank {
-            '1' => Rank::R1,
-            '2' => Rank::R2,
-            '3' => Rank::R3,
-            '4' => Rank::R4,
-            '5' => Rank::R5,
-            '6' => Rank::R6,
-            '7' => Rank::R7,
-            '8' => Rank::R8,
-            _ => unreachable!(),
-        },
-    ))
+    map_opt(anychar, Rank::from_char)(input)
 }
 
 fn uci_square(input: &str) -> IResult<&str, Square> {
     map(pair(uci_file, uci_rank), |(file, rank)| {
-        Square::from_file_and_rank(file, rank)
+        Square::new(file, rank)
     })(input)
 }
 
 fn uci_promotion(input: &str) -> IResult<&str, PromotionPieceKind> {
-    let (input, rank) = one_of("nbrq")(input)?;
+    // Promotions should be lowercase, but some GUIs send them in uppercase
+    let (input, rank) = one_of("nbrqNBRQ")(input)?;
 
     Ok((
         input,
-        match rank {
+        match rank.to_ascii_lowercase() {
             'n' => PromotionPieceKind::Knight,
             'b' => PromotionPieceKind::Bishop,
             'r' => PromotionPieceKind::Rook,
@@ -97,18 +68,15 @@ fn uci_move(input: &str) -> IResult<&str, UciMove> {
     )(input)
 }
 
-pub fn uci_moves(input: &str) -> IResult<&str, Vec<UciMove>> {
-    separated_list1(space1, uci_move)(input)
+fn position_move(input: &str) -> IResult<&str, PositionMove> {
+    alt((
+        value(PositionMove::Null, tag("0000")),
+        map(uci_move, PositionMove::Move),
+    ))(input)
 }
 
-fn command_without_arguments<'a, G, O, E: ParseError<&'a str>>(
-    cmd: &'a str,
-    map_argument_fn: G,
-) -> impl FnMut(&'a str) -> IResult<&'a str, O, E>
-where
-    G: FnMut(&'a str) -> O,
-{
-    map(tag(cmd), map_argument_fn)
+pub fn position_moves(input: &str) -> IResult<&str, Vec<PositionMove>> {
+    separated_list1(space1, position_move)(input)
 }
 
 fn command_with_argument<'a, F, G, OInner, O, E: ParseError<&'a str>>(
@@ -160,6 +128,15 @@ fn cmd_setoption(input: &str) -> IResult<&str, UciCommand> {
     ))
 }
 
+// We don't need the arguments ('later', or a name and code), but they're consumed so that the
+// command isn't rejected for having them
+fn cmd_register(input: &str) -> IResult<&str, UciCommand> {
+    value(
+        UciCommand::Register,
+        pair(tag("register"), alt((eof, preceded(space1, rest)))),
+    )(input)
+}
+
 fn cmd_ucinewgame(input: &str) -> IResult<&str, UciCommand> {
     value(UciCommand::UciNewGame, tag("ucinewgame"))(input)
 }
@@ -174,8 +151,8 @@ fn cmd_position(input: &str) -> IResult<&str, UciCommand> {
         ))(input)
     }
 
-    fn moves_arg(input: &str) -> IResult<&str, Vec<UciMove>> {
-        command_with_argument("moves", uci_moves, |moves| moves)(input)
+    fn moves_arg(input: &str) -> IResult<&str, Vec<PositionMove>> {
+        command_with_argument("moves", position_moves, |moves| moves)(input)
     }
 
     let (input, _) = tag("position")(input)?;
@@ -195,98 +172,83 @@ fn cmd_position(input: &str) -> IResult<&str, UciCommand> {
     ))
 }
 
-// Wraps a closure which modifies `GoCmdArguments`.
-struct GoCmdArgumentsModifyFn(Box<dyn FnOnce(&mut GoCmdArguments)>);
-impl GoCmdArgumentsModifyFn {
-    fn new(f: impl FnOnce(&mut GoCmdArguments) + 'static) -> Self {
-        Self(Box::new(f))
-    }
-}
-
 fn parse_duration(n: i64) -> Duration {
     Duration::from_millis(n.max(0).try_into().unwrap())
 }
 
+// Takes the next token as the value for a `go` parameter, as long as it parses. If it doesn't
+// (e.g. the value is missing and the next token is another parameter), it's left for the next
+// iteration of the loop.
+fn go_value<'a, T: FromStr>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<T> {
+    let value = tokens.peek()?.parse::<T>().ok()?;
+    tokens.next();
+    Some(value)
+}
+
+// Out of range values are clamped rather than rejected, e.g. 'depth 1000'
+fn saturating_go_value<'a, T: TryFrom<u64>>(
+    tokens: &mut Peekable<impl Iterator<Item = &'a str>>,
+    max: T,
+) -> Option<T> {
+    go_value::<u64>(tokens).map(|v| T::try_from(v).unwrap_or(max))
+}
+
+// Node counts can also be given in scientific notation, e.g. 'nodes 1e6'
+#[expect(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    reason = "Only whole, non-negative values are converted, and the conversion saturates"
+)]
+fn node_count_value<'a>(tokens: &mut Peekable<impl Iterator<Item = &'a str>>) -> Option<u64> {
+    if let Some(nodes) = go_value::<u64>(tokens) {
+        return Some(nodes);
+    }
+
+    let nodes = tokens
+        .peek()?
+        .parse::<f64>()
+        .ok()
+        .filter(|n| *n >= 0.0 && n.fract() == 0.0)?;
+    tokens.next();
+    Some(nodes as u64)
+}
+
+// GUIs send `go` parameters in any order and some send parameters we don't support (e.g.
+// 'searchmoves' or 'mate'), so rather than a fixed grammar we walk the tokens and pick out
+// the ones we understand. Unknown tokens and parameters with missing values are skipped.
+fn go_arguments(input: &str) -> GoCmdArguments {
+    let mut args = GoCmdArguments::default();
+    let mut tokens = input.split_whitespace().peekable();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            "ponder" => args.ponder = true,
+            "infinite" => args.infinite = true,
+            "wtime" => args.wtime = go_value(&mut tokens).map(parse_duration).or(args.wtime),
+            "btime" => args.btime = go_value(&mut tokens).map(parse_duration).or(args.btime),
+            "winc" => args.winc = go_value(&mut tokens).map(parse_duration).or(args.winc),
+            "binc" => args.binc = go_value(&mut tokens).map(parse_duration).or(args.binc),
+            "movetime" => {
+                args.movetime = go_value(&mut tokens).map(parse_duration).or(args.movetime);
+            }
+            "movestogo" => {
+                args.movestogo = saturating_go_value(&mut tokens, u32::MAX).or(args.movestogo);
+            }
+            "depth" => args.depth = saturating_go_value(&mut tokens, u8::MAX).or(args.depth),
+            "nodes" => args.nodes = node_count_value(&mut tokens).or(args.nodes),
+            _ => {}
+        }
+    }
+
+    args
+}
+
 fn cmd_go(input: &str) -> IResult<&str, UciCommand> {
     let (input, _) = tag("go")(input)?;
+    let (input, _) = alt((space1, eof))(input)?;
+    let (input, args) = rest(input)?;
 
-    // Parse each potential argument. For each argument, return a function which sets
-    // the relevant field in GoCmdArguments.
-    let (input, args) = fold_many0(
-        preceded(
-            space1,
-            alt((
-                command_without_arguments("ponder", |_| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.ponder = true;
-                    })
-                }),
-                command_with_argument("wtime", nom::character::complete::i64, |wtime| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.wtime = Some(parse_duration(wtime));
-                    })
-                }),
-                command_with_argument("btime", nom::character::complete::i64, |btime| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.btime = Some(parse_duration(btime));
-                    })
-                }),
-                command_with_argument("winc", nom::character::complete::i64, |winc| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.winc = Some(parse_duration(winc));
-                    })
-                }),
-                command_with_argument("binc", nom::character::complete::i64, |binc| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.binc = Some(parse_duration(binc));
-                    })
-                }),
-                command_with_argument("movestogo", nom::character::complete::u32, |movestogo| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.movestogo = Some(movestogo);
-                    })
-                }),
-                command_with_argument("depth", nom::character::complete::u8, |depth| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.depth = Some(depth);
-                    })
-                }),
-                command_with_argument("nodes", nom::character::complete::u32, |nodes| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.nodes = Some(nodes);
-                    })
-                }),
-                command_with_argument("movetime", nom::character::complete::i64, |movetime| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.movetime = Some(parse_duration(movetime));
-                    })
-                }),
-                command_without_arguments("infinite", |_| {
-                    GoCmdArgumentsModifyFn::new(move |acc: &mut GoCmdArguments| {
-                        acc.infinite = true;
-                    })
-                }),
-            )),
-        ),
-        || GoCmdArguments {
-            ponder: false,
-            wtime: None,
-            btime: None,
-            winc: None,
-            binc: None,
-            movestogo: None,
-            depth: None,
-            nodes: None,
-            movetime: None,
-            infinite: false,
-        },
-        |mut acc, GoCmdArgumentsModifyFn(f)| {
-            f(&mut acc);
-            acc
-        },
-    )(input)?;
-
-    Ok((input, UciCommand::Go(args)))
+    Ok((input, UciCommand::Go(go_arguments(args))))
 }
 
 fn cmd_stop(input: &str) -> IResult<&str, UciCommand> {
@@ -313,7 +275,7 @@ fn cmd_d_position(input: &str) -> IResult<&str, UciCommand> {
 fn cmd_d_move(input: &str) -> IResult<&str, UciCommand> {
     let (input, _) = tag("move")(input)?;
     let (input, _) = space1(input)?;
-    let (input, moves) = uci_moves(input)?;
+    let (input, moves) = position_moves(input)?;
 
     Ok((input, UciCommand::D(DebugCommand::Move { moves })))
 }
@@ -341,6 +303,84 @@ fn cmd_d_eval(input: &str) -> IResult<&str, UciCommand> {
     Ok((input, UciCommand::D(DebugCommand::Eval)))
 }
 
+fn file_path(input: &str) -> IResult<&str, String> {
+    map(
+        verify(rest, |path: &str| !path.trim().is_empty()),
+        |path: &str| path.trim_end().to_owned(),
+    )(input)
+}
+
+fn cmd_d_savehash(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("savehash")(input)?;
+
+    let (input, _) = space1(input)?;
+    let (input, path) = file_path(input)?;
+
+    Ok((input, UciCommand::D(DebugCommand::SaveHash { path })))
+}
+
+fn cmd_d_loadhash(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("loadhash")(input)?;
+
+    let (input, _) = space1(input)?;
+    let (input, path) = file_path(input)?;
+
+    Ok((input, UciCommand::D(DebugCommand::LoadHash { path })))
+}
+
+fn cmd_d_hint(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("hint")(input)?;
+
+    let (input, _) = space1(input)?;
+    let (input, time) = nom::character::complete::u64(input)?;
+
+    Ok((
+        input,
+        UciCommand::D(DebugCommand::Hint {
+            time: Duration::from_millis(time),
+        }),
+    ))
+}
+
+fn cmd_d_dumptrace(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("dumptrace")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::DumpTrace)))
+}
+
+fn cmd_d_history(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("history")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::History)))
+}
+
+fn cmd_d_moves(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("moves")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::Moves)))
+}
+
+fn cmd_d_see(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("see")(input)?;
+
+    let (input, _) = space1(input)?;
+    let (input, mv) = uci_move(input)?;
+
+    Ok((input, UciCommand::D(DebugCommand::See { mv })))
+}
+
+fn cmd_d_qsearch(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("qsearch")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::QSearch)))
+}
+
+fn cmd_d_probe(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("probe")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::Probe)))
+}
+
+fn cmd_d_hashkey(input: &str) -> IResult<&str, UciCommand> {
+    let (input, _) = tag("hashkey")(input)?;
+    Ok((input, UciCommand::D(DebugCommand::HashKey)))
+}
+
 fn cmd_d(input: &str) -> IResult<&str, UciCommand> {
     let (input, _) = tag("d")(input)?;
     let (input, _) = space0(input)?;
@@ -348,10 +388,20 @@ fn cmd_d(input: &str) -> IResult<&str, UciCommand> {
     alt((
         cmd_d_fen,
         cmd_d_position,
+        cmd_d_moves,
         cmd_d_move,
         cmd_d_perft,
         cmd_d_perft_div,
         cmd_d_eval,
+        cmd_d_savehash,
+        cmd_d_loadhash,
+        cmd_d_hint,
+        cmd_d_dumptrace,
+        cmd_d_history,
+        cmd_d_see,
+        cmd_d_qsearch,
+        cmd_d_probe,
+        cmd_d_hashkey,
     ))(input)
 }
 
@@ -360,13 +410,40 @@ fn cmd_ponderhit(input: &str) -> IResult<&str, UciCommand> {
 }
 
 fn cmd_bench(input: &str) -> IResult<&str, UciCommand> {
-    value(UciCommand::Bench, tag("bench"))(input)
+    alt((
+        value(
+            UciCommand::BenchMovegen,
+            tuple((tag("bench"), space1, tag("movegen"))),
+        ),
+        value(
+            UciCommand::BenchTt,
+            tuple((tag("bench"), space1, tag("tt"))),
+        ),
+        value(UciCommand::Bench, tag("bench")),
+    ))(input)
 }
 
 fn cmd_quit(input: &str) -> IResult<&str, UciCommand> {
     value(UciCommand::Quit, tag("quit"))(input)
 }
 
+// The first word of each command, which tells us the rest of the line is meant to be that command
+const COMMANDS: [&str; 13] = [
+    "uci",
+    "debug",
+    "isready",
+    "setoption",
+    "register",
+    "ucinewgame",
+    "position",
+    "go",
+    "stop",
+    "ponderhit",
+    "bench",
+    "d",
+    "quit",
+];
+
 pub(super) fn any_uci_command(input: &str) -> IResult<&str, UciCommand> {
     let (input, _) = space0(input)?;
 
@@ -375,6 +452,7 @@ pub(super) fn any_uci_command(input: &str) -> IResult<&str, UciCommand> {
         cmd_debug,
         cmd_isready,
         cmd_setoption,
+        cmd_register,
         cmd_ucinewgame,
         cmd_position,
         cmd_go,
@@ -392,12 +470,27 @@ pub(super) fn any_uci_command(input: &str) -> IResult<&str, UciCommand> {
 }
 
 pub fn parse(input: &str) -> Result<UciCommand, String> {
-    let result = any_uci_command(input);
-
-    match result {
-        Ok((_, cmd)) => Ok(cmd),
-        Err(e) => Err(format!("Unknown command: {input} ({e})")),
+    // The UCI spec says that unknown tokens should be ignored and that the engine should try
+    // to parse the rest of the line, e.g. 'joho debug on' should be treated as 'debug on'. Only
+    // tokens before the command are skipped: once we've found the command, its arguments must
+    // parse, otherwise e.g. 'debug quit' would be treated as 'quit'.
+    let mut remaining = input.trim_start();
+
+    while !remaining.is_empty() {
+        let token_end = remaining
+            .find(char::is_whitespace)
+            .unwrap_or(remaining.len());
+
+        if COMMANDS.contains(&&remaining[..token_end]) {
+            return any_uci_command(remaining)
+                .map(|(_, cmd)| cmd)
+                .map_err(|e| format!("Invalid command: {input} ({e})"));
+        }
+
+        remaining = remaining[token_end..].trim_start();
     }
+
+    Err(format!("Unknown command: {input}"))
 }
 
 #[cfg(test)]
@@ -449,16 +542,402 @@ mod tests {
         assert_eq!(ml, UciCommand::IsReady);
     }
 
+    #[test]
+    fn test_register() {
+        assert_eq!(parse("register later").unwrap(), UciCommand::Register);
+        assert_eq!(
+            parse("register name Stefan MK code 4359874324").unwrap(),
+            UciCommand::Register
+        );
+        assert_eq!(parse("register name uci").unwrap(), UciCommand::Register);
+        assert_eq!(parse("register").unwrap(), UciCommand::Register);
+        assert!(parse("registered").is_err());
+    }
+
     #[test]
     fn test_position_fen() {
         let ml = parse("position fen 6r1/p2p4/3Ppk2/p1R2p2/8/3b4/1r6/4K3 b - - 5 45");
         assert!(ml.is_ok());
     }
 
+    #[test]
+    fn test_skips_unknown_leading_tokens() {
+        assert_eq!(parse("joho debug on").unwrap(), UciCommand::Debug(true));
+        assert_eq!(parse("  foo  bar\tisready ").unwrap(), UciCommand::IsReady);
+        assert!(parse("foo bar").is_err());
+        assert!(parse("isreadymorechars").is_err());
+    }
+
+    #[test]
+    fn test_arguments_are_not_parsed_as_commands() {
+        assert!(parse("debug quit").is_err());
+        assert!(parse("debug stop").is_err());
+        assert!(parse("joho debug quit").is_err());
+    }
+
+    #[test]
+    fn test_does_not_skip_known_command_with_bad_arguments() {
+        assert!(parse("debug maybe").is_err());
+        assert!(parse("d perft").is_err());
+        assert!(parse("position").is_err());
+    }
+
+    #[test]
+    fn test_garbage_input_does_not_panic() {
+        use rand::prelude::*;
+
+        const TOKENS: &[&str] = &[
+            "uci",
+            "debug",
+            "on",
+            "off",
+            "isready",
+            "setoption",
+            "name",
+            "value",
+            "ucinewgame",
+            "position",
+            "startpos",
+            "fen",
+            "moves",
+            "e2e4",
+            "e7e8q",
+            "a9b0",
+            "go",
+            "wtime",
+            "btime",
+            "winc",
+            "binc",
+            "movestogo",
+            "depth",
+            "nodes",
+            "movetime",
+            "infinite",
+            "ponder",
+            "stop",
+            "ponderhit",
+            "d",
+            "perft",
+            "perftdiv",
+            "eval",
+            "bench",
+            "quit",
+            "0",
+            "-1",
+            "255",
+            "256",
+            "99999999999999999999",
+            "",
+            " ",
+            "\t",
+            "é",
+            "\0",
+            "-",
+        ];
+
+        let mut rng = StdRng::seed_from_u64(0x5EED);
+
+        for _ in 0..10_000 {
+            let token_count = rng.gen_range(0..8);
+            let mut line = (0..token_count)
+                .map(|_| *TOKENS.choose(&mut rng).unwrap())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            // Also try random printable and non-ASCII characters in the middle of tokens
+            if rng.gen_bool(0.3) {
+                let idx = line
+                    .char_indices()
+                    .map(|(i, _)| i)
+                    .choose(&mut rng)
+                    .unwrap_or(0);
+                line.insert(idx, rng.gen_range(' '..='\u{3ff}'));
+            }
+
+            let _result = parse(&line);
+        }
+    }
+
+    fn go(input: &str) -> GoCmdArguments {
+        match parse(input).unwrap() {
+            UciCommand::Go(args) => args,
+            cmd => panic!("Expected go command, got {cmd:?}"),
+        }
+    }
+
+    #[test]
+    fn test_go_arguments_in_any_order() {
+        let expected = GoCmdArguments {
+            wtime: Some(Duration::from_secs(1)),
+            btime: Some(Duration::from_secs(2)),
+            winc: Some(Duration::from_millis(10)),
+            binc: Some(Duration::from_millis(20)),
+            movestogo: Some(40),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            go("go wtime 1000 btime 2000 winc 10 binc 20 movestogo 40"),
+            expected
+        );
+        assert_eq!(
+            go("go movestogo 40 binc 20 winc 10 btime 2000 wtime 1000"),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_go_without_arguments() {
+        assert_eq!(go("go"), GoCmdArguments::default());
+        assert_eq!(go("  go  "), GoCmdArguments::default());
+        assert!(parse("gofoo").is_err());
+    }
+
+    #[test]
+    fn test_go_skips_unknown_parameters() {
+        // Arena can send 'searchmoves', and we don't support 'mate'
+        assert_eq!(
+            go("go searchmoves e2e4 d2d4 mate 3 depth 6"),
+            GoCmdArguments {
+                depth: Some(6),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_go_missing_values() {
+        assert_eq!(
+            go("go wtime btime 500 depth"),
+            GoCmdArguments {
+                btime: Some(Duration::from_millis(500)),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_go_negative_time() {
+        // cutechess can send negative clock times when the engine has overstepped its time
+        assert_eq!(
+            go("go wtime -35 btime 1000"),
+            GoCmdArguments {
+                wtime: Some(Duration::ZERO),
+                btime: Some(Duration::from_secs(1)),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_go_out_of_range_values() {
+        assert_eq!(
+            go("go depth 1000 nodes 99999999999"),
+            GoCmdArguments {
+                depth: Some(u8::MAX),
+                nodes: Some(99_999_999_999),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_go_nodes_in_scientific_notation() {
+        assert_eq!(go("go nodes 1e6").nodes, Some(1_000_000));
+        assert_eq!(go("go nodes 2.5e3").nodes, Some(2500));
+        assert_eq!(go("go nodes 1.5").nodes, None);
+        assert_eq!(go("go nodes -1e3").nodes, None);
+    }
+
+    #[test]
+    fn test_go_combined_limits() {
+        assert_eq!(
+            go("go depth 30 movetime 100"),
+            GoCmdArguments {
+                depth: Some(30),
+                movetime: Some(Duration::from_millis(100)),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            go("go nodes 1e6 wtime 1000 btime 2000"),
+            GoCmdArguments {
+                nodes: Some(1_000_000),
+                wtime: Some(Duration::from_secs(1)),
+                btime: Some(Duration::from_secs(2)),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn test_go_ponder_and_infinite() {
+        // Banksia sends 'ponder' after the clock parameters
+        assert_eq!(
+            go("go wtime 1000 btime 1000 ponder"),
+            GoCmdArguments {
+                ponder: true,
+                wtime: Some(Duration::from_secs(1)),
+                btime: Some(Duration::from_secs(1)),
+                ..Default::default()
+            }
+        );
+        assert!(go("go infinite").infinite);
+    }
+
+    #[test]
+    fn test_position_uppercase_promotion() {
+        let ml = parse("position fen 8/4P3/8/8/8/8/k7/7K w - - 0 1 moves e7e8Q").unwrap();
+
+        let UciCommand::Position { moves, .. } = ml else {
+            panic!("Expected position command");
+        };
+
+        let PositionMove::Move(mv) = moves[0] else {
+            panic!("Expected a move");
+        };
+
+        assert_eq!(mv.promotion, Some(PromotionPieceKind::Queen));
+    }
+
+    #[test]
+    fn test_position_null_move() {
+        let ml = parse("position startpos moves e2e4 0000 d2d4").unwrap();
+
+        let UciCommand::Position { moves, .. } = ml else {
+            panic!("Expected position command");
+        };
+
+        assert_eq!(moves.len(), 3);
+        assert_eq!(moves[1], PositionMove::Null);
+        assert_eq!(moves[1].notation(), "0000");
+    }
+
+    #[test]
+    fn test_bench() {
+        assert_eq!(parse("bench").unwrap(), UciCommand::Bench);
+        assert_eq!(parse("bench movegen").unwrap(), UciCommand::BenchMovegen);
+        assert_eq!(parse("bench tt").unwrap(), UciCommand::BenchTt);
+    }
+
+    #[test]
+    fn test_d_savehash_and_loadhash() {
+        assert_eq!(
+            parse("d savehash analysis.hash").unwrap(),
+            UciCommand::D(DebugCommand::SaveHash {
+                path: "analysis.hash".to_string()
+            })
+        );
+        assert_eq!(
+            parse("d loadhash /tmp/my analysis.hash ").unwrap(),
+            UciCommand::D(DebugCommand::LoadHash {
+                path: "/tmp/my analysis.hash".to_string()
+            })
+        );
+        assert!(parse("d savehash").is_err());
+        assert!(parse("d loadhash   ").is_err());
+    }
+
+    #[test]
+    fn test_d_hint() {
+        assert_eq!(
+            parse("d hint 500").unwrap(),
+            UciCommand::D(DebugCommand::Hint {
+                time: Duration::from_millis(500)
+            })
+        );
+        assert!(parse("d hint").is_err());
+    }
+
+    #[test]
+    fn test_d_dumptrace() {
+        assert_eq!(
+            parse("d dumptrace").unwrap(),
+            UciCommand::D(DebugCommand::DumpTrace)
+        );
+    }
+
+    #[test]
+    fn test_d_history() {
+        assert_eq!(
+            parse("d history").unwrap(),
+            UciCommand::D(DebugCommand::History)
+        );
+    }
+
+    #[test]
+    fn test_d_see() {
+        use crate::chess::square::squares::all::{D5, E4};
+
+        assert_eq!(
+            parse("d see e4d5").unwrap(),
+            UciCommand::D(DebugCommand::See {
+                mv: UciMove {
+                    src: E4,
+                    dst: D5,
+                    promotion: None
+                }
+            })
+        );
+        assert!(parse("d see").is_err());
+    }
+
+    #[test]
+    fn test_d_moves() {
+        assert_eq!(
+            parse("d moves").unwrap(),
+            UciCommand::D(DebugCommand::Moves)
+        );
+        assert!(matches!(
+            parse("d move e2e4").unwrap(),
+            UciCommand::D(DebugCommand::Move { .. })
+        ));
+    }
+
+    #[test]
+    fn test_d_qsearch() {
+        assert_eq!(
+            parse("d qsearch").unwrap(),
+            UciCommand::D(DebugCommand::QSearch)
+        );
+    }
+
+    #[test]
+    fn test_d_probe() {
+        assert_eq!(
+            parse("d probe").unwrap(),
+            UciCommand::D(DebugCommand::Probe)
+        );
+    }
+
+    #[test]
+    fn test_d_hashkey() {
+        assert_eq!(
+            parse("d hashkey").unwrap(),
+            UciCommand::D(DebugCommand::HashKey)
+        );
+    }
+
     #[test]
     fn test_position_fen_then_moves() {
         let ml =
             parse("position fen 6r1/p2p4/3Ppk2/p1R2p2/8/3b4/1r6/4K3 b - - 5 45 moves a7a6 c1d1");
         assert!(ml.is_ok());
     }
+
+    #[test]
+    fn test_position_fen_without_move_counters() {
+        let ml =
+            parse("position fen 6r1/p2p4/3Ppk2/p1R2p2/8/3b4/1r6/4K3 b - - moves a7a6").unwrap();
+
+        let UciCommand::Position { position, moves } = ml else {
+            panic!("Expected position command");
+        };
+
+        assert_eq!(
+            position,
+            Position::Fen("6r1/p2p4/3Ppk2/p1R2p2/8/3b4/1r6/4K3 b - -".to_string())
+        );
+        assert_eq!(moves.len(), 1);
+    }
 }
@@ -1,8 +1,8 @@
 use std::fmt::Formatter;
 use std::time::Duration;
 
-use crate::uci::options::{UciOption, UciOptionType};
-use crate::uci::UciMove;
+use crate::engine::uci::options::{UciOption, UciOptionType};
+use crate::engine::uci::UciMove;
 
 #[derive(Debug)]
 pub(super) enum InfoScore {
@@ -23,6 +23,7 @@ pub struct InfoFields {
     pub(super) time: Option<Duration>,
     pub(super) nodes: Option<u64>,
     pub(super) pv: Option<Vec<UciMove>>,
+    pub(super) refutation: Option<Vec<UciMove>>,
     pub(super) score: Option<InfoScore>,
     pub(super) hashfull: Option<usize>,
     pub(super) nps: Option<u64>,
@@ -36,7 +37,8 @@ pub(super) enum UciResponse {
     UciOk,
     ReadyOk,
     BestMove {
-        mv: UciMove,
+        // There's no move to send when the game is over, which is sent as the null move '0000'
+        mv: Option<UciMove>,
         ponder: Option<UciMove>,
     },
     Info(InfoFields),
@@ -47,10 +49,10 @@ pub(super) enum UciResponse {
 }
 
 impl UciResponse {
-    pub(super) fn option<T: UciOption>() -> Self {
+    pub(super) fn option(option: &UciOption) -> Self {
         Self::Option {
-            name: T::NAME,
-            def: T::DEF,
+            name: option.name,
+            def: option.def.clone(),
         }
     }
 }
@@ -65,7 +67,10 @@ impl std::fmt::Display for UciResponse {
             Self::UciOk => write!(f, "uciok")?,
             Self::ReadyOk => write!(f, "readyok")?,
             Self::BestMove { mv, ponder } => {
-                write!(f, "bestmove {}", mv.notation())?;
+                match mv {
+                    Some(mv) => write!(f, "bestmove {}", mv.notation())?,
+                    None => write!(f, "bestmove 0000")?,
+                }
 
                 if let Some(pondermv) = ponder {
                     write!(f, " ponder {}", pondermv.notation())?;
@@ -77,6 +82,7 @@ impl std::fmt::Display for UciResponse {
                 time,
                 nodes,
                 pv,
+                refutation,
                 score,
                 hashfull,
                 nps,
@@ -132,6 +138,14 @@ impl std::fmt::Display for UciResponse {
                     }
                 }
 
+                if let Some(refutation) = refutation {
+                    write!(f, " refutation")?;
+
+                    for mv in refutation {
+                        write!(f, " {}", mv.notation())?;
+                    }
+                }
+
                 if let Some(s) = string {
                     write!(f, " string {s}")?;
                 }
@@ -162,8 +176,8 @@ impl std::fmt::Display for UciResponse {
 
                 match def {
                     UciOptionType::Spin { min, max, .. } => write!(f, " min {min} max {max}")?,
-                    UciOptionType::Combo { ref values, .. } => {
-                        for v in values {
+                    UciOptionType::Combo { values, .. } => {
+                        for v in *values {
                             write!(f, " var {v}")?;
                         }
                     }
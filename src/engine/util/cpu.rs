@@ -0,0 +1,51 @@
+// Each of the target features we check for, with whether the binary was built to use it and
+// whether the CPU we're running on supports it
+#[cfg(target_arch = "x86_64")]
+fn target_features() -> Vec<(&'static str, bool, bool)> {
+    let mut features = Vec::new();
+
+    macro_rules! check_target_features {
+        ($($feature:tt),*) => {
+            $(
+                features.push((
+                    $feature,
+                    cfg!(target_feature = $feature),
+                    std::is_x86_feature_detected!($feature),
+                ));
+            )*
+        };
+    }
+
+    check_target_features!(
+        "sse3", "ssse3", "sse4.1", "sse4.2", "popcnt", "avx", "avx2", "bmi1", "bmi2", "fma",
+        "lzcnt", "avx512f", "avx512bw", "avx512cd", "avx512dq", "avx512vl"
+    );
+
+    features
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn target_features() -> Vec<(&'static str, bool, bool)> {
+    Vec::new()
+}
+
+// Release binaries can be built for newer CPUs (e.g. with `-C target-cpu=x86-64-v3`) to make use
+// of instructions like POPCNT and BMI2. If one of those binaries is run on a CPU that doesn't
+// support them, it'll crash with an illegal instruction as soon as it gets going, so we check
+// up-front and report something more helpful.
+pub fn missing_target_features() -> Vec<&'static str> {
+    target_features()
+        .into_iter()
+        .filter(|&(_, enabled, detected)| enabled && !detected)
+        .map(|(feature, _, _)| feature)
+        .collect()
+}
+
+// The target features this binary was built to use
+pub fn enabled_target_features() -> Vec<&'static str> {
+    target_features()
+        .into_iter()
+        .filter(|&(_, enabled, _)| enabled)
+        .map(|(feature, _, _)| feature)
+        .collect()
+}
@@ -1,11 +1,28 @@
 use std::time::Duration;
 
-#[expect(
-    clippy::cast_possible_truncation,
-    clippy::cast_sign_loss,
-    clippy::cast_precision_loss,
-    reason = "This is intended to be approximate so we don't care about this being lossy"
-)]
+// Saturates rather than overflowing, so that this is safe to call however long a search has been
+// running for (and when no time has passed at all).
 pub fn nodes_per_second(nodes: u64, elapsed_time: Duration) -> u64 {
-    (nodes as f64 / elapsed_time.as_secs_f64()) as u64
+    let micros = elapsed_time.as_micros().max(1);
+    let nps = u128::from(nodes).saturating_mul(1_000_000) / micros;
+
+    u64::try_from(nps).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nodes_per_second() {
+        assert_eq!(nodes_per_second(1_000_000, Duration::from_secs(2)), 500_000);
+        assert_eq!(nodes_per_second(0, Duration::from_secs(1)), 0);
+    }
+
+    #[test]
+    fn test_nodes_per_second_does_not_overflow() {
+        assert_eq!(nodes_per_second(1000, Duration::ZERO), 1_000_000_000);
+        assert_eq!(nodes_per_second(u64::MAX, Duration::ZERO), u64::MAX);
+        assert_eq!(nodes_per_second(u64::MAX, Duration::from_secs(1)), u64::MAX);
+    }
 }
@@ -1,3 +1,5 @@
+pub mod cpu;
 pub mod log;
 pub mod metrics;
 pub mod sync;
+pub mod system;
@@ -7,6 +7,12 @@ pub struct LockLatch {
     v: Condvar,
 }
 
+impl Default for LockLatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl LockLatch {
     #[inline(always)]
     pub const fn new() -> Self {
@@ -0,0 +1,50 @@
+// Probes for the resources available to the engine, so that options like 'Hash' and 'Threads'
+// can be kept within what the machine can actually give us.
+
+pub fn logical_cores() -> usize {
+    std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get)
+}
+
+// The memory that can be allocated without swapping, in MB. This is only known on Linux.
+#[cfg(target_os = "linux")]
+pub fn available_memory_mb() -> Option<usize> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    parse_mem_available_mb(&meminfo)
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn available_memory_mb() -> Option<usize> {
+    None
+}
+
+#[cfg_attr(
+    not(target_os = "linux"),
+    expect(unused, reason = "Only used on Linux")
+)]
+fn parse_mem_available_mb(meminfo: &str) -> Option<usize> {
+    let line = meminfo
+        .lines()
+        .find(|line| line.starts_with("MemAvailable:"))?;
+
+    // The value is always given in kB
+    let kb: usize = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kb / 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mem_available() {
+        let meminfo = "MemTotal:       16318480 kB\nMemFree:         1049788 kB\nMemAvailable:    8388608 kB\n";
+
+        assert_eq!(parse_mem_available_mb(meminfo), Some(8192));
+        assert_eq!(parse_mem_available_mb("MemTotal: 16318480 kB"), None);
+    }
+
+    #[test]
+    fn test_logical_cores() {
+        assert!(logical_cores() >= 1);
+    }
+}
@@ -0,0 +1,99 @@
+pub mod chess;
+pub mod engine;
+
+// The development tools are run through the clap CLI
+#[cfg(feature = "cli")]
+pub mod utils;
+
+#[cfg(test)]
+mod tests;
+
+use engine::util::cpu;
+
+// The engine can be embedded in other programs (e.g. servers or bots) by creating an `Engine`
+pub use engine::handle::Engine;
+
+pub const ENGINE_NAME: &str = "Tcheran";
+
+#[cfg(all(feature = "default", feature = "release"))]
+compile_error!("features \"default\" and \"release\" cannot be enabled simultaneously");
+
+pub fn engine_version() -> String {
+    let cargo_version = env!("CARGO_PKG_VERSION");
+    let version = cargo_version.strip_suffix(".0").unwrap();
+    let dev_suffix = if cfg!(feature = "release") {
+        ""
+    } else {
+        "-dev"
+    };
+
+    let git_hash = env!("GIT_SHORT_HASH");
+
+    // The git hash isn't available if we're not building from a git checkout
+    if git_hash.is_empty() {
+        format!("v{version}{dev_suffix}")
+    } else {
+        format!("v{version}{dev_suffix} ({git_hash})")
+    }
+}
+
+// Builds without the 'release' feature (which is what `cargo build` gives by default) or with
+// debug assertions aren't the ones we release and test the strength of
+pub const fn is_development_build() -> bool {
+    !cfg!(feature = "release") || cfg!(debug_assertions)
+}
+
+// A description of how this binary was built, so that it can be included in bug reports
+pub fn build_info() -> Vec<String> {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "optimised"
+    };
+
+    let features: Vec<&str> = [
+        ("default", cfg!(feature = "default")),
+        ("cli", cfg!(feature = "cli")),
+        ("tuner", cfg!(feature = "tuner")),
+        ("release", cfg!(feature = "release")),
+        ("syzygy", cfg!(feature = "syzygy")),
+    ]
+    .into_iter()
+    .filter(|&(_, enabled)| enabled)
+    .map(|(feature, _)| feature)
+    .collect();
+
+    let target_features = cpu::enabled_target_features();
+    let target_features = if target_features.is_empty() {
+        "none".to_string()
+    } else {
+        target_features.join(" ")
+    };
+
+    vec![
+        format!(
+            "build {profile}, features {}",
+            if features.is_empty() {
+                "none".to_string()
+            } else {
+                features.join(" ")
+            }
+        ),
+        format!(
+            "cpu {}, target features {target_features}",
+            std::env::consts::ARCH
+        ),
+        "eval hand-crafted, no embedded network".to_string(),
+        if cfg!(feature = "syzygy") {
+            "syzygy supported via Fathom"
+        } else {
+            "syzygy not supported"
+        }
+        .to_string(),
+    ]
+}
+
+pub fn init() {
+    chess::init();
+    engine::init();
+}
@@ -1,39 +1,9 @@
-mod chess;
-mod engine;
-
-#[cfg(not(feature = "release"))]
-mod utils;
-
-#[cfg(test)]
-mod tests;
-
-use engine::uci;
-use engine::util::log;
+#[cfg(not(feature = "cli"))]
+use engine::engine::uci;
+use engine::engine::util::{cpu, log};
 use std::panic::PanicHookInfo;
 use std::process::ExitCode;
 
-pub const ENGINE_NAME: &str = "Tcheran";
-
-#[cfg(all(feature = "default", feature = "release"))]
-compile_error!("features \"default\" and \"release\" cannot be enabled simultaneously");
-
-pub fn engine_version() -> String {
-    let cargo_version = env!("CARGO_PKG_VERSION");
-    let version = cargo_version.strip_suffix(".0").unwrap();
-    let dev_suffix = if cfg!(feature = "release") {
-        ""
-    } else {
-        "-dev"
-    };
-
-    format!("v{version}{dev_suffix}")
-}
-
-pub fn init() {
-    chess::init();
-    engine::init();
-}
-
 fn get_panic_message(info: &PanicHookInfo<'_>) -> String {
     if let Some(s) = info.payload().downcast_ref::<&str>() {
         format!("panic occurred: {s:?} {info:?}")
@@ -44,42 +14,72 @@ fn get_panic_message(info: &PanicHookInfo<'_>) -> String {
     }
 }
 
-#[cfg(not(feature = "release"))]
+#[cfg(feature = "cli")]
 fn run() -> ExitCode {
-    use utils::cli;
+    use engine::utils::cli;
 
     cli::run()
 }
 
-#[cfg(feature = "release")]
+// Without the clap CLI (e.g. in release builds), the binary only speaks UCI
+#[cfg(not(feature = "cli"))]
 fn run() -> ExitCode {
-    use crate::engine::uci::UciInputMode;
+    use engine::engine::uci::UciInputMode;
 
-    let args = std::env::args().collect::<Vec<_>>();
-    let uci_input_mode = match args.len() {
-        1 => UciInputMode::Stdin,
-        2 => {
-            let commands = args[1]
-                .replace("\\n", "\n")
-                .lines()
-                .map(ToString::to_string)
-                .collect::<Vec<_>>();
+    let mut args = std::env::args();
+    let binary_name = args.next().unwrap_or_default();
+
+    let mut option_flags = Vec::new();
+    let mut commands: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        if arg == "--selftest" {
+            return if engine::engine::selftest::selftest() {
+                ExitCode::SUCCESS
+            } else {
+                ExitCode::FAILURE
+            };
+        }
 
-            UciInputMode::Commands(commands)
+        if let Some(flag) = arg.strip_prefix("--") {
+            if let Some(value) = args.next() {
+                option_flags.push((flag.to_string(), value));
+                continue;
+            }
+        } else if commands.is_none() {
+            commands = Some(arg);
+            continue;
         }
-        _ => {
-            let binary_name = args[0].clone();
-            eprintln!("usage:");
-            eprintln!("  {binary_name}                  - run in UCI mode");
-            eprintln!(
-                "  {binary_name} \"<uci commands>\" - run specific UCI commands and then exit"
-            );
-
-            return ExitCode::FAILURE;
+
+        eprintln!("usage:");
+        eprintln!("  {binary_name} [options]                  - run in UCI mode");
+        eprintln!(
+            "  {binary_name} [options] \"<uci commands>\" - run specific UCI commands and then exit"
+        );
+        eprintln!(
+            "  {binary_name} --selftest                 - check this build gives the expected results"
+        );
+        eprintln!();
+        eprintln!("options:");
+        for flag in uci::option_flags() {
+            eprintln!("  --{flag} <value>");
         }
+
+        return ExitCode::FAILURE;
+    }
+
+    let uci_input_mode = match commands {
+        None => UciInputMode::Stdin,
+        Some(commands) => UciInputMode::Commands(
+            commands
+                .replace("\\n", "\n")
+                .lines()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+        ),
     };
 
-    let result = uci::uci(uci_input_mode);
+    let result = uci::uci(uci_input_mode, &option_flags);
 
     match result {
         Ok(()) => ExitCode::SUCCESS,
@@ -91,13 +91,29 @@ fn run() -> ExitCode {
 }
 
 fn main() -> ExitCode {
+    // This has to come before anything else, since any code could have been compiled to use the
+    // instructions we're missing
+    let missing_target_features = cpu::missing_target_features();
+    if !missing_target_features.is_empty() {
+        eprintln!(
+            "This binary was built for a newer CPU, and requires CPU features that are not available: {}",
+            missing_target_features.join(", ")
+        );
+        eprintln!("Try a build for an older CPU (e.g. x86-64-v2), or build from source.");
+
+        return ExitCode::FAILURE;
+    }
+
     std::panic::set_hook(Box::new(|info| {
         let panic_message = get_panic_message(info);
 
-        println!("{panic_message}");
+        eprintln!("{panic_message}");
         log::crashlog(panic_message);
+
+        #[cfg(feature = "trace")]
+        log::crashlog(engine::engine::search::trace::dump());
     }));
 
-    init();
+    engine::init();
     run()
 }
@@ -0,0 +1,99 @@
+use crate::chess::fen;
+use crate::chess::game::Game;
+use crate::chess::san;
+use crate::engine::uci::parser;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ITERATIONS: usize = 20_000;
+
+const FEN_SEEDS: [&str; 4] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3",
+];
+
+const SAN_SEEDS: [&str; 10] = [
+    "e4", "Nf3", "exd5", "O-O", "O-O-O", "e8=Q", "Raxd1", "N1c3", "Qh4#", "Bb5+",
+];
+
+const UCI_SEEDS: [&str; 8] = [
+    "position startpos moves e2e4 e7e5",
+    "position fen 8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1 moves a5a6",
+    "go wtime 1000 btime 1000 winc 10 binc 10 movestogo 20",
+    "go depth 10 nodes 1e6 movetime 100",
+    "setoption name Hash value 64",
+    "d perft 3",
+    "d hint 100",
+    "register name Someone code 1234",
+];
+
+// Characters which are meaningful to at least one of the parsers, plus a few that aren't
+const ALPHABET: &[u8] = b"rnbqkpRNBQKP12345678abcdefgh/-+#=xO0 wKQkq\n\t.e9";
+
+// Produces variations of a valid input: characters changed, removed, duplicated or truncated,
+// so that we get close to the valid input rather than just random noise
+fn mutate(rng: &mut StdRng, seed: &str) -> String {
+    let mut bytes = seed.as_bytes().to_vec();
+
+    for _ in 0..rng.gen_range(1..=4) {
+        let idx = rng.gen_range(0..=bytes.len());
+
+        match rng.gen_range(0..5) {
+            0 if idx < bytes.len() => bytes[idx] = ALPHABET[rng.gen_range(0..ALPHABET.len())],
+            1 if idx < bytes.len() => {
+                bytes.remove(idx);
+            }
+            2 => bytes.insert(idx, ALPHABET[rng.gen_range(0..ALPHABET.len())]),
+            3 => bytes.truncate(idx),
+            _ => bytes.insert(idx, rng.gen()),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn fuzz(seeds: &[&str], parse: impl Fn(&str)) {
+    crate::init();
+
+    let mut rng = StdRng::seed_from_u64(0);
+
+    for _ in 0..ITERATIONS {
+        let seed = seeds[rng.gen_range(0..seeds.len())];
+        let input = mutate(&mut rng, seed);
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| parse(&input)));
+        assert!(result.is_ok(), "Parser panicked on input: {input:?}");
+    }
+}
+
+#[test]
+fn fuzz_fen_parser() {
+    fuzz(&FEN_SEEDS, |input| {
+        drop(fen::parse(input));
+    });
+}
+
+#[test]
+fn fuzz_san_parser() {
+    crate::init();
+
+    let games: Vec<Game> = FEN_SEEDS
+        .iter()
+        .map(|f| Game::from_fen(f).unwrap())
+        .collect();
+
+    fuzz(&SAN_SEEDS, |input| {
+        for game in &games {
+            let _ = san::parse_move(game, input);
+        }
+    });
+}
+
+#[test]
+fn fuzz_uci_parser() {
+    fuzz(&UCI_SEEDS, |input| {
+        drop(parser::parse(input));
+    });
+}
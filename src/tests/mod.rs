@@ -1,2 +1,5 @@
+mod fuzz_tests;
 mod move_tests;
 mod perft_tests;
+mod property_tests;
+pub mod temp_file;
@@ -1,33 +1,39 @@
 use crate::chess::game::Game;
-use crate::chess::moves::Move;
+use crate::chess::moves::{Move, MoveListExt};
+use crate::chess::piece::PieceKind;
 use crate::chess::square::squares::all::*;
 use crate::chess::square::Square;
 use crate::engine::options::EngineOptions;
 use crate::engine::search::time_control::TimeStrategy;
 use crate::engine::search::{
-    search, CapturingReporter, PersistentState, SearchRestrictions, SearchScore, TimeControl,
+    search, AggregatingReporter, NullReporter, PersistentState, Reporter, SearchInfo,
+    SearchRestrictions, SearchScore, TimeControl,
 };
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
 fn test_expected_move(fen: &str, depth: u8, mv: (Square, Square)) -> (Move, SearchScore) {
     crate::init();
-    let game = Game::from_fen(fen).unwrap();
+    let mut game = Game::from_fen(fen).unwrap();
     let mut persistent_state = PersistentState::new(16);
 
-    let mut capturing_reporter = CapturingReporter::new();
     let (mut time_strategy, _) =
         TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
 
-    let best_move = search(
-        &game,
+    let result = search(
+        &mut game,
         &mut persistent_state,
         &mut time_strategy,
-        &SearchRestrictions { depth: Some(depth) },
+        &SearchRestrictions {
+            depth: Some(depth),
+            ..SearchRestrictions::default()
+        },
         &EngineOptions::default(),
-        &mut capturing_reporter,
+        &NullReporter,
     );
 
-    assert_eq!((best_move.src(), best_move.dst()), mv);
-    (best_move, capturing_reporter.score.unwrap())
+    assert_eq!((result.best_move.src(), result.best_move.dst()), mv);
+    (result.best_move, result.score)
 }
 
 #[test]
@@ -40,3 +46,370 @@ fn test_mate_on_100th_halfmove_detected() {
 
     assert_eq!(eval, SearchScore::Mate(1));
 }
+
+// Positions where the only way to avoid a draw by the fifty move rule is to make a
+// capture or pawn move. We search them first with a fresh halfmove clock so that the TT
+// is full of scores that don't know about the upcoming draw.
+fn test_avoids_fifty_move_draw(fen_without_clocks: &str) {
+    crate::init();
+    let mut persistent_state = PersistentState::new(16);
+
+    let search_fen = |fen: &str, persistent_state: &mut PersistentState| {
+        let mut game = Game::from_fen(fen).unwrap();
+
+        let (mut time_strategy, _) =
+            TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+        let best_move = search(
+            &mut game,
+            persistent_state,
+            &mut time_strategy,
+            &SearchRestrictions {
+                depth: Some(8),
+                ..SearchRestrictions::default()
+            },
+            &EngineOptions::default(),
+            &NullReporter,
+        )
+        .best_move;
+
+        (game, best_move)
+    };
+
+    search_fen(&format!("{fen_without_clocks} 0 1"), &mut persistent_state);
+
+    let (game, best_move) = search_fen(
+        &format!("{fen_without_clocks} 99 120"),
+        &mut persistent_state,
+    );
+    let moved_piece = game.board.piece_at(best_move.src()).unwrap();
+
+    assert!(
+        best_move.is_capture() || moved_piece.kind == PieceKind::Pawn,
+        "{best_move:?} does not reset the halfmove clock"
+    );
+}
+
+#[test]
+fn test_captures_to_avoid_fifty_move_draw() {
+    test_avoids_fifty_move_draw("8/8/8/n3k3/8/8/8/R3K3 w - -");
+}
+
+#[test]
+fn test_pushes_pawn_to_avoid_fifty_move_draw() {
+    test_avoids_fifty_move_draw("8/8/8/8/8/2k5/4P3/R3K3 w - -");
+}
+
+// A won position where white can shuffle the rook indefinitely. With the halfmove clock high,
+// white should make progress rather than keep shuffling.
+#[test]
+fn test_makes_progress_as_fifty_move_rule_approaches() {
+    crate::init();
+
+    let mut game = Game::from_fen("8/8/8/3k4/8/8/P7/4K2R w - - 70 100").unwrap();
+    let mut persistent_state = PersistentState::new(16);
+
+    let (mut time_strategy, _) =
+        TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+    let best_move = search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: Some(6),
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &NullReporter,
+    )
+    .best_move;
+
+    let moved_piece = game.board.piece_at(best_move.src()).unwrap();
+    assert_eq!(moved_piece.kind, PieceKind::Pawn, "{best_move:?}");
+}
+
+#[test]
+fn test_search_result_records_each_iteration() {
+    crate::init();
+
+    let mut game = Game::new();
+    let mut persistent_state = PersistentState::new(16);
+
+    let (mut time_strategy, _) =
+        TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+    let result = search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: Some(6),
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &NullReporter,
+    );
+
+    let depths: Vec<u8> = result.iterations.iter().map(|i| i.depth).collect();
+    assert_eq!(depths, vec![1, 2, 3, 4, 5, 6]);
+
+    assert!(result
+        .iterations
+        .windows(2)
+        .all(|w| w[0].nodes < w[1].nodes && w[0].time <= w[1].time));
+
+    assert!(result.branching_factor().unwrap() > 1.0);
+
+    let effective_branching_factor = result.effective_branching_factor().unwrap();
+    assert!(effective_branching_factor > 1.0 && effective_branching_factor < 10.0);
+
+    assert_eq!(result.pv.first(), Some(&result.best_move));
+    assert_eq!(result.stats.nodes, result.iterations.last().unwrap().nodes);
+}
+
+// Fails partway through the search, like a bug in the search would
+struct PanickingReporter;
+
+impl Reporter for PanickingReporter {
+    fn generic_report(&self, _: &str) {}
+
+    fn report_search_progress(&self, _: &Game, progress: SearchInfo) {
+        assert!(progress.depth < 3, "Reporter panicked");
+    }
+
+    fn best_move(&self, _: &Game, _: Move) {}
+}
+
+#[test]
+fn test_search_recovers_from_panic() {
+    crate::init();
+
+    let mut game = Game::new();
+    let mut persistent_state = PersistentState::new(16);
+
+    let (mut time_strategy, _) =
+        TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+    let result = search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: Some(6),
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &PanickingReporter,
+    );
+
+    assert!(game.moves().contains(&result.best_move));
+    assert_eq!(result.iterations.len(), 3);
+    assert_eq!(game.zobrist, Game::new().zobrist);
+}
+
+// Collects the depth and seldepth of every iteration it's told about
+struct CapturingReporter(Mutex<Vec<(u8, u8)>>);
+
+impl Reporter for CapturingReporter {
+    fn generic_report(&self, _: &str) {}
+
+    fn report_search_progress(&self, _: &Game, progress: SearchInfo) {
+        self.0
+            .lock()
+            .unwrap()
+            .push((progress.depth, progress.seldepth));
+    }
+
+    fn best_move(&self, _: &Game, _: Move) {}
+}
+
+#[test]
+fn test_reporter_shared_between_searches() {
+    crate::init();
+
+    let reporter = CapturingReporter(Mutex::new(Vec::new()));
+
+    std::thread::scope(|s| {
+        for _ in 0..2 {
+            s.spawn(|| {
+                let mut game = Game::new();
+                let mut persistent_state = PersistentState::new(16);
+
+                let (mut time_strategy, _) =
+                    TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+                search(
+                    &mut game,
+                    &mut persistent_state,
+                    &mut time_strategy,
+                    &SearchRestrictions {
+                        depth: Some(4),
+                        ..SearchRestrictions::default()
+                    },
+                    &EngineOptions::default(),
+                    &reporter,
+                );
+            });
+        }
+    });
+
+    let mut depths: Vec<u8> = reporter
+        .0
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|(depth, _)| depth)
+        .collect();
+    depths.sort_unstable();
+    assert_eq!(depths, [1, 1, 2, 2, 3, 3, 4, 4]);
+}
+
+// Collects the depth of every iteration and counts the best moves it's told about
+#[derive(Default)]
+struct CountingReporter {
+    depths: Mutex<Vec<u8>>,
+    best_moves: AtomicUsize,
+}
+
+impl Reporter for CountingReporter {
+    fn generic_report(&self, _: &str) {}
+
+    fn report_search_progress(&self, _: &Game, progress: SearchInfo) {
+        self.depths.lock().unwrap().push(progress.depth);
+    }
+
+    fn best_move(&self, _: &Game, _: Move) {
+        self.best_moves.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_aggregating_reporter_forwards_one_search() {
+    crate::init();
+
+    let reporter = AggregatingReporter::new(CountingReporter::default());
+
+    std::thread::scope(|s| {
+        for _ in 0..2 {
+            s.spawn(|| {
+                let mut game = Game::new();
+                let mut persistent_state = PersistentState::new(16);
+
+                let (mut time_strategy, _) =
+                    TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+                let result = search(
+                    &mut game,
+                    &mut persistent_state,
+                    &mut time_strategy,
+                    &SearchRestrictions {
+                        depth: Some(4),
+                        ..SearchRestrictions::default()
+                    },
+                    &EngineOptions::default(),
+                    &reporter,
+                );
+
+                reporter.search_complete(&game, &persistent_state, &result);
+            });
+        }
+    });
+
+    let reporter = reporter.into_inner();
+    assert_eq!(reporter.depths.into_inner().unwrap(), [1, 2, 3, 4]);
+    assert_eq!(reporter.best_moves.into_inner(), 1);
+}
+
+#[test]
+fn test_seldepth_includes_quiescence_plies() {
+    crate::init();
+
+    // White can start a long sequence of captures on d5
+    let mut game =
+        Game::from_fen("3r2k1/p2r1p1p/1p2p1p1/3n4/3Q4/1BN5/PPP2PPP/3R2K1 w - - 0 1").unwrap();
+    let mut persistent_state = PersistentState::new(16);
+    let reporter = CapturingReporter(Mutex::new(Vec::new()));
+
+    let (mut time_strategy, _) =
+        TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+    search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: Some(1),
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &reporter,
+    );
+
+    let iterations = reporter.0.into_inner().unwrap();
+    assert_eq!(iterations.len(), 1);
+
+    let (depth, seldepth) = iterations[0];
+    assert!(seldepth > depth);
+}
+
+#[test]
+fn test_search_stops_at_node_limit() {
+    crate::init();
+
+    let mut game = Game::new();
+    let mut persistent_state = PersistentState::new(16);
+
+    let (mut time_strategy, _) =
+        TimeStrategy::new(&game, &TimeControl::Infinite, &EngineOptions::default());
+
+    let result = search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: Some(30),
+            nodes: Some(5000),
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &NullReporter,
+    );
+
+    assert!(game.moves().contains(&result.best_move));
+    assert!(result.iterations.last().unwrap().depth < 30);
+    assert!(result.stats.nodes <= 5000);
+}
+
+#[test]
+fn test_aborted_search_leaves_game_at_root() {
+    crate::init();
+
+    let mut game = Game::new();
+    for mv in [(E2, E4), (E7, E5), (G1, F3)] {
+        game.make_move(game.moves().expect_matching(mv.0, mv.1, None));
+    }
+
+    let root = game.clone();
+    let mut persistent_state = PersistentState::new(16);
+
+    // Too short to finish anything but the first few iterations
+    let time_control = TimeControl::ExactTime(std::time::Duration::from_millis(20));
+    let (mut time_strategy, _) = TimeStrategy::new(&game, &time_control, &EngineOptions::default());
+
+    search(
+        &mut game,
+        &mut persistent_state,
+        &mut time_strategy,
+        &SearchRestrictions {
+            depth: None,
+            ..SearchRestrictions::default()
+        },
+        &EngineOptions::default(),
+        &NullReporter,
+    );
+
+    assert_eq!(game.zobrist, root.zobrist);
+    assert_eq!(game.history.len(), root.history.len());
+    assert_eq!(game.to_fen(), root.to_fen());
+}
@@ -11,7 +11,7 @@ use crate::engine::search::{PersistentState, SearchContext, SearchRestrictions,
 use crate::engine::transposition_table::{TTOverwriteable, TranspositionTable};
 use paste::paste;
 
-fn test_perft(fen: &str, depth: u8, expected_positions: usize) {
+fn test_perft(fen: &str, depth: u8, expected_positions: u64) {
     crate::init();
     let mut game = Game::from_fen(fen).unwrap();
     let actual_positions = perft(depth, &mut game);
@@ -21,7 +21,7 @@ fn test_perft(fen: &str, depth: u8, expected_positions: usize) {
 
 #[derive(Debug, Clone)]
 struct PerftTranspositionTableData {
-    nodes: usize,
+    nodes: u64,
     depth: u8,
 }
 
@@ -33,9 +33,9 @@ impl TTOverwriteable for PerftTranspositionTableData {
 
 type PerftTranspositionTable = TranspositionTable<PerftTranspositionTableData>;
 
-fn tt_perft(depth: u8, game: &mut Game, tt: &mut PerftTranspositionTable) -> usize {
+fn tt_perft(depth: u8, game: &mut Game, tt: &mut PerftTranspositionTable) -> u64 {
     if depth == 1 {
-        return game.moves().len();
+        return game.moves().len() as u64;
     }
 
     if let Some(tt_data) = tt.get(&game.zobrist) {
@@ -67,7 +67,7 @@ fn tt_perft(depth: u8, game: &mut Game, tt: &mut PerftTranspositionTable) -> usi
     result
 }
 
-fn test_perft_with_tt(fen: &str, depth: u8, expected_positions: usize) {
+fn test_perft_with_tt(fen: &str, depth: u8, expected_positions: u64) {
     crate::init();
 
     let mut tt = PerftTranspositionTable::new(256);
@@ -78,7 +78,7 @@ fn test_perft_with_tt(fen: &str, depth: u8, expected_positions: usize) {
     assert_eq!(expected_positions, actual_positions);
 }
 
-fn movepicker_perft(depth: u8, game: &mut Game, ctx: &mut SearchContext<'_>) -> usize {
+fn movepicker_perft(depth: u8, game: &mut Game, ctx: &mut SearchContext<'_>) -> u64 {
     if depth == 0 {
         return 1;
     }
@@ -148,7 +148,7 @@ fn movepicker_perft(depth: u8, game: &mut Game, ctx: &mut SearchContext<'_>) ->
     moves
 }
 
-fn test_perft_with_movepicker(fen: &str, depth: u8, expected_positions: usize) {
+fn test_perft_with_movepicker(fen: &str, depth: u8, expected_positions: u64) {
     crate::init();
 
     let mut game = Game::from_fen(fen).unwrap();
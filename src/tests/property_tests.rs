@@ -0,0 +1,197 @@
+use crate::chess::bitboard::Bitboard;
+use crate::chess::game::Game;
+use crate::chess::movegen;
+use crate::chess::movegen::MovegenCache;
+use crate::chess::moves::{Move, MoveList};
+use crate::chess::perft::perft;
+use crate::chess::piece::PieceKind;
+use crate::chess::player::Player;
+use crate::chess::square::Square;
+use crate::chess::zobrist;
+use crate::engine::eval::IncrementalEvalFields;
+use proptest::prelude::*;
+
+const START_FENS: [&str; 6] = [
+    "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1",
+    "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1",
+    "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1",
+    "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1",
+    "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8",
+    "r4rk1/1pp1qppp/p1np1n2/2b1p1B1/2B1P1b1/P1NP1N2/1PP1QPPP/R4RK1 w - - 0 10",
+];
+
+// A random game: a starting position, and a list of choices which pick one of the legal moves at
+// each ply (modulo the number of legal moves)
+fn random_game() -> impl Strategy<Value = (usize, Vec<usize>)> {
+    (
+        0..START_FENS.len(),
+        prop::collection::vec(any::<usize>(), 0..60),
+    )
+}
+
+// Plays out a random game, calling `check` on each position along the way (including the start).
+// Stops early if the game reaches a position with no legal moves.
+fn play_random_game(
+    fen_idx: usize,
+    choices: &[usize],
+    mut check: impl FnMut(&mut Game) -> Result<(), TestCaseError>,
+) -> Result<Game, TestCaseError> {
+    crate::init();
+
+    let mut game = Game::from_fen(START_FENS[fen_idx]).unwrap();
+    check(&mut game)?;
+
+    for choice in choices {
+        let moves = game.moves();
+        if moves.is_empty() {
+            break;
+        }
+
+        game.make_move(moves[choice % moves.len()]);
+        check(&mut game)?;
+    }
+
+    Ok(game)
+}
+
+fn sorted_moves(moves: impl IntoIterator<Item = Move>) -> Vec<String> {
+    let mut moves: Vec<String> = moves.into_iter().map(|mv| format!("{mv:?}")).collect();
+    moves.sort();
+    moves
+}
+
+// Legal moves found without the legal move generator: pseudo-legal moves which don't leave our
+// king attacked once they've been played
+fn oracle_legal_moves(game: &mut Game) -> Vec<Move> {
+    let mut pseudo_legal_moves = MoveList::new();
+    movegen::generate_pseudo_legal_moves(game, &mut pseudo_legal_moves);
+
+    let player = game.player;
+
+    pseudo_legal_moves
+        .into_iter()
+        .filter(|&mv| {
+            game.make_move(mv);
+            let king = game.board.king(player).single();
+            let is_legal = movegen::generate_attackers_of(&game.board, player, king).is_empty();
+            game.undo_move();
+            is_legal
+        })
+        .collect()
+}
+
+fn oracle_perft(depth: u8, game: &mut Game) -> u64 {
+    let moves = oracle_legal_moves(game);
+
+    if depth == 1 {
+        return moves.len() as u64;
+    }
+
+    moves
+        .into_iter()
+        .map(|mv| {
+            game.make_move(mv);
+            let result = oracle_perft(depth - 1, game);
+            game.undo_move();
+            result
+        })
+        .sum()
+}
+
+fn check_board_consistency(game: &Game) -> Result<(), TestCaseError> {
+    let board = &game.board;
+
+    let white = board.occupancy_for(Player::White);
+    let black = board.occupancy_for(Player::Black);
+    prop_assert!((white & black).is_empty());
+
+    let mut all_kinds = Bitboard::EMPTY;
+    for kind in PieceKind::ALL {
+        let pieces =
+            board.pieces_of_kind(kind, Player::White) | board.pieces_of_kind(kind, Player::Black);
+        prop_assert!(
+            (all_kinds & pieces).is_empty(),
+            "{kind:?} overlaps another piece kind"
+        );
+        all_kinds |= pieces;
+    }
+    prop_assert_eq!(all_kinds, board.occupancy());
+
+    for square in Square::all() {
+        match board.piece_at(square) {
+            Some(piece) => {
+                prop_assert!(board
+                    .pieces_of_kind(piece.kind, piece.player)
+                    .contains(square));
+            }
+            None => prop_assert!(!board.occupancy().contains(square)),
+        }
+    }
+
+    prop_assert_eq!(board.king(Player::White).count(), 1);
+    prop_assert_eq!(board.king(Player::Black).count(), 1);
+
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn make_move_keeps_incremental_state_consistent((fen_idx, choices) in random_game()) {
+        play_random_game(fen_idx, &choices, |game| {
+            prop_assert_eq!(&game.zobrist, &zobrist::hash(game));
+
+            let incremental_eval = IncrementalEvalFields::init(&game.board);
+            prop_assert_eq!(game.incremental_eval.phase_value, incremental_eval.phase_value);
+            prop_assert!(game.incremental_eval.piece_square_tables == incremental_eval.piece_square_tables);
+
+            check_board_consistency(game)
+        })?;
+    }
+
+    #[test]
+    fn undo_move_restores_the_start_position((fen_idx, choices) in random_game()) {
+        let mut game = play_random_game(fen_idx, &choices, |_| Ok(()))?;
+
+        while !game.history.is_empty() {
+            game.undo_move();
+            check_board_consistency(&game)?;
+        }
+
+        let start = Game::from_fen(START_FENS[fen_idx]).unwrap();
+        prop_assert_eq!(game.to_fen(), start.to_fen());
+        prop_assert_eq!(game.zobrist, start.zobrist);
+        prop_assert!(game.repetition_keys.is_empty());
+    }
+
+    #[test]
+    fn captures_and_quiets_are_the_legal_moves((fen_idx, choices) in random_game()) {
+        play_random_game(fen_idx, &choices, |game| {
+            let mut movegen_cache = MovegenCache::new();
+
+            let mut captures = MoveList::new();
+            movegen::generate_captures(game, &mut captures, &mut movegen_cache);
+
+            let mut quiets = MoveList::new();
+            movegen::generate_quiets(game, &mut quiets, &movegen_cache);
+
+            let all_moves = sorted_moves(captures.iter().chain(quiets.iter()).copied());
+
+            // No move should be generated twice, whether by the same generator or by both
+            let mut deduplicated_moves = all_moves.clone();
+            deduplicated_moves.dedup();
+            prop_assert_eq!(&deduplicated_moves, &all_moves);
+
+            prop_assert_eq!(&all_moves, &sorted_moves(game.moves()));
+            prop_assert_eq!(&all_moves, &sorted_moves(oracle_legal_moves(game)));
+
+            Ok(())
+        })?;
+    }
+
+    #[test]
+    fn perft_matches_the_oracle((fen_idx, choices) in random_game()) {
+        let mut game = play_random_game(fen_idx, &choices, |_| Ok(()))?;
+
+        prop_assert_eq!(perft(2, &mut game), oracle_perft(2, &mut game));
+    }
+}
@@ -0,0 +1,30 @@
+use std::path::{Path, PathBuf};
+
+// A path in the temp directory for a test to write to, which is deleted when the test finishes,
+// even if it fails. The process ID is part of the name so that parallel test runs don't clash.
+pub struct TempFile {
+    path: PathBuf,
+}
+
+impl TempFile {
+    pub fn new(name: &str) -> Self {
+        Self {
+            path: std::env::temp_dir().join(format!("tcheran-{name}-{}", std::process::id())),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn path_str(&self) -> &str {
+        self.path.to_str().unwrap()
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        // The test may not have created the file
+        std::fs::remove_file(&self.path).ok();
+    }
+}
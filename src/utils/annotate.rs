@@ -0,0 +1,259 @@
+use std::path::Path;
+
+use crate::chess::game::Game;
+use crate::chess::moves::Move;
+use crate::chess::pgn::{self, Annotation};
+use crate::chess::player::Player;
+use crate::chess::san;
+use crate::engine::options::EngineOptions;
+use crate::engine::search::principal_variation::PrincipalVariation;
+use crate::engine::search::time_control::TimeStrategy;
+use crate::engine::search::{
+    self, NullReporter, PersistentState, SearchRestrictions, SearchScore, TimeControl,
+};
+
+pub const HASH_SIZE_MB: usize = 64;
+
+// Evals are capped when working out how much a move lost, so that a move which only lowers an
+// already winning (or losing) score isn't flagged
+const MAX_EVAL: i32 = 1000;
+
+// How much of the eval a move has to give away, in centipawns, to be annotated
+const INACCURACY_THRESHOLD: i32 = 50;
+const MISTAKE_THRESHOLD: i32 = 100;
+const BLUNDER_THRESHOLD: i32 = 300;
+
+const INACCURACY_NAG: u8 = 6;
+const MISTAKE_NAG: u8 = 2;
+const BLUNDER_NAG: u8 = 4;
+
+pub struct PositionResult {
+    pub best_move: Move,
+    pub score: SearchScore,
+    pub pv: PrincipalVariation,
+}
+
+// Searches every position in each game in the file, returning the games as PGN with the eval
+// after each move and with inaccuracies, mistakes and blunders marked
+pub fn annotate(file: &Path, depth: u8) -> Result<String, String> {
+    let contents = std::fs::read_to_string(file)
+        .map_err(|e| format!("Unable to read {}: {e}", file.display()))?;
+
+    let games = pgn::parse(&contents)?;
+    let mut output = Vec::new();
+
+    for (i, mut game) in games.into_iter().enumerate() {
+        eprintln!("Annotating game {}", i + 1);
+
+        let annotations = annotate_game(&game.start, &game.moves, depth);
+        game.set_tag(
+            "Annotator",
+            &format!("{} {}", crate::ENGINE_NAME, crate::engine_version()),
+        );
+
+        output.push(pgn::write(&game, &annotations));
+    }
+
+    Ok(output.join("\n"))
+}
+
+fn annotate_game(start: &Game, moves: &[Move], depth: u8) -> Vec<Annotation> {
+    let results = search_game(start, moves, depth);
+
+    let mut game = start.clone();
+    let mut annotations = Vec::new();
+
+    for (i, &mv) in moves.iter().enumerate() {
+        let mut annotation = Annotation::default();
+        let mut comment = Vec::new();
+
+        if let (Some(before), Some(after)) = (&results[i], &results[i + 1]) {
+            if mv != before.best_move {
+                let loss = centipawns(before.score) + centipawns(after.score);
+
+                if let Some((nag, description)) = classify(loss) {
+                    annotation.nag = Some(nag);
+                    comment.push(format!(
+                        "{description}. {} was best.",
+                        san::format_move(&game, before.best_move)
+                    ));
+                }
+            }
+        }
+
+        game.make_move(mv);
+
+        if let Some(after) = &results[i + 1] {
+            comment.push(format!("[%eval {}]", format_eval(after.score, game.player)));
+        }
+
+        if !comment.is_empty() {
+            annotation.comment = Some(comment.join(" "));
+        }
+
+        annotations.push(annotation);
+    }
+
+    annotations
+}
+
+pub fn analysis_options() -> EngineOptions {
+    EngineOptions {
+        analyse_mode: true,
+        ..EngineOptions::default()
+    }
+}
+
+// Searches the start position and the position after each move, sharing a TT between them
+pub fn search_game(start: &Game, moves: &[Move], depth: u8) -> Vec<Option<PositionResult>> {
+    let mut persistent_state = PersistentState::new(HASH_SIZE_MB);
+    let options = analysis_options();
+    let search_restrictions = SearchRestrictions {
+        depth: Some(depth),
+        ..SearchRestrictions::default()
+    };
+
+    let mut game = start.clone();
+    let mut results = vec![search_position(
+        &mut game,
+        &mut persistent_state,
+        &options,
+        &search_restrictions,
+    )];
+
+    for &mv in moves {
+        game.make_move(mv);
+        results.push(search_position(
+            &mut game,
+            &mut persistent_state,
+            &options,
+            &search_restrictions,
+        ));
+    }
+
+    results
+}
+
+// Positions where the game is already over don't need searching
+pub fn search_position(
+    game: &mut Game,
+    persistent_state: &mut PersistentState,
+    options: &EngineOptions,
+    search_restrictions: &SearchRestrictions,
+) -> Option<PositionResult> {
+    let has_moves = game
+        .moves()
+        .iter()
+        .any(|mv| !search_restrictions.excluded_moves.contains(mv));
+
+    if !has_moves {
+        return None;
+    }
+
+    let (mut time_strategy, _) = TimeStrategy::new(game, &TimeControl::Infinite, options);
+
+    let result = search::search(
+        game,
+        persistent_state,
+        &mut time_strategy,
+        search_restrictions,
+        options,
+        &NullReporter,
+    );
+
+    Some(PositionResult {
+        best_move: result.best_move,
+        score: result.score,
+        pv: result.pv,
+    })
+}
+
+// The score for the side to move, capped at `MAX_EVAL`. Any forced mate counts as the maximum.
+pub fn centipawns(score: SearchScore) -> i32 {
+    match score {
+        SearchScore::Centipawns(cp) => i32::from(cp).clamp(-MAX_EVAL, MAX_EVAL),
+        SearchScore::Mate(moves) if moves > 0 => MAX_EVAL,
+        SearchScore::Mate(_) => -MAX_EVAL,
+    }
+}
+
+fn classify(loss: i32) -> Option<(u8, &'static str)> {
+    if loss >= BLUNDER_THRESHOLD {
+        Some((BLUNDER_NAG, "Blunder"))
+    } else if loss >= MISTAKE_THRESHOLD {
+        Some((MISTAKE_NAG, "Mistake"))
+    } else if loss >= INACCURACY_THRESHOLD {
+        Some((INACCURACY_NAG, "Inaccuracy"))
+    } else {
+        None
+    }
+}
+
+// Evals in '%eval' comments are always from White's point of view, in pawns or as '#<moves>'
+fn format_eval(score: SearchScore, player: Player) -> String {
+    let sign = if player == Player::White { 1 } else { -1 };
+
+    match score {
+        SearchScore::Centipawns(cp) => format!("{:.2}", f64::from(i32::from(cp) * sign) / 100.0),
+        SearchScore::Mate(moves) => format!("#{}", i32::from(moves) * sign),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_thresholds() {
+        assert_eq!(classify(49), None);
+        assert_eq!(classify(50), Some((INACCURACY_NAG, "Inaccuracy")));
+        assert_eq!(classify(99), Some((INACCURACY_NAG, "Inaccuracy")));
+        assert_eq!(classify(100), Some((MISTAKE_NAG, "Mistake")));
+        assert_eq!(classify(299), Some((MISTAKE_NAG, "Mistake")));
+        assert_eq!(classify(300), Some((BLUNDER_NAG, "Blunder")));
+        assert_eq!(classify(-300), None);
+    }
+
+    #[test]
+    fn test_centipawns() {
+        assert_eq!(centipawns(SearchScore::Centipawns(35)), 35);
+        assert_eq!(centipawns(SearchScore::Centipawns(-2500)), -MAX_EVAL);
+        assert_eq!(centipawns(SearchScore::Mate(3)), MAX_EVAL);
+        assert_eq!(centipawns(SearchScore::Mate(-2)), -MAX_EVAL);
+    }
+
+    #[test]
+    fn test_format_eval() {
+        assert_eq!(
+            format_eval(SearchScore::Centipawns(35), Player::White),
+            "0.35"
+        );
+        assert_eq!(
+            format_eval(SearchScore::Centipawns(35), Player::Black),
+            "-0.35"
+        );
+        assert_eq!(
+            format_eval(SearchScore::Centipawns(-150), Player::Black),
+            "1.50"
+        );
+        assert_eq!(format_eval(SearchScore::Mate(2), Player::White), "#2");
+        assert_eq!(format_eval(SearchScore::Mate(2), Player::Black), "#-2");
+    }
+
+    // Giving the queen away in an equal position is a blunder, and the comment should give the
+    // move that was best instead
+    #[test]
+    fn test_annotate_game_marks_blunders() {
+        crate::init();
+
+        let start = Game::from_fen("3qk3/8/8/8/8/8/3Q4/4K3 w - - 0 1").unwrap();
+        let blunder = san::parse_move(&start, "Qd7+").unwrap();
+
+        let annotations = annotate_game(&start, &[blunder], 4);
+
+        assert_eq!(annotations[0].nag, Some(BLUNDER_NAG));
+        let comment = annotations[0].comment.as_ref().unwrap();
+        assert!(comment.starts_with("Blunder. Qxd8+ was best."), "{comment}");
+        assert!(comment.contains("[%eval -"), "{comment}");
+    }
+}
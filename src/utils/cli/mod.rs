@@ -1,6 +1,6 @@
 use crate::engine::uci;
 use crate::engine::uci::UciInputMode;
-use clap::{Parser, Subcommand};
+use clap::{Arg, CommandFactory, FromArgMatches, Parser, Subcommand};
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
 
@@ -8,6 +8,10 @@ use std::process::ExitCode;
 struct Cli {
     #[command(subcommand)]
     command: Option<Command>,
+
+    // Check that this build gives the expected results, then exit
+    #[arg(long)]
+    selftest: bool,
 }
 
 #[derive(Subcommand)]
@@ -20,10 +24,27 @@ enum Command {
         #[clap(default_value_t = 5000)]
         epochs: usize,
     },
+
+    Annotate {
+        file: PathBuf,
+
+        #[clap(default_value_t = 10)]
+        depth: u8,
+    },
+
+    Puzzles {
+        file: PathBuf,
+
+        #[clap(default_value_t = 10)]
+        depth: u8,
+    },
+
+    // Print the cuckoo tables for src/chess/cuckoo/tables.rs
+    CuckooTables,
 }
 
-pub fn uci_command() -> ExitCode {
-    let result = uci::uci(UciInputMode::Stdin);
+pub fn uci_command(option_flags: &[(String, String)]) -> ExitCode {
+    let result = uci::uci(UciInputMode::Stdin, option_flags);
 
     match result {
         Ok(()) => ExitCode::SUCCESS,
@@ -34,6 +55,14 @@ pub fn uci_command() -> ExitCode {
     }
 }
 
+pub fn selftest_command() -> ExitCode {
+    if crate::engine::selftest::selftest() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
 #[cfg(feature = "tuner")]
 pub fn tune_command(file: &Path, epochs: usize) -> ExitCode {
     crate::utils::tuner::tune(file, epochs);
@@ -46,14 +75,69 @@ pub fn tune_command(_file: &Path, _epochs: usize) -> ExitCode {
     ExitCode::FAILURE
 }
 
+pub fn annotate_command(file: &Path, depth: u8) -> ExitCode {
+    match crate::utils::annotate::annotate(file, depth) {
+        Ok(pgn) => {
+            print!("{pgn}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn puzzles_command(file: &Path, depth: u8) -> ExitCode {
+    match crate::utils::puzzles::find_puzzles(file, depth) {
+        Ok(puzzles) => {
+            for puzzle in puzzles {
+                println!("{puzzle}");
+            }
+
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+pub fn cuckoo_tables_command() -> ExitCode {
+    print!("{}", crate::chess::cuckoo::write_tables());
+    ExitCode::SUCCESS
+}
+
 pub fn run() -> ExitCode {
-    let cli = Cli::parse();
+    // Every UCI option can also be set with a flag, e.g. '--hash 512'
+    let command = Cli::command().args(
+        uci::option_flags().map(|flag| Arg::new(flag).long(flag).value_name("VALUE").global(true)),
+    );
+
+    let matches = command.get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    let option_flags = uci::option_flags()
+        .filter_map(|flag| {
+            matches
+                .get_one::<String>(flag)
+                .map(|value| (flag.to_string(), value.clone()))
+        })
+        .collect::<Vec<_>>();
+
+    if cli.selftest {
+        return selftest_command();
+    }
 
     match cli.command {
         Some(c) => match c {
-            Command::Uci => uci_command(),
+            Command::Uci => uci_command(&option_flags),
             Command::Tune { file, epochs } => tune_command(&file, epochs),
+            Command::Annotate { file, depth } => annotate_command(&file, depth),
+            Command::Puzzles { file, depth } => puzzles_command(&file, depth),
+            Command::CuckooTables => cuckoo_tables_command(),
         },
-        _ => uci_command(),
+        _ => uci_command(&option_flags),
     }
 }
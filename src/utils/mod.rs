@@ -1,4 +1,6 @@
+pub mod annotate;
 pub mod cli;
+pub mod puzzles;
 
 #[cfg(feature = "tuner")]
 pub mod tuner;
@@ -0,0 +1,197 @@
+use std::path::Path;
+
+use crate::chess::fen::{self, FenFormat};
+use crate::chess::game::Game;
+use crate::chess::pgn;
+use crate::chess::san;
+use crate::engine::search::{PersistentState, SearchRestrictions, SearchScore};
+use crate::utils::annotate::{
+    analysis_options, centipawns, search_game, search_position, PositionResult, HASH_SIZE_MB,
+};
+
+// A position is a candidate if the move that led to it gave away at least this much...
+const MIN_SWING: i32 = 200;
+
+// ...and left the side to move at least this far ahead
+const MIN_ADVANTAGE: i32 = 200;
+
+// Candidates are checked with a deeper search, and are only puzzles if the best move still wins
+// and no other move does
+const VERIFICATION_EXTRA_DEPTH: u8 = 2;
+
+const MAX_SOLUTION_LENGTH: usize = 6;
+
+// Finds tactical moments in each game in the file: positions where the previous move was a
+// mistake and the side to move has exactly one way to take advantage of it. Each puzzle is
+// returned as a line of EPD.
+pub fn find_puzzles(file: &Path, depth: u8) -> Result<Vec<String>, String> {
+    let contents = std::fs::read_to_string(file)
+        .map_err(|e| format!("Unable to read {}: {e}", file.display()))?;
+
+    let games = pgn::parse(&contents)?;
+    let mut puzzles = Vec::new();
+
+    for (i, game) in games.iter().enumerate() {
+        eprintln!("Searching game {} for puzzles", i + 1);
+
+        let results = search_game(&game.start, &game.moves, depth);
+        let mut position = game.start.clone();
+
+        for (ply, &mv) in game.moves.iter().enumerate() {
+            position.make_move(mv);
+
+            let (Some(before), Some(after)) = (&results[ply], &results[ply + 1]) else {
+                continue;
+            };
+
+            let swing = centipawns(before.score) + centipawns(after.score);
+
+            if swing < MIN_SWING || centipawns(after.score) < MIN_ADVANTAGE {
+                continue;
+            }
+
+            if let Some(solution) = verify(&position, depth + VERIFICATION_EXTRA_DEPTH) {
+                puzzles.push(format_puzzle(
+                    &position,
+                    &solution,
+                    &format!("{} game {} move {}", file.display(), i + 1, position.turn()),
+                ));
+            }
+        }
+    }
+
+    Ok(puzzles)
+}
+
+fn verify(position: &Game, depth: u8) -> Option<PositionResult> {
+    if position.moves().len() < 2 {
+        return None;
+    }
+
+    let mut game = position.clone();
+    let mut persistent_state = PersistentState::new(HASH_SIZE_MB);
+    let options = analysis_options();
+
+    let mut search_restrictions = SearchRestrictions {
+        depth: Some(depth),
+        ..SearchRestrictions::default()
+    };
+
+    let best = search_position(
+        &mut game,
+        &mut persistent_state,
+        &options,
+        &search_restrictions,
+    )?;
+
+    if centipawns(best.score) < MIN_ADVANTAGE {
+        return None;
+    }
+
+    search_restrictions.excluded_moves.push(best.best_move);
+    let second_best = search_position(
+        &mut game,
+        &mut persistent_state,
+        &options,
+        &search_restrictions,
+    )?;
+
+    if centipawns(second_best.score) >= MIN_ADVANTAGE {
+        return None;
+    }
+
+    Some(best)
+}
+
+// e.g. '<EPD> bm Nxe5; ce 350; pv Nxe5 Nxe5 Qh5+; id "games.pgn game 1 move 12";'
+fn format_puzzle(position: &Game, solution: &PositionResult, id: &str) -> String {
+    let mut game = position.clone();
+    let mut pv = Vec::new();
+
+    for mv in solution.pv.clone().into_iter().take(MAX_SOLUTION_LENGTH) {
+        pv.push(san::format_move(&game, mv));
+        game.make_move(mv);
+    }
+
+    let score = match solution.score {
+        SearchScore::Centipawns(cp) => format!("ce {cp}"),
+        SearchScore::Mate(moves) => format!("dm {moves}"),
+    };
+
+    format!(
+        "{} bm {}; {score}; pv {}; id \"{id}\";",
+        fen::write(position, FenFormat::Epd),
+        san::format_move(position, solution.best_move),
+        pv.join(" ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::search::principal_variation::PrincipalVariation;
+
+    const VERIFY_DEPTH: u8 = 4;
+
+    #[test]
+    fn test_verify_accepts_a_unique_winning_move() {
+        crate::init();
+
+        let position = Game::from_fen("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap();
+        let solution = verify(&position, VERIFY_DEPTH).unwrap();
+
+        assert_eq!(san::format_move(&position, solution.best_move), "Rxd5");
+    }
+
+    #[test]
+    fn test_verify_rejects_two_winning_moves() {
+        crate::init();
+
+        let position = Game::from_fen("4k3/8/8/3q4/8/8/6B1/3RK3 w - - 0 1").unwrap();
+
+        assert!(verify(&position, VERIFY_DEPTH).is_none());
+    }
+
+    fn solution(position: &Game, moves: &[&str], score: SearchScore) -> PositionResult {
+        let mut game = position.clone();
+        let mut pv = PrincipalVariation::new();
+
+        for mv in moves {
+            let mv = san::parse_move(&game, mv).unwrap();
+            pv.append(mv);
+            game.make_move(mv);
+        }
+
+        PositionResult {
+            best_move: *pv.first().unwrap(),
+            score,
+            pv,
+        }
+    }
+
+    #[test]
+    fn test_format_puzzle() {
+        crate::init();
+
+        let position = Game::from_fen("4k3/8/8/3q4/8/8/8/3RK3 w - - 0 1").unwrap();
+        let solution = solution(&position, &["Rxd5", "Ke7"], SearchScore::Centipawns(480));
+
+        assert_eq!(
+            format_puzzle(&position, &solution, "games.pgn game 1 move 1"),
+            "4k3/8/8/3q4/8/8/8/3RK3 w - - bm Rxd5; ce 480; pv Rxd5 Ke7; id \"games.pgn game 1 move 1\";"
+        );
+    }
+
+    #[test]
+    fn test_format_puzzle_with_mate() {
+        crate::init();
+
+        let position = Game::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+        let solution = solution(&position, &["Ra8#"], SearchScore::Mate(1));
+
+        assert_eq!(
+            format_puzzle(&position, &solution, "mate"),
+            "6k1/5ppp/8/8/8/8/8/R5K1 w - - bm Ra8#; dm 1; pv Ra8#; id \"mate\";"
+        );
+    }
+}
@@ -40,7 +40,7 @@ pub fn print_pst(
         write!(f, "    [")?;
 
         for file in File::ALL {
-            let idx = Square::from_file_and_rank(file, *rank).array_idx();
+            let idx = Square::new(file, *rank).array_idx();
             print_param(f, pst[idx])?;
 
             if file != File::H {